@@ -0,0 +1,158 @@
+//! Assists module
+//!
+//! Accessibility aids for aiming: aim magnetism (cone bending) and sticky targeting.
+
+pub mod systems;
+
+pub use systems::*;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::player::player_aim;
+use crate::schedule::GameSet;
+use crate::states::GameState;
+use crate::weapons::fire_weapon_system;
+
+/// Plugin for aim assist functionality
+pub struct AssistsPlugin;
+
+impl Plugin for AssistsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AimAssistSettings>()
+            .init_resource::<RunAssists>()
+            .add_systems(Startup, load_aim_assist_settings)
+            .add_systems(Update, save_aim_assist_settings)
+            .add_systems(OnEnter(GameState::Playing), reset_run_assists)
+            .add_systems(OnExit(GameState::Playing), clear_ranked_run)
+            .add_systems(
+                Update,
+                (update_assisted_aim, track_assist_usage)
+                    .after(player_aim)
+                    .before(fire_weapon_system)
+                    .in_set(GameSet::Input)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Aim assist settings (persisted, off by default)
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AimAssistSettings {
+    /// Aim magnetism strength in percent (0-100)
+    pub strength_percent: u8,
+    /// Slow the effective aim while the aim ray is over a creature
+    pub sticky_target: bool,
+}
+
+impl AimAssistSettings {
+    /// Magnetism strength as a 0.0-1.0 fraction
+    pub fn strength(&self) -> f32 {
+        self.strength_percent.min(100) as f32 / 100.0
+    }
+
+    pub fn adjust_strength(&mut self, delta: i16) {
+        self.strength_percent = (self.strength_percent as i16 + delta).clamp(0, 100) as u8;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.strength_percent > 0 || self.sticky_target
+    }
+}
+
+/// Per-run assist bookkeeping
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RunAssists {
+    /// Run is flagged for leaderboards / daily challenge; assists are disabled
+    pub ranked: bool,
+    /// Whether any assist was active at some point during the run
+    pub used: bool,
+}
+
+impl RunAssists {
+    /// Magnetism strength that actually applies to this run
+    pub fn effective_strength(&self, settings: &AimAssistSettings) -> f32 {
+        if self.ranked {
+            0.0
+        } else {
+            settings.strength()
+        }
+    }
+
+    /// Whether sticky targeting actually applies to this run
+    pub fn sticky_enabled(&self, settings: &AimAssistSettings) -> bool {
+        !self.ranked && settings.sticky_target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aim_assist_default_off() {
+        let settings = AimAssistSettings::default();
+        assert_eq!(settings.strength_percent, 0);
+        assert!(!settings.sticky_target);
+        assert!(!settings.is_active());
+    }
+
+    #[test]
+    fn aim_assist_strength_clamps() {
+        let mut settings = AimAssistSettings::default();
+        settings.adjust_strength(-10);
+        assert_eq!(settings.strength_percent, 0);
+        settings.adjust_strength(250);
+        assert_eq!(settings.strength_percent, 100);
+        assert!((settings.strength() - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn ranked_runs_disable_assists() {
+        let settings = AimAssistSettings {
+            strength_percent: 100,
+            sticky_target: true,
+        };
+        let run = RunAssists {
+            ranked: true,
+            used: false,
+        };
+        assert_eq!(run.effective_strength(&settings), 0.0);
+        assert!(!run.sticky_enabled(&settings));
+    }
+
+    #[test]
+    fn rush_runs_are_ranked_until_they_end() {
+        use crate::states::GameMode;
+        use bevy::state::app::StatesPlugin;
+
+        for (mode, ranked) in [(GameMode::Rush, true), (GameMode::Survival, false), (GameMode::Quest, false)] {
+            let mut app = App::new();
+            app.add_plugins(StatesPlugin)
+                .insert_state(GameState::MainMenu)
+                .insert_resource(mode)
+                .init_resource::<RunAssists>()
+                .add_systems(OnEnter(GameState::Playing), reset_run_assists)
+                .add_systems(OnExit(GameState::Playing), clear_ranked_run);
+            app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Playing);
+            app.update();
+            assert_eq!(app.world().resource::<RunAssists>().ranked, ranked, "{:?}", mode);
+
+            app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::GameOver);
+            app.update();
+            assert!(!app.world().resource::<RunAssists>().ranked);
+        }
+    }
+
+    #[test]
+    fn aim_assist_settings_roundtrip_ron() {
+        let settings = AimAssistSettings {
+            strength_percent: 40,
+            sticky_target: true,
+        };
+        let text = ron::to_string(&settings).unwrap();
+        let parsed: AimAssistSettings = ron::from_str(&text).unwrap();
+        assert_eq!(parsed, settings);
+    }
+}
@@ -0,0 +1,319 @@
+//! Aim assist systems
+
+use bevy::prelude::*;
+
+use super::{AimAssistSettings, RunAssists};
+use crate::creatures::{Creature, MarkedForDespawn};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persist;
+use crate::player::components::{AimDirection, Player};
+use crate::states::GameMode;
+
+/// Half-angle of the magnetism cone at 100% strength (degrees)
+pub const MAX_ASSIST_CONE_DEGREES: f32 = 8.0;
+/// Creatures further than this are never considered by aim assist
+pub const ASSIST_RANGE: f32 = 700.0;
+
+/// Radius used when testing the aim ray against creatures
+const STICKY_RADIUS: f32 = 20.0;
+/// How quickly the effective aim catches up while sticky (per second)
+const STICKY_FOLLOW_RATE: f32 = 12.0;
+/// Maximum lag of the effective aim behind the raw aim (degrees)
+const MAX_STICKY_LAG_DEGREES: f32 = 8.0;
+
+#[cfg(not(target_arch = "wasm32"))]
+const SETTINGS_FILE: &str = "aim_assist.ron";
+
+/// Effective aim angle after sticky targeting (projectiles only, not the crosshair)
+#[derive(Component, Debug, Clone, Default)]
+pub struct AssistedAim {
+    pub angle: f32,
+}
+
+impl AssistedAim {
+    pub fn direction(&self) -> Vec2 {
+        Vec2::from_angle(self.angle)
+    }
+}
+
+/// Bends `aim` toward the nearest target inside the assist cone.
+///
+/// The cone half-angle scales linearly from 0 at 0% strength to
+/// [`MAX_ASSIST_CONE_DEGREES`] at 100%. Returns `aim` unchanged if nothing qualifies.
+pub fn assisted_direction(
+    origin: Vec2,
+    aim: Vec2,
+    targets: impl IntoIterator<Item = Vec2>,
+    strength: f32,
+) -> Vec2 {
+    let cone = MAX_ASSIST_CONE_DEGREES.to_radians() * strength.clamp(0.0, 1.0);
+    if cone <= 0.0 || aim == Vec2::ZERO {
+        return aim;
+    }
+
+    let mut best: Option<(f32, Vec2)> = None;
+    for target in targets {
+        let to_target = target - origin;
+        let distance = to_target.length();
+        if !(0.001..=ASSIST_RANGE).contains(&distance) {
+            continue;
+        }
+        if aim.angle_between(to_target).abs() > cone {
+            continue;
+        }
+        if best.is_none_or(|(best_distance, _)| distance < best_distance) {
+            best = Some((distance, to_target / distance));
+        }
+    }
+
+    best.map_or(aim, |(_, direction)| direction)
+}
+
+/// Returns true if a ray from `origin` along `direction` passes within `radius` of `center`
+pub fn aim_ray_hits(origin: Vec2, direction: Vec2, center: Vec2, radius: f32, range: f32) -> bool {
+    let direction = direction.normalize_or_zero();
+    let to_center = center - origin;
+    let along = to_center.dot(direction);
+    if along < 0.0 || along > range {
+        return false;
+    }
+    let closest = origin + direction * along;
+    closest.distance_squared(center) <= radius * radius
+}
+
+/// Moves the effective aim toward the raw aim, slowing down while the aim ray is on a creature
+#[allow(clippy::type_complexity)]
+pub fn update_assisted_aim(
+    time: Res<Time>,
+    settings: Res<AimAssistSettings>,
+    run: Res<RunAssists>,
+    creature_query: Query<&Transform, (With<Creature>, Without<MarkedForDespawn>, Without<Player>)>,
+    mut player_query: Query<(&Transform, &AimDirection, &mut AssistedAim), With<Player>>,
+) {
+    let sticky = run.sticky_enabled(&settings);
+
+    for (transform, aim, mut assisted) in player_query.iter_mut() {
+        let origin = transform.translation.truncate();
+        let on_target = sticky
+            && creature_query.iter().any(|creature| {
+                aim_ray_hits(
+                    origin,
+                    aim.direction,
+                    creature.translation.truncate(),
+                    STICKY_RADIUS,
+                    ASSIST_RANGE,
+                )
+            });
+
+        if !on_target {
+            assisted.angle = aim.angle;
+            continue;
+        }
+
+        let delta = Vec2::from_angle(assisted.angle).angle_between(aim.direction);
+        let follow = 1.0 - (-STICKY_FOLLOW_RATE * time.delta_seconds()).exp();
+        let max_lag = MAX_STICKY_LAG_DEGREES.to_radians();
+        let remaining = (delta * (1.0 - follow)).clamp(-max_lag, max_lag);
+        assisted.angle = aim.angle - remaining;
+    }
+}
+
+/// Records whether assists were active at any point during the run
+pub fn track_assist_usage(settings: Res<AimAssistSettings>, mut run: ResMut<RunAssists>) {
+    if !run.used && !run.ranked && settings.is_active() {
+        run.used = true;
+    }
+}
+
+/// Resets the per-run usage flag when a run starts. Rush runs are ranked: their scores
+/// are compared against saved bests and ghosts, so they run without assists
+pub fn reset_run_assists(mode: Res<GameMode>, mut run: ResMut<RunAssists>) {
+    run.used = false;
+    run.ranked = *mode == GameMode::Rush;
+}
+
+/// Clears the ranked flag when a run ends
+pub fn clear_ranked_run(mut run: ResMut<RunAssists>) {
+    run.ranked = false;
+}
+
+/// Loads persisted aim assist settings on startup
+pub fn load_aim_assist_settings(mut settings: ResMut<AimAssistSettings>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(loaded) = persist::load::<AimAssistSettings>(SETTINGS_FILE).into_value() {
+            *settings = loaded;
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &mut settings;
+}
+
+/// Persists aim assist settings whenever they change
+pub fn save_aim_assist_settings(settings: Res<AimAssistSettings>) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(err) = persist::save(SETTINGS_FILE, &*settings) {
+        warn!("Failed to save {}: {}", SETTINGS_FILE, err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bonuses::ActiveBonusEffects;
+    use crate::creatures::{rebuild_creature_grid, CreatureGrid, CreatureHealth, CreatureSpeed, CreatureType};
+    use crate::perks::PerkBonuses;
+    use crate::player::components::Firing;
+    use crate::weapons::{
+        fire_hitscan_weapons, fire_weapon_system, projectile_collision, spawn_projectiles_from_fire_events,
+        projectile_movement, EquippedWeapon, FireWeaponEvent, ProjectileHitEvent, WeaponId, WeaponRegistry,
+    };
+
+    fn target_at_degrees(degrees: f32, distance: f32) -> Vec2 {
+        Vec2::from_angle(degrees.to_radians()) * distance
+    }
+
+    #[test]
+    fn zero_strength_leaves_aim_unchanged() {
+        let aim = Vec2::X;
+        let result = assisted_direction(Vec2::ZERO, aim, [target_at_degrees(0.5, 200.0)], 0.0);
+        assert_eq!(result, aim);
+    }
+
+    #[test]
+    fn full_strength_bends_inside_cone() {
+        let target = target_at_degrees(7.5, 200.0);
+        let result = assisted_direction(Vec2::ZERO, Vec2::X, [target], 1.0);
+        assert!(result.angle_between(target).abs() < 0.001);
+    }
+
+    #[test]
+    fn full_strength_ignores_targets_outside_cone() {
+        let result = assisted_direction(Vec2::ZERO, Vec2::X, [target_at_degrees(8.5, 200.0)], 1.0);
+        assert_eq!(result, Vec2::X);
+    }
+
+    #[test]
+    fn half_strength_halves_cone() {
+        let inside = target_at_degrees(3.5, 200.0);
+        let result = assisted_direction(Vec2::ZERO, Vec2::X, [inside], 0.5);
+        assert!(result.angle_between(inside).abs() < 0.001);
+
+        let outside = target_at_degrees(4.5, 200.0);
+        let result = assisted_direction(Vec2::ZERO, Vec2::X, [outside], 0.5);
+        assert_eq!(result, Vec2::X);
+    }
+
+    #[test]
+    fn never_bends_toward_perpendicular_or_behind() {
+        let targets = [
+            target_at_degrees(90.0, 100.0),
+            target_at_degrees(-90.0, 100.0),
+            target_at_degrees(180.0, 100.0),
+        ];
+        let result = assisted_direction(Vec2::ZERO, Vec2::X, targets, 1.0);
+        assert_eq!(result, Vec2::X);
+    }
+
+    #[test]
+    fn picks_nearest_target_in_cone() {
+        let near = target_at_degrees(-6.0, 150.0);
+        let far = target_at_degrees(2.0, 400.0);
+        let result = assisted_direction(Vec2::ZERO, Vec2::X, [far, near], 1.0);
+        assert!(result.angle_between(near).abs() < 0.001);
+    }
+
+    #[test]
+    fn ignores_targets_beyond_range() {
+        let result = assisted_direction(
+            Vec2::ZERO,
+            Vec2::X,
+            [target_at_degrees(1.0, ASSIST_RANGE + 10.0)],
+            1.0,
+        );
+        assert_eq!(result, Vec2::X);
+    }
+
+    #[test]
+    fn aim_ray_hits_creature_in_line() {
+        assert!(aim_ray_hits(Vec2::ZERO, Vec2::X, Vec2::new(300.0, 10.0), 20.0, 700.0));
+        assert!(!aim_ray_hits(Vec2::ZERO, Vec2::X, Vec2::new(300.0, 30.0), 20.0, 700.0));
+        assert!(!aim_ray_hits(Vec2::ZERO, Vec2::X, Vec2::new(-300.0, 0.0), 20.0, 700.0));
+    }
+
+    /// Fires a single Gauss shot 6 degrees off a stationary target and reports whether it hit
+    fn shot_hits_target(strength_percent: u8) -> bool {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<WeaponRegistry>()
+            .insert_resource(AimAssistSettings {
+                strength_percent,
+                sticky_target: false,
+            })
+            .init_resource::<RunAssists>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<crate::weapons::ProjectilePool>()
+            .init_resource::<crate::viewport::ArenaBounds>()
+            .add_event::<FireWeaponEvent>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<crate::effects::SpawnEffectEvent>()
+            .add_systems(
+                Update,
+                (
+                    fire_weapon_system,
+                    spawn_projectiles_from_fire_events,
+                    projectile_movement,
+                    rebuild_creature_grid,
+                    fire_hitscan_weapons,
+                    projectile_collision,
+                )
+                    .chain(),
+            );
+
+        let aim_angle = 6.0_f32.to_radians();
+        app.world_mut().spawn((
+            Player { index: 0 },
+            Transform::default(),
+            AimDirection::from_angle(aim_angle),
+            AssistedAim { angle: aim_angle },
+            Firing {
+                is_firing: true,
+                ..default()
+            },
+            EquippedWeapon::new(WeaponId::GaussGun, Some(1)),
+            PerkBonuses::default(),
+            ActiveBonusEffects::default(),
+        ));
+        let target = app
+            .world_mut()
+            .spawn((
+                Creature {
+                    creature_type: CreatureType::Zombie,
+                },
+                Transform::from_xyz(300.0, 0.0, 0.0),
+                CreatureHealth::new(1000.0),
+                CreatureSpeed(0.0),
+            ))
+            .id();
+
+        for _ in 0..60 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        }
+
+        let health = app.world().get::<CreatureHealth>(target).unwrap();
+        health.current < health.max
+    }
+
+    #[test]
+    fn full_assist_turns_near_miss_into_hit() {
+        assert!(!shot_hits_target(0));
+        assert!(shot_hits_target(100));
+    }
+}
@@ -0,0 +1,196 @@
+//! Distant-horde ambience
+//!
+//! A looping ambience track under the music whose volume follows the live
+//! creature population, so a crowded late game sounds different from the
+//! first few zombies. Swarm and boss announcements push it up briefly, and
+//! past a population threshold it replaces individual creature spawn sounds.
+
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+
+use super::AudioSettings;
+use crate::creatures::{Creature, MarkedForDespawn};
+
+/// Populations at or below this are silent
+pub const AMBIENCE_MIN_POPULATION: usize = 10;
+/// Population at which the ambience reaches full level
+pub const AMBIENCE_FULL_POPULATION: usize = 150;
+/// Above this population creature spawn sounds are dropped
+pub const SPAWN_SOUND_MAX_POPULATION: usize = 40;
+
+/// Seconds for the level to close most of the gap when the horde grows
+const RISE_TIME: f32 = 1.5;
+/// Slower fall so a cleared screen doesn't cut the sound
+const FALL_TIME: f32 = 4.0;
+/// Swell added by a swarm announcement
+pub const SWARM_SWELL: f32 = 0.5;
+/// Swell added by a boss wave
+pub const BOSS_SWELL: f32 = 0.8;
+/// Swell added by a spawn sound that was dropped
+pub const SPAWN_SWELL: f32 = 0.1;
+/// Swell lost per second
+const SWELL_DECAY: f32 = 0.4;
+
+/// Sent when a swarm or boss wave is announced
+#[derive(Event, Debug, Clone, Copy)]
+pub struct HordeSurgeEvent {
+    pub boss: bool,
+}
+
+/// The ambience loop and its current mix state
+#[derive(Resource, Debug, Default)]
+pub struct HordeAmbience {
+    pub handle: Option<Handle<AudioInstance>>,
+    /// Smoothed population level, 0-1
+    pub level: f32,
+    /// Temporary boost on top of the level, decays over time
+    pub swell: f32,
+    /// Creatures alive on the last update
+    pub population: usize,
+}
+
+impl HordeAmbience {
+    pub fn add_swell(&mut self, amount: f32) {
+        self.swell = (self.swell + amount).min(1.0);
+    }
+}
+
+/// Target ambience level for a creature count: silent for small groups, then
+/// rising quickly at first and flattening out towards a full arena
+pub fn population_level(population: usize) -> f32 {
+    let span = (AMBIENCE_FULL_POPULATION - AMBIENCE_MIN_POPULATION) as f32;
+    let over = population.saturating_sub(AMBIENCE_MIN_POPULATION) as f32;
+    (over / span).min(1.0).sqrt()
+}
+
+/// Moves `current` towards `target`, rising faster than it falls
+pub fn smooth_level(current: f32, target: f32, delta: f32) -> f32 {
+    let time_constant = if target > current { RISE_TIME } else { FALL_TIME };
+    current + (target - current) * (1.0 - (-delta / time_constant).exp())
+}
+
+/// Whether a creature spawn sound would just be noise at this population
+pub fn spawn_sound_suppressed(population: usize) -> bool {
+    population > SPAWN_SOUND_MAX_POPULATION
+}
+
+/// Final channel volume: master × ambience setting × population (plus swell)
+pub fn ambience_volume(settings: &AudioSettings, level: f32, swell: f32) -> f64 {
+    settings.effective_ambience_volume() * (level + swell).min(1.0) as f64
+}
+
+/// Follows the creature count, starts the loop once it's audible and keeps
+/// its volume in step with the level and the audio settings
+#[allow(clippy::too_many_arguments)]
+pub fn update_horde_ambience(
+    time: Res<Time>,
+    audio: Res<Audio>,
+    settings: Res<AudioSettings>,
+    asset_server: Res<AssetServer>,
+    creatures: Query<(), (With<Creature>, Without<MarkedForDespawn>)>,
+    mut surges: EventReader<HordeSurgeEvent>,
+    mut ambience: ResMut<HordeAmbience>,
+    mut audio_instances: ResMut<Assets<AudioInstance>>,
+) {
+    let delta = time.delta_seconds();
+    for surge in surges.read() {
+        ambience.add_swell(if surge.boss { BOSS_SWELL } else { SWARM_SWELL });
+    }
+    ambience.population = creatures.iter().count();
+    ambience.level = smooth_level(ambience.level, population_level(ambience.population), delta);
+    ambience.swell = (ambience.swell - SWELL_DECAY * delta).max(0.0);
+
+    let volume = ambience_volume(&settings, ambience.level, ambience.swell);
+    match &ambience.handle {
+        Some(handle) => {
+            if let Some(instance) = audio_instances.get_mut(handle) {
+                instance.set_volume(volume, AudioTween::default());
+            }
+        }
+        None if volume > 0.0 => {
+            let handle = audio
+                .play(asset_server.load("audio/horde_ambience.ogg"))
+                .with_volume(volume)
+                .looped()
+                .handle();
+            ambience.handle = Some(handle);
+        }
+        None => {}
+    }
+}
+
+/// Boss encounters swell the horde like an announced boss wave
+pub fn swell_on_boss_encounter(mut surges: EventWriter<HordeSurgeEvent>) {
+    surges.send(HordeSurgeEvent { boss: true });
+}
+
+/// Stops the loop and resets the mix when leaving the run
+pub fn stop_horde_ambience(
+    mut ambience: ResMut<HordeAmbience>,
+    mut audio_instances: ResMut<Assets<AudioInstance>>,
+) {
+    if let Some(handle) = ambience.handle.take() {
+        if let Some(instance) = audio_instances.get_mut(&handle) {
+            instance.stop(AudioTween::default());
+        }
+    }
+    *ambience = HordeAmbience::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_groups_are_silent_and_big_ones_cap() {
+        assert_eq!(population_level(0), 0.0);
+        assert_eq!(population_level(AMBIENCE_MIN_POPULATION), 0.0);
+        assert_eq!(population_level(AMBIENCE_FULL_POPULATION), 1.0);
+        assert_eq!(population_level(500), 1.0);
+        // Rises faster early on
+        let quarter = AMBIENCE_MIN_POPULATION + (AMBIENCE_FULL_POPULATION - AMBIENCE_MIN_POPULATION) / 4;
+        assert!(population_level(quarter) > 0.45);
+    }
+
+    #[test]
+    fn smoothing_does_not_pump_on_brief_changes() {
+        // One frame of a full arena barely moves the level
+        let after_frame = smooth_level(0.0, 1.0, 1.0 / 60.0);
+        assert!(after_frame > 0.0 && after_frame < 0.02);
+
+        // Converges after a few seconds
+        let mut level = 0.0;
+        for _ in 0..(60 * 6) {
+            level = smooth_level(level, 1.0, 1.0 / 60.0);
+        }
+        assert!(level > 0.95);
+
+        // Falls slower than it rises
+        let rise = smooth_level(0.0, 1.0, 1.0);
+        let fall = 1.0 - smooth_level(1.0, 0.0, 1.0);
+        assert!(fall < rise);
+    }
+
+    #[test]
+    fn volume_follows_settings_and_swell() {
+        let settings = AudioSettings {
+            sfx_enabled: true,
+            master_volume: 0.5,
+            ambience_volume: 0.8,
+            ..default()
+        };
+        assert!((ambience_volume(&settings, 0.5, 0.0) - 0.2).abs() < 1e-6);
+        assert!((ambience_volume(&settings, 0.5, 0.9) - 0.4).abs() < 1e-6);
+        let muted = AudioSettings {
+            sfx_enabled: false,
+            ..settings
+        };
+        assert_eq!(ambience_volume(&muted, 1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn spawn_sounds_drop_out_in_crowds() {
+        assert!(!spawn_sound_suppressed(SPAWN_SOUND_MAX_POPULATION));
+        assert!(spawn_sound_suppressed(SPAWN_SOUND_MAX_POPULATION + 1));
+    }
+}
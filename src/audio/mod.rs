@@ -2,13 +2,16 @@
 //!
 //! Handles sound effects and music.
 
+pub mod ambience;
 pub mod systems;
 
+pub use ambience::*;
 pub use systems::*;
 
 use bevy::prelude::*;
 
-use crate::states::GameState;
+use crate::schedule::GameSet;
+use crate::states::{GameState, PlayingState};
 
 /// Plugin for audio functionality
 pub struct GameAudioPlugin;
@@ -17,12 +20,25 @@ impl Plugin for GameAudioPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AudioSettings>()
             .init_resource::<CurrentMusic>()
+            .init_resource::<HordeAmbience>()
             .add_event::<PlaySoundEvent>()
+            .add_event::<HordeSurgeEvent>()
             .add_systems(OnEnter(GameState::MainMenu), start_menu_music)
             .add_systems(OnExit(GameState::MainMenu), stop_menu_music)
             .add_systems(OnEnter(GameState::Playing), start_game_music)
-            .add_systems(OnExit(GameState::Playing), stop_game_music)
-            .add_systems(Update, play_sound_effects.run_if(in_state(GameState::Playing)))
+            .add_systems(OnExit(GameState::Playing), (stop_game_music, stop_horde_ambience))
+            .add_systems(OnEnter(PlayingState::BossEncounter), swell_on_boss_encounter)
+            .add_systems(
+                Update,
+                (
+                    update_horde_ambience,
+                    (play_weapon_fire_sounds, play_out_of_ammo_click),
+                    play_sound_effects,
+                )
+                    .chain()
+                    .in_set(GameSet::Presentation)
+                    .run_if(in_state(GameState::Playing)),
+            )
             .add_systems(Update, play_menu_sounds.run_if(in_state(GameState::MainMenu)));
     }
 }
@@ -33,6 +49,8 @@ pub struct AudioSettings {
     pub master_volume: f64,
     pub music_volume: f64,
     pub sfx_volume: f64,
+    /// Horde ambience level; plays whenever sound effects are enabled
+    pub ambience_volume: f64,
     pub music_enabled: bool,
     pub sfx_enabled: bool,
 }
@@ -43,6 +61,7 @@ impl Default for AudioSettings {
             master_volume: 1.0,
             music_volume: 0.7,
             sfx_volume: 1.0,
+            ambience_volume: 0.6,
             // Disabled by default until audio files are added to assets/audio/
             music_enabled: false,
             sfx_enabled: false,
@@ -66,6 +85,14 @@ impl AudioSettings {
             0.0
         }
     }
+
+    pub fn effective_ambience_volume(&self) -> f64 {
+        if self.sfx_enabled {
+            self.master_volume * self.ambience_volume
+        } else {
+            0.0
+        }
+    }
 }
 
 /// Sound effect types
@@ -77,17 +104,29 @@ pub enum SoundEffect {
     RifleFire,
     RocketFire,
     PlasmaFire,
+    /// Dry click of an empty weapon before the Pistol comes out
+    OutOfAmmo,
 
     // Impacts
     BulletHit,
+    /// Sharper crack of a critical hit
+    CriticalHit,
+    /// Toll of a Highlander instant kill
+    InstantKill,
     Explosion,
 
     // Creatures
     CreatureDeath,
     CreatureSpawn,
+    /// A boss entering a new phase
+    BossRoar,
 
     // Player
     PlayerHurt,
+    /// Creature hit negated by invincibility or a shield
+    AttackWhiff,
+    /// Last-stand push shoving nearby creatures away
+    LastStandPush,
     PlayerDeath,
     LevelUp,
 
@@ -125,8 +164,10 @@ mod tests {
 
     #[test]
     fn audio_settings_effective_volume_respects_enabled() {
-        let mut settings = AudioSettings::default();
-        settings.music_enabled = true;
+        let mut settings = AudioSettings {
+            music_enabled: true,
+            ..Default::default()
+        };
         assert!(settings.effective_music_volume() > 0.0);
 
         settings.music_enabled = false;
@@ -135,10 +176,12 @@ mod tests {
 
     #[test]
     fn audio_settings_effective_volume_respects_master() {
-        let mut settings = AudioSettings::default();
-        settings.music_enabled = true;
-        settings.master_volume = 0.5;
-        settings.music_volume = 1.0;
+        let settings = AudioSettings {
+            music_enabled: true,
+            master_volume: 0.5,
+            music_volume: 1.0,
+            ..Default::default()
+        };
 
         assert!((settings.effective_music_volume() - 0.5).abs() < 0.001);
     }
@@ -1,16 +1,18 @@
 //! Audio systems
 
 use bevy::prelude::*;
+use bevy::utils::HashMap;
 use bevy_kira_audio::prelude::*;
 
+use super::ambience::{spawn_sound_suppressed, HordeAmbience, SPAWN_SWELL};
 use super::{AudioSettings, PlaySoundEvent, SoundEffect};
 use crate::bonuses::systems::BonusCollectedEvent;
 use crate::bonuses::BonusType;
 use crate::creatures::systems::CreatureDeathEvent;
 use crate::items::{ItemPickedUpEvent, ItemUsedEvent};
-use crate::player::systems::{PlayerDamageEvent, PlayerDeathEvent, PlayerLevelUpEvent};
+use crate::player::systems::{PlayerDeathEvent, PlayerLevelUpEvent};
 use crate::weapons::components::WeaponId;
-use crate::weapons::systems::{FireWeaponEvent, ProjectileHitEvent};
+use crate::weapons::systems::{FireWeaponEvent, OutOfAmmoEvent, ProjectileHitEvent, WeaponGrantedEvent};
 
 /// Resource to track current music
 #[derive(Resource, Default)]
@@ -79,26 +81,16 @@ pub fn play_sound_effects(
     settings: Res<AudioSettings>,
     asset_server: Res<AssetServer>,
     mut creature_deaths: EventReader<CreatureDeathEvent>,
-    mut player_damage: EventReader<PlayerDamageEvent>,
     mut player_deaths: EventReader<PlayerDeathEvent>,
     mut player_levelups: EventReader<PlayerLevelUpEvent>,
-    mut weapon_fires: EventReader<FireWeaponEvent>,
     mut projectile_hits: EventReader<ProjectileHitEvent>,
     mut bonus_collected: EventReader<BonusCollectedEvent>,
+    mut weapon_grants: EventReader<WeaponGrantedEvent>,
     mut item_pickups: EventReader<ItemPickedUpEvent>,
     mut item_uses: EventReader<ItemUsedEvent>,
     mut sound_events: EventReader<PlaySoundEvent>,
+    mut ambience: ResMut<HordeAmbience>,
 ) {
-    // Process weapon fire events with positional audio
-    // Uses shooter and direction from event for future 3D audio
-    for event in weapon_fires.read() {
-        let sound = weapon_fire_sound(event.weapon_id);
-        // Use position for stereo panning, direction for potential Doppler effects
-        let _shooter = event.shooter;
-        let _direction = event.direction;
-        play_sfx_at(&audio, &settings, &asset_server, sound, Some(event.position.truncate()));
-    }
-
     // Process creature deaths - bosses get explosion sound
     for event in creature_deaths.read() {
         let position = Some(event.position.truncate());
@@ -109,13 +101,6 @@ pub fn play_sound_effects(
         }
     }
 
-    // Process player damage - use source entity for directional audio
-    for event in player_damage.read() {
-        // Source can be used for directional damage indicators
-        let _damage_source = event.source;
-        play_sfx(&audio, &settings, &asset_server, SoundEffect::PlayerHurt);
-    }
-
     // Process player deaths - use player_entity for multi-player support
     for event in player_deaths.read() {
         let _dead_player = event.player_entity;
@@ -133,13 +118,26 @@ pub fn play_sound_effects(
         let _hit_projectile = event.projectile;
         let _hit_target = event.target;
         let _damage_dealt = event.damage;
-        play_sfx_at(&audio, &settings, &asset_server, SoundEffect::BulletHit, Some(event.position.truncate()));
+        let sound = if event.instant_kill {
+            SoundEffect::InstantKill
+        } else if event.was_crit {
+            SoundEffect::CriticalHit
+        } else {
+            SoundEffect::BulletHit
+        };
+        play_sfx_at(&audio, &settings, &asset_server, sound, Some(event.position.truncate()));
     }
 
     // Process bonus pickups
     for event in bonus_collected.read() {
-        let sound = bonus_pickup_sound(event.bonus_type);
-        play_sfx(&audio, &settings, &asset_server, sound);
+        if let Some(sound) = bonus_pickup_sound(event.bonus_type) {
+            play_sfx(&audio, &settings, &asset_server, sound);
+        }
+    }
+
+    // Weapon pickups sound once the weapon is actually handed over
+    for event in weapon_grants.read() {
+        play_sfx_at(&audio, &settings, &asset_server, SoundEffect::WeaponPickup, Some(event.position.truncate()));
     }
 
     // Process item pickups - log what was picked up
@@ -163,12 +161,59 @@ pub fn play_sound_effects(
 
     // Process direct sound effect events with positional audio
     for event in sound_events.read() {
+        // In a crowd, spawns feed the horde ambience instead of playing one by one
+        if event.sound == SoundEffect::CreatureSpawn && spawn_sound_suppressed(ambience.population) {
+            ambience.add_swell(SPAWN_SWELL);
+            continue;
+        }
         play_sfx_at(&audio, &settings, &asset_server, event.sound, event.position);
     }
 }
 
-/// Maps weapon ID to sound effect
-fn weapon_fire_sound(weapon_id: WeaponId) -> SoundEffect {
+/// Shortest gap between two firing sounds from the same shooter, so fast
+/// weapons like the Minigun don't flood the mixer
+pub const MIN_FIRE_SOUND_INTERVAL: f32 = 0.06;
+
+/// Firing sound at the muzzle, one per trigger pull however many pellets it fires
+pub fn play_weapon_fire_sounds(
+    time: Res<Time>,
+    mut fire_events: EventReader<FireWeaponEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    mut last_played: Local<HashMap<Entity, f32>>,
+) {
+    let now = time.elapsed_seconds();
+    // Shooters quiet for a full interval have nothing left to limit, despawned ones included
+    last_played.retain(|_, &mut last| now - last < MIN_FIRE_SOUND_INTERVAL);
+    for event in fire_events.read() {
+        if last_played
+            .get(&event.shooter)
+            .is_some_and(|&last| now - last < MIN_FIRE_SOUND_INTERVAL)
+        {
+            continue;
+        }
+        last_played.insert(event.shooter, now);
+        sound_events.send(PlaySoundEvent {
+            sound: weapon_fire_sound(event.weapon_id),
+            position: Some(event.position.truncate()),
+        });
+    }
+}
+
+/// Dry click when a weapon runs out for good
+pub fn play_out_of_ammo_click(
+    mut out_of_ammo_events: EventReader<OutOfAmmoEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for event in out_of_ammo_events.read() {
+        sound_events.send(PlaySoundEvent {
+            sound: SoundEffect::OutOfAmmo,
+            position: Some(event.position.truncate()),
+        });
+    }
+}
+
+/// Maps weapon ID to its firing sound
+pub fn weapon_fire_sound(weapon_id: WeaponId) -> SoundEffect {
     match weapon_id {
         WeaponId::Pistol | WeaponId::Magnum | WeaponId::PocketRocket => SoundEffect::PistolFire,
         WeaponId::AssaultRifle | WeaponId::MachineGun | WeaponId::Minigun | WeaponId::Uzi
@@ -184,14 +229,14 @@ fn weapon_fire_sound(weapon_id: WeaponId) -> SoundEffect {
     }
 }
 
-/// Maps bonus type to sound effect
-fn bonus_pickup_sound(bonus_type: BonusType) -> SoundEffect {
+/// Maps bonus type to sound effect. Weapon pickups play theirs on WeaponGrantedEvent.
+fn bonus_pickup_sound(bonus_type: BonusType) -> Option<SoundEffect> {
     match bonus_type {
         BonusType::SmallHealth | BonusType::LargeHealth | BonusType::FullHealth => {
-            SoundEffect::HealthPickup
+            Some(SoundEffect::HealthPickup)
         }
-        BonusType::WeaponPickup => SoundEffect::WeaponPickup,
-        _ => SoundEffect::BonusPickup,
+        BonusType::WeaponPickup => None,
+        _ => Some(SoundEffect::BonusPickup),
     }
 }
 
@@ -215,11 +260,17 @@ fn play_sfx_at(
         SoundEffect::RifleFire => "audio/rifle.ogg",
         SoundEffect::RocketFire => "audio/rocket.ogg",
         SoundEffect::PlasmaFire => "audio/plasma.ogg",
+        SoundEffect::OutOfAmmo => "audio/empty_click.ogg",
         SoundEffect::BulletHit => "audio/hit.ogg",
+        SoundEffect::CriticalHit => "audio/crit.ogg",
+        SoundEffect::InstantKill => "audio/instant_kill.ogg",
         SoundEffect::Explosion => "audio/explosion.ogg",
         SoundEffect::CreatureDeath => "audio/creature_death.ogg",
         SoundEffect::CreatureSpawn => "audio/creature_spawn.ogg",
+        SoundEffect::BossRoar => "audio/boss_roar.ogg",
         SoundEffect::PlayerHurt => "audio/player_hurt.ogg",
+        SoundEffect::AttackWhiff => "audio/whiff.ogg",
+        SoundEffect::LastStandPush => "audio/push.ogg",
         SoundEffect::PlayerDeath => "audio/player_death.ogg",
         SoundEffect::LevelUp => "audio/levelup.ogg",
         SoundEffect::HealthPickup => "audio/health.ogg",
@@ -279,4 +330,60 @@ mod tests {
         let music = CurrentMusic::default();
         assert!(music.handle.is_none());
     }
+
+    #[test]
+    fn fire_sounds_are_rate_limited_per_shooter() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .add_event::<FireWeaponEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, play_weapon_fire_sounds);
+        let minigunner = app.world_mut().spawn_empty().id();
+        let shotgunner = app.world_mut().spawn_empty().id();
+        let fire = |app: &mut App, shooter: Entity, weapon_id: WeaponId| {
+            app.world_mut().send_event(FireWeaponEvent {
+                shooter,
+                position: Vec3::new(10.0, 20.0, 0.0),
+                direction: Vec2::X,
+                weapon_id,
+                weapon: weapon_id.into(),
+                target: None,
+            });
+        };
+        let sounds = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Events<PlaySoundEvent>>()
+                .drain()
+                .map(|event| (event.sound, event.position))
+                .collect::<Vec<_>>()
+        };
+
+        fire(&mut app, minigunner, WeaponId::Minigun);
+        fire(&mut app, minigunner, WeaponId::Minigun);
+        fire(&mut app, shotgunner, WeaponId::Shotgun);
+        app.update();
+        assert_eq!(
+            sounds(&mut app),
+            vec![
+                (SoundEffect::RifleFire, Some(Vec2::new(10.0, 20.0))),
+                (SoundEffect::ShotgunFire, Some(Vec2::new(10.0, 20.0))),
+            ]
+        );
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(MIN_FIRE_SOUND_INTERVAL / 2.0));
+        fire(&mut app, minigunner, WeaponId::Minigun);
+        app.update();
+        assert!(sounds(&mut app).is_empty(), "too soon after the last shot");
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(MIN_FIRE_SOUND_INTERVAL));
+        fire(&mut app, minigunner, WeaponId::Minigun);
+        app.update();
+        assert_eq!(sounds(&mut app).len(), 1);
+    }
 }
@@ -3,6 +3,9 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::render_layers::{on_layer, PICKUPS};
+use crate::weapons::components::WeaponKey;
+
 /// Types of bonuses that can spawn
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BonusType {
@@ -17,6 +20,8 @@ pub enum BonusType {
 
     // Weapons (random weapon pickup)
     WeaponPickup,
+    /// Refills part of the equipped weapon's clip
+    Ammo,
 
     // Temporary Effects
     SpeedBoost,
@@ -52,6 +57,7 @@ impl BonusType {
             BonusType::SmallExp => 25,
             BonusType::LargeExp => 5,
             BonusType::WeaponPickup => 15,
+            BonusType::Ammo => 8,
             BonusType::SpeedBoost => 8,
             BonusType::FireRateBoost => 8,
             BonusType::DamageBoost => 8,
@@ -70,6 +76,7 @@ impl BonusType {
             }
             BonusType::SmallExp | BonusType::LargeExp => Color::srgb(1.0, 1.0, 0.2),
             BonusType::WeaponPickup => Color::srgb(0.8, 0.5, 0.2),
+            BonusType::Ammo => Color::srgb(0.5, 0.9, 0.3),
             BonusType::SpeedBoost => Color::srgb(0.2, 0.8, 1.0),
             BonusType::FireRateBoost => Color::srgb(1.0, 0.5, 0.0),
             BonusType::DamageBoost => Color::srgb(1.0, 0.0, 0.5),
@@ -88,6 +95,12 @@ pub struct Bonus {
     pub bonus_type: BonusType,
 }
 
+/// Weapon a WeaponPickup bonus grants, rolled when it drops so it can be labelled
+#[derive(Component, Debug, Clone)]
+pub struct WeaponDrop {
+    pub weapon: WeaponKey,
+}
+
 /// Lifetime for bonuses (they despawn after a while)
 #[derive(Component, Debug, Clone)]
 pub struct BonusLifetime {
@@ -137,13 +150,39 @@ impl BonusBundle {
                     custom_size: Some(Vec2::splat(16.0)),
                     ..default()
                 },
-                transform: Transform::from_translation(position),
+                transform: Transform::from_translation(on_layer(position, PICKUPS)),
                 ..default()
             },
         }
     }
 }
 
+/// Share of the equipped weapon's clip an Ammo bonus refills, before ammo perks
+pub const AMMO_REFILL_FRACTION: f32 = 0.3;
+/// XP an Ammo bonus grants instead when the weapon never runs dry
+pub const AMMO_BONUS_EXP: u32 = 10;
+
+/// Chance that a killed creature drops a bonus
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct BonusDropChance(pub f32);
+
+impl Default for BonusDropChance {
+    fn default() -> Self {
+        Self(0.15)
+    }
+}
+
+/// Highest drop chance perks can push a kill to
+pub const MAX_BONUS_DROP_CHANCE: f32 = 0.5;
+
+impl BonusDropChance {
+    /// Drop chance after a spawn multiplier (BonusMagnet), capped. A base chance
+    /// already above the cap is left as it is
+    pub fn scaled(&self, multiplier: f32) -> f32 {
+        (self.0 * multiplier).min(MAX_BONUS_DROP_CHANCE.max(self.0))
+    }
+}
+
 /// Component for active temporary bonus effects on a player
 #[derive(Component, Debug, Clone, Default)]
 pub struct ActiveBonusEffects {
@@ -188,18 +227,51 @@ impl ActiveBonusEffects {
     pub fn has_slow_motion(&self) -> bool {
         self.slow_motion_timer > 0.0
     }
+
+    /// Active effects with their remaining time, for HUD display
+    pub fn active_timers(&self) -> Vec<(&'static str, f32)> {
+        [
+            ("Speed", self.speed_boost_timer),
+            ("Fire Rate", self.fire_rate_boost_timer),
+            ("Damage", self.damage_boost_timer),
+            ("Invincible", self.invincibility_timer),
+            ("Shield", self.shield_timer),
+            ("Slow-Mo", self.slow_motion_timer),
+        ]
+        .into_iter()
+        .filter(|(_, remaining)| *remaining > 0.0)
+        .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn bonus_magnet_raises_drop_chance_up_to_the_cap() {
+        assert!((BonusDropChance::default().scaled(1.5) - 0.225).abs() < 1e-6);
+        assert_eq!(BonusDropChance(0.4).scaled(1.5), MAX_BONUS_DROP_CHANCE);
+        assert_eq!(BonusDropChance(1.0).scaled(1.0), 1.0);
+    }
+
     #[test]
     fn bonus_type_duration_returns_some_for_timed() {
         assert!(BonusType::SpeedBoost.duration().is_some());
         assert!(BonusType::Invincibility.duration().is_some());
     }
 
+    #[test]
+    fn active_timers_lists_only_running_effects() {
+        let effects = ActiveBonusEffects {
+            shield_timer: 6.0,
+            speed_boost_timer: 2.5,
+            ..default()
+        };
+        let timers = effects.active_timers();
+        assert_eq!(timers, vec![("Speed", 2.5), ("Shield", 6.0)]);
+    }
+
     #[test]
     fn bonus_type_duration_returns_none_for_instant() {
         assert!(BonusType::SmallHealth.duration().is_none());
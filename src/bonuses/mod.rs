@@ -10,6 +10,7 @@ pub use systems::*;
 
 use bevy::prelude::*;
 
+use crate::schedule::GameSet;
 use crate::states::GameState;
 
 /// Plugin for bonus-related functionality
@@ -17,22 +18,27 @@ pub struct BonusesPlugin;
 
 impl Plugin for BonusesPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<SpawnBonusEvent>()
+        app.init_resource::<BonusDropChance>()
+            .add_event::<SpawnBonusEvent>()
             .add_event::<BonusCollectedEvent>()
             .add_systems(OnExit(GameState::Playing), despawn_all_bonuses)
             .add_systems(
                 Update,
                 (
-                    spawn_bonus_on_death,
-                    handle_bonus_spawns,
-                    bonus_attraction,
-                    bonus_collection,
-                    bonus_lifetime,
-                    apply_bonus_effects,
-                    update_active_bonus_effects,
-                    apply_speed_boost,
+                    (
+                        bonus_attraction,
+                        bonus_collection,
+                        bonus_lifetime,
+                        apply_bonus_effects,
+                        update_active_bonus_effects,
+                        apply_speed_boost,
+                    )
+                        .chain()
+                        .in_set(GameSet::Simulation),
+                    (spawn_bonus_on_death, handle_bonus_spawns)
+                        .chain()
+                        .in_set(GameSet::Consequences),
                 )
-                    .chain()
                     .run_if(in_state(GameState::Playing)),
             );
     }
@@ -7,8 +7,12 @@ use super::components::*;
 use crate::creatures::components::{Creature, CreatureHealth, MarkedForDespawn};
 use crate::creatures::systems::CreatureDeathEvent;
 use crate::perks::components::PerkBonuses;
-use crate::player::components::{Experience, Health, MoveSpeed, Player};
-use crate::weapons::components::{EquippedWeapon, WeaponId};
+use crate::player::components::{Health, MoveSpeed, Player};
+use crate::player::systems::{GrantXpEvent, XpSource};
+use crate::survival::SurvivalState;
+use crate::weapons::components::{AlternateWeaponSlot, EquippedWeapon, WeaponKey};
+use crate::weapons::registry::WeaponRegistry;
+use crate::weapons::systems::WeaponGrantedEvent;
 
 /// Event to spawn a bonus
 #[derive(Event)]
@@ -22,64 +26,126 @@ pub struct SpawnBonusEvent {
 pub struct BonusCollectedEvent {
     pub player_entity: Entity,
     pub bonus_type: BonusType,
+    /// Weapon carried by a WeaponPickup bonus
+    pub weapon: Option<WeaponKey>,
+    /// Rounds an Ammo bonus refills, or the XP it grants for a weapon that never runs dry
+    pub amount: Option<u32>,
 }
 
-/// Handles bonus spawn events
-pub fn handle_bonus_spawns(mut commands: Commands, mut events: EventReader<SpawnBonusEvent>) {
+/// What an Ammo bonus gives: rounds up to a full clip of the equipped weapon,
+/// or a little XP when the weapon has infinite ammo
+pub fn ammo_bonus_amount(
+    weapon: &EquippedWeapon,
+    weapon_registry: &WeaponRegistry,
+    perk_bonuses: &PerkBonuses,
+) -> u32 {
+    let (Some(ammo), Some(capacity)) = (
+        weapon.ammo,
+        weapon_registry
+            .get_key(&weapon.weapon)
+            .and_then(|data| data.ammo_capacity),
+    ) else {
+        return AMMO_BONUS_EXP;
+    };
+    let full_clip = (capacity as f32 * perk_bonuses.ammo_multiplier).round() as u32;
+    let refill = (capacity as f32 * AMMO_REFILL_FRACTION * perk_bonuses.ammo_multiplier).ceil() as u32;
+    refill.min(full_clip.saturating_sub(ammo))
+}
+
+/// Handles bonus spawn events. Weapon pickups roll their weapon here, and
+/// don't spawn at all while a player has MyFavouriteWeapon.
+pub fn handle_bonus_spawns(
+    mut commands: Commands,
+    mut events: EventReader<SpawnBonusEvent>,
+    weapon_registry: Res<WeaponRegistry>,
+    survival: Option<Res<SurvivalState>>,
+    player_query: Query<&PerkBonuses, With<Player>>,
+) {
+    let mut rng = rand::thread_rng();
+    let game_time = survival.map_or(0.0, |survival| survival.game_time);
+    let weapons_disabled = player_query.iter().any(|bonuses| bonuses.disable_weapon_bonuses);
     for event in events.read() {
-        commands.spawn(BonusBundle::new(event.bonus_type, event.position));
+        if event.bonus_type == BonusType::WeaponPickup && weapons_disabled {
+            continue;
+        }
+        let mut bonus = commands.spawn(BonusBundle::new(event.bonus_type, event.position));
+        if event.bonus_type == BonusType::WeaponPickup {
+            if let Some(weapon) = weapon_registry.roll_drop(game_time, &mut rng) {
+                bonus.insert(WeaponDrop { weapon });
+            }
+        }
+    }
+}
+
+/// Bonuses drift toward any player this close
+pub const BONUS_ATTRACTION_DISTANCE: f32 = 100.0;
+
+/// Moves a pickup toward `target` if it's within `range`, faster the closer it gets
+/// (up to twice `speed` at the target). Returns whether it was pulled.
+pub fn pull_pickup(transform: &mut Transform, target: Vec2, range: f32, speed: f32, delta: f32) -> bool {
+    let position = transform.translation.truncate();
+    let distance = target.distance(position);
+    if distance >= range {
+        return false;
     }
+    let proximity = 1.0 - distance / range;
+    let step = ((target - position).normalize_or_zero() * speed * (1.0 + proximity) * delta).clamp_length_max(distance);
+    transform.translation.x += step.x;
+    transform.translation.y += step.y;
+    true
 }
 
-/// Attracts bonuses toward nearby players
+/// Attracts bonuses toward nearby players, from further away with Telekinetic
 #[allow(clippy::type_complexity)]
 pub fn bonus_attraction(
     time: Res<Time>,
-    player_query: Query<(Entity, &Transform), With<Player>>,
+    player_query: Query<(Entity, &Transform, Option<&PerkBonuses>), With<Player>>,
     mut bonus_query: Query<(&mut Transform, &mut BonusAttraction), (With<Bonus>, Without<Player>)>,
 ) {
-    const ATTRACTION_DISTANCE: f32 = 100.0;
-
-    for (player_entity, player_transform) in player_query.iter() {
+    for (player_entity, player_transform, perk_bonuses) in player_query.iter() {
         let player_pos = player_transform.translation.truncate();
+        let range = perk_bonuses.map_or(0.0, |bonuses| bonuses.telekinetic_range).max(BONUS_ATTRACTION_DISTANCE);
 
         for (mut bonus_transform, mut attraction) in bonus_query.iter_mut() {
-            let bonus_pos = bonus_transform.translation.truncate();
-            let distance = player_pos.distance(bonus_pos);
-
-            if distance < ATTRACTION_DISTANCE {
+            if pull_pickup(&mut bonus_transform, player_pos, range, attraction.speed, time.delta_seconds()) {
                 attraction.target = Some(player_entity);
-
-                // Move toward player
-                let direction = (player_pos - bonus_pos).normalize_or_zero();
-                let movement = direction * attraction.speed * time.delta_seconds();
-                bonus_transform.translation.x += movement.x;
-                bonus_transform.translation.y += movement.y;
             }
         }
     }
 }
 
 /// Handles bonus collection when player touches a bonus
+#[allow(clippy::type_complexity)]
 pub fn bonus_collection(
     mut commands: Commands,
-    player_query: Query<(Entity, &Transform), With<Player>>,
-    bonus_query: Query<(Entity, &Transform, &Bonus)>,
+    player_query: Query<(Entity, &Transform, Option<&EquippedWeapon>, Option<&PerkBonuses>), With<Player>>,
+    bonus_query: Query<(Entity, &Transform, &Bonus, Option<&WeaponDrop>)>,
+    weapon_registry: Res<WeaponRegistry>,
     mut collected_events: EventWriter<BonusCollectedEvent>,
 ) {
     const COLLECTION_RADIUS: f32 = 24.0;
 
-    for (player_entity, player_transform) in player_query.iter() {
+    for (player_entity, player_transform, weapon, perk_bonuses) in player_query.iter() {
         let player_pos = player_transform.translation.truncate();
 
-        for (bonus_entity, bonus_transform, bonus) in bonus_query.iter() {
+        for (bonus_entity, bonus_transform, bonus, weapon_drop) in bonus_query.iter() {
             let bonus_pos = bonus_transform.translation.truncate();
             let distance = player_pos.distance(bonus_pos);
 
             if distance < COLLECTION_RADIUS {
+                let amount = match (bonus.bonus_type, weapon) {
+                    (BonusType::Ammo, Some(weapon)) => Some(ammo_bonus_amount(
+                        weapon,
+                        &weapon_registry,
+                        perk_bonuses.unwrap_or(&PerkBonuses::default()),
+                    )),
+                    _ => None,
+                };
                 collected_events.send(BonusCollectedEvent {
                     player_entity,
                     bonus_type: bonus.bonus_type,
+                    weapon: weapon_drop.map(|drop| drop.weapon.clone()),
+                    amount,
                 });
                 commands.entity(bonus_entity).despawn_recursive();
             }
@@ -102,29 +168,37 @@ pub fn bonus_lifetime(
 }
 
 /// Applies the effects of collected bonuses
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn apply_bonus_effects(
     mut events: EventReader<BonusCollectedEvent>,
+    weapon_registry: Res<WeaponRegistry>,
     mut player_query: Query<
         (
+            &Transform,
             &mut Health,
-            &mut Experience,
             &mut EquippedWeapon,
             Option<&mut ActiveBonusEffects>,
             &PerkBonuses,
+            Option<&mut AlternateWeaponSlot>,
         ),
         With<Player>,
     >,
     _commands: Commands,
     creatures: Query<Entity, (With<Creature>, Without<MarkedForDespawn>)>,
     mut creature_health: Query<&mut CreatureHealth>,
+    mut granted_events: EventWriter<WeaponGrantedEvent>,
+    mut xp_events: EventWriter<GrantXpEvent>,
 ) {
     for event in events.read() {
-        let Ok((mut health, mut exp, mut weapon, active_effects, perk_bonuses)) =
+        let Ok((transform, mut health, mut weapon, active_effects, perk_bonuses, alternate_slot)) =
             player_query.get_mut(event.player_entity)
         else {
             continue;
         };
+        // BonusEconomist stretches timed effects
+        let timed = |bonus_type: BonusType, fallback: f32| {
+            bonus_type.duration().unwrap_or(fallback) * perk_bonuses.bonus_duration_multiplier
+        };
 
         match event.bonus_type {
             // Health bonuses
@@ -140,64 +214,92 @@ pub fn apply_bonus_effects(
 
             // Experience bonuses
             BonusType::SmallExp => {
-                exp.add(25);
+                xp_events.send(GrantXpEvent {
+                    player_entity: event.player_entity,
+                    amount: 25,
+                    source: XpSource::Bonus,
+                });
             }
             BonusType::LargeExp => {
-                exp.add(100);
+                xp_events.send(GrantXpEvent {
+                    player_entity: event.player_entity,
+                    amount: 100,
+                    source: XpSource::Bonus,
+                });
             }
 
-            // Weapon pickup (random weapon)
-            BonusType::WeaponPickup => {
-                let weapons = [
-                    WeaponId::Shotgun,
-                    WeaponId::Uzi,
-                    WeaponId::AssaultRifle,
-                    WeaponId::PlasmaRifle,
-                    WeaponId::RocketLauncher,
-                    WeaponId::Flamethrower,
-                    WeaponId::Minigun,
-                ];
-                let mut rng = rand::thread_rng();
-                let idx = rng.gen_range(0..weapons.len());
-                let new_weapon_id = weapons[idx];
-                // Apply ammo multiplier from perks
-                let base_ammo = 100;
-                let bonus_ammo = (base_ammo as f32 * perk_bonuses.ammo_multiplier) as u32;
-                // Use EquippedWeapon::new to create new weapon with proper initialization
-                *weapon = EquippedWeapon::new(new_weapon_id, Some(bonus_ammo));
+            // Weapon pickup (random weapon); MyFavouriteWeapon keeps the current one
+            BonusType::WeaponPickup if !perk_bonuses.disable_weapon_bonuses => {
+                // Drops roll their weapon when spawned; fall back to an early-game roll
+                let new_weapon = event
+                    .weapon
+                    .clone()
+                    .or_else(|| weapon_registry.roll_drop(0.0, &mut rand::thread_rng()));
+                let Some(new_weapon) = new_weapon else {
+                    continue;
+                };
+                // Full clip of the new weapon, scaled by ammo perks
+                let ammo = weapon_registry
+                    .get_key(&new_weapon)
+                    .and_then(|data| data.ammo_capacity)
+                    .map(|capacity| (capacity as f32 * perk_bonuses.ammo_multiplier).round() as u32);
+                let previous = std::mem::replace(&mut *weapon, EquippedWeapon::new(new_weapon, ammo));
+                // With a second slot the old weapon is stowed instead of dropped
+                if let Some(mut slot) = alternate_slot {
+                    slot.stowed = Some(previous);
+                }
+                granted_events.send(WeaponGrantedEvent {
+                    position: transform.translation,
+                });
+            }
+            BonusType::WeaponPickup => {}
+
+            // Ammo refills limited weapons; infinite ones get a little XP instead
+            BonusType::Ammo => {
+                let amount = event
+                    .amount
+                    .unwrap_or_else(|| ammo_bonus_amount(&weapon, &weapon_registry, perk_bonuses));
+                match weapon.ammo {
+                    Some(ammo) => weapon.ammo = Some(ammo + amount),
+                    None => {
+                        xp_events.send(GrantXpEvent {
+                            player_entity: event.player_entity,
+                            amount,
+                            source: XpSource::Bonus,
+                        });
+                    }
+                }
             }
 
             // Temporary effects
             BonusType::SpeedBoost => {
                 if let Some(mut effects) = active_effects {
-                    effects.speed_boost_timer = BonusType::SpeedBoost.duration().unwrap_or(10.0);
+                    effects.speed_boost_timer = timed(BonusType::SpeedBoost, 10.0);
                 }
             }
             BonusType::FireRateBoost => {
                 if let Some(mut effects) = active_effects {
-                    effects.fire_rate_boost_timer =
-                        BonusType::FireRateBoost.duration().unwrap_or(10.0);
+                    effects.fire_rate_boost_timer = timed(BonusType::FireRateBoost, 10.0);
                 }
             }
             BonusType::DamageBoost => {
                 if let Some(mut effects) = active_effects {
-                    effects.damage_boost_timer = BonusType::DamageBoost.duration().unwrap_or(10.0);
+                    effects.damage_boost_timer = timed(BonusType::DamageBoost, 10.0);
                 }
             }
             BonusType::Invincibility => {
                 if let Some(mut effects) = active_effects {
-                    effects.invincibility_timer =
-                        BonusType::Invincibility.duration().unwrap_or(5.0);
+                    effects.invincibility_timer = timed(BonusType::Invincibility, 5.0);
                 }
             }
             BonusType::Shield => {
                 if let Some(mut effects) = active_effects {
-                    effects.shield_timer = BonusType::Shield.duration().unwrap_or(15.0);
+                    effects.shield_timer = timed(BonusType::Shield, 15.0);
                 }
             }
             BonusType::SlowMotion => {
                 if let Some(mut effects) = active_effects {
-                    effects.slow_motion_timer = BonusType::SlowMotion.duration().unwrap_or(5.0);
+                    effects.slow_motion_timer = timed(BonusType::SlowMotion, 5.0);
                 }
             }
 
@@ -218,13 +320,16 @@ pub fn apply_bonus_effects(
     }
 }
 
-/// Spawns bonuses when creatures die (chance-based with weighted selection)
+/// Spawns bonuses when creatures die (chance-based with weighted selection).
+/// BonusMagnet raises the drop chance; ammo perks make Ammo bonuses more common.
+/// With several players the best multiplier of any of them counts
 pub fn spawn_bonus_on_death(
     mut death_events: EventReader<CreatureDeathEvent>,
     mut spawn_events: EventWriter<SpawnBonusEvent>,
+    drop_chance: Res<BonusDropChance>,
+    player_query: Query<&PerkBonuses, With<Player>>,
 ) {
     let mut rng = rand::thread_rng();
-    const DROP_CHANCE: f32 = 0.15; // 15% chance to drop a bonus
 
     // All bonus types for weighted selection
     let bonus_types = [
@@ -234,6 +339,7 @@ pub fn spawn_bonus_on_death(
         BonusType::SmallExp,
         BonusType::LargeExp,
         BonusType::WeaponPickup,
+        BonusType::Ammo,
         BonusType::SpeedBoost,
         BonusType::FireRateBoost,
         BonusType::DamageBoost,
@@ -244,12 +350,27 @@ pub fn spawn_bonus_on_death(
         BonusType::SlowMotion,
     ];
 
+    let chance = drop_chance.scaled(
+        player_query
+            .iter()
+            .map(|bonuses| bonuses.bonus_spawn_multiplier)
+            .fold(1.0, f32::max),
+    );
+    let ammo_multiplier = player_query
+        .iter()
+        .map(|bonuses| bonuses.ammo_multiplier)
+        .fold(1.0, f32::max);
+    let weight = |bonus_type: &BonusType| match bonus_type {
+        BonusType::Ammo => (bonus_type.spawn_weight() as f32 * ammo_multiplier).round() as u32,
+        _ => bonus_type.spawn_weight(),
+    };
+
     // Calculate total weight
-    let total_weight: u32 = bonus_types.iter().map(|b| b.spawn_weight()).sum();
+    let total_weight: u32 = bonus_types.iter().map(weight).sum();
 
     for event in death_events.read() {
         // Roll for drop
-        if rng.gen::<f32>() > DROP_CHANCE {
+        if rng.gen::<f32>() > chance {
             continue;
         }
 
@@ -259,7 +380,7 @@ pub fn spawn_bonus_on_death(
         let mut selected = BonusType::SmallHealth;
 
         for bonus_type in &bonus_types {
-            cumulative += bonus_type.spawn_weight();
+            cumulative += weight(bonus_type);
             if roll < cumulative {
                 selected = *bonus_type;
                 break;
@@ -283,17 +404,24 @@ pub fn update_active_bonus_effects(
     }
 }
 
-/// Applies speed boost to player movement
+/// Sets player move speed: base speed scaled by perks, the speed boost and a carried second weapon
+#[allow(clippy::type_complexity)]
 pub fn apply_speed_boost(
-    mut query: Query<(&mut MoveSpeed, &ActiveBonusEffects), With<Player>>,
+    mut query: Query<
+        (
+            &mut MoveSpeed,
+            &ActiveBonusEffects,
+            &PerkBonuses,
+            Option<&AlternateWeaponSlot>,
+        ),
+        With<Player>,
+    >,
     base_speed: Res<crate::player::resources::PlayerConfig>,
 ) {
-    for (mut speed, effects) in query.iter_mut() {
-        if effects.has_speed_boost() {
-            speed.0 = base_speed.base_move_speed * 1.5; // 50% speed boost
-        } else {
-            speed.0 = base_speed.base_move_speed;
-        }
+    for (mut speed, effects, perk_bonuses, alternate_slot) in query.iter_mut() {
+        let boost = if effects.has_speed_boost() { 1.5 } else { 1.0 }; // 50% speed boost
+        let carry = alternate_slot.map_or(1.0, |slot| slot.speed_multiplier());
+        speed.0 = base_speed.base_move_speed * perk_bonuses.speed_multiplier * boost * carry;
     }
 }
 
@@ -307,6 +435,7 @@ pub fn despawn_all_bonuses(mut commands: Commands, query: Query<Entity, With<Bon
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::player::components::Experience;
 
     #[test]
     fn spawn_bonus_event_can_be_created() {
@@ -322,7 +451,306 @@ mod tests {
         let event = BonusCollectedEvent {
             player_entity: Entity::PLACEHOLDER,
             bonus_type: BonusType::LargeExp,
+            weapon: None,
+            amount: None,
         };
         assert_eq!(event.bonus_type, BonusType::LargeExp);
     }
+
+    #[test]
+    fn telekinetic_pulls_bonuses_from_further_away() {
+        use std::time::Duration;
+
+        let bonus_after_a_moment = |perk_bonuses: PerkBonuses| {
+            let mut app = App::new();
+            app.insert_resource(Time::<()>::default())
+                .add_systems(Update, bonus_attraction);
+            app.world_mut()
+                .spawn((Player::default(), Transform::default(), perk_bonuses));
+            let bonus = app
+                .world_mut()
+                .spawn(BonusBundle::new(BonusType::SmallHealth, Vec3::new(180.0, 0.0, 0.0)))
+                .id();
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(100));
+            app.update();
+            app.world().get::<Transform>(bonus).unwrap().translation.x
+        };
+
+        assert_eq!(bonus_after_a_moment(PerkBonuses::default()), 180.0);
+        let telekinetic = PerkBonuses {
+            telekinetic_range: 200.0,
+            ..default()
+        };
+        assert!(bonus_after_a_moment(telekinetic) < 180.0);
+    }
+
+    #[test]
+    fn pulled_pickups_speed_up_and_never_overshoot() {
+        let mut far = Transform::from_xyz(90.0, 0.0, 0.0);
+        let mut near = Transform::from_xyz(10.0, 0.0, 0.0);
+        assert!(pull_pickup(&mut far, Vec2::ZERO, 100.0, 100.0, 0.05));
+        assert!(pull_pickup(&mut near, Vec2::ZERO, 100.0, 100.0, 0.05));
+        assert!(10.0 - near.translation.x > 90.0 - far.translation.x);
+
+        let mut touching = Transform::from_xyz(1.0, 0.0, 0.0);
+        pull_pickup(&mut touching, Vec2::ZERO, 100.0, 100.0, 1.0);
+        assert_eq!(touching.translation.x, 0.0);
+    }
+
+    #[test]
+    fn shield_does_not_drain_during_perk_select() {
+        use crate::perks::PerkBonuses;
+        use crate::audio::PlaySoundEvent;
+        use crate::player::{
+            apply_player_damage, Health, PlayerConfig, PlayerDamageEvent, PlayerHitBlockedEvent,
+            PlayerHitLandedEvent,
+        };
+        use crate::states::{gameplay_frozen, GameState, PlayingState};
+        use bevy::state::app::StatesPlugin;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .insert_state(GameState::Playing)
+            .add_sub_state::<PlayingState>()
+            .insert_resource(Time::<()>::default())
+            .init_resource::<PlayerConfig>()
+            .add_event::<PlayerDamageEvent>()
+            .add_event::<PlayerHitBlockedEvent>()
+            .add_event::<PlayerHitLandedEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (
+                    update_active_bonus_effects.run_if(not(gameplay_frozen)),
+                    apply_player_damage,
+                )
+                    .chain(),
+            );
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player { index: 0 },
+                Health::new(100.0),
+                PerkBonuses::default(),
+                ActiveBonusEffects {
+                    shield_timer: 6.0,
+                    ..default()
+                },
+            ))
+            .id();
+
+        let advance = |app: &mut App, seconds: f32| {
+            for _ in 0..(seconds * 10.0).round() as u32 {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(100));
+                app.update();
+            }
+        };
+
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<PlayingState>>()
+            .set(PlayingState::PerkSelect);
+        advance(&mut app, 3.0);
+        app.world_mut()
+            .resource_mut::<NextState<PlayingState>>()
+            .set(PlayingState::Active);
+        advance(&mut app, 5.0);
+
+        let remaining = app.world().get::<ActiveBonusEffects>(player).unwrap().shield_timer;
+        assert!((remaining - 1.0).abs() < 0.15, "shield remaining {}", remaining);
+
+        app.world_mut().send_event(PlayerDamageEvent {
+            player_entity: player,
+            damage: 25.0,
+            source: None,
+            unavoidable: false,
+        });
+        app.update();
+        assert_eq!(app.world().get::<Health>(player).unwrap().current, 100.0);
+    }
+
+    #[test]
+    fn bonus_economist_stretches_timed_effects() {
+        let mut app = App::new();
+        app.init_resource::<WeaponRegistry>()
+            .add_event::<BonusCollectedEvent>()
+            .add_event::<WeaponGrantedEvent>()
+            .add_event::<GrantXpEvent>()
+            .add_systems(Update, apply_bonus_effects);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player { index: 0 },
+                Transform::default(),
+                Health::new(100.0),
+                EquippedWeapon::default(),
+                ActiveBonusEffects::default(),
+                PerkBonuses {
+                    bonus_duration_multiplier: 1.5,
+                    ..default()
+                },
+            ))
+            .id();
+        for bonus_type in [BonusType::SpeedBoost, BonusType::Shield] {
+            app.world_mut().send_event(BonusCollectedEvent {
+                player_entity: player,
+                bonus_type,
+                weapon: None,
+                amount: None,
+            });
+        }
+        app.update();
+
+        let effects = app.world().get::<ActiveBonusEffects>(player).unwrap();
+        assert_eq!(effects.speed_boost_timer, 15.0);
+        assert_eq!(effects.shield_timer, 22.5);
+    }
+
+    fn weapon_pickup_app(perk_bonuses: PerkBonuses) -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<WeaponRegistry>()
+            .add_event::<BonusCollectedEvent>()
+            .add_event::<WeaponGrantedEvent>()
+            .add_event::<GrantXpEvent>()
+            .add_systems(Update, apply_bonus_effects);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player { index: 0 },
+                Transform::from_xyz(5.0, 6.0, 0.0),
+                Health::new(100.0),
+                Experience::new(),
+                EquippedWeapon::default(),
+                perk_bonuses,
+            ))
+            .id();
+        app.world_mut().send_event(BonusCollectedEvent {
+            player_entity: player,
+            bonus_type: BonusType::WeaponPickup,
+            weapon: Some(crate::weapons::WeaponId::Shotgun.into()),
+            amount: None,
+        });
+        app.update();
+        (app, player)
+    }
+
+    fn granted(app: &App) -> usize {
+        app.world().resource::<Events<WeaponGrantedEvent>>().len()
+    }
+
+    #[test]
+    fn weapon_pickup_grants_dropped_weapon_with_full_clip() {
+        let perk_bonuses = PerkBonuses {
+            ammo_multiplier: 1.5,
+            ..default()
+        };
+        let (app, player) = weapon_pickup_app(perk_bonuses);
+        let shotgun = WeaponRegistry::new()
+            .get(crate::weapons::WeaponId::Shotgun)
+            .unwrap()
+            .ammo_capacity
+            .unwrap();
+
+        let weapon = app.world().get::<EquippedWeapon>(player).unwrap();
+        assert_eq!(weapon.weapon, crate::weapons::WeaponId::Shotgun.into());
+        assert_eq!(weapon.ammo, Some((shotgun as f32 * 1.5).round() as u32));
+        assert_eq!(granted(&app), 1);
+    }
+
+    #[test]
+    fn favourite_weapon_ignores_weapon_pickups() {
+        let perk_bonuses = PerkBonuses {
+            disable_weapon_bonuses: true,
+            ..default()
+        };
+        let (mut app, player) = weapon_pickup_app(perk_bonuses);
+        assert_eq!(
+            app.world().get::<EquippedWeapon>(player).unwrap().weapon,
+            crate::weapons::WeaponId::Pistol.into()
+        );
+        assert_eq!(granted(&app), 0);
+
+        // No new weapon pickups spawn either
+        app.add_event::<SpawnBonusEvent>()
+            .add_systems(Update, handle_bonus_spawns);
+        for bonus_type in [BonusType::WeaponPickup, BonusType::SmallHealth] {
+            app.world_mut().send_event(SpawnBonusEvent {
+                bonus_type,
+                position: Vec3::ZERO,
+            });
+        }
+        app.update();
+        let spawned: Vec<BonusType> = app
+            .world_mut()
+            .query::<&Bonus>()
+            .iter(app.world())
+            .map(|bonus| bonus.bonus_type)
+            .collect();
+        assert_eq!(spawned, vec![BonusType::SmallHealth]);
+    }
+
+    #[test]
+    fn ammo_bonus_refills_limited_weapons_and_grants_xp_for_the_pistol() {
+        let mut app = App::new();
+        app.init_resource::<WeaponRegistry>()
+            .add_event::<BonusCollectedEvent>()
+            .add_event::<WeaponGrantedEvent>()
+            .add_event::<GrantXpEvent>()
+            .add_event::<crate::player::PlayerLevelUpEvent>()
+            .init_resource::<crate::player::PlayerConfig>()
+            .add_systems(
+                Update,
+                (bonus_collection, apply_bonus_effects, crate::player::apply_experience_grants).chain(),
+            );
+        let shotgun = WeaponRegistry::new()
+            .get(crate::weapons::WeaponId::Shotgun)
+            .unwrap()
+            .ammo_capacity
+            .unwrap();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player { index: 0 },
+                Transform::default(),
+                Health::new(100.0),
+                Experience::new(),
+                EquippedWeapon::new(crate::weapons::WeaponId::Shotgun, Some(0)),
+                PerkBonuses {
+                    ammo_multiplier: 2.0,
+                    ..default()
+                },
+            ))
+            .id();
+        let collect = |app: &mut App| {
+            app.world_mut().spawn((
+                Bonus {
+                    bonus_type: BonusType::Ammo,
+                },
+                Transform::default(),
+            ));
+            app.update();
+            let events = app.world().resource::<Events<BonusCollectedEvent>>();
+            events.get_reader().read(events).last().and_then(|event| event.amount)
+        };
+
+        let refill = (shotgun as f32 * AMMO_REFILL_FRACTION * 2.0).ceil() as u32;
+        assert_eq!(collect(&mut app), Some(refill));
+        assert_eq!(app.world().get::<EquippedWeapon>(player).unwrap().ammo, Some(refill));
+
+        // Never past a full clip
+        app.world_mut().get_mut::<EquippedWeapon>(player).unwrap().ammo = Some(shotgun * 2 - 1);
+        assert_eq!(collect(&mut app), Some(1));
+        assert_eq!(app.world().get::<EquippedWeapon>(player).unwrap().ammo, Some(shotgun * 2));
+
+        *app.world_mut().get_mut::<EquippedWeapon>(player).unwrap() = EquippedWeapon::default();
+        assert_eq!(collect(&mut app), Some(AMMO_BONUS_EXP));
+        assert_eq!(app.world().get::<Experience>(player).unwrap().current, AMMO_BONUS_EXP);
+        assert_eq!(app.world().get::<EquippedWeapon>(player).unwrap().ammo, None);
+    }
 }
@@ -0,0 +1,357 @@
+//! Boss phase scripts: each boss escalates through phases as its health drops.
+//! Phases are attached at spawn, so every mode that spawns a boss gets the same fight
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::components::{
+    AIMode, AIState, Creature, CreatureHealth, CreatureType, EnemyProjectileBundle, EnemyRangedAttack,
+    MarkedForDespawn, WorldTimeScale,
+};
+use super::systems::SpawnCreatureEvent;
+use crate::audio::{PlaySoundEvent, SoundEffect};
+use crate::effects::ScreenShake;
+use crate::player::components::Player;
+use crate::weapons::ProjectilePool;
+
+/// Seconds between a BossSpider's broods of Spider adds
+pub const BOSS_SPIDER_ADD_INTERVAL: f32 = 5.0;
+
+/// Spiders a BossSpider calls in per brood
+pub const BOSS_SPIDER_ADDS: usize = 2;
+
+/// Seconds between BossSpider lunges once it is below half health
+pub const BOSS_SPIDER_LUNGE_INTERVAL: f32 = 3.0;
+
+/// Speed of a BossSpider lunge, on top of its walk
+pub const BOSS_SPIDER_LUNGE_SPEED: f32 = 450.0;
+
+/// Seconds a BossSpider lunge lasts
+pub const BOSS_SPIDER_LUNGE_TIME: f32 = 0.4;
+
+/// Seconds between BossAlien barrages
+pub const BOSS_ALIEN_BARRAGE_INTERVAL: f32 = 3.0;
+
+/// Projectiles in a BossAlien barrage per phase
+pub const BOSS_ALIEN_BARRAGE_SHOTS: usize = 8;
+
+/// Shots of a BossAlien barrage
+pub const BOSS_ALIEN_BARRAGE: EnemyRangedAttack = EnemyRangedAttack {
+    cooldown: BOSS_ALIEN_BARRAGE_INTERVAL,
+    projectile_speed: 220.0,
+    damage: 12.0,
+    range: 500.0,
+};
+
+/// Seconds between BossNest spawns in its first phase; each later phase divides it
+pub const BOSS_NEST_SPAWN_INTERVAL: f32 = 4.0;
+
+/// Creatures a BossNest hatches, picked at random
+pub const BOSS_NEST_BROOD: [CreatureType; 2] = [CreatureType::Spider, CreatureType::AlienSpider];
+
+/// How far from a boss its adds appear
+pub const BOSS_ADD_OFFSET: f32 = 50.0;
+
+/// Screen shake (intensity, seconds) when a boss enters a new phase
+pub const BOSS_PHASE_SHAKE: (f32, f32) = (12.0, 0.6);
+
+/// Health fractions below which a boss enters each phase after the first
+pub fn phase_thresholds(creature_type: CreatureType) -> &'static [f32] {
+    match creature_type {
+        CreatureType::BossSpider => &[0.5],
+        CreatureType::BossAlien => &[0.66, 0.33],
+        CreatureType::BossNest => &[0.5, 0.25],
+        _ => &[],
+    }
+}
+
+/// Phase a boss is in at `health_fraction`, starting from 1
+pub fn phase_for(creature_type: CreatureType, health_fraction: f32) -> u32 {
+    1 + phase_thresholds(creature_type)
+        .iter()
+        .filter(|&&threshold| health_fraction < threshold)
+        .count() as u32
+}
+
+/// Seconds between a boss's scripted actions (adds, barrages, spawns) in `phase`
+pub fn action_interval(creature_type: CreatureType, phase: u32) -> f32 {
+    match creature_type {
+        CreatureType::BossSpider => BOSS_SPIDER_ADD_INTERVAL,
+        CreatureType::BossAlien => BOSS_ALIEN_BARRAGE_INTERVAL,
+        CreatureType::BossNest => BOSS_NEST_SPAWN_INTERVAL / phase as f32,
+        _ => f32::INFINITY,
+    }
+}
+
+/// Projectiles in a BossAlien barrage during `phase`
+pub fn barrage_shots(phase: u32) -> usize {
+    BOSS_ALIEN_BARRAGE_SHOTS * phase as usize
+}
+
+/// A boss's current phase and the timers of its script
+#[derive(Component, Debug, Clone)]
+pub struct BossPhase {
+    /// Starts at 1 and only goes up
+    pub phase: u32,
+    /// Seconds until the next add brood, barrage or spawn
+    pub action_timer: f32,
+    /// Seconds until the next lunge (BossSpider from phase 2)
+    pub lunge_timer: f32,
+    /// Direction and seconds left of a lunge in progress
+    pub lunge: Option<(Vec2, f32)>,
+}
+
+impl BossPhase {
+    pub fn new(creature_type: CreatureType) -> Self {
+        Self {
+            phase: 1,
+            action_timer: action_interval(creature_type, 1),
+            lunge_timer: BOSS_SPIDER_LUNGE_INTERVAL,
+            lunge: None,
+        }
+    }
+}
+
+/// Moves bosses into a new phase as their health drops, with a roar and screen shake
+pub fn boss_phase_transitions(
+    mut boss_query: Query<(&Transform, &Creature, &CreatureHealth, &mut BossPhase), Without<MarkedForDespawn>>,
+    mut shake: ResMut<ScreenShake>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for (transform, creature, health, mut phase) in boss_query.iter_mut() {
+        if health.is_dead() {
+            continue;
+        }
+        let next = phase_for(creature.creature_type, health.percentage());
+        if next <= phase.phase {
+            continue;
+        }
+        phase.phase = next;
+        // A faster cadence starts right away instead of after the old wait
+        phase.action_timer = phase.action_timer.min(action_interval(creature.creature_type, next));
+        shake.add(BOSS_PHASE_SHAKE.0, BOSS_PHASE_SHAKE.1);
+        sound_events.send(PlaySoundEvent {
+            sound: SoundEffect::BossRoar,
+            position: Some(transform.translation.truncate()),
+        });
+    }
+}
+
+/// Runs each boss's script: BossSpider calls in Spiders and lunges below half health,
+/// BossAlien fires radial barrages that thicken each phase, and BossNest hatches
+/// creatures faster the more it is hurt
+#[allow(clippy::type_complexity)]
+pub fn run_boss_scripts(
+    mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
+    mut boss_query: Query<(Entity, &mut Transform, &Creature, &AIState, &mut BossPhase), Without<MarkedForDespawn>>,
+    player_query: Query<&Transform, (With<Player>, Without<Creature>)>,
+    time: Res<Time>,
+    time_scale: Res<WorldTimeScale>,
+    mut spawn_events: EventWriter<SpawnCreatureEvent>,
+) {
+    let delta = time_scale.delta(&time);
+    let mut rng = rand::thread_rng();
+
+    for (entity, mut transform, creature, ai_state, mut phase) in boss_query.iter_mut() {
+        if ai_state.mode == AIMode::Dead {
+            continue;
+        }
+        let creature_type = creature.creature_type;
+        let position = transform.translation;
+
+        if creature_type == CreatureType::BossSpider && phase.phase >= 2 {
+            if let Some((direction, remaining)) = phase.lunge.as_mut() {
+                transform.translation += (*direction * BOSS_SPIDER_LUNGE_SPEED * delta).extend(0.0);
+                *remaining -= delta;
+                if *remaining <= 0.0 {
+                    phase.lunge = None;
+                }
+            } else {
+                phase.lunge_timer -= delta;
+                let nearest = player_query
+                    .iter()
+                    .map(|player| player.translation.truncate())
+                    .min_by(|a, b| {
+                        position.truncate().distance_squared(*a).total_cmp(&position.truncate().distance_squared(*b))
+                    });
+                if let Some(target) = nearest.filter(|_| phase.lunge_timer <= 0.0) {
+                    phase.lunge = Some(((target - position.truncate()).normalize_or_zero(), BOSS_SPIDER_LUNGE_TIME));
+                    phase.lunge_timer = BOSS_SPIDER_LUNGE_INTERVAL;
+                }
+            }
+        }
+
+        phase.action_timer -= delta;
+        if phase.action_timer > 0.0 {
+            continue;
+        }
+        phase.action_timer += action_interval(creature_type, phase.phase);
+
+        let around = |rng: &mut rand::rngs::ThreadRng| {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            position + (Vec2::from_angle(angle) * BOSS_ADD_OFFSET).extend(0.0)
+        };
+        match creature_type {
+            CreatureType::BossSpider => {
+                for _ in 0..BOSS_SPIDER_ADDS {
+                    spawn_events.send(SpawnCreatureEvent {
+                        creature_type: CreatureType::Spider,
+                        position: Some(around(&mut rng)),
+                        summoner: None,
+                    });
+                }
+            }
+            CreatureType::BossAlien => {
+                let shots = barrage_shots(phase.phase);
+                let start = rng.gen_range(0.0..std::f32::consts::TAU);
+                for shot in 0..shots {
+                    let angle = start + shot as f32 / shots as f32 * std::f32::consts::TAU;
+                    pool.spawn(
+                        &mut commands,
+                        EnemyProjectileBundle::new(&BOSS_ALIEN_BARRAGE, entity, position, Vec2::from_angle(angle)),
+                    );
+                }
+            }
+            CreatureType::BossNest => {
+                spawn_events.send(SpawnCreatureEvent {
+                    creature_type: BOSS_NEST_BROOD[rng.gen_range(0..BOSS_NEST_BROOD.len())],
+                    position: Some(around(&mut rng)),
+                    summoner: None,
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::creatures::{CreatureBundle, EnemyProjectile};
+
+    fn boss_app(creature_type: CreatureType) -> (App, Entity) {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<WorldTimeScale>()
+            .init_resource::<ScreenShake>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<PlaySoundEvent>()
+            .add_event::<SpawnCreatureEvent>()
+            .add_systems(Update, (boss_phase_transitions, run_boss_scripts).chain());
+        app.world_mut().spawn((Player::default(), Transform::from_xyz(300.0, 0.0, 0.0)));
+        let boss = app
+            .world_mut()
+            .spawn((CreatureBundle::new(creature_type, Vec3::ZERO), BossPhase::new(creature_type)))
+            .id();
+        (app, boss)
+    }
+
+    fn set_health(app: &mut App, boss: Entity, fraction: f32) {
+        let mut health = app.world_mut().get_mut::<CreatureHealth>(boss).unwrap();
+        health.current = health.max * fraction;
+    }
+
+    fn advance(app: &mut App, seconds: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(seconds));
+        app.update();
+    }
+
+    fn roars(app: &mut App) -> usize {
+        app.world_mut()
+            .resource_mut::<Events<PlaySoundEvent>>()
+            .drain()
+            .filter(|event| event.sound == SoundEffect::BossRoar)
+            .count()
+    }
+
+    fn spawned(app: &mut App) -> Vec<CreatureType> {
+        app.world_mut()
+            .resource_mut::<Events<SpawnCreatureEvent>>()
+            .drain()
+            .map(|event| event.creature_type)
+            .collect()
+    }
+
+    #[test]
+    fn phases_follow_health_thresholds() {
+        assert_eq!(phase_for(CreatureType::BossSpider, 1.0), 1);
+        assert_eq!(phase_for(CreatureType::BossSpider, 0.49), 2);
+        assert_eq!(phase_for(CreatureType::BossAlien, 0.5), 2);
+        assert_eq!(phase_for(CreatureType::BossAlien, 0.1), 3);
+        assert_eq!(phase_for(CreatureType::BossNest, 0.2), 3);
+        assert_eq!(phase_for(CreatureType::Zombie, 0.1), 1);
+    }
+
+    #[test]
+    fn entering_a_phase_roars_and_shakes_once() {
+        let (mut app, boss) = boss_app(CreatureType::BossSpider);
+        app.update();
+        assert_eq!(roars(&mut app), 0);
+        assert_eq!(app.world().resource::<ScreenShake>().intensity, 0.0);
+
+        set_health(&mut app, boss, 0.4);
+        app.update();
+        assert_eq!(app.world().get::<BossPhase>(boss).unwrap().phase, 2);
+        assert_eq!(roars(&mut app), 1);
+        assert!(app.world().resource::<ScreenShake>().intensity > 0.0);
+
+        app.update();
+        assert_eq!(roars(&mut app), 0);
+    }
+
+    #[test]
+    fn boss_spider_calls_spiders_and_lunges_below_half_health() {
+        let (mut app, boss) = boss_app(CreatureType::BossSpider);
+        advance(&mut app, BOSS_SPIDER_ADD_INTERVAL);
+        assert_eq!(spawned(&mut app), vec![CreatureType::Spider; BOSS_SPIDER_ADDS]);
+        assert!(app.world().get::<BossPhase>(boss).unwrap().lunge.is_none());
+
+        set_health(&mut app, boss, 0.4);
+        advance(&mut app, BOSS_SPIDER_LUNGE_INTERVAL);
+        let (direction, _) = app.world().get::<BossPhase>(boss).unwrap().lunge.unwrap();
+        assert!(direction.abs_diff_eq(Vec2::X, 1e-5));
+        advance(&mut app, 0.1);
+        assert!(app.world().get::<Transform>(boss).unwrap().translation.x > 0.0);
+    }
+
+    #[test]
+    fn boss_alien_barrages_thicken_each_phase() {
+        let (mut app, boss) = boss_app(CreatureType::BossAlien);
+        let mut barrages = Vec::new();
+        for fraction in [1.0, 0.5, 0.2] {
+            set_health(&mut app, boss, fraction);
+            advance(&mut app, BOSS_ALIEN_BARRAGE_INTERVAL);
+            let mut projectiles = app.world_mut().query_filtered::<Entity, With<EnemyProjectile>>();
+            let fired: Vec<Entity> = projectiles.iter(app.world()).collect();
+            barrages.push(fired.len());
+            for projectile in fired {
+                app.world_mut().despawn(projectile);
+            }
+        }
+        assert_eq!(barrages, vec![barrage_shots(1), barrage_shots(2), barrage_shots(3)]);
+    }
+
+    #[test]
+    fn boss_nest_stays_put_and_spawns_faster_when_hurt() {
+        let spawns_in = |fraction: f32| {
+            let (mut app, boss) = boss_app(CreatureType::BossNest);
+            set_health(&mut app, boss, fraction);
+            app.update();
+            let mut count = 0;
+            for _ in 0..40 {
+                advance(&mut app, 0.25);
+                let brood = spawned(&mut app);
+                assert!(brood.iter().all(|creature| BOSS_NEST_BROOD.contains(creature)));
+                count += brood.len();
+            }
+            assert_eq!(app.world().get::<Transform>(boss).unwrap().translation.truncate(), Vec2::ZERO);
+            count
+        };
+        assert_eq!(spawns_in(1.0), 2);
+        assert_eq!(spawns_in(0.4), 5);
+        assert_eq!(spawns_in(0.2), 7);
+    }
+}
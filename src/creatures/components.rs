@@ -3,6 +3,10 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::status::StatusEffects;
+use crate::render_layers::{on_layer, CREATURES, PROJECTILES};
+use crate::weapons::{Lifetime, Velocity};
+
 /// Types of creatures in the game
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CreatureType {
@@ -30,6 +34,8 @@ pub enum CreatureType {
     Ghost,
     Exploder,
     Splitter,
+    /// Smaller half of a Splitter; does not split again
+    SplitterSpawn,
 
     // Bosses
     BossSpider,
@@ -55,6 +61,7 @@ impl CreatureType {
             CreatureType::Ghost => 50.0,
             CreatureType::Exploder => 15.0,
             CreatureType::Splitter => 40.0,
+            CreatureType::SplitterSpawn => 16.0,
             CreatureType::BossSpider => 500.0,
             CreatureType::BossAlien => 800.0,
             CreatureType::BossNest => 1000.0,
@@ -78,6 +85,7 @@ impl CreatureType {
             CreatureType::Ghost => 70.0,
             CreatureType::Exploder => 100.0,
             CreatureType::Splitter => 60.0,
+            CreatureType::SplitterSpawn => 75.0,
             CreatureType::BossSpider => 40.0,
             CreatureType::BossAlien => 50.0,
             CreatureType::BossNest => 0.0,
@@ -101,6 +109,7 @@ impl CreatureType {
             CreatureType::Ghost => 15.0,
             CreatureType::Exploder => 50.0,
             CreatureType::Splitter => 15.0,
+            CreatureType::SplitterSpawn => 8.0,
             CreatureType::BossSpider => 40.0,
             CreatureType::BossAlien => 50.0,
             CreatureType::BossNest => 0.0,
@@ -124,6 +133,7 @@ impl CreatureType {
             CreatureType::Ghost => 35,
             CreatureType::Exploder => 20,
             CreatureType::Splitter => 25,
+            CreatureType::SplitterSpawn => 10,
             CreatureType::BossSpider => 500,
             CreatureType::BossAlien => 800,
             CreatureType::BossNest => 1000,
@@ -136,6 +146,98 @@ impl CreatureType {
             CreatureType::BossSpider | CreatureType::BossAlien | CreatureType::BossNest
         )
     }
+
+    /// Bosses can be staggered but not shoved
+    pub fn knockback_immune(&self) -> bool {
+        self.is_boss()
+    }
+
+    /// Resistance to being shoved by hits; knockback is divided by it.
+    /// Stationary creatures cannot be moved at all.
+    pub fn mass(&self) -> f32 {
+        match self {
+            CreatureType::Zombie => 1.0,
+            CreatureType::Spider => 0.6,
+            CreatureType::Lizard => 0.8,
+            CreatureType::Beetle => 1.5,
+            CreatureType::AlienSpider => 0.8,
+            CreatureType::Giant => 4.0,
+            CreatureType::Necromancer => 1.2,
+            CreatureType::GiantSpider => 3.0,
+            CreatureType::Dog => 0.7,
+            CreatureType::Runner => 0.6,
+            CreatureType::AlienShooter => 1.0,
+            CreatureType::Turret => f32::INFINITY,
+            CreatureType::Ghost => 0.5,
+            CreatureType::Exploder => 0.8,
+            CreatureType::Splitter => 1.2,
+            CreatureType::SplitterSpawn => 0.6,
+            CreatureType::BossSpider => 15.0,
+            CreatureType::BossAlien => 15.0,
+            CreatureType::BossNest => f32::INFINITY,
+        }
+    }
+
+    /// Radius other creatures are pushed out of when crowding this one
+    pub fn personal_space(&self) -> f32 {
+        match self {
+            _ if self.is_boss() => 36.0,
+            CreatureType::Giant | CreatureType::GiantSpider => 26.0,
+            CreatureType::Spider | CreatureType::Beetle | CreatureType::SplitterSpawn => 11.0,
+            _ => 15.0,
+        }
+    }
+
+    /// Seconds between contact attacks
+    pub fn attack_cooldown(&self) -> f32 {
+        match self {
+            _ if self.is_boss() => 1.2,
+            CreatureType::Giant | CreatureType::GiantSpider => 1.5,
+            CreatureType::Spider | CreatureType::Dog | CreatureType::Runner | CreatureType::SplitterSpawn => 0.8,
+            _ => 1.0,
+        }
+    }
+
+    /// Seconds a creature telegraphs a contact attack before it lands
+    pub fn attack_windup(&self) -> f32 {
+        match self {
+            _ if self.is_boss() => 0.4,
+            CreatureType::Giant | CreatureType::GiantSpider => 0.5,
+            CreatureType::Spider
+            | CreatureType::Beetle
+            | CreatureType::Dog
+            | CreatureType::Runner
+            | CreatureType::SplitterSpawn => 0.15,
+            _ => 0.3,
+        }
+    }
+}
+
+#[cfg(test)]
+impl CreatureType {
+    pub fn all() -> &'static [CreatureType] {
+        &[
+            CreatureType::Zombie,
+            CreatureType::Spider,
+            CreatureType::Lizard,
+            CreatureType::Beetle,
+            CreatureType::AlienSpider,
+            CreatureType::Giant,
+            CreatureType::Necromancer,
+            CreatureType::GiantSpider,
+            CreatureType::Dog,
+            CreatureType::Runner,
+            CreatureType::AlienShooter,
+            CreatureType::Turret,
+            CreatureType::Ghost,
+            CreatureType::Exploder,
+            CreatureType::Splitter,
+            CreatureType::SplitterSpawn,
+            CreatureType::BossSpider,
+            CreatureType::BossAlien,
+            CreatureType::BossNest,
+        ]
+    }
 }
 
 /// AI behavior modes
@@ -171,6 +273,8 @@ pub struct AIState {
     pub wander_timer: f32,
     /// Time since last attack
     pub attack_cooldown: f32,
+    /// Time until the target is re-picked
+    pub retarget_timer: f32,
 }
 
 /// Creature health (separate from player health for potential different behavior)
@@ -210,6 +314,66 @@ pub struct CreatureSpeed(pub f32);
 #[derive(Component, Debug, Clone)]
 pub struct ContactDamage(pub f32);
 
+/// How a creature bites: configured per creature type in the creature registry.
+/// The damage actually dealt is the live [`ContactDamage`], which shrinking scales
+#[derive(Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AttackStats {
+    pub damage: f32,
+    /// Seconds after a bite before the next windup can start
+    pub cooldown: f32,
+    /// Seconds from entering range to the bite landing
+    pub windup: f32,
+}
+
+impl AttackStats {
+    /// Built-in attack of a creature type
+    pub fn for_type(creature_type: CreatureType) -> Self {
+        Self {
+            damage: creature_type.base_damage(),
+            cooldown: creature_type.attack_cooldown(),
+            windup: creature_type.attack_windup(),
+        }
+    }
+}
+
+/// Size a creature swells to while rearing back for a bite
+pub const ATTACK_WINDUP_SCALE: f32 = 1.2;
+
+/// A contact attack being telegraphed; knockback, freezing or a stagger cancels it
+#[derive(Component, Debug, Clone)]
+pub struct AttackWindup {
+    pub target: Entity,
+    pub remaining: f32,
+    pub duration: f32,
+    /// Scale multiplier currently applied to the transform, divided back out when the windup ends
+    pub applied_scale: f32,
+}
+
+impl AttackWindup {
+    pub fn new(target: Entity, duration: f32) -> Self {
+        Self {
+            target,
+            remaining: duration,
+            duration,
+            applied_scale: 1.0,
+        }
+    }
+
+    pub fn tick(&mut self, delta: f32) {
+        self.remaining -= delta;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.remaining <= 0.0
+    }
+
+    /// Rear-back scale for the current point of the windup, swelling toward [`ATTACK_WINDUP_SCALE`]
+    pub fn scale(&self) -> f32 {
+        let progress = (1.0 - self.remaining / self.duration.max(f32::EPSILON)).clamp(0.0, 1.0);
+        1.0 + (ATTACK_WINDUP_SCALE - 1.0) * progress
+    }
+}
+
 /// Experience granted when killed
 #[derive(Component, Debug, Clone)]
 pub struct ExperienceValue(pub u32);
@@ -218,6 +382,366 @@ pub struct ExperienceValue(pub u32);
 #[derive(Component)]
 pub struct MarkedForDespawn;
 
+/// Shoots at players in range; configured per creature type in the creature registry
+#[derive(Component, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EnemyRangedAttack {
+    /// Seconds between shots
+    pub cooldown: f32,
+    pub projectile_speed: f32,
+    pub damage: f32,
+    pub range: f32,
+}
+
+/// Seconds until a ranged creature can shoot again, in world time
+#[derive(Component, Debug, Clone, Default)]
+pub struct RangedAttackCooldown(pub f32);
+
+/// Color of creature projectiles, distinct from every player weapon
+pub const ENEMY_PROJECTILE_COLOR: Color = Color::srgb(0.9, 0.2, 0.9);
+
+/// Sprite size of creature projectiles
+pub const ENEMY_PROJECTILE_SIZE: f32 = 10.0;
+
+/// Projectile fired by a creature; it only collides with players
+#[derive(Component, Debug, Clone)]
+pub struct EnemyProjectile {
+    pub damage: f32,
+    /// Creature that fired it
+    pub source: Entity,
+}
+
+/// Bundle for spawning creature projectiles
+#[derive(Bundle)]
+pub struct EnemyProjectileBundle {
+    pub projectile: EnemyProjectile,
+    pub velocity: Velocity,
+    pub lifetime: Lifetime,
+    pub sprite: SpriteBundle,
+}
+
+impl EnemyProjectileBundle {
+    /// A shot from `source` at `position` toward `direction`; it flies a bit past the attack's range
+    pub fn new(attack: &EnemyRangedAttack, source: Entity, position: Vec3, direction: Vec2) -> Self {
+        let direction = direction.normalize_or_zero();
+        Self {
+            projectile: EnemyProjectile {
+                damage: attack.damage,
+                source,
+            },
+            velocity: Velocity(direction * attack.projectile_speed),
+            lifetime: Lifetime::new(1.5 * attack.range / attack.projectile_speed.max(1.0)),
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: ENEMY_PROJECTILE_COLOR,
+                    custom_size: Some(Vec2::splat(ENEMY_PROJECTILE_SIZE)),
+                    ..default()
+                },
+                transform: Transform::from_translation(on_layer(position, PROJECTILES)),
+                ..default()
+            },
+        }
+    }
+}
+
+/// Seconds a spawn warning shows before its creature appears
+pub const SPAWN_WARNING_TIME: f32 = 0.5;
+
+/// Color of the marker where a creature is about to appear
+pub const SPAWN_WARNING_COLOR: Color = Color::srgb(1.0, 0.15, 0.1);
+
+/// Marker where a creature is about to appear; the creature spawns when it runs out
+#[derive(Component, Debug, Clone)]
+pub struct SpawnWarning {
+    pub creature_type: CreatureType,
+    /// Necromancer that summoned it, which counts it as a minion
+    pub summoner: Option<Entity>,
+    pub remaining: f32,
+}
+
+/// Bundle for spawning a spawn warning marker
+#[derive(Bundle)]
+pub struct SpawnWarningBundle {
+    pub warning: SpawnWarning,
+    pub sprite: SpriteBundle,
+}
+
+impl SpawnWarningBundle {
+    pub fn new(creature_type: CreatureType, position: Vec3, summoner: Option<Entity>) -> Self {
+        Self {
+            warning: SpawnWarning {
+                creature_type,
+                summoner,
+                remaining: SPAWN_WARNING_TIME,
+            },
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: SPAWN_WARNING_COLOR,
+                    custom_size: Some(Vec2::splat(12.0)),
+                    ..default()
+                },
+                // Turned on its corner so it reads as a diamond
+                transform: Transform::from_translation(on_layer(position, CREATURES))
+                    .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                ..default()
+            },
+        }
+    }
+}
+
+/// Distance to a player at which an Exploder lights its fuse
+pub const EXPLODER_TRIGGER_RANGE: f32 = 60.0;
+
+/// Seconds from lighting the fuse to the blast
+pub const EXPLODER_FUSE_TIME: f32 = 0.7;
+
+/// Blast radius of an Exploder whose fuse runs out; damage is its contact damage
+pub const EXPLODER_BLAST_RADIUS: f32 = 120.0;
+
+/// Blast radius and damage multiplier when an Exploder is killed before going off
+pub const EXPLODER_DUD_SCALE: f32 = 0.5;
+
+/// Tint flashes per second while the fuse burns
+pub const EXPLODER_FLASH_RATE: f32 = 8.0;
+
+/// Tint of a lit Exploder on the "on" beat of its flash
+pub const EXPLODER_FUSE_TINT: Color = Color::srgb(1.0, 0.0, 0.0);
+
+/// An Exploder's lit fuse; it stands still and flashes until the blast
+#[derive(Component, Debug, Clone)]
+pub struct ExploderFuse {
+    pub remaining: f32,
+    /// Sprite color to flash back to
+    pub original_color: Color,
+}
+
+impl ExploderFuse {
+    pub fn new(original_color: Color) -> Self {
+        Self {
+            remaining: EXPLODER_FUSE_TIME,
+            original_color,
+        }
+    }
+
+    pub fn tick(&mut self, delta: f32) {
+        self.remaining -= delta;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.remaining <= 0.0
+    }
+
+    /// Color the sprite shows right now
+    pub fn color(&self) -> Color {
+        let burnt = EXPLODER_FUSE_TIME - self.remaining;
+        if (burnt * EXPLODER_FLASH_RATE).fract() < 0.5 {
+            EXPLODER_FUSE_TINT
+        } else {
+            self.original_color
+        }
+    }
+}
+
+/// Necromancers flee players closer than this
+pub const NECROMANCER_MIN_RANGE: f32 = 250.0;
+
+/// Necromancers close in on players farther than this
+pub const NECROMANCER_MAX_RANGE: f32 = 400.0;
+
+/// Seconds between a Necromancer's summoning rituals
+pub const NECROMANCER_SUMMON_INTERVAL: f32 = 6.0;
+
+/// Seconds a Necromancer stands still channeling before its minions appear
+pub const NECROMANCER_CHANNEL_TIME: f32 = 1.0;
+
+/// Most live minions one Necromancer can have
+pub const NECROMANCER_MAX_MINIONS: usize = 6;
+
+/// A Necromancer's summoning ritual and the minions it has raised
+#[derive(Component, Debug, Clone)]
+pub struct Summoner {
+    /// Seconds until the next ritual starts
+    pub next_summon_in: f32,
+    /// Seconds left in the ritual being channeled
+    pub channel_remaining: Option<f32>,
+    /// Live minions; dead ones are pruned each frame
+    pub minions: Vec<Entity>,
+}
+
+impl Default for Summoner {
+    fn default() -> Self {
+        Self {
+            next_summon_in: NECROMANCER_SUMMON_INTERVAL,
+            channel_remaining: None,
+            minions: Vec::new(),
+        }
+    }
+}
+
+impl Summoner {
+    pub fn is_channeling(&self) -> bool {
+        self.channel_remaining.is_some()
+    }
+
+    /// Minions it can still raise before hitting the cap
+    pub fn room(&self) -> usize {
+        NECROMANCER_MAX_MINIONS.saturating_sub(self.minions.len())
+    }
+}
+
+/// Seconds a Ghost spends in each of its solid and phased states
+pub const GHOST_PHASE_DURATION: f32 = 2.0;
+
+/// Sprite alpha of a phased Ghost (70% transparent)
+pub const GHOST_PHASED_ALPHA: f32 = 0.3;
+
+/// Sprite alpha of a phased Ghost while a player has MonsterVision
+pub const GHOST_REVEALED_ALPHA: f32 = 0.6;
+
+/// Sprite alpha of a solid Ghost
+pub const GHOST_SOLID_ALPHA: f32 = 0.8;
+
+/// A Ghost's cycle between solid and phased. Phased, projectiles pass through it
+/// and its touch does no harm
+#[derive(Component, Debug, Clone)]
+pub struct GhostPhase {
+    pub phased: bool,
+    /// Seconds until it switches state
+    pub remaining: f32,
+}
+
+impl Default for GhostPhase {
+    fn default() -> Self {
+        Self {
+            phased: false,
+            remaining: GHOST_PHASE_DURATION,
+        }
+    }
+}
+
+impl GhostPhase {
+    /// Advances the cycle, returning true when the Ghost switched state
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.remaining -= delta;
+        if self.remaining > 0.0 {
+            return false;
+        }
+        self.phased = !self.phased;
+        self.remaining += GHOST_PHASE_DURATION;
+        true
+    }
+
+    pub fn is_phased(&self) -> bool {
+        self.phased
+    }
+}
+
+/// Shove velocity that decays over time (last-stand push, projectile hits)
+#[derive(Component, Debug, Clone)]
+pub struct Knockback {
+    pub velocity: Vec2,
+}
+
+/// Creature is reeling and cannot attack until this runs out
+#[derive(Component, Debug, Clone)]
+pub struct Staggered {
+    pub remaining: f32,
+}
+
+/// Held in place by a player's EvilEyes stare; lingers briefly once the aim moves off
+#[derive(Component, Debug, Clone)]
+pub struct Stared {
+    pub by: Entity,
+    /// Seconds left once no longer aimed at
+    pub linger: f32,
+    /// Movement multiplier while stared at: bosses only slow down
+    pub speed_multiplier: f32,
+}
+
+/// Seconds a creature flashes after taking a hit
+pub const HIT_FLASH_TIME: f32 = 0.08;
+
+/// Seconds a non-boss creature stops moving after taking a hit
+pub const HIT_STAGGER_TIME: f32 = 0.05;
+
+/// Color a freshly hit creature flashes toward
+pub const HIT_FLASH_COLOR: Color = Color::WHITE;
+
+/// A creature flashing from a fresh hit; further hits refresh it rather than stack.
+///
+/// Tint priority, highest first: hit flash, frozen, infected, the creature's own color.
+/// Status systems resolve frozen over infected between themselves; the flash is layered
+/// on top at presentation and lifted again before they run, so they never see it
+#[derive(Component, Debug, Clone)]
+pub struct HitFlash {
+    pub remaining: f32,
+    /// Seconds left with movement stopped; always zero for bosses
+    pub stagger: f32,
+    /// Sprite color under the flash, status tints included
+    pub underlying: Color,
+}
+
+impl HitFlash {
+    pub fn new(creature_type: CreatureType, underlying: Color) -> Self {
+        let mut flash = Self {
+            remaining: 0.0,
+            stagger: 0.0,
+            underlying,
+        };
+        flash.refresh(creature_type);
+        flash
+    }
+
+    /// Another hit: restarts the flash and stagger without stacking them
+    pub fn refresh(&mut self, creature_type: CreatureType) {
+        self.remaining = HIT_FLASH_TIME;
+        self.stagger = if creature_type.is_boss() { 0.0 } else { HIT_STAGGER_TIME };
+    }
+
+    pub fn tick(&mut self, delta: f32) {
+        self.remaining -= delta;
+        self.stagger = (self.stagger - delta).max(0.0);
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.remaining <= 0.0
+    }
+
+    pub fn is_staggering(&self) -> bool {
+        self.stagger > 0.0
+    }
+
+    /// Color the sprite shows right now: the underlying color fading in from the flash
+    pub fn color(&self) -> Color {
+        let strength = (self.remaining / HIT_FLASH_TIME).clamp(0.0, 1.0);
+        let alpha = self.underlying.alpha();
+        self.underlying.mix(&HIT_FLASH_COLOR, strength).with_alpha(alpha)
+    }
+}
+
+/// World speed during the SlowMotion bonus
+pub const SLOW_MOTION_TIME_SCALE: f32 = 0.3;
+
+/// How fast the world runs relative to the player. Creature movement, attacks and
+/// spawn timers tick on scaled time; the player's own movement and firing don't
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct WorldTimeScale(pub f32);
+
+impl Default for WorldTimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl WorldTimeScale {
+    /// This frame's delta in world time
+    pub fn delta(&self, time: &Time) -> f32 {
+        time.delta_seconds() * self.0
+    }
+}
+
+/// Tint applied to a frozen creature's sprite
+pub const FROZEN_TINT: Color = Color::srgb(0.45, 0.7, 1.0);
+
 /// Status effect: creature is frozen/slowed
 #[derive(Component, Debug, Clone)]
 pub struct FrozenStatus {
@@ -227,17 +751,27 @@ pub struct FrozenStatus {
     pub original_speed: f32,
     /// Current slow amount (0.0 = full stop, 1.0 = no slow)
     pub slow_multiplier: f32,
+    /// Sprite color to restore when the effect ends
+    pub original_color: Color,
 }
 
 impl FrozenStatus {
-    pub fn new(duration: f32, original_speed: f32, slow_multiplier: f32) -> Self {
+    pub fn new(duration: f32, original_speed: f32, slow_multiplier: f32, original_color: Color) -> Self {
         Self {
             remaining_duration: duration,
             original_speed,
             slow_multiplier,
+            original_color,
         }
     }
 
+    /// Another freezing hit: tops the duration back up and keeps the strongest
+    /// slow, without compounding the multipliers
+    pub fn refresh(&mut self, duration: f32, slow_multiplier: f32) {
+        self.remaining_duration = self.remaining_duration.max(duration);
+        self.slow_multiplier = self.slow_multiplier.min(slow_multiplier);
+    }
+
     pub fn tick(&mut self, delta: f32) {
         self.remaining_duration -= delta;
     }
@@ -247,6 +781,42 @@ impl FrozenStatus {
     }
 }
 
+/// Smallest a creature can be shrunk to; at this size it can be squashed
+pub const SHRINK_FLOOR: f32 = 0.3;
+
+/// Status effect: creature is shrunk, scaling its size, contact damage and health
+#[derive(Component, Debug, Clone)]
+pub struct Shrunk {
+    /// Current size relative to normal
+    pub factor: f32,
+    /// Seconds until the creature grows back
+    pub remaining: f32,
+}
+
+impl Shrunk {
+    /// Size after shrinking `factor` further by `per_hit`, never below [`SHRINK_FLOOR`]
+    pub fn shrink(factor: f32, per_hit: f32) -> f32 {
+        (factor * per_hit).max(SHRINK_FLOOR)
+    }
+
+    pub fn at_floor(&self) -> bool {
+        self.factor <= SHRINK_FLOOR
+    }
+}
+
+/// Scales a creature's size, contact damage and health (current and max) by `ratio`
+pub fn resize_creature(
+    transform: &mut Transform,
+    contact_damage: &mut ContactDamage,
+    health: &mut CreatureHealth,
+    ratio: f32,
+) {
+    transform.scale *= ratio;
+    contact_damage.0 *= ratio;
+    health.max *= ratio;
+    health.current *= ratio;
+}
+
 /// Bundle for spawning creatures
 #[derive(Bundle)]
 pub struct CreatureBundle {
@@ -255,7 +825,9 @@ pub struct CreatureBundle {
     pub ai_state: AIState,
     pub speed: CreatureSpeed,
     pub contact_damage: ContactDamage,
+    pub attack: AttackStats,
     pub experience_value: ExperienceValue,
+    pub status_effects: StatusEffects,
     pub sprite: SpriteBundle,
 }
 
@@ -265,7 +837,7 @@ impl CreatureBundle {
             CreatureType::Zombie => Color::srgb(0.3, 0.5, 0.3),
             CreatureType::Spider => Color::srgb(0.2, 0.2, 0.2),
             CreatureType::Dog | CreatureType::Runner => Color::srgb(0.6, 0.3, 0.1),
-            CreatureType::Ghost => Color::srgba(0.8, 0.8, 1.0, 0.5),
+            CreatureType::Ghost => Color::srgba(0.8, 0.8, 1.0, GHOST_SOLID_ALPHA),
             CreatureType::Exploder => Color::srgb(1.0, 0.3, 0.1),
             _ if creature_type.is_boss() => Color::srgb(0.8, 0.1, 0.1),
             _ => Color::srgb(0.5, 0.3, 0.3),
@@ -277,6 +849,7 @@ impl CreatureBundle {
             match creature_type {
                 CreatureType::Giant | CreatureType::GiantSpider => 48.0,
                 CreatureType::Spider | CreatureType::Beetle => 20.0,
+                CreatureType::SplitterSpawn => 18.0,
                 _ => 28.0,
             }
         };
@@ -287,14 +860,16 @@ impl CreatureBundle {
             ai_state: AIState::default(),
             speed: CreatureSpeed(creature_type.base_speed()),
             contact_damage: ContactDamage(creature_type.base_damage()),
+            attack: AttackStats::for_type(creature_type),
             experience_value: ExperienceValue(creature_type.experience_value()),
+            status_effects: StatusEffects::default(),
             sprite: SpriteBundle {
                 sprite: Sprite {
                     color,
                     custom_size: Some(Vec2::splat(size)),
                     ..default()
                 },
-                transform: Transform::from_translation(position),
+                transform: Transform::from_translation(on_layer(position, CREATURES)),
                 ..default()
             },
         }
@@ -319,6 +894,7 @@ mod tests {
             assert!(ct.base_damage() >= 0.0);
             assert!(ct.base_speed() >= 0.0);
             assert!(ct.experience_value() > 0);
+            assert!(ct.attack_windup() > 0.0 && ct.attack_windup() < ct.attack_cooldown());
         }
     }
 
@@ -351,4 +927,17 @@ mod tests {
     fn ai_mode_default_is_chase() {
         assert_eq!(AIMode::default(), AIMode::Chase);
     }
+
+    #[test]
+    fn ghost_phase_alternates_every_duration() {
+        let mut phase = GhostPhase::default();
+        assert!(!phase.is_phased());
+        assert!(!phase.tick(GHOST_PHASE_DURATION - 0.5));
+        assert!(phase.tick(1.0));
+        assert!(phase.is_phased());
+        // The overshoot carries into the next state
+        assert!((phase.remaining - (GHOST_PHASE_DURATION - 0.5)).abs() < 1e-5);
+        assert!(phase.tick(GHOST_PHASE_DURATION));
+        assert!(!phase.is_phased());
+    }
 }
@@ -0,0 +1,147 @@
+//! Spatial hash of creature positions
+//!
+//! Rebuilt every frame once creatures have moved, so hit checks and target
+//! searches only look at creatures in nearby cells instead of all of them.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use super::components::{Creature, MarkedForDespawn};
+use crate::weapons::COLLISION_RADIUS;
+
+/// Cell edge length; a projectile hit check touches at most 4 cells
+pub const GRID_CELL_SIZE: f32 = COLLISION_RADIUS * 2.0;
+
+/// Creatures bucketed by grid cell
+#[derive(Resource, Debug)]
+pub struct CreatureGrid {
+    cell_size: f32,
+    /// Indices into `entries`, per cell
+    cells: HashMap<IVec2, Vec<usize>>,
+    /// Creatures in insertion order
+    entries: Vec<(Entity, Vec2)>,
+}
+
+impl Default for CreatureGrid {
+    fn default() -> Self {
+        Self::new(GRID_CELL_SIZE)
+    }
+}
+
+impl CreatureGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::default(),
+            entries: Vec::new(),
+        }
+    }
+
+    fn cell(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.entries.clear();
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2) {
+        let cell = self.cell(position);
+        self.cells.entry(cell).or_default().push(self.entries.len());
+        self.entries.push((entity, position));
+    }
+
+    /// Creatures in the cells overlapping the circle, in insertion order.
+    /// May include creatures outside `radius`; callers do the exact distance check.
+    pub fn candidates(&self, center: Vec2, radius: f32) -> Vec<(Entity, Vec2)> {
+        let min = self.cell(center - Vec2::splat(radius));
+        let max = self.cell(center + Vec2::splat(radius));
+        let span = (max - min + IVec2::ONE).as_vec2();
+
+        // A circle covering more cells than are occupied is cheaper to answer from the list
+        if span.x * span.y >= self.cells.len() as f32 {
+            return self.entries.clone();
+        }
+        let mut indices: Vec<usize> = Vec::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if let Some(cell) = self.cells.get(&IVec2::new(x, y)) {
+                    indices.extend_from_slice(cell);
+                }
+            }
+        }
+        indices.sort_unstable();
+        indices.into_iter().map(|i| self.entries[i]).collect()
+    }
+}
+
+/// Re-buckets every live creature at its current position
+#[allow(clippy::type_complexity)]
+pub fn rebuild_creature_grid(
+    mut grid: ResMut<CreatureGrid>,
+    creature_query: Query<(Entity, &Transform), (With<Creature>, Without<MarkedForDespawn>)>,
+) {
+    grid.clear();
+    for (entity, transform) in creature_query.iter() {
+        grid.insert(entity, transform.translation.truncate());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    /// The brute-force answer: every creature within `radius`, in insertion order
+    fn within(creatures: &[(Entity, Vec2)], center: Vec2, radius: f32) -> Vec<Entity> {
+        creatures
+            .iter()
+            .filter(|(_, position)| center.distance(*position) < radius)
+            .map(|&(entity, _)| entity)
+            .collect()
+    }
+
+    #[test]
+    fn grid_matches_brute_force_search() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(755);
+        let creatures: Vec<(Entity, Vec2)> = (0..300)
+            .map(|i| {
+                let position = Vec2::new(rng.gen_range(-600.0..600.0), rng.gen_range(-400.0..400.0));
+                (Entity::from_raw(i), position)
+            })
+            .collect();
+        let mut grid = CreatureGrid::default();
+        for &(entity, position) in &creatures {
+            grid.insert(entity, position);
+        }
+
+        for radius in [20.0, 60.0, 150.0, 2000.0] {
+            for _ in 0..200 {
+                let center = Vec2::new(rng.gen_range(-700.0..700.0), rng.gen_range(-500.0..500.0));
+                let from_grid: Vec<Entity> = grid
+                    .candidates(center, radius)
+                    .into_iter()
+                    .filter(|(_, position)| center.distance(*position) < radius)
+                    .map(|(entity, _)| entity)
+                    .collect();
+                assert_eq!(from_grid, within(&creatures, center, radius));
+            }
+        }
+    }
+
+    #[test]
+    fn small_queries_skip_far_cells() {
+        let mut grid = CreatureGrid::default();
+        grid.insert(Entity::from_raw(1), Vec2::new(5.0, 5.0));
+        grid.insert(Entity::from_raw(2), Vec2::new(-30.0, 10.0));
+        for i in 0..20 {
+            grid.insert(Entity::from_raw(10 + i), Vec2::new(500.0 + i as f32 * 50.0, 500.0));
+        }
+        let near: Vec<Entity> = grid.candidates(Vec2::ZERO, 20.0).into_iter().map(|(e, _)| e).collect();
+        assert_eq!(near, vec![Entity::from_raw(1), Entity::from_raw(2)]);
+
+        grid.clear();
+        assert!(grid.candidates(Vec2::ZERO, 1000.0).is_empty());
+    }
+}
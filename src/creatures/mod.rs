@@ -2,16 +2,24 @@
 //!
 //! Handles enemy creatures, their AI, spawning, and behavior.
 
+pub mod boss;
 pub mod components;
+pub mod grid;
 pub mod spawner;
+pub mod status;
 pub mod systems;
+pub mod targeting;
 
+pub use boss::*;
 pub use components::*;
+pub use grid::*;
 pub use spawner::*;
+pub use status::*;
 pub use systems::*;
 
 use bevy::prelude::*;
 
+use crate::schedule::GameSet;
 use crate::states::GameState;
 
 /// Plugin for creature-related functionality
@@ -20,20 +28,58 @@ pub struct CreaturesPlugin;
 impl Plugin for CreaturesPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(CreatureRegistry::new())
+            .init_resource::<CreatureGrid>()
+            .init_resource::<WorldTimeScale>()
+            .init_resource::<PendingCreatureSpawns>()
             .add_event::<SpawnCreatureEvent>()
             .add_event::<CreatureDeathEvent>()
-            .add_systems(OnExit(GameState::Playing), despawn_all_creatures)
+            .add_event::<CreatureTouchedPlayerEvent>()
+            .add_event::<StatusDamageEvent>()
+            .add_systems(Startup, load_creature_table)
+            .add_systems(
+                OnExit(GameState::Playing),
+                (despawn_all_creatures, despawn_all_spawn_warnings, despawn_all_enemy_projectiles),
+            )
             .add_systems(
                 Update,
                 (
-                    handle_creature_spawns,
-                    creature_ai_update,
-                    creature_movement,
-                    creature_attack,
-                    check_creature_death,
-                    cleanup_dead_creatures,
+                    (
+                        clear_hit_flash_tints,
+                        handle_creature_spawns,
+                        hatch_spawn_warnings,
+                        update_world_time_scale,
+                        creature_ai_update,
+                        creature_movement,
+                        update_knockback_and_stagger,
+                        rebuild_creature_grid,
+                        creature_attack,
+                        exploder_fuse,
+                        necromancer_summon,
+                        ghost_phase_cycle,
+                        boss_phase_transitions,
+                        run_boss_scripts,
+                        enemy_ranged_attack,
+                        enemy_projectile_movement,
+                    )
+                        .chain()
+                        .in_set(GameSet::Simulation),
+                    (
+                        tick_status_effects,
+                        squash_shrunk_creatures,
+                        enemy_projectile_collision,
+                        start_hit_flashes.after(crate::weapons::projectile_collision),
+                    )
+                        .in_set(GameSet::DamageResolution),
+                    (
+                        check_creature_death,
+                        explode_killed_exploders,
+                        split_splitters,
+                        cleanup_dead_creatures,
+                    )
+                        .chain()
+                        .in_set(GameSet::DeathResolution),
+                    tint_hit_flashes.in_set(GameSet::Presentation),
                 )
-                    .chain()
                     .run_if(in_state(GameState::Playing)),
             );
     }
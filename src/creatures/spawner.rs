@@ -4,7 +4,11 @@ use bevy::prelude::*;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use super::components::CreatureType;
+use super::components::{AttackStats, CreatureType, EnemyRangedAttack};
+use crate::viewport::{ArenaBounds, ViewportInfo, SPAWN_BAND_WIDTH};
+
+/// Creature table read at startup over the built-in creatures
+pub const CREATURES_FILE: &str = "assets/data/creatures.ron";
 
 /// Registry of creature data
 #[derive(Resource, Default)]
@@ -20,93 +24,123 @@ impl CreatureRegistry {
     }
 
     fn register_default_creatures(&mut self) {
-        // All creature types with their spawn weights and wave requirements
+        // Every creature type with its Rush score, wave requirements and Survival unlock time
         self.definitions = vec![
-            CreatureDefinition {
-                creature_type: CreatureType::Zombie,
-                min_wave: 1,
-                spawn_weight: 10,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Spider,
-                min_wave: 1,
-                spawn_weight: 8,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Lizard,
-                min_wave: 2,
-                spawn_weight: 6,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Beetle,
-                min_wave: 2,
-                spawn_weight: 7,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Dog,
-                min_wave: 3,
-                spawn_weight: 5,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::AlienSpider,
-                min_wave: 5,
-                spawn_weight: 4,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Giant,
-                min_wave: 6,
-                spawn_weight: 2,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Necromancer,
-                min_wave: 7,
-                spawn_weight: 2,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Runner,
-                min_wave: 4,
-                spawn_weight: 4,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::AlienShooter,
-                min_wave: 8,
-                spawn_weight: 3,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Ghost,
-                min_wave: 10,
-                spawn_weight: 2,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Exploder,
-                min_wave: 6,
-                spawn_weight: 3,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Splitter,
-                min_wave: 8,
-                spawn_weight: 2,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::GiantSpider,
-                min_wave: 12,
-                spawn_weight: 1,
-            },
-            CreatureDefinition {
-                creature_type: CreatureType::Turret,
-                min_wave: 10,
-                spawn_weight: 2,
-            },
+            CreatureDefinition::built_in(CreatureType::Zombie, 10)
+                .with_waves(1, 10)
+                .with_survival_unlock(0.0),
+            CreatureDefinition::built_in(CreatureType::Spider, 15)
+                .with_waves(1, 8)
+                .with_survival_unlock(10.0),
+            CreatureDefinition::built_in(CreatureType::Lizard, 20)
+                .with_waves(2, 6)
+                .with_survival_unlock(60.0),
+            CreatureDefinition::built_in(CreatureType::Beetle, 15)
+                .with_waves(2, 7)
+                .with_survival_unlock(20.0),
+            CreatureDefinition::built_in(CreatureType::Dog, 25)
+                .with_waves(3, 5)
+                .with_survival_unlock(45.0),
+            CreatureDefinition::built_in(CreatureType::AlienSpider, 35)
+                .with_waves(5, 4)
+                .with_survival_unlock(90.0),
+            CreatureDefinition::built_in(CreatureType::Giant, 100)
+                .with_waves(6, 2)
+                .with_survival_unlock(180.0),
+            CreatureDefinition::built_in(CreatureType::Necromancer, 80)
+                .with_waves(7, 2)
+                .with_survival_unlock(135.0),
+            CreatureDefinition::built_in(CreatureType::Runner, 30)
+                .with_waves(4, 4)
+                .with_survival_unlock(30.0),
+            CreatureDefinition::built_in(CreatureType::AlienShooter, 40)
+                .with_waves(8, 3)
+                .with_survival_unlock(120.0)
+                .with_ranged_attack(EnemyRangedAttack {
+                    cooldown: 1.5,
+                    projectile_speed: 300.0,
+                    damage: 8.0,
+                    range: 450.0,
+                }),
+            CreatureDefinition::built_in(CreatureType::Ghost, 45)
+                .with_waves(10, 2)
+                .with_survival_unlock(75.0),
+            CreatureDefinition::built_in(CreatureType::Exploder, 35)
+                .with_waves(6, 3)
+                .with_survival_unlock(105.0),
+            CreatureDefinition::built_in(CreatureType::Splitter, 40)
+                .with_waves(8, 2)
+                .with_survival_unlock(165.0),
+            CreatureDefinition::built_in(CreatureType::GiantSpider, 120)
+                .with_waves(12, 1)
+                .with_survival_unlock(150.0),
+            CreatureDefinition::built_in(CreatureType::Turret, 50)
+                .with_waves(10, 2)
+                .with_ranged_attack(EnemyRangedAttack {
+                    cooldown: 1.0,
+                    projectile_speed: 380.0,
+                    damage: 6.0,
+                    range: 500.0,
+                }),
+            // Only ever split off a Splitter
+            CreatureDefinition::built_in(CreatureType::SplitterSpawn, 15),
+            // Bosses arrive through boss waves, not the regular spawn pools
+            CreatureDefinition::built_in(CreatureType::BossSpider, 500).with_survival_unlock(240.0),
+            CreatureDefinition::built_in(CreatureType::BossAlien, 800).with_survival_unlock(300.0),
+            CreatureDefinition::built_in(CreatureType::BossNest, 1000),
         ];
     }
 
+    /// Applies a RON creature table over the current definitions. Entries replace the
+    /// definition of the same creature type; invalid entries are logged and keep the old one.
+    /// Returns the number of entries applied, or the parse error with nothing changed.
+    pub fn apply_table(&mut self, text: &str) -> Result<usize, ron::error::SpannedError> {
+        let entries: Vec<CreatureDefinition> = ron::from_str(text)?;
+        let mut applied = 0;
+        for entry in entries {
+            if let Err(reason) = entry.validate() {
+                warn!("Rejected creature entry {:?}: {}", entry.creature_type, reason);
+                continue;
+            }
+            match self.definitions.iter_mut().find(|d| d.creature_type == entry.creature_type) {
+                Some(existing) => *existing = entry,
+                None => self.definitions.push(entry),
+            }
+            applied += 1;
+        }
+        Ok(applied)
+    }
+
+    /// Definition of the given creature type, if registered
+    pub fn get(&self, creature_type: CreatureType) -> Option<&CreatureDefinition> {
+        self.definitions.iter().find(|d| d.creature_type == creature_type)
+    }
+
+    /// Creatures that can turn up in the given wave; types without a spawn weight never do
     pub fn get_available_for_wave(&self, wave: u32) -> Vec<&CreatureDefinition> {
         self.definitions
             .iter()
-            .filter(|d| d.min_wave <= wave)
+            .filter(|d| d.spawn_weight > 0 && d.min_wave <= wave)
             .collect()
     }
 
+    /// Creatures unlocked `game_time` seconds into a Survival run, in unlock order
+    pub fn unlocked_for_survival(&self, game_time: f32) -> Vec<CreatureType> {
+        let mut unlocked: Vec<(f32, CreatureType)> = self
+            .definitions
+            .iter()
+            .filter_map(|d| d.survival_unlock.map(|unlock| (unlock, d.creature_type)))
+            .filter(|(unlock, _)| game_time >= *unlock)
+            .collect();
+        unlocked.sort_by(|a, b| a.0.total_cmp(&b.0));
+        unlocked.into_iter().map(|(_, creature_type)| creature_type).collect()
+    }
+
+    /// Rush points for killing the given creature type
+    pub fn score(&self, creature_type: CreatureType) -> u32 {
+        self.get(creature_type).map_or(0, |d| d.score)
+    }
+
     pub fn pick_random_for_wave(&self, wave: u32) -> Option<CreatureType> {
         let available = self.get_available_for_wave(wave);
         if available.is_empty() {
@@ -132,21 +166,164 @@ impl CreatureRegistry {
     }
 }
 
+#[cfg(test)]
+impl CreatureRegistry {
+    /// Stats and spawn rules of every registered creature type
+    pub fn snapshot_repr(&self) -> crate::snapshot::SnapshotTable {
+        let mut table = crate::snapshot::SnapshotTable::new();
+        for def in &self.definitions {
+            let mut entry = crate::snapshot::SnapshotTable::new();
+            entry
+                .float("health", def.health)
+                .float("speed", def.speed)
+                .float("damage", def.attack.damage)
+                .float("attack_cooldown", def.attack.cooldown)
+                .float("attack_windup", def.attack.windup)
+                .int("experience", def.experience)
+                .float("mass", def.creature_type.mass())
+                .int("score", def.score);
+            if def.spawn_weight > 0 {
+                entry
+                    .int("min_wave", def.min_wave)
+                    .int("spawn_weight", def.spawn_weight);
+            }
+            if let Some(unlock) = def.survival_unlock {
+                entry.float("survival_unlock", unlock);
+            }
+            if let Some(attack) = def.ranged_attack {
+                entry
+                    .float("ranged_cooldown", attack.cooldown)
+                    .float("ranged_projectile_speed", attack.projectile_speed)
+                    .float("ranged_damage", attack.damage)
+                    .float("ranged_range", attack.range);
+            }
+            table.table(&format!("{:?}", def.creature_type), entry);
+        }
+        table
+    }
+}
+
+/// Loads [`CREATURES_FILE`] over the built-in creatures. A missing file keeps the
+/// defaults; an unreadable or malformed one is logged and ignored.
+pub fn load_creature_table(mut registry: ResMut<CreatureRegistry>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let text = match std::fs::read_to_string(CREATURES_FILE) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return,
+            Err(err) => {
+                warn!("Cannot read {}, using built-in creatures: {}", CREATURES_FILE, err);
+                return;
+            }
+        };
+        match registry.apply_table(&text) {
+            Ok(applied) => info!("Loaded {} creatures from {}", applied, CREATURES_FILE),
+            Err(err) => warn!("Ignoring invalid {}, using built-in creatures: {}", CREATURES_FILE, err),
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &mut registry;
+}
+
 /// Definition for a creature type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreatureDefinition {
     pub creature_type: CreatureType,
+    pub health: f32,
+    pub speed: f32,
+    /// Experience granted when killed
+    pub experience: u32,
+    /// Contact attack; its damage is the creature's contact damage
+    pub attack: AttackStats,
+    /// Rush points for a kill
+    pub score: u32,
+    /// First wave the type can spawn in
     pub min_wave: u32,
+    /// Relative chance among the wave's spawns; 0 keeps the type out of waves
     pub spawn_weight: u32,
+    /// Seconds into a Survival run before the type joins the spawn pool; None keeps it out
+    #[serde(default)]
+    pub survival_unlock: Option<f32>,
+    /// Creatures of this type shoot at players
+    #[serde(default)]
+    pub ranged_attack: Option<EnemyRangedAttack>,
+}
+
+impl CreatureDefinition {
+    /// The type's built-in stats, kept out of waves and Survival until configured
+    fn built_in(creature_type: CreatureType, score: u32) -> Self {
+        Self {
+            creature_type,
+            health: creature_type.base_health(),
+            speed: creature_type.base_speed(),
+            experience: creature_type.experience_value(),
+            attack: AttackStats::for_type(creature_type),
+            score,
+            min_wave: 0,
+            spawn_weight: 0,
+            survival_unlock: None,
+            ranged_attack: None,
+        }
+    }
+
+    fn with_waves(mut self, min_wave: u32, spawn_weight: u32) -> Self {
+        self.min_wave = min_wave;
+        self.spawn_weight = spawn_weight;
+        self
+    }
+
+    fn with_survival_unlock(mut self, seconds: f32) -> Self {
+        self.survival_unlock = Some(seconds);
+        self
+    }
+
+    fn with_ranged_attack(mut self, attack: EnemyRangedAttack) -> Self {
+        self.ranged_attack = Some(attack);
+        self
+    }
+
+    /// Why the definition is unusable, or Ok if creatures can be spawned from it
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if !self.health.is_finite() || self.health <= 0.0 {
+            return Err("health must be positive");
+        }
+        if !self.speed.is_finite() || self.speed < 0.0 {
+            return Err("speed must not be negative");
+        }
+        let attack = &self.attack;
+        if !attack.damage.is_finite() || attack.damage < 0.0 {
+            return Err("attack damage must not be negative");
+        }
+        if !attack.cooldown.is_finite() || attack.cooldown <= 0.0 {
+            return Err("attack cooldown must be positive");
+        }
+        if !attack.windup.is_finite() || attack.windup < 0.0 {
+            return Err("attack windup must not be negative");
+        }
+        if self.survival_unlock.is_some_and(|unlock| !unlock.is_finite() || unlock < 0.0) {
+            return Err("survival_unlock must not be negative");
+        }
+        if let Some(ranged) = &self.ranged_attack {
+            if !ranged.cooldown.is_finite() || ranged.cooldown <= 0.0 {
+                return Err("ranged cooldown must be positive");
+            }
+            if ranged.projectile_speed <= 0.0 || ranged.range <= 0.0 {
+                return Err("ranged projectile_speed and range must be positive");
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Configuration for spawn behavior
 #[derive(Debug, Clone)]
 pub struct SpawnConfig {
-    /// Minimum distance from player to spawn
-    pub min_spawn_distance: f32,
-    /// Maximum distance from player to spawn
-    pub max_spawn_distance: f32,
+    /// Center of the camera's view
+    pub view_center: Vec2,
+    /// Half extents of the rectangle around the view that spawns start beyond
+    pub spawn_edge: Vec2,
+    /// Depth of the band past the spawn edge that creatures appear in
+    pub band_width: f32,
     /// Arena bounds (half-width, half-height)
     pub arena_bounds: Vec2,
 }
@@ -154,34 +331,47 @@ pub struct SpawnConfig {
 impl Default for SpawnConfig {
     fn default() -> Self {
         Self {
-            min_spawn_distance: 400.0,
-            max_spawn_distance: 600.0,
-            arena_bounds: Vec2::new(800.0, 600.0),
+            view_center: Vec2::ZERO,
+            spawn_edge: ViewportInfo::default().spawn_edge(),
+            band_width: SPAWN_BAND_WIDTH,
+            arena_bounds: ArenaBounds::default().half_extents,
         }
     }
 }
 
-/// Calculate a spawn position outside the player's view
-pub fn calculate_spawn_position(player_pos: Vec2, config: &SpawnConfig) -> Vec3 {
+/// Calculate a spawn position just outside the camera's view, on a side of the view
+/// with arena left beyond it. When the whole arena is on screen, creatures come in
+/// at the arena's edge instead
+pub fn calculate_spawn_position(config: &SpawnConfig) -> Vec3 {
     let mut rng = rand::thread_rng();
+    let arena = config.arena_bounds;
 
-    // Random angle
-    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-
-    // Random distance within range
-    let distance = rng.gen_range(config.min_spawn_distance..config.max_spawn_distance);
+    // (axis, sign) for right, left, top and bottom; a side is open when its spawn edge is inside the arena
+    let open_sides: Vec<(usize, f32)> = [(0, 1.0), (0, -1.0), (1, 1.0), (1, -1.0)]
+        .into_iter()
+        .filter(|&(axis, sign)| {
+            let edge = config.view_center[axis] + sign * config.spawn_edge[axis];
+            edge.abs() <= arena[axis]
+        })
+        .collect();
 
-    // Calculate position
-    let offset = Vec2::new(angle.cos() * distance, angle.sin() * distance);
-    let position = player_pos + offset;
+    let Some(&(axis, sign)) = open_sides.get(rng.gen_range(0..open_sides.len().max(1))) else {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let position = config.view_center + Vec2::from_angle(angle) * arena.length() * 2.0;
+        return position.clamp(-arena, arena).extend(0.0);
+    };
 
-    // Clamp to arena bounds
-    let clamped = Vec2::new(
-        position.x.clamp(-config.arena_bounds.x, config.arena_bounds.x),
-        position.y.clamp(-config.arena_bounds.y, config.arena_bounds.y),
-    );
+    let mut position = Vec2::ZERO;
+    let edge = config.view_center[axis] + sign * config.spawn_edge[axis];
+    position[axis] = (edge + sign * rng.gen_range(0.0..config.band_width)).clamp(-arena[axis], arena[axis]);
+    // Anywhere along the side, corners included
+    let across = 1 - axis;
+    let reach = config.spawn_edge[across] + config.band_width;
+    let low = (config.view_center[across] - reach).max(-arena[across]);
+    let high = (config.view_center[across] + reach).min(arena[across]);
+    position[across] = if low < high { rng.gen_range(low..high) } else { low };
 
-    Vec3::new(clamped.x, clamped.y, 0.0)
+    position.extend(0.0)
 }
 
 #[cfg(test)]
@@ -205,6 +395,74 @@ mod tests {
         assert!(types.contains(&CreatureType::Spider));
     }
 
+    #[test]
+    fn only_shooters_and_turrets_have_ranged_attacks() {
+        let registry = CreatureRegistry::new();
+        for creature_type in CreatureType::all() {
+            let ranged = matches!(creature_type, CreatureType::AlienShooter | CreatureType::Turret);
+            assert_eq!(registry.get(*creature_type).unwrap().ranged_attack.is_some(), ranged, "{creature_type:?}");
+        }
+    }
+
+    #[test]
+    fn every_creature_type_is_defined_and_valid() {
+        let registry = CreatureRegistry::new();
+        for creature_type in CreatureType::all() {
+            let definition = registry.get(*creature_type).unwrap_or_else(|| panic!("{creature_type:?}"));
+            assert_eq!(definition.validate(), Ok(()), "{creature_type:?}");
+        }
+        assert_eq!(registry.definitions.len(), CreatureType::all().len());
+    }
+
+    #[test]
+    fn bosses_never_spawn_in_waves() {
+        let registry = CreatureRegistry::new();
+        let late = registry.get_available_for_wave(100);
+        assert!(late.iter().all(|d| !d.creature_type.is_boss()));
+        assert!(late.iter().all(|d| d.creature_type != CreatureType::SplitterSpawn));
+    }
+
+    #[test]
+    fn table_entries_replace_definitions_and_invalid_ones_fall_back() {
+        let mut registry = CreatureRegistry::new();
+        let mut zombie = registry.get(CreatureType::Zombie).unwrap().clone();
+        zombie.health = 99.0;
+        zombie.score = 7;
+        let mut spider = registry.get(CreatureType::Spider).unwrap().clone();
+        spider.attack.cooldown = 0.0;
+        let text = ron::to_string(&vec![zombie, spider]).unwrap();
+
+        assert_eq!(registry.apply_table(&text).unwrap(), 1);
+        assert_eq!(registry.get(CreatureType::Zombie).unwrap().health, 99.0);
+        assert_eq!(registry.score(CreatureType::Zombie), 7);
+        assert_eq!(
+            registry.get(CreatureType::Spider),
+            CreatureRegistry::new().get(CreatureType::Spider)
+        );
+    }
+
+    #[test]
+    fn malformed_table_changes_nothing() {
+        let mut registry = CreatureRegistry::new();
+        assert!(registry.apply_table("[(creature_type: Zombie, health: ").is_err());
+        assert_eq!(registry.definitions, CreatureRegistry::new().definitions);
+    }
+
+    #[test]
+    fn shipped_table_matches_the_built_in_creatures() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(CREATURES_FILE);
+        let mut registry = CreatureRegistry::new();
+        let defaults = registry.definitions.clone();
+        if std::env::var_os(crate::snapshot::BLESS_ENV).is_some() {
+            let text = ron::ser::to_string_pretty(&defaults, Default::default()).unwrap();
+            std::fs::write(&path, text + "\n").unwrap();
+        }
+
+        let text = std::fs::read_to_string(&path).expect("shipped creature table");
+        assert_eq!(registry.apply_table(&text).unwrap(), defaults.len());
+        assert_eq!(registry.definitions, defaults);
+    }
+
     #[test]
     fn later_waves_have_more_creatures() {
         let registry = CreatureRegistry::new();
@@ -224,25 +482,40 @@ mod tests {
     fn spawn_position_is_within_bounds() {
         let config = SpawnConfig::default();
         for _ in 0..100 {
-            let pos = calculate_spawn_position(Vec2::ZERO, &config);
+            let pos = calculate_spawn_position(&config);
             assert!(pos.x.abs() <= config.arena_bounds.x);
             assert!(pos.y.abs() <= config.arena_bounds.y);
         }
     }
 
     #[test]
-    fn spawn_position_respects_min_distance() {
+    fn spawn_position_is_off_screen_wherever_the_camera_is() {
+        let viewport = ViewportInfo::new(Vec2::new(800.0, 600.0));
+        for view_center in [Vec2::ZERO, Vec2::new(1400.0, -1200.0), Vec2::new(-900.0, 0.0)] {
+            let config = SpawnConfig {
+                view_center,
+                spawn_edge: viewport.spawn_edge(),
+                band_width: 200.0,
+                arena_bounds: Vec2::new(2000.0, 1500.0),
+            };
+            for _ in 0..100 {
+                let offset = (calculate_spawn_position(&config).truncate() - view_center).abs();
+                let outside = offset.x >= config.spawn_edge.x || offset.y >= config.spawn_edge.y;
+                assert!(outside, "{offset} from the view center {view_center}");
+                assert!(offset.x <= config.spawn_edge.x + 200.0 && offset.y <= config.spawn_edge.y + 200.0);
+            }
+        }
+    }
+
+    #[test]
+    fn whole_arena_on_screen_spawns_at_its_edge() {
         let config = SpawnConfig {
-            min_spawn_distance: 100.0,
-            max_spawn_distance: 200.0,
-            arena_bounds: Vec2::new(1000.0, 1000.0),
+            arena_bounds: Vec2::new(300.0, 200.0),
+            ..default()
         };
-
         for _ in 0..100 {
-            let pos = calculate_spawn_position(Vec2::ZERO, &config);
-            let distance = pos.truncate().length();
-            // Allow some tolerance for boundary clamping
-            assert!(distance >= config.min_spawn_distance * 0.5);
+            let pos = calculate_spawn_position(&config);
+            assert!(pos.x.abs() == 300.0 || pos.y.abs() == 200.0, "{pos}");
         }
     }
 }
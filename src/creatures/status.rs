@@ -0,0 +1,292 @@
+//! Damage-over-time status effects shared by all DoT sources
+
+use bevy::prelude::*;
+
+/// Maximum number of distinct status entries a creature can carry
+pub const MAX_STATUS_EFFECTS: usize = 4;
+
+/// Kinds of damage-over-time status effects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    Burning,
+    Poisoned,
+    /// Plaguebearer; spreads to nearby creatures
+    Infected,
+}
+
+/// How a kind behaves when applied to a creature that already has it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackPolicy {
+    /// Keep the longer duration and the higher dps (Burning)
+    RefreshMaxDps,
+    /// Each application adds a stack up to `max`; every stack adds dps and refreshes duration (Poisoned)
+    AddStacks { max: u8 },
+    /// Only one instance; reapplication is ignored until it expires (Infected)
+    SingleInstance,
+}
+
+impl StatusKind {
+    pub fn stack_policy(&self) -> StackPolicy {
+        match self {
+            StatusKind::Burning => StackPolicy::RefreshMaxDps,
+            StatusKind::Poisoned => StackPolicy::AddStacks { max: 3 },
+            StatusKind::Infected => StackPolicy::SingleInstance,
+        }
+    }
+
+    /// Tint used for tick particles
+    pub fn color(&self) -> Color {
+        match self {
+            StatusKind::Burning => Color::srgb(1.0, 0.5, 0.1),
+            StatusKind::Poisoned => Color::srgb(0.4, 0.9, 0.2),
+            StatusKind::Infected => Color::srgb(0.6, 0.8, 0.25),
+        }
+    }
+}
+
+/// One active status effect
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusEntry {
+    pub kind: StatusKind,
+    /// Damage per second per stack
+    pub dps: f32,
+    pub remaining: f32,
+    pub source: Option<Entity>,
+    pub stacks: u8,
+}
+
+impl StatusEntry {
+    pub fn total_dps(&self) -> f32 {
+        self.dps * self.stacks as f32
+    }
+}
+
+/// Damage dealt by one status entry during a tick
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusTick {
+    pub kind: StatusKind,
+    pub damage: f32,
+    pub source: Option<Entity>,
+}
+
+/// Active damage-over-time effects on a creature
+#[derive(Component, Debug, Clone, Default)]
+pub struct StatusEffects {
+    pub entries: Vec<StatusEntry>,
+}
+
+impl StatusEffects {
+    /// Applies a status following its kind's stacking policy.
+    /// Returns false if the application was ignored.
+    pub fn apply(
+        &mut self,
+        kind: StatusKind,
+        dps: f32,
+        duration: f32,
+        source: Option<Entity>,
+    ) -> bool {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.kind == kind) {
+            return match kind.stack_policy() {
+                StackPolicy::RefreshMaxDps => {
+                    entry.remaining = entry.remaining.max(duration);
+                    if dps > entry.dps {
+                        entry.dps = dps;
+                        entry.source = source;
+                    }
+                    true
+                }
+                StackPolicy::AddStacks { max } => {
+                    entry.stacks = (entry.stacks + 1).min(max);
+                    entry.dps = entry.dps.max(dps);
+                    entry.remaining = entry.remaining.max(duration);
+                    entry.source = source;
+                    true
+                }
+                StackPolicy::SingleInstance => false,
+            };
+        }
+
+        if self.entries.len() >= MAX_STATUS_EFFECTS {
+            return false;
+        }
+
+        self.entries.push(StatusEntry {
+            kind,
+            dps,
+            remaining: duration,
+            source,
+            stacks: 1,
+        });
+        true
+    }
+
+    /// Removes a status kind. Returns true if it was present.
+    pub fn cleanse(&mut self, kind: StatusKind) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.kind != kind);
+        self.entries.len() != before
+    }
+
+    /// Advances all effects by `delta` seconds, returning the damage each dealt.
+    /// An effect expiring mid-tick only deals damage for its remaining time.
+    pub fn tick(&mut self, delta: f32) -> Vec<StatusTick> {
+        let ticks = self
+            .entries
+            .iter_mut()
+            .map(|entry| {
+                let active_time = delta.min(entry.remaining).max(0.0);
+                entry.remaining -= delta;
+                StatusTick {
+                    kind: entry.kind,
+                    damage: entry.total_dps() * active_time,
+                    source: entry.source,
+                }
+            })
+            .filter(|tick| tick.damage > 0.0)
+            .collect();
+
+        self.entries.retain(|e| e.remaining > 0.0);
+        ticks
+    }
+}
+
+/// Records the status that dealt the killing blow, for death attribution
+#[derive(Component, Debug, Clone, Copy)]
+pub struct KilledBy(pub StatusKind);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(effects: &StatusEffects, kind: StatusKind) -> Option<&StatusEntry> {
+        effects.entries.iter().find(|e| e.kind == kind)
+    }
+
+    #[test]
+    fn policy_table() {
+        assert_eq!(StatusKind::Burning.stack_policy(), StackPolicy::RefreshMaxDps);
+        assert_eq!(
+            StatusKind::Poisoned.stack_policy(),
+            StackPolicy::AddStacks { max: 3 }
+        );
+        assert_eq!(StatusKind::Infected.stack_policy(), StackPolicy::SingleInstance);
+    }
+
+    #[test]
+    fn burning_refreshes_duration_and_keeps_max_dps() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Burning, 10.0, 2.0, None);
+        effects.apply(StatusKind::Burning, 5.0, 4.0, None);
+
+        let entry = entry(&effects, StatusKind::Burning).unwrap();
+        assert_eq!(entry.dps, 10.0);
+        assert_eq!(entry.remaining, 4.0);
+        assert_eq!(entry.stacks, 1);
+    }
+
+    #[test]
+    fn burning_refresh_never_shortens() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Burning, 10.0, 4.0, None);
+        effects.apply(StatusKind::Burning, 20.0, 1.0, None);
+
+        let entry = entry(&effects, StatusKind::Burning).unwrap();
+        assert_eq!(entry.dps, 20.0);
+        assert_eq!(entry.remaining, 4.0);
+    }
+
+    #[test]
+    fn poison_stacks_cap_at_three() {
+        let mut effects = StatusEffects::default();
+        for _ in 0..5 {
+            assert!(effects.apply(StatusKind::Poisoned, 4.0, 3.0, None));
+        }
+
+        let entry = entry(&effects, StatusKind::Poisoned).unwrap();
+        assert_eq!(entry.stacks, 3);
+        assert_eq!(entry.total_dps(), 12.0);
+    }
+
+    #[test]
+    fn infection_is_single_instance() {
+        let mut effects = StatusEffects::default();
+        assert!(effects.apply(StatusKind::Infected, 5.0, 3.0, None));
+        assert!(!effects.apply(StatusKind::Infected, 50.0, 10.0, None));
+
+        let entry = entry(&effects, StatusKind::Infected).unwrap();
+        assert_eq!(entry.dps, 5.0);
+        assert_eq!(entry.remaining, 3.0);
+    }
+
+    #[test]
+    fn each_kind_is_one_entry() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Burning, 1.0, 1.0, None);
+        effects.apply(StatusKind::Poisoned, 1.0, 1.0, None);
+        effects.apply(StatusKind::Burning, 1.0, 1.0, None);
+        effects.apply(StatusKind::Poisoned, 1.0, 1.0, None);
+        assert_eq!(effects.entries.len(), 2);
+        assert!(effects.entries.len() <= MAX_STATUS_EFFECTS);
+    }
+
+    #[test]
+    fn tick_deals_dps_times_delta() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Burning, 10.0, 2.0, None);
+
+        let ticks = effects.tick(0.5);
+        assert_eq!(ticks.len(), 1);
+        assert!((ticks[0].damage - 5.0).abs() < 0.001);
+        assert!((entry(&effects, StatusKind::Burning).unwrap().remaining - 1.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn tick_clamps_damage_at_expiry() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Burning, 10.0, 0.25, None);
+
+        let ticks = effects.tick(1.0);
+        assert!((ticks[0].damage - 2.5).abs() < 0.001);
+        assert!(entry(&effects, StatusKind::Burning).is_none());
+    }
+
+    #[test]
+    fn simultaneous_expiry_removes_all_and_damages_all() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Burning, 10.0, 1.0, None);
+        effects.apply(StatusKind::Poisoned, 4.0, 1.0, None);
+        effects.apply(StatusKind::Poisoned, 4.0, 1.0, None);
+
+        let ticks = effects.tick(1.0);
+        let total: f32 = ticks.iter().map(|t| t.damage).sum();
+        assert!((total - 18.0).abs() < 0.001);
+        assert!(effects.entries.is_empty());
+    }
+
+    #[test]
+    fn cleanse_removes_only_that_kind() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Burning, 10.0, 3.0, None);
+        effects.apply(StatusKind::Poisoned, 4.0, 3.0, None);
+
+        assert!(effects.cleanse(StatusKind::Burning));
+        assert!(!effects.cleanse(StatusKind::Burning));
+        assert!(entry(&effects, StatusKind::Burning).is_none());
+        assert!(entry(&effects, StatusKind::Poisoned).is_some());
+    }
+
+    #[test]
+    fn capacity_limits_distinct_kinds() {
+        let mut effects = StatusEffects::default();
+        for _ in 0..MAX_STATUS_EFFECTS {
+            effects.entries.push(StatusEntry {
+                kind: StatusKind::Infected,
+                dps: 1.0,
+                remaining: 1.0,
+                source: None,
+                stacks: 1,
+            });
+        }
+        assert!(!effects.apply(StatusKind::Burning, 1.0, 1.0, None));
+    }
+}
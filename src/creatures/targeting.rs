@@ -0,0 +1,143 @@
+//! Creature target selection between players and a defend objective
+
+use bevy::prelude::*;
+
+use super::components::CreatureType;
+
+/// Seconds between target re-evaluations for a creature
+pub const RETARGET_INTERVAL: f32 = 0.5;
+/// A new target must score below this fraction of the current target's score
+pub const SWITCH_MARGIN: f32 = 0.75;
+/// Ranged creatures can hit targets within this distance
+pub const RANGED_RANGE: f32 = 400.0;
+
+/// Point creatures go after instead of the player in defend quests
+#[derive(Component, Debug, Clone)]
+// Spawned by the DefendPoint quest type once it lands
+#[allow(dead_code)]
+pub struct DefendObjective {
+    /// Creatures reaching this distance from the centre are attacking it
+    pub radius: f32,
+}
+
+/// What a target candidate is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetKind {
+    Player,
+    Objective,
+}
+
+/// One possible target as seen from a creature
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetCandidate {
+    pub entity: Entity,
+    pub kind: TargetKind,
+    /// Distance from the creature; for objectives, to the edge of their radius
+    pub distance: f32,
+}
+
+/// Distance multiplier per creature type and target kind; lower means preferred
+pub fn target_weight(creature_type: CreatureType, kind: TargetKind) -> f32 {
+    match kind {
+        TargetKind::Player => 1.0,
+        // Bosses hunt the player, swarmers go for whatever is closer
+        TargetKind::Objective if creature_type.is_boss() => 2.0,
+        TargetKind::Objective => 1.0,
+    }
+}
+
+fn is_ranged(creature_type: CreatureType) -> bool {
+    matches!(creature_type, CreatureType::AlienShooter | CreatureType::Turret)
+}
+
+/// Picks a target from `candidates`. The current target keeps its place unless
+/// another candidate scores clearly better, so targets near a tie don't flicker.
+/// Ranged creatures shoot an objective in range before anything else.
+pub fn select_target(
+    creature_type: CreatureType,
+    candidates: &[TargetCandidate],
+    current: Option<Entity>,
+) -> Option<Entity> {
+    if is_ranged(creature_type) {
+        let objective_in_range = candidates
+            .iter()
+            .find(|c| c.kind == TargetKind::Objective && c.distance <= RANGED_RANGE);
+        if let Some(objective) = objective_in_range {
+            return Some(objective.entity);
+        }
+    }
+
+    let score = |c: &TargetCandidate| c.distance * target_weight(creature_type, c.kind);
+    let best = candidates.iter().min_by(|a, b| score(a).total_cmp(&score(b)))?;
+    match candidates.iter().find(|c| Some(c.entity) == current) {
+        Some(kept) if score(best) >= score(kept) * SWITCH_MARGIN => Some(kept.entity),
+        _ => Some(best.entity),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(index: u32, kind: TargetKind, distance: f32) -> TargetCandidate {
+        TargetCandidate {
+            entity: Entity::from_raw(index),
+            kind,
+            distance,
+        }
+    }
+
+    #[test]
+    fn current_target_is_kept_until_clearly_beaten() {
+        let player = candidate(1, TargetKind::Player, 100.0);
+        let objective = candidate(2, TargetKind::Objective, 90.0);
+        let candidates = [player, objective];
+
+        // Without a current target the nearer one wins
+        assert_eq!(select_target(CreatureType::Zombie, &candidates, None), Some(objective.entity));
+        // Slightly nearer is not enough to switch away from the player
+        assert_eq!(
+            select_target(CreatureType::Zombie, &candidates, Some(player.entity)),
+            Some(player.entity)
+        );
+        // Clearly nearer is
+        let objective = candidate(2, TargetKind::Objective, 70.0);
+        assert_eq!(
+            select_target(CreatureType::Zombie, &[player, objective], Some(player.entity)),
+            Some(objective.entity)
+        );
+    }
+
+    #[test]
+    fn lost_target_is_replaced() {
+        let player = candidate(1, TargetKind::Player, 300.0);
+        let gone = Entity::from_raw(9);
+        assert_eq!(select_target(CreatureType::Spider, &[player], Some(gone)), Some(player.entity));
+        assert_eq!(select_target(CreatureType::Spider, &[], Some(gone)), None);
+    }
+
+    #[test]
+    fn ranged_creatures_prefer_objective_in_range() {
+        let player = candidate(1, TargetKind::Player, 50.0);
+        let objective = candidate(2, TargetKind::Objective, 350.0);
+        assert_eq!(
+            select_target(CreatureType::AlienShooter, &[player, objective], Some(player.entity)),
+            Some(objective.entity)
+        );
+        // Out of range, the usual scoring applies
+        let far = candidate(2, TargetKind::Objective, 500.0);
+        assert_eq!(select_target(CreatureType::Turret, &[player, far], None), Some(player.entity));
+        // Melee creatures take the much closer player
+        assert_eq!(select_target(CreatureType::Zombie, &[player, objective], None), Some(player.entity));
+    }
+
+    #[test]
+    fn bosses_weigh_objectives_down() {
+        let player = candidate(1, TargetKind::Player, 150.0);
+        let objective = candidate(2, TargetKind::Objective, 100.0);
+        assert_eq!(
+            select_target(CreatureType::BossSpider, &[player, objective], None),
+            Some(player.entity)
+        );
+    }
+}
@@ -1,9 +1,14 @@
 //! Effect components
 
+use std::collections::VecDeque;
+
 use bevy::prelude::*;
 
+use crate::creatures::CreatureType;
+use crate::render_layers::{on_layer, DECALS, EFFECTS_HIGH, EFFECTS_LOW};
+
 /// Types of visual effects
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EffectType {
     /// Blood splatter when creatures die
     BloodSplatter,
@@ -13,12 +18,90 @@ pub enum EffectType {
     MuzzleFlash,
     /// Bullet impact on creature
     BulletImpact,
+    /// Bigger, brighter burst for a critical hit
+    CriticalImpact,
+    /// White flash and dark shards of a Highlander instant kill
+    InstantKill,
     /// Pickup collected
     PickupCollect,
     /// Level up effect
     LevelUp,
     /// Death effect
     Death,
+    /// Damage-over-time tick puff (colored by status kind)
+    StatusTick,
+    /// Creature attack glancing off a shield or invincibility
+    Whiff,
+    /// Expanding ring from the last-stand push
+    Shockwave,
+    /// Where a lobbed grenade will come down
+    LandingMarker,
+    /// Chain lightning jumping between two creatures
+    LightningArc { from: Vec3, to: Vec3 },
+    /// Hitscan beam from the muzzle to where it stopped
+    Beam { from: Vec3, to: Vec3 },
+    /// One flame of a burning fire patch: a low ember with a rising tongue above it
+    Flame,
+    /// Smoke puff where a creature was removed without dying (Lifeline 50-50)
+    Vanish,
+    /// Translucent ion ring expanding out to `radius` (ManBomb)
+    IonRing { radius: f32 },
+    /// Green splash on a creature poisoned by biting the player
+    PoisonSplash,
+    /// Quick slash across a creature struck by a MrMelee counter
+    Slash,
+    /// Faint ring of dots around a player showing their aura's reach
+    AuraRing,
+    /// Faint line from a player to the creature their EvilEyes hold
+    StareBeam,
+    /// Thin red line along a Sharpshooter's aim
+    LaserSight,
+    /// Bright rim around a creature seen with MonsterVision
+    MonsterVisionOutline,
+    /// Streak trailing a player running at high LongDistanceRunner momentum
+    SpeedLines { heading: Vec2 },
+    /// Brief wash over the screen when Jinxed strikes, gold for good luck and purple for bad
+    JinxFlash { good: bool },
+    /// Green motes rising around a Necromancer as its minions appear
+    SummonRitual,
+    /// Pale sparkle as a Ghost turns solid or phases out
+    GhostShimmer,
+}
+
+impl EffectType {
+    /// Render layer: gore and small puffs stay under bursts and flashes
+    pub fn layer(&self) -> f32 {
+        match self {
+            EffectType::BloodSplatter
+            | EffectType::BulletImpact
+            | EffectType::Death
+            | EffectType::StatusTick
+            | EffectType::LandingMarker
+            | EffectType::Flame
+            | EffectType::Vanish
+            | EffectType::GhostShimmer
+            | EffectType::PoisonSplash
+            | EffectType::AuraRing
+            | EffectType::StareBeam
+            | EffectType::LaserSight
+            | EffectType::MonsterVisionOutline
+            | EffectType::SpeedLines { .. } => EFFECTS_LOW,
+            EffectType::Explosion
+            | EffectType::MuzzleFlash
+            | EffectType::PickupCollect
+            | EffectType::LevelUp
+            | EffectType::Whiff
+            | EffectType::Slash
+            | EffectType::Shockwave
+            | EffectType::CriticalImpact
+            | EffectType::InstantKill
+            | EffectType::LightningArc { .. }
+            | EffectType::Beam { .. }
+            | EffectType::IonRing { .. }
+            | EffectType::JinxFlash { .. }
+            | EffectType::SummonRitual => EFFECTS_HIGH,
+        }
+    }
 }
 
 /// Marker component for effect entities
@@ -27,6 +110,34 @@ pub struct Effect {
     pub effect_type: EffectType,
 }
 
+/// Faint marker under a grenade's landing spot, gone once the grenade is
+#[derive(Component, Debug)]
+pub struct LandingMarker {
+    pub grenade: Entity,
+}
+
+/// Follows a player with a Radioactive or Pyrokinetic aura, gone once the aura is
+#[derive(Component, Debug)]
+pub struct AuraRing {
+    pub owner: Entity,
+}
+
+/// Eye-beam toward a creature held by EvilEyes, gone once it's released
+#[derive(Component, Debug)]
+pub struct StareBeam {
+    pub target: Entity,
+}
+
+/// Laser sight of a player with Sharpshooter, gone once the perk is
+#[derive(Component, Debug)]
+pub struct LaserSight {
+    pub owner: Entity,
+}
+
+/// Outline behind a creature's sprite (MonsterVision), a child of the creature
+#[derive(Component, Debug)]
+pub struct MonsterVisionOutline;
+
 /// Component for particle effects
 #[derive(Component, Debug)]
 pub struct Particle {
@@ -119,6 +230,107 @@ impl ScreenShake {
     }
 }
 
+/// Most decals left on the ground at once; past this the oldest are recycled
+pub const DECAL_CAP: usize = 300;
+/// Width of the blood splat a regular creature leaves
+pub const DECAL_SIZE: f32 = 24.0;
+/// Boss decals are this many times bigger
+pub const BOSS_DECAL_SCALE: f32 = 3.0;
+/// BloodyMess: decal size multiplier
+pub const BLOODY_MESS_DECAL_SCALE: f32 = 1.5;
+/// BloodyMess: splats left per kill
+pub const BLOODY_MESS_DECALS: u32 = 3;
+/// Dark patch left by a creature that burned to death
+pub const SCORCH_DECAL_COLOR: Color = Color::srgba(0.08, 0.06, 0.05, 0.7);
+
+/// Blood splat or corpse on the ground, kept for the rest of the run
+#[derive(Component, Debug)]
+pub struct Decal;
+
+/// Every decal on the ground, oldest first, capped at [`DECAL_CAP`]
+#[derive(Resource, Debug)]
+pub struct DecalRing {
+    entities: VecDeque<Entity>,
+    cap: usize,
+}
+
+impl Default for DecalRing {
+    fn default() -> Self {
+        Self::with_cap(DECAL_CAP)
+    }
+}
+
+impl DecalRing {
+    pub fn with_cap(cap: usize) -> Self {
+        Self {
+            entities: VecDeque::with_capacity(cap),
+            cap,
+        }
+    }
+
+    /// The decal to reuse for the next splat once the ring is full: the oldest one,
+    /// which moves to the back as the newest
+    pub fn recycle(&mut self) -> Option<Entity> {
+        if self.entities.len() < self.cap {
+            return None;
+        }
+        let oldest = self.entities.pop_front()?;
+        self.entities.push_back(oldest);
+        Some(oldest)
+    }
+
+    /// Records a freshly spawned decal
+    pub fn push(&mut self, entity: Entity) {
+        self.entities.push_back(entity);
+    }
+
+    pub fn clear(&mut self) {
+        self.entities.clear();
+    }
+}
+
+/// Color of the blood a creature type leaves behind
+pub fn blood_color(creature_type: CreatureType) -> Color {
+    match creature_type {
+        CreatureType::Zombie => Color::srgba(0.25, 0.3, 0.1, 0.8),
+        CreatureType::Spider
+        | CreatureType::GiantSpider
+        | CreatureType::Beetle
+        | CreatureType::AlienSpider
+        | CreatureType::AlienShooter
+        | CreatureType::BossSpider
+        | CreatureType::BossAlien => Color::srgba(0.2, 0.45, 0.1, 0.8),
+        CreatureType::Ghost => Color::srgba(0.75, 0.8, 1.0, 0.35),
+        CreatureType::Exploder | CreatureType::Turret => SCORCH_DECAL_COLOR,
+        _ => Color::srgba(0.45, 0.02, 0.02, 0.8),
+    }
+}
+
+/// Bundle for a decal on the ground
+#[derive(Bundle)]
+pub struct DecalBundle {
+    pub decal: Decal,
+    pub sprite: SpriteBundle,
+}
+
+impl DecalBundle {
+    pub fn new(color: Color, position: Vec3, size: f32, rotation: f32) -> Self {
+        Self {
+            decal: Decal,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                transform: Transform::from_translation(on_layer(position, DECALS))
+                    .with_rotation(Quat::from_rotation_z(rotation)),
+                ..default()
+            },
+        }
+    }
+}
+
 /// Bundle for spawning particle effects
 #[derive(Bundle)]
 pub struct ParticleBundle {
@@ -140,7 +352,7 @@ impl ParticleBundle {
                     custom_size: Some(Vec2::splat(4.0)),
                     ..default()
                 },
-                transform: Transform::from_translation(position),
+                transform: Transform::from_translation(on_layer(position, EFFECTS_LOW)),
                 ..default()
             },
         }
@@ -160,7 +372,7 @@ impl ParticleBundle {
                     custom_size: Some(Vec2::splat(8.0)),
                     ..default()
                 },
-                transform: Transform::from_translation(position),
+                transform: Transform::from_translation(on_layer(position, EFFECTS_HIGH)),
                 ..default()
             },
         }
@@ -178,7 +390,7 @@ impl ParticleBundle {
                     custom_size: Some(Vec2::new(16.0, 8.0)),
                     ..default()
                 },
-                transform: Transform::from_translation(position),
+                transform: Transform::from_translation(on_layer(position, EFFECTS_HIGH)),
                 ..default()
             },
         }
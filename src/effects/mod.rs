@@ -10,6 +10,7 @@ pub use systems::*;
 
 use bevy::prelude::*;
 
+use crate::schedule::GameSet;
 use crate::states::GameState;
 
 /// Plugin for visual effects
@@ -20,20 +21,34 @@ impl Plugin for EffectsPlugin {
         app.add_event::<SpawnEffectEvent>()
             .init_resource::<ScreenShake>()
             .init_resource::<CameraBasePosition>()
-            .add_systems(OnExit(GameState::Playing), cleanup_all_effects)
+            .init_resource::<DecalRing>()
+            .add_systems(OnExit(GameState::Playing), (cleanup_all_effects, despawn_all_decals))
             .add_systems(
                 Update,
                 (
                     // Event listeners that spawn effects
-                    spawn_blood_on_death,
+                    (spawn_blood_on_death, spawn_death_decals),
                     spawn_levelup_effect,
                     spawn_pickup_effect,
+                    spawn_weapon_grant_effect,
+                    spawn_whiff_effect,
                     spawn_muzzle_flash,
                     spawn_hit_effect,
+                    spawn_status_tick_effects,
                     // Trigger screen shake from hits
                     trigger_screen_shake_on_hit,
                     // Explosion effects
                     spawn_explosion_effects,
+                    spawn_landing_markers,
+                    (
+                        spawn_fire_patch_flames,
+                        update_aura_rings,
+                        update_stare_beams,
+                        update_laser_sights,
+                        update_monster_vision_outlines,
+                        spawn_speed_lines,
+                    ),
+                    update_landing_markers,
                     // Effect processing
                     handle_effect_spawns,
                     update_particles,
@@ -42,6 +57,7 @@ impl Plugin for EffectsPlugin {
                     cleanup_expired_effects,
                 )
                     .chain()
+                    .in_set(GameSet::Presentation)
                     .run_if(in_state(GameState::Playing)),
             );
     }
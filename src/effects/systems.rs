@@ -4,15 +4,27 @@ use bevy::prelude::*;
 use rand::Rng;
 
 use super::components::{
-    CameraBasePosition, Effect, EffectType, Particle, ParticleBundle, ScreenShake,
+    blood_color, AuraRing, CameraBasePosition, Decal, DecalBundle, DecalRing, Effect, EffectType, LandingMarker,
+    LaserSight, MonsterVisionOutline, Particle, ParticleBundle, ScreenShake, StareBeam, BLOODY_MESS_DECALS,
+    BLOODY_MESS_DECAL_SCALE, BOSS_DECAL_SCALE, DECAL_SIZE, SCORCH_DECAL_COLOR,
 };
 use crate::audio::{PlaySoundEvent, SoundEffect};
 use crate::bonuses::systems::BonusCollectedEvent;
-use crate::creatures::systems::CreatureDeathEvent;
-use crate::player::components::Player;
-use crate::player::systems::PlayerLevelUpEvent;
-use crate::weapons::components::Explosive;
-use crate::weapons::systems::{FireWeaponEvent, ProjectileHitEvent};
+use crate::creatures::systems::{CreatureDeathEvent, StatusDamageEvent};
+use crate::creatures::{Creature, CreatureGrid, Stared, StatusKind};
+use crate::perks::components::{PerkBonuses, RunnerMomentum, AURA_RADIUS};
+use crate::player::components::{AimDirection, Player};
+use crate::player::systems::{PlayerHitBlockedEvent, PlayerLevelUpEvent};
+use crate::render_layers::{on_layer, EFFECTS_HIGH, EFFECTS_LOW};
+use crate::states::{gameplay_frozen, BossEncounterState, PlayingState};
+use crate::viewport::{ArenaBounds, ViewportInfo};
+use crate::weapons::components::{
+    EquippedWeapon, Explosive, FirePatch, GrenadeArc, BEAM_COLOR, CHAIN_LIGHTNING_COLOR,
+};
+use crate::weapons::systems::{
+    creatures_along_ray, weapon_range, ExplosionEvent, FireWeaponEvent, ProjectileHitEvent, WeaponGrantedEvent,
+};
+use crate::weapons::WeaponRegistry;
 
 /// Event to spawn an effect
 #[derive(Event)]
@@ -22,18 +34,26 @@ pub struct SpawnEffectEvent {
     pub count: u32,
 }
 
+/// Seconds a chain lightning arc takes to fade
+pub const LIGHTNING_ARC_LIFETIME: f32 = 0.15;
+/// Seconds a hitscan beam takes to fade
+pub const BEAM_LIFETIME: f32 = 0.25;
+/// Jinxed flash size, centered on the player and wide enough to cover the screen
+const JINX_FLASH_SIZE: f32 = 4000.0;
+
 /// Handles effect spawn events
 pub fn handle_effect_spawns(mut commands: Commands, mut events: EventReader<SpawnEffectEvent>) {
     let mut rng = rand::thread_rng();
 
     for event in events.read() {
+        let position = on_layer(event.position, event.effect_type.layer());
         match event.effect_type {
             EffectType::BloodSplatter => {
                 for _ in 0..event.count {
                     let angle = rng.gen_range(0.0..std::f32::consts::TAU);
                     let speed = rng.gen_range(50.0..150.0);
                     let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
-                    commands.spawn(ParticleBundle::blood(event.position, velocity));
+                    commands.spawn(ParticleBundle::blood(position, velocity));
                 }
             }
             EffectType::Explosion => {
@@ -41,11 +61,11 @@ pub fn handle_effect_spawns(mut commands: Commands, mut events: EventReader<Spaw
                     let angle = rng.gen_range(0.0..std::f32::consts::TAU);
                     let speed = rng.gen_range(100.0..300.0);
                     let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
-                    commands.spawn(ParticleBundle::explosion(event.position, velocity));
+                    commands.spawn(ParticleBundle::explosion(position, velocity));
                 }
             }
             EffectType::MuzzleFlash => {
-                commands.spawn(ParticleBundle::muzzle_flash(event.position));
+                commands.spawn(ParticleBundle::muzzle_flash(position));
             }
             EffectType::BulletImpact => {
                 for _ in 0..event.count.min(5) {
@@ -64,7 +84,68 @@ pub fn handle_effect_spawns(mut commands: Commands, mut events: EventReader<Spaw
                                 custom_size: Some(Vec2::splat(3.0)),
                                 ..default()
                             },
-                            transform: Transform::from_translation(event.position),
+                            transform: Transform::from_translation(position),
+                            ..default()
+                        },
+                    ));
+                }
+            }
+            EffectType::CriticalImpact => {
+                for _ in 0..event.count.min(10) {
+                    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                    let speed = rng.gen_range(80.0..180.0);
+                    let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
+
+                    commands.spawn((
+                        Effect {
+                            effect_type: EffectType::CriticalImpact,
+                        },
+                        Particle::new(velocity, 0.3).with_fade(true),
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgb(1.0, 1.0, 0.2),
+                                custom_size: Some(Vec2::splat(6.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position),
+                            ..default()
+                        },
+                    ));
+                }
+            }
+            EffectType::InstantKill => {
+                commands.spawn((
+                    Effect {
+                        effect_type: EffectType::InstantKill,
+                    },
+                    Particle::new(Vec2::ZERO, 0.25)
+                        .with_fade(true)
+                        .with_scale_change(3.0),
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::srgb(1.0, 1.0, 1.0),
+                            custom_size: Some(Vec2::splat(20.0)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(position),
+                        ..default()
+                    },
+                ));
+                for i in 0..8 {
+                    let angle = (i as f32 / 8.0) * std::f32::consts::TAU;
+                    commands.spawn((
+                        Effect {
+                            effect_type: EffectType::InstantKill,
+                        },
+                        Particle::new(Vec2::from_angle(angle) * 140.0, 0.4).with_fade(true),
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgb(0.45, 0.0, 0.05),
+                                custom_size: Some(Vec2::new(10.0, 3.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position)
+                                .with_rotation(Quat::from_rotation_z(angle)),
                             ..default()
                         },
                     ));
@@ -86,7 +167,7 @@ pub fn handle_effect_spawns(mut commands: Commands, mut events: EventReader<Spaw
                                 custom_size: Some(Vec2::splat(4.0)),
                                 ..default()
                             },
-                            transform: Transform::from_translation(event.position),
+                            transform: Transform::from_translation(position),
                             ..default()
                         },
                     ));
@@ -110,7 +191,7 @@ pub fn handle_effect_spawns(mut commands: Commands, mut events: EventReader<Spaw
                                 custom_size: Some(Vec2::splat(6.0)),
                                 ..default()
                             },
-                            transform: Transform::from_translation(event.position),
+                            transform: Transform::from_translation(position),
                             ..default()
                         },
                     ));
@@ -122,9 +203,313 @@ pub fn handle_effect_spawns(mut commands: Commands, mut events: EventReader<Spaw
                     let angle = rng.gen_range(0.0..std::f32::consts::TAU);
                     let speed = rng.gen_range(80.0..200.0);
                     let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
-                    commands.spawn(ParticleBundle::blood(event.position, velocity));
+                    commands.spawn(ParticleBundle::blood(position, velocity));
+                }
+            }
+            EffectType::StatusTick => {
+                // Spawned directly by spawn_status_tick_effects, which knows the status color
+            }
+            EffectType::LandingMarker => {
+                // Spawned directly by spawn_landing_markers, which follows the grenade
+            }
+            EffectType::AuraRing => {
+                // Spawned directly by update_aura_rings, which follows the player
+            }
+            EffectType::StareBeam => {
+                // Spawned directly by update_stare_beams, which tracks the stare
+            }
+            EffectType::LaserSight => {
+                // Spawned directly by update_laser_sights, which follows the aim
+            }
+            EffectType::MonsterVisionOutline => {
+                // Spawned directly by update_monster_vision_outlines, under each creature
+            }
+            EffectType::SpeedLines { heading } => {
+                // Thin streaks beside and behind the runner, left behind as they pull away
+                let side = Vec2::new(-heading.y, heading.x) * rng.gen_range(-12.0..12.0);
+                let origin = position.truncate() - heading * 14.0 + side;
+                commands.spawn((
+                    Effect {
+                        effect_type: EffectType::SpeedLines { heading },
+                    },
+                    Particle::new(-heading * 40.0, 0.2).with_fade(true),
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::srgba(1.0, 1.0, 1.0, 0.35),
+                            custom_size: Some(Vec2::new(rng.gen_range(14.0..24.0), 1.5)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(origin.extend(position.z))
+                            .with_rotation(Quat::from_rotation_z(heading.to_angle())),
+                        ..default()
+                    },
+                ));
+            }
+            EffectType::Whiff => {
+                for i in 0..6 {
+                    let angle = (i as f32 / 6.0) * std::f32::consts::TAU + rng.gen_range(-0.3..0.3);
+                    let velocity = Vec2::new(angle.cos() * 120.0, angle.sin() * 120.0);
+
+                    commands.spawn((
+                        Effect {
+                            effect_type: EffectType::Whiff,
+                        },
+                        Particle::new(velocity, 0.2).with_fade(true),
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgb(0.7, 0.85, 1.0),
+                                custom_size: Some(Vec2::new(6.0, 2.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position)
+                                .with_rotation(Quat::from_rotation_z(angle)),
+                            ..default()
+                        },
+                    ));
+                }
+            }
+            EffectType::PoisonSplash => {
+                let color = StatusKind::Poisoned.color();
+                for _ in 0..6 {
+                    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                    let velocity = Vec2::from_angle(angle) * rng.gen_range(40.0..90.0);
+                    commands.spawn((
+                        Effect {
+                            effect_type: EffectType::PoisonSplash,
+                        },
+                        Particle::new(velocity, 0.3).with_fade(true),
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color,
+                                custom_size: Some(Vec2::splat(5.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position),
+                            ..default()
+                        },
+                    ));
+                }
+            }
+            EffectType::Slash => {
+                // A short bright stroke at a random angle, drifting along its own line
+                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                commands.spawn((
+                    Effect {
+                        effect_type: EffectType::Slash,
+                    },
+                    Particle::new(Vec2::from_angle(angle) * 60.0, 0.15).with_fade(true),
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::srgb(1.0, 1.0, 0.9),
+                            custom_size: Some(Vec2::new(28.0, 3.0)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(position)
+                            .with_rotation(Quat::from_rotation_z(angle)),
+                        ..default()
+                    },
+                ));
+            }
+            EffectType::IonRing { radius } => {
+                const SEGMENTS: u32 = 32;
+                const LIFETIME: f32 = 0.4;
+                for i in 0..SEGMENTS {
+                    let angle = (i as f32 / SEGMENTS as f32) * std::f32::consts::TAU;
+                    let velocity = Vec2::from_angle(angle) * radius / LIFETIME;
+
+                    commands.spawn((
+                        Effect {
+                            effect_type: EffectType::IonRing { radius },
+                        },
+                        Particle::new(velocity, LIFETIME).with_fade(true).with_scale_change(1.5),
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgba(0.4, 0.7, 1.0, 0.5),
+                                custom_size: Some(Vec2::new(radius * std::f32::consts::TAU / SEGMENTS as f32, 4.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position)
+                                .with_rotation(Quat::from_rotation_z(angle + std::f32::consts::FRAC_PI_2)),
+                            ..default()
+                        },
+                    ));
+                }
+            }
+            EffectType::JinxFlash { good } => {
+                let color = if good {
+                    Color::srgba(1.0, 0.85, 0.3, 0.25)
+                } else {
+                    Color::srgba(0.6, 0.1, 0.8, 0.25)
+                };
+                commands.spawn((
+                    Effect {
+                        effect_type: event.effect_type,
+                    },
+                    Particle::new(Vec2::ZERO, 0.3).with_fade(true),
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color,
+                            custom_size: Some(Vec2::splat(JINX_FLASH_SIZE)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(position),
+                        ..default()
+                    },
+                ));
+            }
+            EffectType::SummonRitual => {
+                for _ in 0..event.count {
+                    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                    let offset = Vec2::from_angle(angle) * rng.gen_range(10.0..40.0);
+                    let velocity = Vec2::new(offset.x, rng.gen_range(60.0..120.0));
+                    commands.spawn((
+                        Effect {
+                            effect_type: EffectType::SummonRitual,
+                        },
+                        Particle::new(velocity, 0.6).with_fade(true),
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgb(0.3, 1.0, 0.4),
+                                custom_size: Some(Vec2::splat(4.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position + offset.extend(0.0)),
+                            ..default()
+                        },
+                    ));
+                }
+            }
+            EffectType::GhostShimmer => {
+                for _ in 0..event.count {
+                    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                    let offset = Vec2::from_angle(angle) * rng.gen_range(4.0..18.0);
+
+                    commands.spawn((
+                        Effect {
+                            effect_type: EffectType::GhostShimmer,
+                        },
+                        Particle::new(offset * 2.0, 0.3).with_fade(true),
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgba(0.85, 0.9, 1.0, 0.8),
+                                custom_size: Some(Vec2::splat(3.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position + offset.extend(0.0)),
+                            ..default()
+                        },
+                    ));
+                }
+            }
+            EffectType::Vanish => {
+                for _ in 0..8 {
+                    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                    let speed = rng.gen_range(20.0..60.0);
+                    let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed);
+
+                    commands.spawn((
+                        Effect {
+                            effect_type: EffectType::Vanish,
+                        },
+                        Particle::new(velocity, 0.4).with_fade(true).with_scale_change(1.5),
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgb(0.75, 0.75, 0.8),
+                                custom_size: Some(Vec2::splat(8.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position),
+                            ..default()
+                        },
+                    ));
+                }
+            }
+            EffectType::Shockwave => {
+                for i in 0..24 {
+                    let angle = (i as f32 / 24.0) * std::f32::consts::TAU;
+                    let velocity = Vec2::new(angle.cos() * 300.0, angle.sin() * 300.0);
+
+                    commands.spawn((
+                        Effect {
+                            effect_type: EffectType::Shockwave,
+                        },
+                        Particle::new(velocity, 0.3)
+                            .with_fade(true)
+                            .with_scale_change(2.0),
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: Color::srgb(0.9, 0.9, 1.0),
+                                custom_size: Some(Vec2::new(10.0, 3.0)),
+                                ..default()
+                            },
+                            transform: Transform::from_translation(position)
+                                .with_rotation(Quat::from_rotation_z(angle + std::f32::consts::FRAC_PI_2)),
+                            ..default()
+                        },
+                    ));
                 }
             }
+            EffectType::Flame => {
+                // Ember on the ground, tongue flickering up over creatures
+                let drift = Vec2::new(rng.gen_range(-10.0..10.0), rng.gen_range(5.0..15.0));
+                commands.spawn((
+                    Effect {
+                        effect_type: EffectType::Flame,
+                    },
+                    Particle::new(drift, 0.5).with_fade(true),
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::srgb(0.8, 0.2, 0.05),
+                            custom_size: Some(Vec2::splat(10.0)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(position),
+                        ..default()
+                    },
+                ));
+                commands.spawn((
+                    Effect {
+                        effect_type: EffectType::Flame,
+                    },
+                    Particle::new(drift + Vec2::new(0.0, rng.gen_range(30.0..60.0)), 0.35)
+                        .with_fade(true)
+                        .with_scale_change(-1.5),
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color: Color::srgb(1.0, 0.75, 0.2),
+                            custom_size: Some(Vec2::splat(6.0)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(on_layer(event.position, EFFECTS_HIGH)),
+                        ..default()
+                    },
+                ));
+            }
+            EffectType::LightningArc { from, to } | EffectType::Beam { from, to } => {
+                // One thin sprite stretched and rotated to span the jump or beam
+                let (color, width, lifetime) = match event.effect_type {
+                    EffectType::Beam { .. } => (BEAM_COLOR, 4.0, BEAM_LIFETIME),
+                    _ => (CHAIN_LIGHTNING_COLOR, 3.0, LIGHTNING_ARC_LIFETIME),
+                };
+                let span = (to - from).truncate();
+                let midpoint = on_layer((from + to) / 2.0, event.effect_type.layer());
+                commands.spawn((
+                    Effect {
+                        effect_type: event.effect_type,
+                    },
+                    Particle::new(Vec2::ZERO, lifetime).with_fade(true),
+                    SpriteBundle {
+                        sprite: Sprite {
+                            color,
+                            custom_size: Some(Vec2::new(span.length(), width)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(midpoint)
+                            .with_rotation(Quat::from_rotation_z(span.to_angle())),
+                        ..default()
+                    },
+                ));
+            }
         }
     }
 }
@@ -181,9 +566,11 @@ pub fn update_particles(
     }
 }
 
-/// Updates camera to follow the player
+/// Updates camera to follow the player, keeping the view inside the arena
 pub fn update_camera_follow(
     player_query: Query<&Transform, (With<Player>, Without<Camera2d>)>,
+    viewport: Res<ViewportInfo>,
+    arena: Res<ArenaBounds>,
     mut base_pos: ResMut<CameraBasePosition>,
 ) {
     // Camera follows the average player position
@@ -196,7 +583,7 @@ pub fn update_camera_follow(
     }
 
     if count > 0 {
-        base_pos.position = total_pos / count as f32;
+        base_pos.position = viewport.clamp_camera(total_pos / count as f32, &arena);
     }
 }
 
@@ -245,6 +632,66 @@ pub fn cleanup_all_effects(mut commands: Commands, query: Query<Entity, With<Eff
     }
 }
 
+/// Despawns every decal and empties the ring; the next run starts on a clean floor
+pub fn despawn_all_decals(mut commands: Commands, mut ring: ResMut<DecalRing>, query: Query<Entity, With<Decal>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    ring.clear();
+}
+
+/// Leaves a blood splat where each creature died, or a scorch mark if it burned.
+/// Once the ring is full the oldest decal is moved to the new spot instead of spawning another
+pub fn spawn_death_decals(
+    mut commands: Commands,
+    mut death_events: EventReader<CreatureDeathEvent>,
+    mut ring: ResMut<DecalRing>,
+    player_query: Query<&PerkBonuses, With<Player>>,
+) {
+    let mut rng = rand::thread_rng();
+    let bloody_mess = player_query.iter().any(|bonuses| bonuses.bloody_mess);
+    let (splats, mess_scale) = if bloody_mess {
+        (BLOODY_MESS_DECALS, BLOODY_MESS_DECAL_SCALE)
+    } else {
+        (1, 1.0)
+    };
+
+    for event in death_events.read() {
+        let boss_scale = if event.creature_type.is_boss() { BOSS_DECAL_SCALE } else { 1.0 };
+        let size = DECAL_SIZE * boss_scale * mess_scale;
+        let color = if event.killed_by == Some(StatusKind::Burning) {
+            SCORCH_DECAL_COLOR
+        } else {
+            blood_color(event.creature_type)
+        };
+
+        for splat in 0..splats {
+            // Extra splats spatter around the body
+            let offset = if splat == 0 {
+                Vec2::ZERO
+            } else {
+                Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * rng.gen_range(0.3..0.8) * size
+            };
+            let splat_size = if splat == 0 { size } else { size * rng.gen_range(0.4..0.7) };
+            let decal = DecalBundle::new(
+                color,
+                event.position + offset.extend(0.0),
+                splat_size,
+                rng.gen_range(0.0..std::f32::consts::TAU),
+            );
+            match ring.recycle() {
+                Some(oldest) => {
+                    commands.entity(oldest).insert(decal);
+                }
+                None => {
+                    let entity = commands.spawn(decal).id();
+                    ring.push(entity);
+                }
+            }
+        }
+    }
+}
+
 /// Spawns blood effects when creatures die
 pub fn spawn_blood_on_death(
     mut death_events: EventReader<CreatureDeathEvent>,
@@ -259,12 +706,20 @@ pub fn spawn_blood_on_death(
         let is_boss = event.creature_type.is_boss();
         let blood_count = if is_boss { 20 } else { 8 };
 
-        // Spawn blood splatter
-        effect_events.send(SpawnEffectEvent {
-            effect_type: EffectType::BloodSplatter,
-            position: event.position,
-            count: blood_count,
-        });
+        // Burned-out creatures leave embers instead of a blood splatter
+        if event.killed_by == Some(StatusKind::Burning) {
+            effect_events.send(SpawnEffectEvent {
+                effect_type: EffectType::Explosion,
+                position: event.position,
+                count: blood_count / 2,
+            });
+        } else {
+            effect_events.send(SpawnEffectEvent {
+                effect_type: EffectType::BloodSplatter,
+                position: event.position,
+                count: blood_count,
+            });
+        }
 
         // Also spawn death effect for larger impact
         effect_events.send(SpawnEffectEvent {
@@ -333,6 +788,47 @@ pub fn spawn_pickup_effect(
     }
 }
 
+/// Ring burst around the player when a weapon pickup hands over a new weapon
+pub fn spawn_weapon_grant_effect(
+    mut granted_events: EventReader<WeaponGrantedEvent>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+) {
+    for event in granted_events.read() {
+        effect_events.send(SpawnEffectEvent {
+            effect_type: EffectType::LevelUp,
+            position: event.position,
+            count: 1,
+        });
+    }
+}
+
+/// Whiff cue when a hit is blocked: a glancing spark at the attacker and a swish sound
+pub fn spawn_whiff_effect(
+    mut blocked_events: EventReader<PlayerHitBlockedEvent>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    transforms: Query<&Transform>,
+) {
+    for event in blocked_events.read() {
+        let Some(transform) = event
+            .source
+            .and_then(|source| transforms.get(source).ok())
+            .or_else(|| transforms.get(event.player_entity).ok())
+        else {
+            continue;
+        };
+        effect_events.send(SpawnEffectEvent {
+            effect_type: EffectType::Whiff,
+            position: transform.translation,
+            count: 1,
+        });
+        sound_events.send(PlaySoundEvent {
+            sound: SoundEffect::AttackWhiff,
+            position: Some(transform.translation.truncate()),
+        });
+    }
+}
+
 /// Spawns muzzle flash when weapons fire
 pub fn spawn_muzzle_flash(
     mut fire_events: EventReader<FireWeaponEvent>,
@@ -347,45 +843,438 @@ pub fn spawn_muzzle_flash(
     }
 }
 
-/// Spawns bullet impact effect when projectiles hit
-pub fn spawn_hit_effect(
-    mut hit_events: EventReader<ProjectileHitEvent>,
+/// Flames each fire patch spawns per second, per 1000 square pixels of its area
+pub const FIRE_PATCH_FLAME_DENSITY: f32 = 2.5;
+
+/// Keeps fire patches alight with a steady stream of short-lived flames
+pub fn spawn_fire_patch_flames(
+    time: Res<Time>,
+    patch_query: Query<(&Transform, &FirePatch)>,
     mut effect_events: EventWriter<SpawnEffectEvent>,
 ) {
-    for event in hit_events.read() {
-        effect_events.send(SpawnEffectEvent {
-            effect_type: EffectType::BulletImpact,
-            position: event.position,
-            count: 3,
-        });
+    let mut rng = rand::thread_rng();
+    for (transform, patch) in patch_query.iter() {
+        let area = std::f32::consts::PI * patch.radius * patch.radius;
+        let expected = FIRE_PATCH_FLAME_DENSITY * area / 1000.0 * time.delta_seconds();
+        // Whole flames plus a chance at one more, so low frame times still flicker
+        let count = expected as u32 + u32::from(rng.gen::<f32>() < expected.fract());
+        for _ in 0..count {
+            let offset = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU))
+                * patch.radius
+                * rng.gen::<f32>().sqrt();
+            effect_events.send(SpawnEffectEvent {
+                effect_type: EffectType::Flame,
+                position: transform.translation + offset.extend(0.0),
+                count: 1,
+            });
+        }
     }
 }
 
-/// Plays explosion sound and effects for explosive projectiles
-pub fn spawn_explosion_effects(
-    query: Query<(&Transform, &Explosive)>,
-    despawned: Query<Entity, Added<crate::weapons::components::ProjectileDespawn>>,
-    explosive_query: Query<(&Transform, &Explosive)>,
+/// LongDistanceRunner momentum past which speed lines start to show
+pub const SPEED_LINES_MOMENTUM: f32 = 1.8;
+/// Speed lines per second for each point of momentum past the threshold
+pub const SPEED_LINES_RATE: f32 = 60.0;
+
+/// Trails speed lines behind players running at high LongDistanceRunner momentum
+pub fn spawn_speed_lines(
+    time: Res<Time>,
+    player_query: Query<(&Transform, &RunnerMomentum), With<Player>>,
     mut effect_events: EventWriter<SpawnEffectEvent>,
-    mut sound_events: EventWriter<PlaySoundEvent>,
 ) {
-    // When an explosive projectile is despawned, spawn explosion effects
-    for entity in despawned.iter() {
-        if let Ok((transform, _explosive)) = explosive_query.get(entity) {
+    let mut rng = rand::thread_rng();
+    for (transform, momentum) in player_query.iter() {
+        if momentum.multiplier < SPEED_LINES_MOMENTUM || momentum.heading == Vec2::ZERO {
+            continue;
+        }
+        let expected = SPEED_LINES_RATE * (momentum.multiplier - SPEED_LINES_MOMENTUM) * time.delta_seconds();
+        let count = expected as u32 + u32::from(rng.gen::<f32>() < expected.fract());
+        for _ in 0..count {
             effect_events.send(SpawnEffectEvent {
-                effect_type: EffectType::Explosion,
+                effect_type: EffectType::SpeedLines {
+                    heading: momentum.heading,
+                },
                 position: transform.translation,
-                count: 20,
+                count: 1,
             });
-            sound_events.send(PlaySoundEvent {
-                sound: SoundEffect::Explosion,
-                position: Some(transform.translation.truncate()),
+        }
+    }
+}
+
+/// Spawns bullet impact effect when projectiles hit; crits get a bigger yellow burst
+/// and instant kills a flash on top
+pub fn spawn_hit_effect(
+    mut hit_events: EventReader<ProjectileHitEvent>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+) {
+    for event in hit_events.read() {
+        if event.instant_kill {
+            effect_events.send(SpawnEffectEvent {
+                effect_type: EffectType::InstantKill,
+                position: event.position,
+                count: 1,
             });
         }
+        effect_events.send(if event.was_crit {
+            SpawnEffectEvent {
+                effect_type: EffectType::CriticalImpact,
+                position: event.position,
+                count: 8,
+            }
+        } else {
+            SpawnEffectEvent {
+                effect_type: EffectType::BulletImpact,
+                position: event.position,
+                count: 3,
+            }
+        });
     }
+}
+
+/// Spawns small colored puffs where damage-over-time effects tick
+pub fn spawn_status_tick_effects(
+    mut commands: Commands,
+    mut status_events: EventReader<StatusDamageEvent>,
+) {
+    let mut rng = rand::thread_rng();
 
-    // Suppress unused warning for query
-    let _ = query;
+    for event in status_events.read() {
+        // Ticks arrive every frame, so only puff occasionally (more often for heavy damage)
+        if rng.gen::<f32>() > (event.damage * 2.0).min(1.0) {
+            continue;
+        }
+
+        let velocity = Vec2::new(rng.gen_range(-15.0..15.0), rng.gen_range(20.0..40.0));
+        commands.spawn((
+            Effect {
+                effect_type: EffectType::StatusTick,
+            },
+            Particle::new(velocity, 0.5).with_fade(true),
+            SpriteBundle {
+                sprite: Sprite {
+                    color: event.kind.color(),
+                    custom_size: Some(Vec2::splat(4.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(on_layer(event.position, EFFECTS_LOW)),
+                ..default()
+            },
+        ));
+    }
+}
+
+/// Marks the landing spot of each newly thrown grenade, sized to its blast
+pub fn spawn_landing_markers(
+    mut commands: Commands,
+    grenades: Query<(Entity, &GrenadeArc, Option<&Explosive>), Added<GrenadeArc>>,
+) {
+    for (grenade, arc, explosive) in grenades.iter() {
+        let radius = explosive.map_or(30.0, |e| e.radius);
+        commands.spawn((
+            Effect {
+                effect_type: EffectType::LandingMarker,
+            },
+            LandingMarker { grenade },
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::srgba(1.0, 0.5, 0.1, 0.0),
+                    custom_size: Some(Vec2::splat(radius * std::f32::consts::SQRT_2)),
+                    ..default()
+                },
+                transform: Transform::from_translation(arc.target.extend(EFFECTS_LOW))
+                    .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                ..default()
+            },
+        ));
+    }
+}
+
+/// Brightens landing markers as their grenade comes down and removes them once it has
+pub fn update_landing_markers(
+    mut commands: Commands,
+    mut markers: Query<(Entity, &LandingMarker, &mut Sprite)>,
+    grenades: Query<&GrenadeArc>,
+) {
+    for (entity, marker, mut sprite) in markers.iter_mut() {
+        match grenades.get(marker.grenade) {
+            Ok(arc) => sprite.color.set_alpha(0.08 + 0.17 * arc.progress()),
+            Err(_) => commands.entity(entity).despawn_recursive(),
+        }
+    }
+}
+
+/// Dots making up an aura ring
+const AURA_RING_DOTS: usize = 24;
+
+/// Keeps a faint ring around each player with a Radioactive or Pyrokinetic aura,
+/// green when radioactive, orange when only pyrokinetic
+pub fn update_aura_rings(
+    mut commands: Commands,
+    players: Query<(Entity, &Transform, &PerkBonuses), With<Player>>,
+    mut rings: Query<(Entity, &AuraRing, &mut Transform), Without<Player>>,
+) {
+    let mut ringed = Vec::new();
+    for (entity, ring, mut transform) in rings.iter_mut() {
+        match players.get(ring.owner) {
+            Ok((_, owner, bonuses)) if bonuses.radioactive_aura || bonuses.pyrokinetic_aura => {
+                transform.translation = on_layer(owner.translation, EFFECTS_LOW);
+                ringed.push(ring.owner);
+            }
+            _ => commands.entity(entity).despawn_recursive(),
+        }
+    }
+
+    for (owner, transform, bonuses) in players.iter() {
+        if ringed.contains(&owner) || !(bonuses.radioactive_aura || bonuses.pyrokinetic_aura) {
+            continue;
+        }
+        let color = if bonuses.radioactive_aura {
+            Color::srgba(0.4, 1.0, 0.3, 0.25)
+        } else {
+            Color::srgba(1.0, 0.5, 0.1, 0.25)
+        };
+        commands
+            .spawn((
+                Effect {
+                    effect_type: EffectType::AuraRing,
+                },
+                AuraRing { owner },
+                SpatialBundle::from_transform(Transform::from_translation(on_layer(
+                    transform.translation,
+                    EFFECTS_LOW,
+                ))),
+            ))
+            .with_children(|ring| {
+                for i in 0..AURA_RING_DOTS {
+                    let angle = i as f32 / AURA_RING_DOTS as f32 * std::f32::consts::TAU;
+                    ring.spawn(SpriteBundle {
+                        sprite: Sprite {
+                            color,
+                            custom_size: Some(Vec2::splat(3.0)),
+                            ..default()
+                        },
+                        transform: Transform::from_translation(
+                            (Vec2::from_angle(angle) * AURA_RADIUS).extend(0.0),
+                        ),
+                        ..default()
+                    });
+                }
+            });
+    }
+}
+
+/// Keeps a faint red line from each EvilEyes player to the creature they hold
+#[allow(clippy::type_complexity)]
+pub fn update_stare_beams(
+    mut commands: Commands,
+    stared: Query<(Entity, &Transform, &Stared), Without<StareBeam>>,
+    players: Query<&Transform, (With<Player>, Without<StareBeam>)>,
+    mut beams: Query<(Entity, &StareBeam, &mut Transform, &mut Sprite)>,
+) {
+    let span = |target: Entity| {
+        let (_, creature, stared) = stared.get(target).ok()?;
+        let player = players.get(stared.by).ok()?;
+        Some((player.translation, creature.translation))
+    };
+    let place = |from: Vec3, to: Vec3, transform: &mut Transform, sprite: &mut Sprite| {
+        let line = (to - from).truncate();
+        *transform = Transform::from_translation(on_layer((from + to) / 2.0, EFFECTS_LOW))
+            .with_rotation(Quat::from_rotation_z(line.to_angle()));
+        sprite.custom_size = Some(Vec2::new(line.length(), 2.0));
+    };
+
+    let mut beamed = Vec::new();
+    for (entity, beam, mut transform, mut sprite) in beams.iter_mut() {
+        match span(beam.target) {
+            Some((from, to)) => {
+                place(from, to, &mut transform, &mut sprite);
+                beamed.push(beam.target);
+            }
+            None => commands.entity(entity).despawn_recursive(),
+        }
+    }
+
+    for (target, _, _) in stared.iter() {
+        if beamed.contains(&target) {
+            continue;
+        }
+        let Some((from, to)) = span(target) else {
+            continue;
+        };
+        let mut transform = Transform::default();
+        let mut sprite = Sprite {
+            color: Color::srgba(1.0, 0.15, 0.15, 0.3),
+            ..default()
+        };
+        place(from, to, &mut transform, &mut sprite);
+        commands.spawn((
+            Effect {
+                effect_type: EffectType::StareBeam,
+            },
+            StareBeam { target },
+            SpriteBundle {
+                sprite,
+                transform,
+                ..default()
+            },
+        ));
+    }
+}
+
+/// Distance from the player's center to the muzzle, where the laser starts
+const LASER_SIGHT_MUZZLE: f32 = 20.0;
+
+/// Keeps a laser sight on each Sharpshooter player, running from the muzzle along
+/// the aim out to the weapon's range or the first creature in the way.
+/// Hidden while gameplay is frozen
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn update_laser_sights(
+    mut commands: Commands,
+    weapon_registry: Res<WeaponRegistry>,
+    creature_grid: Res<CreatureGrid>,
+    playing_state: Option<Res<State<PlayingState>>>,
+    boss_state: Option<Res<BossEncounterState>>,
+    players: Query<(Entity, &Transform, &AimDirection, &PerkBonuses, &EquippedWeapon), With<Player>>,
+    mut lasers: Query<(Entity, &LaserSight, &mut Transform, &mut Sprite, &mut Visibility), Without<Player>>,
+) {
+    let visibility = if gameplay_frozen(playing_state, boss_state) {
+        Visibility::Hidden
+    } else {
+        Visibility::Inherited
+    };
+    let span = |owner: Entity| {
+        let (_, transform, aim, bonuses, weapon) = players.get(owner).ok()?;
+        if !bonuses.laser_sight || aim.direction == Vec2::ZERO {
+            return None;
+        }
+        let range = weapon_registry.get_key(&weapon.weapon).map_or(0.0, |data| weapon_range(data, bonuses));
+        let from = transform.translation.truncate() + aim.direction * LASER_SIGHT_MUZZLE;
+        let length = creatures_along_ray(&creature_grid, from, aim.direction, range)
+            .first()
+            .map_or(range, |&(_, _, along)| along.max(0.0));
+        Some((from, from + aim.direction * length))
+    };
+    let place = |from: Vec2, to: Vec2, transform: &mut Transform, sprite: &mut Sprite| {
+        let line = to - from;
+        *transform = Transform::from_translation(on_layer(from.midpoint(to).extend(0.0), EFFECTS_LOW))
+            .with_rotation(Quat::from_rotation_z(line.to_angle()));
+        sprite.custom_size = Some(Vec2::new(line.length(), 1.0));
+    };
+
+    let mut sighted = Vec::new();
+    for (entity, laser, mut transform, mut sprite, mut laser_visibility) in lasers.iter_mut() {
+        match span(laser.owner) {
+            Some((from, to)) => {
+                place(from, to, &mut transform, &mut sprite);
+                *laser_visibility = visibility;
+                sighted.push(laser.owner);
+            }
+            None => commands.entity(entity).despawn_recursive(),
+        }
+    }
+
+    for (owner, ..) in players.iter() {
+        if sighted.contains(&owner) {
+            continue;
+        }
+        let Some((from, to)) = span(owner) else {
+            continue;
+        };
+        let mut transform = Transform::default();
+        let mut sprite = Sprite {
+            color: Color::srgba(1.0, 0.1, 0.1, 0.6),
+            ..default()
+        };
+        place(from, to, &mut transform, &mut sprite);
+        commands.spawn((
+            Effect {
+                effect_type: EffectType::LaserSight,
+            },
+            LaserSight { owner },
+            SpriteBundle {
+                sprite,
+                transform,
+                visibility,
+                ..default()
+            },
+        ));
+    }
+}
+
+/// How far a MonsterVision outline reaches past the creature's sprite
+const MONSTER_VISION_OUTLINE_WIDTH: f32 = 3.0;
+
+/// MonsterVision: rims every creature with a bright outline so it stands out from
+/// the ground, for as long as any player has the perk
+#[allow(clippy::type_complexity)]
+pub fn update_monster_vision_outlines(
+    mut commands: Commands,
+    players: Query<&PerkBonuses, With<Player>>,
+    creatures: Query<(Entity, &Sprite), (With<Creature>, Without<MonsterVisionOutline>)>,
+    mut outlines: Query<(Entity, &Parent, &mut Sprite), With<MonsterVisionOutline>>,
+) {
+    let active = players.iter().any(|bonuses| bonuses.monster_vision);
+    let outline_size = |sprite: &Sprite| {
+        sprite.custom_size.unwrap_or(Vec2::ONE) + Vec2::splat(MONSTER_VISION_OUTLINE_WIDTH * 2.0)
+    };
+
+    let mut outlined = Vec::new();
+    for (entity, parent, mut sprite) in outlines.iter_mut() {
+        match creatures.get(parent.get()) {
+            Ok((creature, creature_sprite)) if active => {
+                sprite.custom_size = Some(outline_size(creature_sprite));
+                outlined.push(creature);
+            }
+            _ => commands.entity(entity).despawn_recursive(),
+        }
+    }
+    if !active {
+        return;
+    }
+
+    for (creature, sprite) in creatures.iter() {
+        if outlined.contains(&creature) {
+            continue;
+        }
+        let outline = commands
+            .spawn((
+                Effect {
+                    effect_type: EffectType::MonsterVisionOutline,
+                },
+                MonsterVisionOutline,
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::srgba(1.0, 0.95, 0.4, 0.7),
+                        custom_size: Some(outline_size(sprite)),
+                        ..default()
+                    },
+                    // Just under the creature's own sprite
+                    transform: Transform::from_xyz(0.0, 0.0, -0.01),
+                    ..default()
+                },
+            ))
+            .id();
+        commands.entity(creature).add_child(outline);
+    }
+}
+
+/// Plays explosion sound and effects wherever an explosive detonates
+pub fn spawn_explosion_effects(
+    mut explosion_events: EventReader<ExplosionEvent>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for explosion in explosion_events.read() {
+        effect_events.send(SpawnEffectEvent {
+            effect_type: EffectType::Explosion,
+            position: explosion.position,
+            count: 20,
+        });
+        sound_events.send(PlaySoundEvent {
+            sound: SoundEffect::Explosion,
+            position: Some(explosion.position.truncate()),
+        });
+    }
 }
 
 #[cfg(test)]
@@ -401,4 +1290,214 @@ mod tests {
         };
         assert_eq!(event.count, 10);
     }
+
+    #[test]
+    fn aura_ring_follows_the_player_and_goes_with_the_aura() {
+        let mut app = App::new();
+        app.add_systems(Update, update_aura_rings);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                PerkBonuses {
+                    radioactive_aura: true,
+                    ..default()
+                },
+            ))
+            .id();
+        let rings = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<&Transform, With<AuraRing>>()
+                .iter(app.world())
+                .map(|transform| transform.translation.truncate())
+                .collect::<Vec<_>>()
+        };
+
+        app.update();
+        assert_eq!(rings(&mut app), vec![Vec2::ZERO]);
+
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation = Vec3::new(50.0, 10.0, 0.0);
+        app.update();
+        assert_eq!(rings(&mut app), vec![Vec2::new(50.0, 10.0)]);
+
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().radioactive_aura = false;
+        app.update();
+        assert!(rings(&mut app).is_empty());
+    }
+
+    #[test]
+    fn laser_sight_stops_at_the_first_creature_and_hides_while_frozen() {
+        use crate::creatures::{rebuild_creature_grid, CreatureBundle, CreatureType};
+        use crate::weapons::WeaponId;
+
+        let mut app = App::new();
+        app.init_resource::<WeaponRegistry>()
+            .init_resource::<CreatureGrid>()
+            .add_systems(Update, (rebuild_creature_grid, update_laser_sights).chain());
+        let bonuses = PerkBonuses {
+            laser_sight: true,
+            ..default()
+        };
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                AimDirection::from_direction(Vec2::X),
+                EquippedWeapon::new(WeaponId::Pistol, Some(12)),
+                bonuses.clone(),
+            ))
+            .id();
+        let laser = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(&Sprite, &Visibility), With<LaserSight>>()
+                .iter(app.world())
+                .map(|(sprite, visibility)| (sprite.custom_size.unwrap().x, *visibility))
+                .collect::<Vec<_>>()
+        };
+
+        // Nothing in the way: out to the weapon's full range from the muzzle
+        app.update();
+        let range = weapon_range(WeaponRegistry::default().get(WeaponId::Pistol).unwrap(), &bonuses);
+        assert_eq!(laser(&mut app), vec![(range, Visibility::Inherited)]);
+
+        // A creature in line cuts it short
+        app.world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(200.0, 0.0, 0.0)));
+        app.update();
+        assert_eq!(laser(&mut app), vec![(200.0 - LASER_SIGHT_MUZZLE, Visibility::Inherited)]);
+
+        app.insert_resource(State::new(PlayingState::PerkSelect));
+        app.update();
+        assert_eq!(laser(&mut app)[0].1, Visibility::Hidden);
+
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().laser_sight = false;
+        app.update();
+        assert!(laser(&mut app).is_empty());
+    }
+
+    #[test]
+    fn monster_vision_outlines_every_creature_while_held() {
+        use crate::creatures::{CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.add_systems(Update, update_monster_vision_outlines);
+        let player = app.world_mut().spawn((Player::default(), PerkBonuses::default())).id();
+        for x in [0.0, 5000.0] {
+            app.world_mut()
+                .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(x, 0.0, 0.0)));
+        }
+        let outlines = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<&Sprite, With<MonsterVisionOutline>>()
+                .iter(app.world())
+                .map(|sprite| sprite.custom_size.unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        app.update();
+        assert!(outlines(&mut app).is_empty());
+
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().monster_vision = true;
+        app.update();
+        app.update();
+        assert_eq!(outlines(&mut app), vec![Vec2::splat(34.0); 2]);
+
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().monster_vision = false;
+        app.update();
+        assert!(outlines(&mut app).is_empty());
+    }
+
+    fn decal_test_app(cap: usize) -> App {
+        let mut app = App::new();
+        app.insert_resource(DecalRing::with_cap(cap))
+            .add_event::<CreatureDeathEvent>()
+            .add_systems(Update, spawn_death_decals);
+        app
+    }
+
+    fn kill(app: &mut App, creature_type: crate::creatures::CreatureType, x: f32) {
+        app.world_mut().send_event(CreatureDeathEvent {
+            entity: Entity::PLACEHOLDER,
+            creature_type,
+            position: Vec3::new(x, 0.0, 0.0),
+            experience: 0,
+            killed_by: None,
+        });
+    }
+
+    fn decals(app: &mut App) -> Vec<(Vec3, f32)> {
+        let mut query = app.world_mut().query_filtered::<(&Transform, &Sprite), With<Decal>>();
+        let mut decals: Vec<_> = query
+            .iter(app.world())
+            .map(|(transform, sprite)| (transform.translation, sprite.custom_size.unwrap().x))
+            .collect();
+        decals.sort_by(|a, b| a.0.x.total_cmp(&b.0.x));
+        decals
+    }
+
+    #[test]
+    fn decals_past_the_cap_recycle_the_oldest() {
+        use crate::creatures::CreatureType;
+        use crate::render_layers::DECALS;
+
+        let mut app = decal_test_app(3);
+        for x in 0..3 {
+            kill(&mut app, CreatureType::Zombie, x as f32);
+        }
+        app.update();
+        let first: Vec<Entity> = app
+            .world_mut()
+            .query_filtered::<Entity, With<Decal>>()
+            .iter(app.world())
+            .collect();
+        assert_eq!(first.len(), 3);
+
+        for x in 3..5 {
+            kill(&mut app, CreatureType::Zombie, x as f32);
+        }
+        app.update();
+        let positions: Vec<f32> = decals(&mut app).iter().map(|(position, _)| position.x).collect();
+        assert_eq!(positions, vec![2.0, 3.0, 4.0]);
+        assert!(decals(&mut app).iter().all(|(position, _)| position.z == DECALS));
+
+        // Recycled, not respawned
+        let mut now: Vec<Entity> = app
+            .world_mut()
+            .query_filtered::<Entity, With<Decal>>()
+            .iter(app.world())
+            .collect();
+        now.sort();
+        let mut first = first;
+        first.sort();
+        assert_eq!(now, first);
+    }
+
+    #[test]
+    fn bosses_and_bloody_mess_leave_bigger_messes_cleared_with_the_run() {
+        use crate::creatures::CreatureType;
+        use crate::effects::components::DECAL_CAP;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = decal_test_app(DECAL_CAP);
+        kill(&mut app, CreatureType::Zombie, 0.0);
+        kill(&mut app, CreatureType::BossSpider, 100.0);
+        app.update();
+        let sizes: Vec<f32> = decals(&mut app).iter().map(|(_, size)| *size).collect();
+        assert_eq!(sizes, vec![DECAL_SIZE, DECAL_SIZE * BOSS_DECAL_SCALE]);
+
+        let mut inventory = crate::perks::components::PerkInventory::new();
+        inventory.add_perk(crate::perks::components::PerkId::BloodyMess);
+        app.world_mut().spawn((Player::default(), PerkBonuses::calculate(&inventory)));
+        kill(&mut app, CreatureType::Zombie, 200.0);
+        app.update();
+        let messy: Vec<_> = decals(&mut app).into_iter().filter(|(position, _)| position.x > 150.0).collect();
+        assert_eq!(messy.len(), BLOODY_MESS_DECALS as usize);
+        assert!(messy.iter().any(|(_, size)| *size == DECAL_SIZE * BLOODY_MESS_DECAL_SCALE));
+
+        app.world_mut().run_system_once(despawn_all_decals);
+        assert!(decals(&mut app).is_empty());
+        assert_eq!(app.world_mut().resource_mut::<DecalRing>().recycle(), None);
+    }
 }
@@ -0,0 +1,461 @@
+//! Rush Ghost
+//!
+//! Records the player's position, aim and score pace during rush runs and
+//! replays the best run for the same loadout as a translucent ghost.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::perks::PerkRegistry;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persist;
+use crate::player::components::{AimDirection, Player};
+use crate::render_layers::{GHOST_OFFSET, PLAYER};
+use crate::rush::{RushLoadout, RushState};
+use crate::schedule::GameSet;
+use crate::states::GameState;
+use crate::weapons::WeaponRegistry;
+
+/// Seconds between trace samples (10 Hz)
+pub const GHOST_SAMPLE_INTERVAL: f32 = 0.1;
+/// Maximum samples per trace (a full 120s rush round)
+pub const MAX_GHOST_SAMPLES: usize = 1200;
+
+#[cfg(not(target_arch = "wasm32"))]
+const GHOSTS_FILE: &str = "rush_ghosts.ron";
+
+/// Plugin for rush ghost recording and playback
+pub struct GhostPlugin;
+
+impl Plugin for GhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GhostRecords>()
+            .add_systems(Startup, load_ghost_records)
+            .add_systems(Update, save_ghost_records)
+            .add_systems(OnEnter(GameState::Playing), start_ghost_run)
+            .add_systems(OnExit(GameState::Playing), finish_ghost_run)
+            .add_systems(
+                Update,
+                (record_ghost_trace, update_ghost_playback)
+                    .in_set(GameSet::Presentation)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(resource_exists::<RushState>)
+                    .run_if(resource_exists::<GhostRecorder>),
+            );
+    }
+}
+
+/// One 10 Hz sample of a rush run
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GhostSample {
+    pub x: f32,
+    pub y: f32,
+    /// Aim angle in radians
+    pub aim: f32,
+    pub kills: u32,
+    pub score: u32,
+}
+
+/// Recorded best run for one loadout
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GhostTrace {
+    pub loadout: String,
+    /// [`loadout_hash`] at recording time; the trace is dropped if it no longer matches
+    pub loadout_hash: u64,
+    pub score: u32,
+    pub samples: Vec<GhostSample>,
+}
+
+impl GhostTrace {
+    /// Interpolated position and aim at `elapsed` seconds; holds the last sample after the end
+    pub fn pose_at(&self, elapsed: f32) -> Option<(Vec2, f32)> {
+        let last = self.samples.len().checked_sub(1)?;
+        let t = (elapsed / GHOST_SAMPLE_INTERVAL).max(0.0);
+        let index = (t.floor() as usize).min(last);
+        let next = (index + 1).min(last);
+        let frac = if index == last { 0.0 } else { t - index as f32 };
+
+        let (a, b) = (&self.samples[index], &self.samples[next]);
+        let position = Vec2::new(a.x, a.y).lerp(Vec2::new(b.x, b.y), frac);
+        let turn = Vec2::from_angle(a.aim).angle_between(Vec2::from_angle(b.aim));
+        Some((position, a.aim + turn * frac))
+    }
+
+    /// Kills and score the record run had reached at `elapsed` seconds
+    pub fn pace_at(&self, elapsed: f32) -> (u32, u32) {
+        let Some(last) = self.samples.len().checked_sub(1) else {
+            return (0, 0);
+        };
+        let index = ((elapsed / GHOST_SAMPLE_INTERVAL).max(0.0) as usize).min(last);
+        let sample = &self.samples[index];
+        (sample.kills, sample.score)
+    }
+}
+
+/// Best rush traces per loadout (persisted)
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GhostRecords {
+    pub traces: Vec<GhostTrace>,
+}
+
+impl GhostRecords {
+    pub fn get(&self, loadout: &str) -> Option<&GhostTrace> {
+        self.traces.iter().find(|t| t.loadout == loadout)
+    }
+
+    /// Stores `trace` if it beats the current record for its loadout
+    pub fn submit(&mut self, trace: GhostTrace) -> bool {
+        match self.traces.iter_mut().find(|t| t.loadout == trace.loadout) {
+            Some(existing) if existing.score >= trace.score => false,
+            Some(existing) => {
+                *existing = trace;
+                true
+            }
+            None => {
+                self.traces.push(trace);
+                true
+            }
+        }
+    }
+
+    /// Removes the record for `loadout` if it was recorded against different definitions
+    pub fn invalidate_stale(&mut self, loadout: &str, hash: u64) -> bool {
+        let before = self.traces.len();
+        self.traces
+            .retain(|t| t.loadout != loadout || t.loadout_hash == hash);
+        self.traces.len() != before
+    }
+}
+
+/// Samples the current run at a fixed rate
+#[derive(Resource, Debug, Clone)]
+pub struct GhostRecorder {
+    pub loadout_hash: u64,
+    pub samples: Vec<GhostSample>,
+}
+
+impl GhostRecorder {
+    pub fn new(loadout_hash: u64) -> Self {
+        Self {
+            loadout_hash,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Appends samples until the trace covers `elapsed`.
+    /// Long frames repeat the sample so index `i` always maps to `i * GHOST_SAMPLE_INTERVAL`.
+    pub fn record(&mut self, elapsed: f32, sample: GhostSample) {
+        while self.samples.len() < MAX_GHOST_SAMPLES
+            && self.samples.len() as f32 * GHOST_SAMPLE_INTERVAL <= elapsed
+        {
+            self.samples.push(sample);
+        }
+    }
+}
+
+/// Marker for the ghost sprite
+#[derive(Component)]
+pub struct RushGhost;
+
+/// Marker for the ghost pace readout
+#[derive(Component)]
+pub struct GhostPaceText;
+
+/// Stable FNV-1a hash of the weapon and perk definitions a loadout uses
+pub fn loadout_hash(loadout: &RushLoadout, weapons: &WeaponRegistry, perks: &PerkRegistry) -> u64 {
+    let mut text = ron::to_string(&weapons.get(loadout.weapon)).unwrap_or_default();
+    for perk in &loadout.perks {
+        text.push_str(&ron::to_string(&perks.get(*perk)).unwrap_or_default());
+    }
+
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn elapsed(rush: &RushState) -> f32 {
+    rush.round_duration - rush.time_remaining.max(0.0)
+}
+
+/// Starts recording a rush run and spawns the ghost of the loadout's record, if any
+fn start_ghost_run(
+    mut commands: Commands,
+    rush: Option<Res<RushState>>,
+    weapons: Res<WeaponRegistry>,
+    perks: Res<PerkRegistry>,
+    mut records: ResMut<GhostRecords>,
+) {
+    let Some(rush) = rush else { return };
+
+    let hash = loadout_hash(&rush.loadout, &weapons, &perks);
+    if records.invalidate_stale(&rush.loadout.name, hash) {
+        info!("Dropped ghost for {}: loadout definitions changed", rush.loadout.name);
+    }
+    commands.insert_resource(GhostRecorder::new(hash));
+
+    let Some(trace) = records.get(&rush.loadout.name) else {
+        return;
+    };
+    let (position, _) = trace.pose_at(0.0).unwrap_or_default();
+
+    commands.spawn((
+        RushGhost,
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgba(0.6, 0.8, 1.0, 0.35),
+                custom_size: Some(Vec2::new(32.0, 32.0)),
+                ..default()
+            },
+            transform: Transform::from_translation(position.extend(PLAYER + GHOST_OFFSET)),
+            ..default()
+        },
+    ));
+    commands.spawn((
+        GhostPaceText,
+        TextBundle::from_section(
+            format!("Ghost score: 0 / {}", trace.score),
+            TextStyle {
+                font_size: 18.0,
+                color: Color::srgba(0.6, 0.8, 1.0, 0.8),
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(70.0),
+            right: Val::Px(10.0),
+            ..default()
+        }),
+    ));
+}
+
+/// Samples the player at 10 Hz for the current rush run
+fn record_ghost_trace(
+    rush: Res<RushState>,
+    mut recorder: ResMut<GhostRecorder>,
+    player_query: Query<(&Transform, &AimDirection), With<Player>>,
+) {
+    let Ok((transform, aim)) = player_query.get_single() else {
+        return;
+    };
+    recorder.record(
+        elapsed(&rush),
+        GhostSample {
+            x: transform.translation.x,
+            y: transform.translation.y,
+            aim: aim.angle,
+            kills: rush.total_kills,
+            score: rush.score,
+        },
+    );
+}
+
+/// Moves the ghost along the recorded trace and shows the record pace
+fn update_ghost_playback(
+    rush: Res<RushState>,
+    records: Res<GhostRecords>,
+    mut ghost_query: Query<&mut Transform, With<RushGhost>>,
+    mut text_query: Query<&mut Text, With<GhostPaceText>>,
+) {
+    let Some(trace) = records.get(&rush.loadout.name) else {
+        return;
+    };
+    let elapsed = elapsed(&rush);
+
+    if let Some((position, aim)) = trace.pose_at(elapsed) {
+        for mut transform in ghost_query.iter_mut() {
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+            transform.rotation = Quat::from_rotation_z(aim);
+        }
+    }
+
+    let (_, ghost_score) = trace.pace_at(elapsed);
+    for mut text in text_query.iter_mut() {
+        let ahead = rush.score as i64 - ghost_score as i64;
+        text.sections[0].value = format!("Ghost score: {} ({:+})", ghost_score, ahead);
+        text.sections[0].style.color = if ahead >= 0 {
+            Color::srgba(0.5, 1.0, 0.5, 0.8)
+        } else {
+            Color::srgba(1.0, 0.5, 0.5, 0.8)
+        };
+    }
+}
+
+/// Keeps the run's trace if it set a new record, and removes the ghost
+#[allow(clippy::type_complexity)]
+fn finish_ghost_run(
+    mut commands: Commands,
+    rush: Option<Res<RushState>>,
+    recorder: Option<Res<GhostRecorder>>,
+    mut records: ResMut<GhostRecords>,
+    ghost_query: Query<Entity, Or<(With<RushGhost>, With<GhostPaceText>)>>,
+) {
+    for entity in ghost_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<GhostRecorder>();
+
+    let (Some(rush), Some(recorder)) = (rush, recorder) else {
+        return;
+    };
+    let trace = GhostTrace {
+        loadout: rush.loadout.name.clone(),
+        loadout_hash: recorder.loadout_hash,
+        score: rush.score,
+        samples: recorder.samples.clone(),
+    };
+    if records.submit(trace) {
+        info!("New rush record for {}: {}", rush.loadout.name, rush.score);
+    }
+}
+
+/// Loads persisted ghost records on startup, dropping oversized traces
+fn load_ghost_records(mut records: ResMut<GhostRecords>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(mut loaded) = persist::load::<GhostRecords>(GHOSTS_FILE).into_value() {
+            loaded.traces.retain(|t| t.samples.len() <= MAX_GHOST_SAMPLES);
+            *records = loaded;
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &mut records;
+}
+
+/// Persists ghost records whenever they change
+fn save_ghost_records(records: Res<GhostRecords>) {
+    if !records.is_changed() || records.is_added() {
+        return;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(err) = persist::save(GHOSTS_FILE, &*records) {
+        warn!("Failed to save {}: {}", GHOSTS_FILE, err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::perks::PerkId;
+    use crate::weapons::WeaponId;
+
+    fn sample(x: f32, aim: f32, score: u32) -> GhostSample {
+        GhostSample {
+            x,
+            y: 0.0,
+            aim,
+            kills: score / 10,
+            score,
+        }
+    }
+
+    fn trace(samples: Vec<GhostSample>) -> GhostTrace {
+        GhostTrace {
+            loadout: "Assault".into(),
+            loadout_hash: 0,
+            score: samples.last().map_or(0, |s| s.score),
+            samples,
+        }
+    }
+
+    #[test]
+    fn recorder_samples_at_ten_hz() {
+        let mut recorder = GhostRecorder::new(0);
+        let mut t = 0.0;
+        while t < 1.0 {
+            recorder.record(t, sample(t, 0.0, 0));
+            t += 1.0 / 60.0;
+        }
+        assert_eq!(recorder.samples.len(), 10);
+    }
+
+    #[test]
+    fn recorder_fills_long_frames_and_caps() {
+        let mut recorder = GhostRecorder::new(0);
+        recorder.record(0.0, sample(0.0, 0.0, 0));
+        recorder.record(0.35, sample(5.0, 0.0, 0));
+        assert_eq!(recorder.samples.len(), 4);
+
+        recorder.record(1000.0, sample(9.0, 0.0, 0));
+        assert_eq!(recorder.samples.len(), MAX_GHOST_SAMPLES);
+    }
+
+    #[test]
+    fn pose_interpolates_between_samples() {
+        let trace = trace(vec![sample(0.0, 0.0, 0), sample(10.0, 1.0, 0)]);
+        let (position, aim) = trace.pose_at(0.05).unwrap();
+        assert!((position.x - 5.0).abs() < 0.001);
+        assert!((aim - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn pose_holds_last_sample_and_handles_empty() {
+        let recorded = trace(vec![sample(0.0, 0.0, 0), sample(10.0, 0.0, 0)]);
+        assert_eq!(recorded.pose_at(50.0).unwrap().0, Vec2::new(10.0, 0.0));
+        assert!(trace(Vec::new()).pose_at(1.0).is_none());
+    }
+
+    #[test]
+    fn aim_interpolates_the_short_way_around() {
+        let pi = std::f32::consts::PI;
+        let trace = trace(vec![sample(0.0, pi - 0.1, 0), sample(0.0, -pi + 0.1, 0)]);
+        let (_, aim) = trace.pose_at(0.05).unwrap();
+        assert!((Vec2::from_angle(aim).x + 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn pace_lookup_uses_sample_at_elapsed() {
+        let trace = trace(vec![sample(0.0, 0.0, 0), sample(0.0, 0.0, 50), sample(0.0, 0.0, 120)]);
+        assert_eq!(trace.pace_at(0.0), (0, 0));
+        assert_eq!(trace.pace_at(0.15), (5, 50));
+        assert_eq!(trace.pace_at(99.0), (12, 120));
+    }
+
+    #[test]
+    fn records_keep_only_the_best_score() {
+        let mut records = GhostRecords::default();
+        assert!(records.submit(trace(vec![sample(0.0, 0.0, 100)])));
+        assert!(!records.submit(trace(vec![sample(0.0, 0.0, 80)])));
+        assert!(records.submit(trace(vec![sample(0.0, 0.0, 150)])));
+        assert_eq!(records.traces.len(), 1);
+        assert_eq!(records.get("Assault").unwrap().score, 150);
+    }
+
+    #[test]
+    fn hash_changes_with_definitions() {
+        let loadout = RushLoadout::default();
+        let mut weapons = WeaponRegistry::new();
+        let perks = PerkRegistry::new();
+        let original = loadout_hash(&loadout, &weapons, &perks);
+        assert_eq!(original, loadout_hash(&loadout, &weapons, &perks));
+
+        weapons
+            .weapons
+            .iter_mut()
+            .find(|w| w.id == WeaponId::AssaultRifle)
+            .unwrap()
+            .damage += 1.0;
+        assert_ne!(original, loadout_hash(&loadout, &weapons, &perks));
+
+        let other = RushLoadout {
+            perks: vec![PerkId::Regeneration],
+            ..RushLoadout::default()
+        };
+        assert_ne!(original, loadout_hash(&other, &WeaponRegistry::new(), &perks));
+    }
+
+    #[test]
+    fn stale_trace_is_invalidated() {
+        let mut records = GhostRecords::default();
+        records.submit(GhostTrace {
+            loadout_hash: 7,
+            ..trace(vec![sample(0.0, 0.0, 10)])
+        });
+        assert!(!records.invalidate_stale("Assault", 7));
+        assert!(records.invalidate_stale("Assault", 8));
+        assert!(records.get("Assault").is_none());
+    }
+}
@@ -3,6 +3,8 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::render_layers::{on_layer, PICKUPS};
+
 /// Types of items the player can carry and activate
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ItemType {
@@ -167,7 +169,7 @@ impl ItemPickupBundle {
                     custom_size: Some(Vec2::splat(24.0)), // Larger than bonuses
                     ..default()
                 },
-                transform: Transform::from_translation(position),
+                transform: Transform::from_translation(on_layer(position, PICKUPS)),
                 ..default()
             },
         }
@@ -187,18 +189,18 @@ mod tests {
     #[test]
     fn carried_item_starts_empty() {
         let carried = CarriedItem::new();
-        assert!(!carried.has_item());
+        assert!(carried.item.is_none());
     }
 
     #[test]
     fn carried_item_can_set_and_take() {
         let mut carried = CarriedItem::new();
         carried.set_item(ItemType::Nuke);
-        assert!(carried.has_item());
+        assert!(carried.item.is_some());
 
         let taken = carried.take_item();
         assert_eq!(taken, Some(ItemType::Nuke));
-        assert!(!carried.has_item());
+        assert!(carried.item.is_none());
     }
 
     #[test]
@@ -11,6 +11,7 @@ pub use systems::*;
 
 use bevy::prelude::*;
 
+use crate::schedule::GameSet;
 use crate::states::GameState;
 
 /// Plugin for the carried item system
@@ -23,11 +24,10 @@ impl Plugin for ItemsPlugin {
             .add_systems(
                 Update,
                 (
-                    handle_item_use,
-                    apply_item_effects,
-                    spawn_item_on_death,
-                    collect_items,
-                    update_item_lifetime,
+                    handle_item_use.in_set(GameSet::Input),
+                    (apply_item_effects, item_attraction, collect_items, update_item_lifetime)
+                        .in_set(GameSet::Simulation),
+                    spawn_item_on_death.in_set(GameSet::Consequences),
                 )
                     .run_if(in_state(GameState::Playing)),
             );
@@ -4,11 +4,14 @@ use bevy::prelude::*;
 use rand::Rng;
 
 use super::components::*;
-use crate::creatures::{Creature, CreatureHealth};
+use crate::creatures::{Creature, CreatureHealth, StatusEffects, StatusKind};
 use crate::creatures::systems::CreatureDeathEvent;
 use crate::player::components::Player;
 use crate::player::resources::PlayerInputMapping;
+use crate::bonuses::components::BonusAttraction;
+use crate::bonuses::systems::pull_pickup;
 use crate::bonuses::ActiveBonusEffects;
+use crate::perks::components::PerkBonuses;
 
 /// Event fired when a player uses their carried item
 #[derive(Event)]
@@ -50,7 +53,10 @@ pub fn handle_item_use(
 pub fn apply_item_effects(
     mut commands: Commands,
     mut item_events: EventReader<ItemUsedEvent>,
-    mut creatures: Query<(Entity, &Transform, &mut CreatureHealth), With<Creature>>,
+    mut creatures: Query<
+        (Entity, &Transform, &mut CreatureHealth, Option<&mut StatusEffects>),
+        With<Creature>,
+    >,
     mut player_query: Query<&mut ActiveBonusEffects, With<Player>>,
 ) {
     for event in item_events.read() {
@@ -58,7 +64,7 @@ pub fn apply_item_effects(
             ItemType::Nuke => {
                 // Kill all creatures on screen
                 info!("NUKE! Killing all creatures");
-                for (entity, _, _) in creatures.iter() {
+                for (entity, _, _, _) in creatures.iter() {
                     commands.entity(entity).despawn_recursive();
                 }
             }
@@ -66,8 +72,12 @@ pub fn apply_item_effects(
             ItemType::Freeze => {
                 // Damage and slow all creatures (simplified: just damage)
                 info!("FREEZE! Damaging all creatures");
-                for (_, _, mut health) in creatures.iter_mut() {
+                for (_, _, mut health, status) in creatures.iter_mut() {
                     health.damage(20.0);
+                    // Freezing puts out fires
+                    if let Some(mut status) = status {
+                        status.cleanse(StatusKind::Burning);
+                    }
                 }
                 // TODO: Add frozen status effect to creatures
             }
@@ -84,7 +94,7 @@ pub fn apply_item_effects(
                 // Damage all creatures based on distance
                 info!("PLASMA BLAST!");
                 let player_pos = event.position.truncate();
-                for (_, transform, mut health) in creatures.iter_mut() {
+                for (_, transform, mut health, _) in creatures.iter_mut() {
                     let creature_pos = transform.translation.truncate();
                     let distance = player_pos.distance(creature_pos);
                     // More damage the closer they are
@@ -114,7 +124,7 @@ pub fn apply_item_effects(
             ItemType::MissileSalvo => {
                 // Damage all creatures (simplified from actual homing missiles)
                 info!("MISSILE SALVO!");
-                for (_, _, mut health) in creatures.iter_mut() {
+                for (_, _, mut health, _) in creatures.iter_mut() {
                     health.damage(50.0);
                 }
             }
@@ -123,7 +133,7 @@ pub fn apply_item_effects(
                 // Damage nearby creatures
                 info!("SHOCKWAVE!");
                 let player_pos = event.position.truncate();
-                for (_, transform, mut health) in creatures.iter_mut() {
+                for (_, transform, mut health, _) in creatures.iter_mut() {
                     let creature_pos = transform.translation.truncate();
                     let distance = player_pos.distance(creature_pos);
                     if distance < 200.0 {
@@ -133,17 +143,23 @@ pub fn apply_item_effects(
             }
 
             ItemType::ToxicCloud => {
-                // Poison nearby creatures (simplified: instant damage)
+                // Poison nearby creatures (10 dps for 3 seconds per stack)
                 info!("TOXIC CLOUD!");
                 let player_pos = event.position.truncate();
-                for (_, transform, mut health) in creatures.iter_mut() {
+                for (_, transform, _, status) in creatures.iter_mut() {
                     let creature_pos = transform.translation.truncate();
                     let distance = player_pos.distance(creature_pos);
                     if distance < 250.0 {
-                        health.damage(30.0);
+                        if let Some(mut status) = status {
+                            status.apply(
+                                StatusKind::Poisoned,
+                                10.0,
+                                3.0,
+                                Some(event.player_entity),
+                            );
+                        }
                     }
                 }
-                // TODO: Add poison status effect
             }
 
             ItemType::Overdrive => {
@@ -213,8 +229,8 @@ pub fn collect_items(
                 info!(
                     "Picked up {:?}{}",
                     pickup.item_type,
-                    if replaced.is_some() {
-                        format!(" (replaced {:?})", replaced.unwrap())
+                    if let Some(old) = replaced {
+                        format!(" (replaced {:?})", old)
                     } else {
                         String::new()
                     }
@@ -226,6 +242,30 @@ pub fn collect_items(
     }
 }
 
+/// Telekinetic players pull item pickups toward them from the same range as bonuses
+pub fn item_attraction(
+    time: Res<Time>,
+    player_query: Query<(&Transform, &PerkBonuses), With<Player>>,
+    mut pickup_query: Query<&mut Transform, (With<ItemPickup>, Without<Player>)>,
+) {
+    let speed = BonusAttraction::default().speed;
+    for (player_transform, perk_bonuses) in player_query.iter() {
+        if perk_bonuses.telekinetic_range <= 0.0 {
+            continue;
+        }
+        let player_pos = player_transform.translation.truncate();
+        for mut pickup_transform in pickup_query.iter_mut() {
+            pull_pickup(
+                &mut pickup_transform,
+                player_pos,
+                perk_bonuses.telekinetic_range,
+                speed,
+                time.delta_seconds(),
+            );
+        }
+    }
+}
+
 /// Updates item pickup lifetimes and despawns expired ones
 pub fn update_item_lifetime(
     mut commands: Commands,
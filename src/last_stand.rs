@@ -0,0 +1,381 @@
+//! Last-stand push
+//!
+//! Built-in escape valve for getting boxed in: when a contact hit lands while
+//! the player is surrounded, nearby creatures are shoved back and staggered.
+//! Long cooldown, no damage, can be turned off, never active in ranked runs.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::assists::RunAssists;
+use crate::audio::{PlaySoundEvent, SoundEffect};
+use crate::creatures::{AIMode, AIState, Creature, CreatureGrid, Knockback, Staggered};
+use crate::effects::{EffectType, SpawnEffectEvent};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persist;
+use crate::player::{apply_player_damage, Invincibility, Player, PlayerHitLandedEvent};
+use crate::schedule::GameSet;
+use crate::states::GameState;
+
+/// Creatures within this distance count towards being surrounded
+pub const SURROUND_RADIUS: f32 = 60.0;
+/// Creatures needed within [`SURROUND_RADIUS`] to trigger the push
+pub const SURROUND_COUNT: usize = 6;
+/// Creatures within this distance get shoved
+pub const PUSH_RADIUS: f32 = 90.0;
+/// Initial shove speed (decays through creature knockback drag)
+pub const PUSH_IMPULSE: f32 = 600.0;
+/// Seconds shoved creatures cannot attack
+pub const STAGGER_DURATION: f32 = 0.5;
+/// Invincibility granted to the player by the push
+pub const PUSH_INVINCIBILITY: f32 = 1.0;
+/// Seconds between pushes
+pub const PUSH_COOLDOWN: f32 = 45.0;
+
+#[cfg(not(target_arch = "wasm32"))]
+const SETTINGS_FILE: &str = "last_stand.ron";
+
+/// Plugin for the last-stand push
+pub struct LastStandPlugin;
+
+impl Plugin for LastStandPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LastStandSettings>()
+            .init_resource::<LastStand>()
+            .add_event::<LastStandPushEvent>()
+            .add_systems(Startup, load_last_stand_settings)
+            .add_systems(Update, save_last_stand_settings)
+            .add_systems(OnEnter(GameState::Playing), reset_last_stand)
+            .add_systems(
+                Update,
+                (
+                    tick_last_stand.in_set(GameSet::Simulation),
+                    trigger_last_stand
+                        .after(apply_player_damage)
+                        .in_set(GameSet::DamageResolution),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Sent when the push fires
+#[derive(Event, Debug, Clone, Copy)]
+pub struct LastStandPushEvent;
+
+/// Player option for the push (persisted, on by default)
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LastStandSettings {
+    pub enabled: bool,
+}
+
+impl Default for LastStandSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Push cooldown for the current run
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LastStand {
+    pub cooldown: f32,
+}
+
+impl LastStand {
+    pub fn is_ready(&self) -> bool {
+        self.cooldown <= 0.0
+    }
+
+    /// Fraction of the cooldown already recovered (1.0 when ready)
+    pub fn recovery(&self) -> f32 {
+        1.0 - (self.cooldown / PUSH_COOLDOWN).clamp(0.0, 1.0)
+    }
+
+    pub fn tick(&mut self, delta: f32) {
+        self.cooldown = (self.cooldown - delta).max(0.0);
+    }
+
+    /// Starts the cooldown if the push is ready. Returns whether it fired.
+    pub fn try_trigger(&mut self) -> bool {
+        if !self.is_ready() {
+            return false;
+        }
+        self.cooldown = PUSH_COOLDOWN;
+        true
+    }
+}
+
+/// Whether the push may fire in this run at all
+pub fn last_stand_allowed(settings: &LastStandSettings, run_assists: &RunAssists) -> bool {
+    settings.enabled && !run_assists.ranked
+}
+
+/// True if at least [`SURROUND_COUNT`] creatures are within [`SURROUND_RADIUS`] of `player`
+pub fn is_surrounded(player: Vec2, creatures: impl IntoIterator<Item = Vec2>) -> bool {
+    creatures
+        .into_iter()
+        .filter(|position| position.distance(player) <= SURROUND_RADIUS)
+        .count()
+        >= SURROUND_COUNT
+}
+
+/// Shove velocity for a creature at `creature` pushed away from `player`
+pub fn push_velocity(player: Vec2, creature: Vec2) -> Vec2 {
+    let away = (creature - player).normalize_or_zero();
+    // A creature exactly on top of the player still gets pushed somewhere
+    let away = if away == Vec2::ZERO { Vec2::X } else { away };
+    away * PUSH_IMPULSE
+}
+
+fn reset_last_stand(mut last_stand: ResMut<LastStand>) {
+    *last_stand = LastStand::default();
+}
+
+fn tick_last_stand(time: Res<Time>, mut last_stand: ResMut<LastStand>) {
+    if !last_stand.is_ready() {
+        last_stand.tick(time.delta_seconds());
+    }
+}
+
+/// Fires the push when a creature's hit lands on a surrounded player
+#[allow(clippy::too_many_arguments)]
+pub fn trigger_last_stand(
+    mut commands: Commands,
+    mut hit_events: EventReader<PlayerHitLandedEvent>,
+    settings: Res<LastStandSettings>,
+    run_assists: Res<RunAssists>,
+    mut last_stand: ResMut<LastStand>,
+    mut player_query: Query<(&Transform, Option<&mut Invincibility>), With<Player>>,
+    creature_query: Query<(&Transform, &Creature, &AIState)>,
+    creature_grid: Res<CreatureGrid>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    mut push_events: EventWriter<LastStandPushEvent>,
+) {
+    if !last_stand_allowed(&settings, &run_assists) {
+        hit_events.clear();
+        return;
+    }
+
+    for event in hit_events.read() {
+        // Only contact hits from creatures count
+        if !event.source.is_some_and(|source| creature_query.contains(source)) {
+            continue;
+        }
+        let Ok((player_transform, invincibility)) = player_query.get_mut(event.player_entity)
+        else {
+            continue;
+        };
+        let player_pos = player_transform.translation.truncate();
+
+        // Living creatures close enough to count; the push reaches further than the surround check
+        let nearby: Vec<(Entity, Vec2, &Creature)> = creature_grid
+            .candidates(player_pos, SURROUND_RADIUS.max(PUSH_RADIUS))
+            .into_iter()
+            .filter_map(|(entity, _)| {
+                let (transform, creature, ai) = creature_query.get(entity).ok()?;
+                (ai.mode != AIMode::Dead).then_some((entity, transform.translation.truncate(), creature))
+            })
+            .collect();
+        if !is_surrounded(player_pos, nearby.iter().map(|&(_, position, _)| position)) {
+            continue;
+        }
+        if !last_stand.try_trigger() {
+            continue;
+        }
+
+        for &(entity, position, creature) in &nearby {
+            if position.distance(player_pos) > PUSH_RADIUS {
+                continue;
+            }
+            let mut entity_commands = commands.entity(entity);
+            entity_commands.insert(Staggered {
+                remaining: STAGGER_DURATION,
+            });
+            if !creature.creature_type.knockback_immune() {
+                entity_commands.insert(Knockback {
+                    velocity: push_velocity(player_pos, position),
+                });
+            }
+        }
+
+        match invincibility {
+            Some(mut inv) => inv.timer = inv.timer.max(PUSH_INVINCIBILITY),
+            None => {
+                commands
+                    .entity(event.player_entity)
+                    .insert(Invincibility::new(PUSH_INVINCIBILITY));
+            }
+        }
+        effect_events.send(SpawnEffectEvent {
+            effect_type: EffectType::Shockwave,
+            position: player_transform.translation,
+            count: 1,
+        });
+        sound_events.send(PlaySoundEvent {
+            sound: SoundEffect::LastStandPush,
+            position: Some(player_pos),
+        });
+        push_events.send(LastStandPushEvent);
+        info!("Last-stand push triggered");
+    }
+}
+
+/// Loads the push option on startup
+fn load_last_stand_settings(mut settings: ResMut<LastStandSettings>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(loaded) = persist::load::<LastStandSettings>(SETTINGS_FILE).into_value() {
+            *settings = loaded;
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &mut settings;
+}
+
+/// Persists the push option whenever it changes
+fn save_last_stand_settings(settings: Res<LastStandSettings>) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(err) = persist::save(SETTINGS_FILE, &*settings) {
+        warn!("Failed to save {}: {}", SETTINGS_FILE, err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::creatures::{rebuild_creature_grid, CreatureBundle, CreatureType};
+
+    fn ring(count: usize, radius: f32) -> Vec<Vec2> {
+        (0..count)
+            .map(|i| Vec2::from_angle(i as f32 / count as f32 * std::f32::consts::TAU) * radius)
+            .collect()
+    }
+
+    #[test]
+    fn surround_needs_six_close_creatures() {
+        assert!(is_surrounded(Vec2::ZERO, ring(6, 50.0)));
+        assert!(!is_surrounded(Vec2::ZERO, ring(5, 50.0)));
+        assert!(!is_surrounded(Vec2::ZERO, ring(12, 70.0)));
+
+        let mut mixed = ring(5, 40.0);
+        mixed.push(Vec2::new(200.0, 0.0));
+        assert!(!is_surrounded(Vec2::ZERO, mixed.clone()));
+        mixed.push(Vec2::new(0.0, 59.0));
+        assert!(is_surrounded(Vec2::ZERO, mixed));
+    }
+
+    #[test]
+    fn cooldown_gates_the_push() {
+        let mut last_stand = LastStand::default();
+        assert!(last_stand.try_trigger());
+        assert!(!last_stand.try_trigger());
+        assert_eq!(last_stand.recovery(), 0.0);
+
+        last_stand.tick(PUSH_COOLDOWN - 1.0);
+        assert!(!last_stand.try_trigger());
+        last_stand.tick(1.0);
+        assert_eq!(last_stand.recovery(), 1.0);
+        assert!(last_stand.try_trigger());
+    }
+
+    #[test]
+    fn disabled_in_options_and_ranked_runs() {
+        let on = LastStandSettings::default();
+        let off = LastStandSettings { enabled: false };
+        let casual = RunAssists::default();
+        let ranked = RunAssists {
+            ranked: true,
+            used: false,
+        };
+        assert!(last_stand_allowed(&on, &casual));
+        assert!(!last_stand_allowed(&off, &casual));
+        assert!(!last_stand_allowed(&on, &ranked));
+    }
+
+    fn push_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.init_resource::<LastStandSettings>()
+            .init_resource::<LastStand>()
+            .init_resource::<RunAssists>()
+            .init_resource::<CreatureGrid>()
+            .add_event::<PlayerHitLandedEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_event::<LastStandPushEvent>()
+            .add_systems(Update, (rebuild_creature_grid, trigger_last_stand).chain());
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), Transform::default()))
+            .id();
+        (app, player)
+    }
+
+    fn hit(app: &mut App, player: Entity, source: Entity) {
+        app.world_mut().send_event(PlayerHitLandedEvent {
+            player_entity: player,
+            source: Some(source),
+            damage: 10.0,
+        });
+        app.update();
+    }
+
+    #[test]
+    fn bosses_are_staggered_but_not_shoved() {
+        let (mut app, player) = push_app();
+        let mut zombies = Vec::new();
+        for position in ring(6, 40.0) {
+            zombies.push(
+                app.world_mut()
+                    .spawn(CreatureBundle::new(CreatureType::Zombie, position.extend(0.0)))
+                    .id(),
+            );
+        }
+        let boss = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::BossSpider, Vec3::new(0.0, 80.0, 0.0)))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(200.0, 0.0, 0.0)))
+            .id();
+
+        hit(&mut app, player, zombies[0]);
+
+        let world = app.world();
+        for &zombie in &zombies {
+            assert!(world.get::<Staggered>(zombie).is_some());
+            assert!(world.get::<Knockback>(zombie).is_some());
+        }
+        assert!(world.get::<Staggered>(boss).is_some());
+        assert!(world.get::<Knockback>(boss).is_none());
+        assert!(world.get::<Staggered>(far).is_none());
+        assert!(world.get::<Invincibility>(player).is_some_and(|inv| inv.is_active()));
+        // A built-in ability, not an assist
+        assert!(!world.resource::<RunAssists>().used);
+        assert!(!world.resource::<LastStand>().is_ready());
+    }
+
+    #[test]
+    fn push_waits_for_cooldown() {
+        let (mut app, player) = push_app();
+        let zombies: Vec<Entity> = ring(6, 40.0)
+            .into_iter()
+            .map(|position| {
+                app.world_mut()
+                    .spawn(CreatureBundle::new(CreatureType::Zombie, position.extend(0.0)))
+                    .id()
+            })
+            .collect();
+        app.world_mut().resource_mut::<LastStand>().cooldown = 10.0;
+
+        hit(&mut app, player, zombies[0]);
+        assert!(app.world().get::<Staggered>(zombies[1]).is_none());
+        assert_eq!(app.world().resource::<LastStand>().cooldown, 10.0);
+    }
+}
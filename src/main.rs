@@ -5,24 +5,41 @@
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
 
+mod assists;
 mod audio;
 mod bonuses;
 mod creatures;
 mod effects;
+mod ghost;
 mod items;
+mod last_stand;
+mod mods;
 mod perks;
+mod persist;
 mod player;
+mod profile;
 mod quests;
+mod render_layers;
+mod run_context;
+mod rumble;
 mod rush;
+mod schedule;
+#[cfg(test)]
+mod snapshot;
+mod start_kit;
 mod states;
 mod survival;
+#[cfg(feature = "debug-tools")]
+mod timeline;
 mod ui;
+mod viewport;
 mod weapons;
 
 use states::GameStatePlugin;
 
 fn main() {
-    App::new()
+    let mut app = App::new();
+    app
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "Crimsonland".into(),
@@ -40,6 +57,8 @@ fn main() {
         }))
         .add_plugins(AudioPlugin)
         .add_plugins(GameStatePlugin)
+        .add_plugins(schedule::GameSchedulePlugin)
+        .add_plugins(viewport::ViewportPlugin)
         .add_plugins(player::PlayerPlugin)
         .add_plugins(creatures::CreaturesPlugin)
         .add_plugins(weapons::WeaponsPlugin)
@@ -52,8 +71,19 @@ fn main() {
         .add_plugins(audio::GameAudioPlugin)
         .add_plugins(survival::SurvivalPlugin)
         .add_plugins(rush::RushPlugin)
-        .add_systems(Startup, setup_camera)
-        .run();
+        .add_plugins(ghost::GhostPlugin)
+        .add_plugins(assists::AssistsPlugin)
+        .add_plugins(mods::ModsPlugin)
+        .add_plugins(run_context::RunContextPlugin)
+        .add_plugins(profile::ProfilePlugin)
+        .add_plugins(last_stand::LastStandPlugin)
+        .add_plugins(rumble::RumblePlugin)
+        .add_plugins(start_kit::StartKitPlugin);
+
+    #[cfg(feature = "debug-tools")]
+    app.add_plugins(timeline::TimelinePlugin);
+
+    app.add_systems(Startup, setup_camera).run();
 }
 
 fn setup_camera(mut commands: Commands) {
@@ -0,0 +1,483 @@
+//! Mod parsing, validation and registration
+
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use super::{ModError, ModManifest};
+use crate::perks::{CustomPerk, PerkDeltas, PerkRarity, PerkRegistry};
+use crate::weapons::{
+    CustomWeapon, WeaponClass, WeaponData, WeaponId, WeaponRegistry, DEFAULT_HOMING_TURN_RATE,
+    DEFAULT_MOVING_SPREAD_PENALTY,
+};
+
+/// Directory scanned for mods at startup
+#[cfg(not(target_arch = "wasm32"))]
+pub const MODS_DIR: &str = "mods";
+
+/// A weapon entry in `weapons.ron`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModWeaponDef {
+    /// Entry id, namespaced with the mod name on load
+    pub id: String,
+    pub name: String,
+    /// Built-in weapon whose projectile visuals, sounds and special behavior are reused
+    pub base: WeaponId,
+    /// Drop tier; 0 keeps the weapon out of WeaponPickup drops
+    #[serde(default)]
+    pub tier: u8,
+    /// Defaults to the base weapon's class
+    #[serde(default)]
+    pub class: Option<WeaponClass>,
+    pub damage: f32,
+    pub fire_rate: f32,
+    pub projectile_speed: f32,
+    #[serde(default)]
+    pub spread: f32,
+    pub projectiles_per_shot: u32,
+    #[serde(default)]
+    pub ammo_capacity: Option<u32>,
+    #[serde(default)]
+    pub reload_time: f32,
+    pub projectile_lifetime: f32,
+    #[serde(default)]
+    pub pierce_count: u32,
+    #[serde(default)]
+    pub homing: bool,
+    /// Defaults to the Homing Missile's turn rate
+    #[serde(default)]
+    pub homing_turn_rate: Option<f32>,
+    #[serde(default)]
+    pub explosive_radius: f32,
+    #[serde(default)]
+    pub recoil: f32,
+    #[serde(default)]
+    pub spin_up: bool,
+    #[serde(default)]
+    pub knockback: f32,
+    /// Defaults to whether the weapon explodes
+    #[serde(default)]
+    pub self_damage: Option<bool>,
+    #[serde(default)]
+    pub heat_per_shot: f32,
+    #[serde(default)]
+    pub cooling_rate: f32,
+    /// Defaults to whether the base weapon is hitscan
+    #[serde(default)]
+    pub hitscan: Option<bool>,
+    /// Defaults to the base weapon's penalty
+    #[serde(default)]
+    pub moving_spread_penalty: Option<f32>,
+}
+
+/// A perk entry in `perks.ron`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModPerkDef {
+    /// Entry id, namespaced with the mod name on load
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub rarity: PerkRarity,
+    pub effects: PerkDeltas,
+}
+
+/// A parsed and validated mod, ready to register
+#[derive(Debug, Clone)]
+pub struct LoadedMod {
+    /// Directory the mod came from (used in error messages)
+    pub source: String,
+    pub manifest: ModManifest,
+    pub weapons: Vec<CustomWeapon>,
+    pub perks: Vec<CustomPerk>,
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn parse_ron<T: for<'de> Deserialize<'de>>(path: String, text: &str) -> Result<T, ModError> {
+    ron::from_str(text).map_err(|err| ModError::Parse {
+        path,
+        message: err.to_string(),
+    })
+}
+
+/// Parses one mod from the text of its files and namespaces its ids
+pub fn parse_mod(
+    source: &str,
+    manifest: &str,
+    weapons: Option<&str>,
+    perks: Option<&str>,
+) -> Result<LoadedMod, ModError> {
+    let manifest: ModManifest = parse_ron(format!("{}/mod.ron", source), manifest)?;
+    if !is_valid_name(&manifest.name) {
+        return Err(ModError::InvalidName {
+            source: source.to_string(),
+            name: manifest.name,
+        });
+    }
+
+    let mut ids: Vec<String> = Vec::new();
+    let mut namespaced = |entry: &str| -> Result<String, ModError> {
+        if !is_valid_name(entry) {
+            return Err(ModError::InvalidName {
+                source: source.to_string(),
+                name: entry.to_string(),
+            });
+        }
+        let id = format!("{}:{}", manifest.name, entry);
+        if ids.contains(&id) {
+            return Err(ModError::DuplicateId {
+                source: source.to_string(),
+                id,
+            });
+        }
+        ids.push(id.clone());
+        Ok(id)
+    };
+
+    let weapon_defs: Vec<ModWeaponDef> = match weapons {
+        Some(text) => parse_ron(format!("{}/weapons.ron", source), text)?,
+        None => Vec::new(),
+    };
+    let builtins = WeaponRegistry::new();
+    let mut custom_weapons = Vec::with_capacity(weapon_defs.len());
+    for def in weapon_defs {
+        let id = namespaced(&def.id)?;
+        if def.fire_rate <= 0.0 || def.projectiles_per_shot == 0 {
+            return Err(ModError::InvalidEntry {
+                id,
+                reason: "fire_rate and projectiles_per_shot must be positive".into(),
+            });
+        }
+        if def.heat_per_shot > 0.0 && def.cooling_rate <= 0.0 {
+            return Err(ModError::InvalidEntry {
+                id,
+                reason: "cooling_rate must be positive for a weapon that heats up".into(),
+            });
+        }
+        let class = def
+            .class
+            .or_else(|| builtins.get(def.base).map(|w| w.class))
+            .unwrap_or(WeaponClass::Exotic);
+        let hitscan = def
+            .hitscan
+            .unwrap_or_else(|| builtins.get(def.base).is_some_and(|w| w.hitscan));
+        let moving_spread_penalty = def
+            .moving_spread_penalty
+            .or_else(|| builtins.get(def.base).map(|w| w.moving_spread_penalty))
+            .unwrap_or(DEFAULT_MOVING_SPREAD_PENALTY)
+            .max(0.0);
+        custom_weapons.push(CustomWeapon {
+            id,
+            tier: def.tier,
+            data: WeaponData {
+                id: def.base,
+                name: def.name,
+                class,
+                damage: def.damage,
+                fire_rate: def.fire_rate,
+                projectile_speed: def.projectile_speed,
+                spread: def.spread,
+                projectiles_per_shot: def.projectiles_per_shot,
+                ammo_capacity: def.ammo_capacity,
+                reload_time: def.reload_time,
+                projectile_lifetime: def.projectile_lifetime,
+                pierce_count: def.pierce_count,
+                homing: def.homing,
+                homing_turn_rate: def.homing_turn_rate.unwrap_or(DEFAULT_HOMING_TURN_RATE),
+                explosive_radius: def.explosive_radius,
+                recoil: def.recoil.clamp(0.0, 1.0),
+                spin_up: def.spin_up,
+                knockback: def.knockback.max(0.0),
+                self_damage: def.self_damage.unwrap_or(def.explosive_radius > 0.0),
+                heat_per_shot: def.heat_per_shot.max(0.0),
+                cooling_rate: def.cooling_rate.max(0.0),
+                hitscan,
+                moving_spread_penalty,
+            },
+        });
+    }
+
+    let perk_defs: Vec<ModPerkDef> = match perks {
+        Some(text) => parse_ron(format!("{}/perks.ron", source), text)?,
+        None => Vec::new(),
+    };
+    let mut custom_perks = Vec::with_capacity(perk_defs.len());
+    for def in perk_defs {
+        custom_perks.push(CustomPerk {
+            id: namespaced(&def.id)?,
+            name: def.name,
+            description: def.description,
+            rarity: def.rarity,
+            effects: def.effects,
+        });
+    }
+
+    Ok(LoadedMod {
+        source: source.to_string(),
+        manifest,
+        weapons: custom_weapons,
+        perks: custom_perks,
+    })
+}
+
+/// Adds a mod's entries to the registries.
+/// The whole mod is rejected if any of its ids is already registered.
+pub fn register_mod(
+    loaded: LoadedMod,
+    weapons: &mut WeaponRegistry,
+    perks: &mut PerkRegistry,
+) -> Result<(), ModError> {
+    let collision = loaded
+        .weapons
+        .iter()
+        .map(|w| &w.id)
+        .chain(loaded.perks.iter().map(|p| &p.id))
+        .find(|id| weapons.get_custom(id).is_some() || perks.get_custom(id).is_some());
+    if let Some(id) = collision {
+        return Err(ModError::DuplicateId {
+            source: loaded.source,
+            id: id.clone(),
+        });
+    }
+
+    weapons.custom.extend(loaded.weapons);
+    perks.custom.extend(loaded.perks);
+    Ok(())
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, ModError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    std::fs::read_to_string(path)
+        .map(Some)
+        .map_err(|err| ModError::Io {
+            path: path.display().to_string(),
+            message: err.to_string(),
+        })
+}
+
+/// Reads every mod directory under `dir`, in name order
+pub fn read_mods_dir(dir: &Path) -> Vec<Result<LoadedMod, ModError>> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut mod_dirs: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    mod_dirs.sort();
+
+    mod_dirs
+        .iter()
+        .map(|path| {
+            let source = path.display().to_string();
+            let manifest_path = path.join("mod.ron");
+            let manifest = read_optional(&manifest_path)?.ok_or_else(|| ModError::Io {
+                path: manifest_path.display().to_string(),
+                message: "missing manifest".into(),
+            })?;
+            let weapons = read_optional(&path.join("weapons.ron"))?;
+            let perks = read_optional(&path.join("perks.ron"))?;
+            parse_mod(&source, &manifest, weapons.as_deref(), perks.as_deref())
+        })
+        .collect()
+}
+
+/// Loads and registers all mods from [`MODS_DIR`]; rejected mods are logged and skipped
+pub fn load_mods(mut weapons: ResMut<WeaponRegistry>, mut perks: ResMut<PerkRegistry>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    for result in read_mods_dir(Path::new(MODS_DIR)) {
+        let registered = result.and_then(|loaded| {
+            let summary = format!(
+                "{} v{} ({} weapons, {} perks)",
+                loaded.manifest.name,
+                loaded.manifest.version,
+                loaded.weapons.len(),
+                loaded.perks.len()
+            );
+            register_mod(loaded, &mut weapons, &mut perks).map(|()| summary)
+        });
+        match registered {
+            Ok(summary) => info!("Loaded mod {}", summary),
+            Err(err) => error!("Rejected mod: {}", err),
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = (&mut weapons, &mut perks);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::perks::{PerkBonuses, PerkId, PerkInventory, PerkKey};
+    use crate::weapons::WeaponKey;
+
+    fn fixtures() -> Vec<Result<LoadedMod, ModError>> {
+        read_mods_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/mods"))
+    }
+
+    fn load_fixture(dir: &str) -> LoadedMod {
+        fixtures()
+            .into_iter()
+            .filter_map(Result::ok)
+            .find(|m| m.source.ends_with(dir))
+            .expect("fixture mod")
+    }
+
+    #[test]
+    fn fixture_mod_loads_with_namespaced_ids() {
+        let loaded = load_fixture("heavy_arms");
+        assert_eq!(loaded.manifest.name, "heavy_arms");
+        assert_eq!(loaded.manifest.version, "1.2.0");
+
+        let mut weapons = WeaponRegistry::new();
+        let mut perks = PerkRegistry::new();
+        register_mod(loaded, &mut weapons, &mut perks).unwrap();
+
+        let railgun = weapons
+            .get_key(&WeaponKey::Custom("heavy_arms:railgun".into()))
+            .unwrap();
+        assert_eq!(railgun.name, "Railgun");
+        assert_eq!(railgun.id, WeaponId::GaussGun);
+        assert_eq!(railgun.ammo_capacity, Some(8));
+        assert_eq!(railgun.class, WeaponClass::Energy);
+        assert!(railgun.hitscan, "inherits the Gauss Gun's beam");
+        let prototype = weapons
+            .get_key(&WeaponKey::Custom("heavy_arms:prototype".into()))
+            .unwrap();
+        assert_eq!(prototype.class, WeaponClass::Ion);
+        assert!(perks.get_custom("heavy_arms:glass_cannon").is_some());
+    }
+
+    #[test]
+    fn colliding_mod_is_rejected_whole() {
+        let mut weapons = WeaponRegistry::new();
+        let mut perks = PerkRegistry::new();
+        register_mod(load_fixture("heavy_arms"), &mut weapons, &mut perks).unwrap();
+
+        let err = register_mod(load_fixture("heavy_arms_fork"), &mut weapons, &mut perks)
+            .unwrap_err();
+        assert!(matches!(
+            &err,
+            ModError::DuplicateId { id, .. } if id == "heavy_arms:railgun"
+        ));
+        assert!(err.to_string().contains("already defined by another mod"));
+        // The fork's non-colliding entry was not registered either
+        assert!(weapons.get_custom("heavy_arms:peashooter").is_none());
+    }
+
+    #[test]
+    fn duplicate_entry_within_one_mod_is_rejected() {
+        let weapon = r#"(id: "a", name: "A", base: Pistol, damage: 1.0, fire_rate: 1.0,
+            projectile_speed: 100.0, projectiles_per_shot: 1, projectile_lifetime: 1.0)"#;
+        let result = parse_mod(
+            "dupes",
+            r#"(name: "dupes", version: "1")"#,
+            Some(&format!("[{}, {}]", weapon, weapon)),
+            None,
+        );
+        assert!(matches!(result, Err(ModError::DuplicateId { id, .. }) if id == "dupes:a"));
+    }
+
+    #[test]
+    fn invalid_names_and_stats_are_rejected() {
+        let result = parse_mod("bad", r#"(name: "bad:name", version: "1")"#, None, None);
+        assert!(matches!(result, Err(ModError::InvalidName { .. })));
+
+        let result = parse_mod(
+            "stats",
+            r#"(name: "stats", version: "1")"#,
+            Some(
+                r#"[(id: "jam", name: "Jam", base: Pistol, damage: 1.0, fire_rate: 0.0,
+                projectile_speed: 100.0, projectiles_per_shot: 1, projectile_lifetime: 1.0)]"#,
+            ),
+            None,
+        );
+        assert!(matches!(result, Err(ModError::InvalidEntry { .. })));
+    }
+
+    #[test]
+    fn custom_perks_cannot_declare_behaviors() {
+        let result = parse_mod(
+            "behaviors",
+            r#"(name: "behaviors", version: "1")"#,
+            None,
+            Some(
+                r#"[(id: "boom", name: "Boom", description: "", rarity: Rare,
+                effects: (final_revenge: true))]"#,
+            ),
+        );
+        assert!(matches!(result, Err(ModError::Parse { .. })));
+    }
+
+    #[test]
+    fn drop_pool_includes_only_tiered_custom_weapons() {
+        let mut weapons = WeaponRegistry::new();
+        let mut perks = PerkRegistry::new();
+        register_mod(load_fixture("heavy_arms"), &mut weapons, &mut perks).unwrap();
+
+        let pool: Vec<WeaponKey> = weapons.drop_table().into_iter().map(|(key, _)| key).collect();
+        assert!(pool.contains(&WeaponKey::Custom("heavy_arms:railgun".into())));
+        assert!(!pool.contains(&WeaponKey::Custom("heavy_arms:prototype".into())));
+        assert!(pool.contains(&WeaponKey::Builtin(WeaponId::Shotgun)));
+    }
+
+    #[test]
+    fn custom_perk_offers_use_declared_rarity_and_deltas() {
+        let mut weapons = WeaponRegistry::new();
+        let mut perks = PerkRegistry::new();
+        register_mod(load_fixture("heavy_arms"), &mut weapons, &mut perks).unwrap();
+
+        let key = PerkKey::Custom("heavy_arms:glass_cannon".into());
+        let offer = perks.offer(&key).unwrap();
+        assert_eq!(offer.rarity, PerkRarity::Legendary);
+
+        let mut inventory = PerkInventory::new();
+        inventory.add(&key);
+        inventory.add(&key);
+        let bonuses = perks.bonuses_for(&inventory);
+        let base = PerkBonuses::default();
+        assert!((bonuses.damage_multiplier - (base.damage_multiplier + 1.0)).abs() < 0.001);
+        assert!((bonuses.max_health_multiplier - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn missing_mod_content_falls_back() {
+        // Keys saved while a mod was installed, read back without it
+        let weapon: WeaponKey = ron::from_str(r#"Custom("gone:blaster")"#).unwrap();
+        let perk: PerkKey = ron::from_str(r#"Custom("gone:lucky")"#).unwrap();
+        let weapons = WeaponRegistry::new();
+        let perks = PerkRegistry::new();
+
+        assert!(weapons.get_key(&weapon).is_none());
+        assert!(perks.offer(&perk).is_none());
+
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::Regeneration);
+        inventory.add(&perk);
+        let bonuses = perks.bonuses_for(&inventory);
+        assert_eq!(
+            bonuses.regen_per_second,
+            PerkBonuses::calculate(&inventory).regen_per_second
+        );
+    }
+
+    #[test]
+    fn keys_roundtrip_ron() {
+        let keys = vec![
+            WeaponKey::Builtin(WeaponId::Shotgun),
+            WeaponKey::Custom("heavy_arms:railgun".into()),
+        ];
+        let text = ron::to_string(&keys).unwrap();
+        let parsed: Vec<WeaponKey> = ron::from_str(&text).unwrap();
+        assert_eq!(parsed, keys);
+    }
+}
@@ -0,0 +1,62 @@
+//! Mods module
+//!
+//! Loads extra weapons and perks from the `mods/` directory. Each mod is a
+//! directory with a `mod.ron` manifest plus optional `weapons.ron` / `perks.ron`.
+//! Entries are registered under namespaced ids ("modname:entry").
+
+pub mod loader;
+
+pub use loader::*;
+
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Plugin for mod loading
+pub struct ModsPlugin;
+
+impl Plugin for ModsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_mods);
+    }
+}
+
+/// Contents of a mod's `mod.ron`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModManifest {
+    /// Namespace for every id in the mod
+    pub name: String,
+    pub version: String,
+}
+
+/// Why a mod was rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModError {
+    /// A file could not be read
+    Io { path: String, message: String },
+    /// A file is not valid RON for its schema
+    Parse { path: String, message: String },
+    /// Mod or entry name is empty or contains characters other than `[A-Za-z0-9_-]`
+    InvalidName { source: String, name: String },
+    /// An entry has unusable stats
+    InvalidEntry { id: String, reason: String },
+    /// Namespaced id is already registered (by another mod or earlier in the same one)
+    DuplicateId { source: String, id: String },
+}
+
+impl fmt::Display for ModError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModError::Io { path, message } => write!(f, "cannot read {}: {}", path, message),
+            ModError::Parse { path, message } => write!(f, "invalid {}: {}", path, message),
+            ModError::InvalidName { source, name } => {
+                write!(f, "{}: invalid name \"{}\" (use letters, digits, _ or -)", source, name)
+            }
+            ModError::InvalidEntry { id, reason } => write!(f, "{}: {}", id, reason),
+            ModError::DuplicateId { source, id } => {
+                write!(f, "{}: id \"{}\" is already defined by another mod", source, id)
+            }
+        }
+    }
+}
@@ -3,6 +3,8 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::weapons::WeaponClass;
+
 /// All 58 original Crimsonland perks (index matches original game)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[repr(u8)]
@@ -188,6 +190,29 @@ impl PerkId {
         ]
     }
 
+    /// Whether the perk can come up on the perk selection screen
+    pub fn is_offered(&self) -> bool {
+        Self::all().contains(self)
+    }
+
+    /// Whether taking the perk again does anything: perks that scale with their
+    /// stack count, and one-shot perks that fire on every pick
+    pub fn is_repeatable(&self) -> bool {
+        matches!(
+            self,
+            PerkId::LongDistanceRunner
+                | PerkId::Regeneration
+                | PerkId::MrMelee
+                | PerkId::Radioactive
+                | PerkId::InstantWinner
+                | PerkId::GrimDeal
+                | PerkId::FatalLottery
+                | PerkId::InfernalContract
+                | PerkId::Bandage
+                | PerkId::Lifeline5050
+        )
+    }
+
     /// Returns the number of perk choices based on PerkExpert/PerkMaster perks
     pub fn perk_choice_count(inventory: &PerkInventory) -> usize {
         if inventory.has_perk(PerkId::PerkMaster) {
@@ -200,16 +225,34 @@ impl PerkId {
     }
 }
 
+/// Registry key for a perk: a built-in id or a mod perk namespaced as "modname:entry"
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PerkKey {
+    Builtin(PerkId),
+    Custom(String),
+}
+
+impl From<PerkId> for PerkKey {
+    fn from(id: PerkId) -> Self {
+        PerkKey::Builtin(id)
+    }
+}
+
 /// Component storing the player's acquired perks
 #[derive(Component, Debug, Clone)]
 pub struct PerkInventory {
     /// Count of each perk type (some perks stack)
     counts: [u8; 64],
+    /// Counts of mod perks by namespaced id
+    custom: Vec<(String, u8)>,
 }
 
 impl Default for PerkInventory {
     fn default() -> Self {
-        Self { counts: [0; 64] }
+        Self {
+            counts: [0; 64],
+            custom: Vec::new(),
+        }
     }
 }
 
@@ -238,8 +281,239 @@ impl PerkInventory {
         }
     }
 
+    pub fn add(&mut self, key: &PerkKey) {
+        match key {
+            PerkKey::Builtin(perk) => self.add_perk(*perk),
+            PerkKey::Custom(id) => match self.custom.iter_mut().find(|(owned, _)| owned == id) {
+                Some((_, count)) => *count = count.saturating_add(1),
+                None => self.custom.push((id.clone(), 1)),
+            },
+        }
+    }
+
+    pub fn count(&self, key: &PerkKey) -> u8 {
+        match key {
+            PerkKey::Builtin(perk) => self.get_count(*perk),
+            PerkKey::Custom(id) => self
+                .custom
+                .iter()
+                .find(|(owned, _)| owned == id)
+                .map_or(0, |(_, count)| *count),
+        }
+    }
+
+    /// Owned mod perks with their counts
+    pub fn custom_perks(&self) -> impl Iterator<Item = (&str, u8)> {
+        self.custom.iter().map(|(id, count)| (id.as_str(), *count))
+    }
+
+    /// Every owned perk with its count: built-ins in offer order, then mod perks
+    pub fn owned(&self) -> Vec<(PerkKey, u8)> {
+        PerkId::all()
+            .iter()
+            .map(|&id| PerkKey::Builtin(id))
+            .chain(self.custom.iter().map(|(id, _)| PerkKey::Custom(id.clone())))
+            .map(|key| {
+                let count = self.count(&key);
+                (key, count)
+            })
+            .filter(|&(_, count)| count > 0)
+            .collect()
+    }
+
     pub fn total_perks(&self) -> u32 {
-        self.counts.iter().map(|&c| c as u32).sum()
+        let custom: u32 = self.custom.iter().map(|&(_, c)| c as u32).sum();
+        self.counts.iter().map(|&c| c as u32).sum::<u32>() + custom
+    }
+}
+
+/// Seconds between HotTempered's rings of shots
+pub const HOT_TEMPERED_INTERVAL: f32 = 4.0;
+/// Shots in a HotTempered ring
+pub const HOT_TEMPERED_RING_SHOTS: u32 = 8;
+
+/// Time since the player's last HotTempered ring
+#[derive(Component, Debug, Clone, Default)]
+pub struct HotTemperedTimer {
+    pub elapsed: f32,
+}
+
+/// Seconds between FireCough's involuntary shots
+pub const FIRE_COUGH_INTERVAL: f32 = 3.0;
+
+/// Time since the player's last FireCough shot; present only while the perk is held
+#[derive(Component, Debug, Clone, Default)]
+pub struct FireCoughTimer {
+    pub elapsed: f32,
+}
+
+/// Reach of the Radioactive and Pyrokinetic auras
+pub const AURA_RADIUS: f32 = 120.0;
+/// Radioactive damage per second per pick to creatures inside the aura
+pub const RADIOACTIVE_DPS: f32 = 10.0;
+/// Seconds between Pyrokinetic ignitions
+pub const PYROKINETIC_INTERVAL: f32 = 1.0;
+/// Burning applied by Pyrokinetic
+pub const PYROKINETIC_BURN_DPS: f32 = 8.0;
+pub const PYROKINETIC_BURN_DURATION: f32 = 2.0;
+
+/// Time since the player's Pyrokinetic aura last ignited creatures
+#[derive(Component, Debug, Clone, Default)]
+pub struct PyrokineticTimer {
+    pub elapsed: f32,
+}
+
+/// Plaguebearer infection dealt to a creature the player hits
+pub const PLAGUE_DPS: f32 = 6.0;
+pub const PLAGUE_DURATION: f32 = 5.0;
+/// Infected creatures pass the infection on to creatures this close
+pub const PLAGUE_SPREAD_RADIUS: f32 = 60.0;
+/// Seconds between spreads; dying spreads it once more
+pub const PLAGUE_SPREAD_INTERVAL: f32 = 1.0;
+/// Each generation of spread keeps this share of the previous dps
+pub const PLAGUE_SPREAD_FALLOFF: f32 = 0.7;
+/// Infections weaker than this don't spread any further
+pub const PLAGUE_MIN_SPREAD_DPS: f32 = 1.5;
+/// Sickly green tint on infected creatures
+pub const INFECTED_TINT: Color = Color::srgb(0.6, 0.85, 0.4);
+
+/// A creature carrying Plaguebearer infection. The damage itself is the
+/// `StatusKind::Infected` status; this tracks spreading and the tint
+#[derive(Component, Debug, Clone)]
+pub struct Infection {
+    pub dps: f32,
+    pub source: Option<Entity>,
+    pub spread_elapsed: f32,
+    /// Sprite color to restore once the infection runs out
+    pub original_color: Color,
+}
+
+/// How far an EvilEyes stare reaches
+pub const EVIL_EYES_RANGE: f32 = 600.0;
+/// Half-angle (radians) around the aim line that counts as being stared at
+pub const EVIL_EYES_CONE: f32 = 0.1;
+/// Seconds a creature stays held after the aim moves off it
+pub const EVIL_EYES_LINGER: f32 = 0.3;
+/// Bosses resist the stare and only slow to this share of their speed
+pub const EVIL_EYES_BOSS_SPEED: f32 = 0.5;
+
+/// The player's FinalRevenge blast has gone off
+#[derive(Component, Debug, Clone, Copy)]
+pub struct FinalRevengeSpent;
+
+/// Shove given to a creature struck by a MrMelee counter, divided by its mass
+pub const MR_MELEE_KNOCKBACK: f32 = 150.0;
+
+/// Poison given to creatures that bite a VeinsOfPoison player
+pub const VEINS_OF_POISON_DPS: f32 = 3.0;
+pub const VEINS_OF_POISON_DURATION: f32 = 5.0;
+/// ToxicAvenger's stronger contact poison, used instead of VeinsOfPoison's when both are held
+pub const TOXIC_AVENGER_DPS: f32 = 10.0;
+pub const TOXIC_AVENGER_DURATION: f32 = 6.0;
+
+/// Poison from a PoisonBullets proc; a flat dose, so crits don't strengthen it
+pub const POISON_BULLETS_DPS: f32 = 5.0;
+pub const POISON_BULLETS_DURATION: f32 = 4.0;
+
+/// LivingFortress damage bonus gained per second of standing still
+pub const LIVING_FORTRESS_RAMP: f32 = 0.1;
+/// Most LivingFortress can add to damage
+pub const LIVING_FORTRESS_MAX_BONUS: f32 = 1.0;
+
+/// Shots in an AngryReloader ring
+pub const ANGRY_RELOADER_RING_SHOTS: u32 = 10;
+/// Reload progress at which AngryReloader fires its ring
+pub const ANGRY_RELOADER_TRIGGER_PROGRESS: f32 = 0.5;
+
+/// Whether AngryReloader already fired its ring during the current reload
+#[derive(Component, Debug, Clone, Default)]
+pub struct AngryReloaderState {
+    pub ring_fired: bool,
+}
+
+/// Seconds the player must stand still before ManBomb starts pulsing
+pub const MAN_BOMB_STILL_TIME: f32 = 1.0;
+/// Seconds between ManBomb ion rings
+pub const MAN_BOMB_INTERVAL: f32 = 2.0;
+/// ManBomb ring radius before IonGunMaster
+pub const MAN_BOMB_RADIUS: f32 = 150.0;
+/// ManBomb damage at the center of the ring before IonGunMaster
+pub const MAN_BOMB_DAMAGE: f32 = 30.0;
+
+/// Time since the player's last ManBomb ring while standing still
+#[derive(Component, Debug, Clone, Default)]
+pub struct ManBombTimer {
+    pub elapsed: f32,
+}
+
+/// LongDistanceRunner momentum gained per second of moving
+pub const RUNNER_MOMENTUM_RAMP: f32 = 0.1;
+/// LongDistanceRunner momentum lost per second of standing still
+pub const RUNNER_MOMENTUM_DECAY: f32 = 2.0;
+/// LongDistanceRunner top speed added per stack
+pub const RUNNER_CAP_PER_STACK: f32 = 0.9;
+/// LongDistanceRunner top speed however many stacks are held
+pub const RUNNER_MAX_CAP: f32 = 2.8;
+
+/// LongDistanceRunner speed multiplier, built up by moving and lost by stopping
+#[derive(Component, Debug, Clone)]
+pub struct RunnerMomentum {
+    pub multiplier: f32,
+    /// Direction of the last movement
+    pub heading: Vec2,
+    last_position: Option<Vec2>,
+}
+
+impl Default for RunnerMomentum {
+    fn default() -> Self {
+        Self {
+            multiplier: 1.0,
+            heading: Vec2::ZERO,
+            last_position: None,
+        }
+    }
+}
+
+impl RunnerMomentum {
+    /// Ramps toward `cap` while the position changes and falls back to 1.0 when it holds
+    pub fn update(&mut self, position: Vec2, cap: f32, delta: f32) {
+        let step = self.last_position.map_or(Vec2::ZERO, |last| position - last);
+        if step.length_squared() >= 0.01 {
+            self.heading = step.normalize();
+            self.multiplier = (self.multiplier + RUNNER_MOMENTUM_RAMP * delta).min(cap);
+        } else {
+            self.multiplier = (self.multiplier - RUNNER_MOMENTUM_DECAY * delta).max(1.0);
+        }
+        self.last_position = Some(position);
+    }
+}
+
+/// Reload time AnxiousLoader takes off for each pull of the trigger mid-reload
+pub const ANXIOUS_LOADER_RELOAD_CUT: f32 = 0.05;
+
+/// XP LeanMeanExpMachine has built up toward its next whole point; present only
+/// while the perk is held
+#[derive(Component, Debug, Clone, Default)]
+pub struct PassiveXp {
+    pub progress: f32,
+}
+
+/// Jinxed countdown to its next event and the fire rate burst it may have granted;
+/// present only while the perk is held
+#[derive(Component, Debug, Clone, Default)]
+pub struct JinxState {
+    pub next_event_in: f32,
+    pub fire_rate_burst: f32,
+}
+
+impl JinxState {
+    /// Fire rate factor while a Jinxed burst lasts
+    pub fn fire_rate_multiplier(&self) -> f32 {
+        if self.fire_rate_burst > 0.0 {
+            2.0
+        } else {
+            1.0
+        }
     }
 }
 
@@ -249,12 +523,16 @@ pub struct PerkBonuses {
     // === XP & Progression ===
     /// Experience gain multiplier (BloodyMess: +30%)
     pub exp_multiplier: f32,
+    /// Kills leave more and bigger blood decals (BloodyMess)
+    pub bloody_mess: bool,
     /// Passive XP per second (LeanMeanExpMachine)
     pub passive_xp_per_second: f32,
 
     // === Movement ===
-    /// Movement speed multiplier (LongDistanceRunner ramps to 2.8)
+    /// Movement speed multiplier
     pub speed_multiplier: f32,
+    /// Top speed LongDistanceRunner momentum can build to (1.0 without it)
+    pub runner_momentum_cap: f32,
     /// No knockback on damage (Unstoppable)
     pub unstoppable: bool,
 
@@ -277,6 +555,8 @@ pub struct PerkBonuses {
     pub spread_multiplier: f32,
     /// Accuracy bonus (0.0-1.0, reduces spread)
     pub accuracy_bonus: f32,
+    /// Draw a laser sight along the aim (Sharpshooter)
+    pub laser_sight: bool,
     /// Fire rate multiplier (Fastshot: 0.88 cooldown)
     pub fire_rate_multiplier: f32,
     /// Critical hit chance (Highlander uses instant_kill instead)
@@ -293,7 +573,7 @@ pub struct PerkBonuses {
     pub clip_size_multiplier: f32,
     /// Fixed clip bonus (MyFavouriteWeapon: +2)
     pub clip_size_bonus: i32,
-    /// Reload speed multiplier (Fastloader: 0.7)
+    /// Reload speed multiplier (Fastloader: 1/0.7, for 0.7x the reload time)
     pub reload_speed_multiplier: f32,
     /// Stationary reload multiplier (StationaryReloader: 3.0)
     pub stationary_reload_multiplier: f32,
@@ -309,8 +589,6 @@ pub struct PerkBonuses {
     pub max_health_multiplier: f32,
     /// Damage taken multiplier (ThickSkinned: 2/3)
     pub damage_taken_multiplier: f32,
-    /// Damage reduction (0.0-1.0, derived from damage_taken_multiplier)
-    pub damage_reduction: f32,
     /// Damage taken during reload (ToughReloader: 0.5)
     pub reload_damage_multiplier: f32,
     /// Dodge chance (Dodger: 20%, Ninja: 33%)
@@ -381,8 +659,10 @@ impl Default for PerkBonuses {
     fn default() -> Self {
         Self {
             exp_multiplier: 1.0,
+            bloody_mess: false,
             passive_xp_per_second: 0.0,
             speed_multiplier: 1.0,
+            runner_momentum_cap: 1.0,
             unstoppable: false,
             damage_multiplier: 1.0,
             fire_damage_multiplier: 1.0,
@@ -392,6 +672,7 @@ impl Default for PerkBonuses {
             projectile_speed_multiplier: 1.0,
             spread_multiplier: 1.0,
             accuracy_bonus: 0.0,
+            laser_sight: false,
             fire_rate_multiplier: 1.0,
             crit_chance: 0.0,
             crit_multiplier: 2.0,
@@ -406,7 +687,6 @@ impl Default for PerkBonuses {
             anxious_loader: false,
             max_health_multiplier: 1.0,
             damage_taken_multiplier: 1.0,
-            damage_reduction: 0.0,
             reload_damage_multiplier: 1.0,
             dodge_chance: 0.0,
             regen_per_second: 0.0,
@@ -445,9 +725,10 @@ impl PerkBonuses {
         let mut bonuses = Self::default();
 
         // === XP Perks ===
-        // BloodyMess: +30% XP
+        // BloodyMess: +30% XP and a messier floor
         if inventory.has_perk(PerkId::BloodyMess) {
             bonuses.exp_multiplier += 0.30;
+            bonuses.bloody_mess = true;
         }
         // LeanMeanExpMachine: passive XP every 0.25s (4 XP/sec)
         if inventory.has_perk(PerkId::LeanMeanExpMachine) {
@@ -455,9 +736,9 @@ impl PerkBonuses {
         }
 
         // === Movement ===
-        // LongDistanceRunner: speed ramps to 2.8 (simplified to flat bonus)
+        // LongDistanceRunner: speed ramps while moving (RunnerMomentum), up to 2.8
         let runner_count = inventory.get_count(PerkId::LongDistanceRunner) as f32;
-        bonuses.speed_multiplier = 1.0 + runner_count * 0.4; // Caps around 2.8 with multiple
+        bonuses.runner_momentum_cap = (1.0 + runner_count * RUNNER_CAP_PER_STACK).min(RUNNER_MAX_CAP);
         // Unstoppable: no knockback
         bonuses.unstoppable = inventory.has_perk(PerkId::Unstoppable);
 
@@ -495,6 +776,7 @@ impl PerkBonuses {
         if inventory.has_perk(PerkId::Sharpshooter) {
             bonuses.spread_multiplier = 0.5;
             bonuses.accuracy_bonus = 0.5; // Derived: 1 - spread_multiplier
+            bonuses.laser_sight = true;
         }
         // Fastshot: cooldown * 0.88 (fire rate / 0.88 = faster)
         if inventory.has_perk(PerkId::Fastshot) {
@@ -515,9 +797,9 @@ impl PerkBonuses {
             bonuses.clip_size_bonus = 2;
             bonuses.disable_weapon_bonuses = true;
         }
-        // Fastloader: reload * 0.7
+        // Fastloader: reload time * 0.7
         if inventory.has_perk(PerkId::Fastloader) {
-            bonuses.reload_speed_multiplier = 0.7;
+            bonuses.reload_speed_multiplier = 1.0 / 0.7;
         }
         // StationaryReloader: 3x reload speed while still
         if inventory.has_perk(PerkId::StationaryReloader) {
@@ -535,7 +817,6 @@ impl PerkBonuses {
         if inventory.has_perk(PerkId::ThickSkinned) {
             bonuses.max_health_multiplier = 2.0 / 3.0;
             bonuses.damage_taken_multiplier = 2.0 / 3.0;
-            bonuses.damage_reduction = 1.0 / 3.0; // Derived: 1 - damage_taken_multiplier
         }
         // ToughReloader: 0.5x damage during reload
         if inventory.has_perk(PerkId::ToughReloader) {
@@ -602,10 +883,8 @@ impl PerkBonuses {
         if inventory.has_perk(PerkId::ReflexBoosted) {
             bonuses.time_scale = 0.9;
         }
-        // MrMelee: counter-hit for 25 damage
-        if inventory.has_perk(PerkId::MrMelee) {
-            bonuses.melee_counter_damage = 25.0;
-        }
+        // MrMelee: counter-hit for 25 damage per pick
+        bonuses.melee_counter_damage = 25.0 * inventory.get_count(PerkId::MrMelee) as f32;
         // FinalRevenge: explosion on death
         bonuses.final_revenge = inventory.has_perk(PerkId::FinalRevenge);
         // DeathClock: health drain + immunity
@@ -621,6 +900,123 @@ impl PerkBonuses {
 
         bonuses
     }
+
+    /// Extra damage multiplier for a weapon class (Pyromaniac, IonGunMaster)
+    pub fn class_damage_multiplier(&self, class: WeaponClass) -> f32 {
+        match class {
+            WeaponClass::Fire => self.fire_damage_multiplier,
+            WeaponClass::Ion => self.ion_damage_multiplier,
+            _ => 1.0,
+        }
+    }
+
+    /// Explosion radius multiplier for a weapon class (IonGunMaster)
+    pub fn class_aoe_multiplier(&self, class: WeaponClass) -> f32 {
+        match class {
+            WeaponClass::Ion => self.ion_aoe_multiplier,
+            _ => 1.0,
+        }
+    }
+
+    /// Net passive health change per second from perks. Regeneration adds to it;
+    /// perks that drain health subtract from it, so they net out in one place.
+    pub fn health_per_second(&self, death_clock_drain: f32) -> f32 {
+        let drain = if self.death_clock { death_clock_drain } else { 0.0 };
+        self.regen_per_second - drain
+    }
+
+    /// Damage factor from LivingFortress after standing still for `still_seconds`
+    pub fn living_fortress_multiplier(&self, still_seconds: f32) -> f32 {
+        if self.living_fortress {
+            1.0 + (still_seconds * LIVING_FORTRESS_RAMP).min(LIVING_FORTRESS_MAX_BONUS)
+        } else {
+            1.0
+        }
+    }
+
+    /// Clip size for a weapon holding `base` rounds (AmmoManiac, MyFavouriteWeapon)
+    pub fn clip_size(&self, base: u32) -> u32 {
+        let scaled = (base as f32 * self.clip_size_multiplier).ceil() as i32;
+        (scaled + self.clip_size_bonus).max(1) as u32
+    }
+
+    /// Poison (dps, duration) given to creatures that bite this player, if any
+    pub fn contact_poison(&self) -> Option<(f32, f32)> {
+        if self.toxic_avenger {
+            Some((TOXIC_AVENGER_DPS, TOXIC_AVENGER_DURATION))
+        } else if self.poison_on_contact {
+            Some((VEINS_OF_POISON_DPS, VEINS_OF_POISON_DURATION))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+impl PerkBonuses {
+    /// Every bonus field, for the balance snapshot
+    pub fn snapshot_repr(&self) -> crate::snapshot::SnapshotTable {
+        let mut table = crate::snapshot::SnapshotTable::new();
+        table
+            .float("exp_multiplier", self.exp_multiplier)
+            .flag("bloody_mess", self.bloody_mess)
+            .float("passive_xp_per_second", self.passive_xp_per_second)
+            .float("speed_multiplier", self.speed_multiplier)
+            .float("runner_momentum_cap", self.runner_momentum_cap)
+            .flag("unstoppable", self.unstoppable)
+            .float("damage_multiplier", self.damage_multiplier)
+            .float("fire_damage_multiplier", self.fire_damage_multiplier)
+            .float("ion_damage_multiplier", self.ion_damage_multiplier)
+            .float("ion_aoe_multiplier", self.ion_aoe_multiplier)
+            .float("instant_kill_chance", self.instant_kill_chance)
+            .float("projectile_speed_multiplier", self.projectile_speed_multiplier)
+            .float("spread_multiplier", self.spread_multiplier)
+            .float("accuracy_bonus", self.accuracy_bonus)
+            .flag("laser_sight", self.laser_sight)
+            .float("fire_rate_multiplier", self.fire_rate_multiplier)
+            .float("crit_chance", self.crit_chance)
+            .float("crit_multiplier", self.crit_multiplier)
+            .float("range_multiplier", self.range_multiplier)
+            .float("ammo_multiplier", self.ammo_multiplier)
+            .float("clip_size_multiplier", self.clip_size_multiplier)
+            .int("clip_size_bonus", self.clip_size_bonus)
+            .float("reload_speed_multiplier", self.reload_speed_multiplier)
+            .float("stationary_reload_multiplier", self.stationary_reload_multiplier)
+            .flag("regression_bullets", self.regression_bullets)
+            .flag("ammunition_within", self.ammunition_within)
+            .flag("anxious_loader", self.anxious_loader)
+            .float("max_health_multiplier", self.max_health_multiplier)
+            .float("damage_taken_multiplier", self.damage_taken_multiplier)
+            .float("reload_damage_multiplier", self.reload_damage_multiplier)
+            .float("dodge_chance", self.dodge_chance)
+            .float("regen_per_second", self.regen_per_second)
+            .float("poison_chance", self.poison_chance)
+            .flag("poison_on_contact", self.poison_on_contact)
+            .flag("toxic_avenger", self.toxic_avenger)
+            .flag("radioactive_aura", self.radioactive_aura)
+            .flag("pyrokinetic_aura", self.pyrokinetic_aura)
+            .flag("hot_tempered", self.hot_tempered)
+            .flag("fire_cough", self.fire_cough)
+            .flag("man_bomb", self.man_bomb)
+            .flag("angry_reloader", self.angry_reloader)
+            .float("telekinetic_range", self.telekinetic_range)
+            .float("bonus_spawn_multiplier", self.bonus_spawn_multiplier)
+            .float("bonus_duration_multiplier", self.bonus_duration_multiplier)
+            .flag("show_creature_health", self.show_creature_health)
+            .flag("monster_vision", self.monster_vision)
+            .int("perk_choices", self.perk_choices as i64)
+            .flag("alternate_weapon", self.alternate_weapon)
+            .float("time_scale", self.time_scale)
+            .float("melee_counter_damage", self.melee_counter_damage)
+            .flag("final_revenge", self.final_revenge)
+            .flag("death_clock", self.death_clock)
+            .flag("plaguebearer", self.plaguebearer)
+            .flag("evil_eyes", self.evil_eyes)
+            .flag("jinxed", self.jinxed)
+            .flag("living_fortress", self.living_fortress)
+            .flag("disable_weapon_bonuses", self.disable_weapon_bonuses);
+        table
+    }
 }
 
 #[cfg(test)]
@@ -650,6 +1046,14 @@ mod tests {
         assert_eq!(inv.get_count(PerkId::Regeneration), 3);
     }
 
+    #[test]
+    fn perks_that_scale_with_their_count_are_repeatable() {
+        // Every perk whose effect reads PerkInventory::get_count
+        for perk in [PerkId::LongDistanceRunner, PerkId::Regeneration, PerkId::MrMelee, PerkId::Radioactive] {
+            assert!(perk.is_repeatable(), "{:?}", perk);
+        }
+    }
+
     #[test]
     fn perk_inventory_total_perks() {
         let mut inv = PerkInventory::new();
@@ -686,13 +1090,26 @@ mod tests {
         assert!((bonuses.dodge_chance - 1.0 / 3.0).abs() < 0.001);
     }
 
+    #[test]
+    fn clip_size_perks_grow_the_clip() {
+        let mut inv = PerkInventory::new();
+        assert_eq!(PerkBonuses::calculate(&inv).clip_size(50), 50);
+        inv.add_perk(PerkId::AmmoManiac);
+        assert_eq!(PerkBonuses::calculate(&inv).clip_size(50), 63);
+        inv.add_perk(PerkId::MyFavouriteWeapon);
+        assert_eq!(PerkBonuses::calculate(&inv).clip_size(50), 65);
+    }
+
     #[test]
     fn perk_bonuses_speed_stacks() {
         let mut inv = PerkInventory::new();
         inv.add_perk(PerkId::LongDistanceRunner);
         inv.add_perk(PerkId::LongDistanceRunner);
         let bonuses = PerkBonuses::calculate(&inv);
-        assert!((bonuses.speed_multiplier - 1.8).abs() < 0.001);
+        assert_eq!(bonuses.speed_multiplier, 1.0);
+        assert!((bonuses.runner_momentum_cap - RUNNER_MAX_CAP).abs() < 0.001);
+        inv.add_perk(PerkId::LongDistanceRunner);
+        assert_eq!(PerkBonuses::calculate(&inv).runner_momentum_cap, RUNNER_MAX_CAP);
     }
 
     #[test]
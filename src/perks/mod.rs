@@ -4,14 +4,17 @@
 
 pub mod components;
 pub mod registry;
+pub mod resources;
 pub mod systems;
 
 pub use components::*;
 pub use registry::*;
+pub use resources::*;
 pub use systems::*;
 
 use bevy::prelude::*;
 
+use crate::schedule::GameSet;
 use crate::states::{GameState, PlayingState};
 
 /// Plugin for perk-related functionality
@@ -20,12 +23,61 @@ pub struct PerksPlugin;
 impl Plugin for PerksPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PerkRegistry>()
+            .init_resource::<PerkEffectConfig>()
+            .init_resource::<PendingLevelUps>()
+            .init_resource::<JinxConfig>()
             .add_event::<PerkSelectedEvent>()
+            .add_systems(OnEnter(GameState::Playing), reset_pending_level_ups)
             .add_systems(OnEnter(PlayingState::PerkSelect), setup_perk_selection)
             .add_systems(
                 Update,
                 (
-                    apply_perk_effects.run_if(in_state(GameState::Playing)),
+                    (apply_perk_effects, player_regeneration)
+                        .chain()
+                        .run_if(in_state(GameState::Playing))
+                        .in_set(GameSet::Simulation),
+                    (apply_instant_perk_effects, apply_lifeline_perk)
+                        .run_if(in_state(GameState::Playing))
+                        .in_set(GameSet::Simulation),
+                    (
+                        hot_tempered_ring,
+                        fire_cough_shot,
+                        angry_reloader_ring.after(crate::weapons::weapon_reload_system),
+                        man_bomb_pulse.after(crate::player::systems::update_stationary_timers),
+                        runner_momentum,
+                        jinx_tick,
+                        passive_experience,
+                    )
+                        .run_if(in_state(GameState::Playing))
+                        .before(crate::weapons::spawn_projectiles_from_fire_events)
+                        .in_set(GameSet::Simulation),
+                    evil_eyes_stare
+                        .before(crate::creatures::creature_movement)
+                        .run_if(in_state(GameState::Playing))
+                        .in_set(GameSet::Simulation),
+                    (aura_damage_system, mr_melee_counter, contact_poison)
+                        .run_if(in_state(GameState::Playing))
+                        .in_set(GameSet::DamageResolution),
+                    poison_bullets
+                        .after(crate::weapons::projectile_collision)
+                        .run_if(in_state(GameState::Playing))
+                        .in_set(GameSet::DamageResolution),
+                    plaguebearer_infection
+                        .after(crate::weapons::projectile_collision)
+                        .after(crate::creatures::tick_status_effects)
+                        .before(crate::weapons::cleanup_projectiles)
+                        .run_if(in_state(GameState::Playing))
+                        .in_set(GameSet::DamageResolution),
+                    final_revenge_blast
+                        .before(crate::player::systems::check_player_death)
+                        .before(crate::creatures::check_creature_death)
+                        .run_if(in_state(GameState::Playing))
+                        .in_set(GameSet::DeathResolution),
+                    (queue_level_ups, open_perk_select.run_if(in_state(PlayingState::Active)))
+                        .chain()
+                        .after(crate::player::systems::apply_experience_grants)
+                        .run_if(in_state(GameState::Playing))
+                        .in_set(GameSet::Consequences),
                     handle_perk_selection.run_if(in_state(PlayingState::PerkSelect)),
                 ),
             );
@@ -1,14 +1,17 @@
 //! Perk registry and data - All 58 original Crimsonland perks
 
 use bevy::prelude::*;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use super::components::PerkId;
+use super::components::{PerkBonuses, PerkId, PerkInventory, PerkKey};
 
 /// Registry containing all perk definitions
 #[derive(Resource)]
 pub struct PerkRegistry {
     pub perks: Vec<PerkData>,
+    /// Perks added by mods
+    pub custom: Vec<CustomPerk>,
 }
 
 impl Default for PerkRegistry {
@@ -19,7 +22,10 @@ impl Default for PerkRegistry {
 
 impl PerkRegistry {
     pub fn new() -> Self {
-        let mut registry = Self { perks: Vec::new() };
+        let mut registry = Self {
+            perks: Vec::new(),
+            custom: Vec::new(),
+        };
         registry.register_all_perks();
         registry
     }
@@ -28,6 +34,40 @@ impl PerkRegistry {
         self.perks.iter().find(|p| p.id == id)
     }
 
+    pub fn get_custom(&self, id: &str) -> Option<&CustomPerk> {
+        self.custom.iter().find(|p| p.id == id)
+    }
+
+    /// Display data for a built-in or custom perk
+    pub fn offer(&self, key: &PerkKey) -> Option<PerkOffer> {
+        match key {
+            PerkKey::Builtin(id) => self.get(*id).map(|p| PerkOffer {
+                key: key.clone(),
+                name: p.name.clone(),
+                description: p.description.clone(),
+                rarity: p.rarity,
+            }),
+            PerkKey::Custom(id) => self.get_custom(id).map(|p| PerkOffer {
+                key: key.clone(),
+                name: p.name.clone(),
+                description: p.description.clone(),
+                rarity: p.rarity,
+            }),
+        }
+    }
+
+    /// Bonuses for an inventory, including the deltas of owned custom perks.
+    /// Custom perks whose mod is not loaded are ignored.
+    pub fn bonuses_for(&self, inventory: &PerkInventory) -> PerkBonuses {
+        let mut bonuses = PerkBonuses::calculate(inventory);
+        for (id, count) in inventory.custom_perks() {
+            if let Some(perk) = self.get_custom(id) {
+                perk.effects.apply(&mut bonuses, count);
+            }
+        }
+        bonuses
+    }
+
     fn register_all_perks(&mut self) {
         self.perks = vec![
             // === XP & Progression ===
@@ -396,13 +436,59 @@ impl PerkRegistry {
         ];
     }
 
-    /// Get a random selection of perks for the perk selection screen
-    pub fn get_random_selection(&self, count: usize) -> Vec<&PerkData> {
-        use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
-        let mut shuffled: Vec<_> = self.perks.iter().collect();
-        shuffled.shuffle(&mut rng);
-        shuffled.into_iter().take(count).collect()
+    /// Up to `count` different perks for the perk selection screen, drawn by rarity
+    /// weight. Perks that are never offered (quest-only, two-player) and owned perks
+    /// that don't stack are left out; custom perks always stack
+    pub fn get_random_selection(&self, inventory: &PerkInventory, count: usize, rng: &mut impl Rng) -> Vec<PerkOffer> {
+        let mut pool: Vec<PerkOffer> = self
+            .perks
+            .iter()
+            .filter(|p| p.id.is_offered() && (p.id.is_repeatable() || !inventory.has_perk(p.id)))
+            .map(|p| PerkKey::Builtin(p.id))
+            .chain(self.custom.iter().map(|p| PerkKey::Custom(p.id.clone())))
+            .filter_map(|key| self.offer(&key))
+            .collect();
+
+        let mut selection = Vec::with_capacity(count);
+        while selection.len() < count && !pool.is_empty() {
+            let total_weight: u32 = pool.iter().map(|offer| offer.rarity.weight()).sum();
+            let mut roll = rng.gen_range(0..total_weight);
+            let index = pool
+                .iter()
+                .position(|offer| {
+                    let weight = offer.rarity.weight();
+                    if roll < weight {
+                        return true;
+                    }
+                    roll -= weight;
+                    false
+                })
+                .unwrap_or(0);
+            selection.push(pool.swap_remove(index));
+        }
+        selection
+    }
+}
+
+#[cfg(test)]
+impl PerkRegistry {
+    /// Rarity and single-perk bonus changes (against the no-perk baseline) for each built-in perk
+    pub fn snapshot_repr(&self) -> crate::snapshot::SnapshotTable {
+        let baseline = PerkBonuses::default().snapshot_repr();
+        let mut table = crate::snapshot::SnapshotTable::new();
+        table.table("_baseline", baseline.clone());
+        for perk in &self.perks {
+            let mut inventory = PerkInventory::new();
+            inventory.add_perk(perk.id);
+            let bonuses = PerkBonuses::calculate(&inventory).snapshot_repr();
+
+            let mut entry = crate::snapshot::SnapshotTable::new();
+            entry
+                .ident("rarity", perk.rarity)
+                .table("bonuses", bonuses.changed_from(&baseline));
+            table.table(&format!("{:?}", perk.id), entry);
+        }
+        table
     }
 }
 
@@ -416,6 +502,16 @@ pub enum PerkRarity {
 }
 
 impl PerkRarity {
+    /// Relative chance of being drawn for the perk selection screen
+    pub fn weight(&self) -> u32 {
+        match self {
+            PerkRarity::Common => 10,
+            PerkRarity::Uncommon => 6,
+            PerkRarity::Rare => 3,
+            PerkRarity::Legendary => 1,
+        }
+    }
+
     pub fn color(&self) -> Color {
         match self {
             PerkRarity::Common => Color::srgb(0.7, 0.7, 0.7),      // Gray
@@ -435,6 +531,73 @@ pub struct PerkData {
     pub rarity: PerkRarity,
 }
 
+/// A perk as shown on the perk select screen
+#[derive(Debug, Clone)]
+pub struct PerkOffer {
+    pub key: PerkKey,
+    pub name: String,
+    pub description: String,
+    pub rarity: PerkRarity,
+}
+
+/// A perk added by a mod. Limited to numeric bonus deltas.
+#[derive(Debug, Clone)]
+pub struct CustomPerk {
+    /// Namespaced id ("modname:entry")
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub rarity: PerkRarity,
+    pub effects: PerkDeltas,
+}
+
+/// Additive changes to [`PerkBonuses`], applied once per stack
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PerkDeltas {
+    pub exp_multiplier: f32,
+    pub speed_multiplier: f32,
+    pub damage_multiplier: f32,
+    pub fire_rate_multiplier: f32,
+    pub spread_multiplier: f32,
+    pub reload_speed_multiplier: f32,
+    pub projectile_speed_multiplier: f32,
+    pub range_multiplier: f32,
+    pub ammo_multiplier: f32,
+    pub max_health_multiplier: f32,
+    pub damage_taken_multiplier: f32,
+    pub dodge_chance: f32,
+    pub crit_chance: f32,
+    pub regen_per_second: f32,
+}
+
+impl PerkDeltas {
+    /// Minimum any multiplier can be pushed down to by deltas
+    const MIN_MULTIPLIER: f32 = 0.1;
+
+    pub fn apply(&self, bonuses: &mut PerkBonuses, stacks: u8) {
+        let n = stacks as f32;
+        let scale = |value: &mut f32, delta: f32| {
+            *value = (*value + delta * n).max(Self::MIN_MULTIPLIER);
+        };
+        scale(&mut bonuses.exp_multiplier, self.exp_multiplier);
+        scale(&mut bonuses.speed_multiplier, self.speed_multiplier);
+        scale(&mut bonuses.damage_multiplier, self.damage_multiplier);
+        scale(&mut bonuses.fire_rate_multiplier, self.fire_rate_multiplier);
+        scale(&mut bonuses.spread_multiplier, self.spread_multiplier);
+        scale(&mut bonuses.reload_speed_multiplier, self.reload_speed_multiplier);
+        scale(&mut bonuses.projectile_speed_multiplier, self.projectile_speed_multiplier);
+        scale(&mut bonuses.range_multiplier, self.range_multiplier);
+        scale(&mut bonuses.ammo_multiplier, self.ammo_multiplier);
+        scale(&mut bonuses.max_health_multiplier, self.max_health_multiplier);
+        scale(&mut bonuses.damage_taken_multiplier, self.damage_taken_multiplier);
+
+        bonuses.dodge_chance = (bonuses.dodge_chance + self.dodge_chance * n).clamp(0.0, 1.0);
+        bonuses.crit_chance = (bonuses.crit_chance + self.crit_chance * n).clamp(0.0, 1.0);
+        bonuses.regen_per_second = (bonuses.regen_per_second + self.regen_per_second * n).max(0.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -460,10 +623,69 @@ mod tests {
     #[test]
     fn random_selection_returns_correct_count() {
         let registry = PerkRegistry::new();
-        let selection = registry.get_random_selection(4);
+        let selection = registry.get_random_selection(&PerkInventory::new(), 4, &mut rand::thread_rng());
         assert_eq!(selection.len(), 4);
     }
 
+    #[test]
+    fn random_selection_skips_owned_non_stacking_and_unoffered_perks() {
+        use rand::SeedableRng;
+
+        let registry = PerkRegistry::new();
+        let mut inventory = PerkInventory::new();
+        for perk in [PerkId::Unstoppable, PerkId::Doctor, PerkId::MonsterVision, PerkId::Regeneration] {
+            inventory.add_perk(perk);
+        }
+        let mut rng = rand::rngs::StdRng::seed_from_u64(809);
+        let mut regeneration_offered = false;
+        for _ in 0..500 {
+            let selection = registry.get_random_selection(&inventory, 7, &mut rng);
+            assert_eq!(selection.len(), 7);
+            let keys: Vec<PerkKey> = selection.iter().map(|offer| offer.key.clone()).collect();
+            for banned in [
+                PerkId::Unstoppable,
+                PerkId::Doctor,
+                PerkId::MonsterVision,
+                PerkId::RandomWeapon,
+                PerkId::BreathingRoom,
+            ] {
+                assert!(!keys.contains(&banned.into()), "{banned:?} offered");
+            }
+            assert!(keys.iter().enumerate().all(|(i, key)| !keys[..i].contains(key)), "duplicate offer");
+            regeneration_offered |= keys.contains(&PerkId::Regeneration.into());
+        }
+        // Stacking perks stay on offer
+        assert!(regeneration_offered);
+    }
+
+    #[test]
+    fn random_selection_draws_by_rarity_weight() {
+        use rand::SeedableRng;
+
+        let registry = PerkRegistry::new();
+        let inventory = PerkInventory::new();
+        let offered: Vec<&PerkData> = registry.perks.iter().filter(|p| p.id.is_offered()).collect();
+        let weight = |rarity: PerkRarity| -> u32 {
+            offered.iter().filter(|p| p.rarity == rarity).map(|p| p.rarity.weight()).sum()
+        };
+        let total: u32 = offered.iter().map(|p| p.rarity.weight()).sum();
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(809);
+        let draws = 20_000;
+        let mut counts = [0u32; 4];
+        for _ in 0..draws {
+            let rarity = registry.get_random_selection(&inventory, 1, &mut rng)[0].rarity;
+            counts[rarity as usize] += 1;
+        }
+        for rarity in [PerkRarity::Common, PerkRarity::Legendary] {
+            let expected = weight(rarity) as f32 / total as f32;
+            let seen = counts[rarity as usize] as f32 / draws as f32;
+            assert!((seen - expected).abs() < 0.02, "{rarity:?}: {seen} vs {expected}");
+        }
+        // Each Legendary is a tenth as likely as each Common
+        assert_eq!(PerkRarity::Common.weight(), 10 * PerkRarity::Legendary.weight());
+    }
+
     #[test]
     fn perk_rarities_have_distinct_colors() {
         let common = PerkRarity::Common.color();
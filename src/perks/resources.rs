@@ -0,0 +1,172 @@
+//! Perk resources
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::bonuses::BonusType;
+use crate::creatures::CreatureType;
+
+/// Tunable numbers for perk effects
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct PerkEffectConfig {
+    /// Reach of the FinalRevenge blast around the player's corpse
+    pub final_revenge_radius: f32,
+    /// FinalRevenge damage at the center, falling off to nothing at the edge
+    pub final_revenge_damage: f32,
+    /// Health DeathClock drains per second; outpaces a single Regeneration pick
+    pub death_clock_drain: f32,
+}
+
+impl Default for PerkEffectConfig {
+    fn default() -> Self {
+        Self {
+            final_revenge_radius: 250.0,
+            final_revenge_damage: 500.0,
+            death_clock_drain: 4.0,
+        }
+    }
+}
+
+/// Level-ups still waiting for their perk selection, opened one at a time
+#[derive(Resource, Debug, Default)]
+pub struct PendingLevelUps(pub u32);
+
+/// Something Jinxed can do to the run when its timer runs out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JinxEvent {
+    /// Kills a random non-boss creature
+    KillCreature,
+    /// Hurts the player
+    HurtPlayer,
+    /// Drops a bonus at the player's feet
+    DropBonus,
+    /// Doubles the player's fire rate for a moment
+    FireRateBurst,
+    /// Brings in extra creatures
+    SpawnCreatures,
+}
+
+impl JinxEvent {
+    /// Whether the event helps the player (picks the flash color)
+    pub fn is_good(&self) -> bool {
+        !matches!(self, JinxEvent::HurtPlayer | JinxEvent::SpawnCreatures)
+    }
+}
+
+/// Tunable numbers and the weighted event table for Jinxed
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct JinxConfig {
+    /// Shortest wait between two events
+    pub min_interval: f32,
+    /// Longest wait between two events
+    pub max_interval: f32,
+    /// Events and their weights
+    pub events: Vec<(JinxEvent, u32)>,
+    /// Damage range of HurtPlayer
+    pub min_damage: f32,
+    pub max_damage: f32,
+    /// Bonuses DropBonus picks from
+    pub bonuses: Vec<BonusType>,
+    /// Seconds FireRateBurst lasts
+    pub fire_rate_burst_duration: f32,
+    /// Creatures SpawnCreatures brings in
+    pub extra_creature: CreatureType,
+    pub extra_creature_count: u32,
+}
+
+impl Default for JinxConfig {
+    fn default() -> Self {
+        Self {
+            min_interval: 5.0,
+            max_interval: 10.0,
+            events: vec![
+                (JinxEvent::KillCreature, 3),
+                (JinxEvent::HurtPlayer, 3),
+                (JinxEvent::DropBonus, 2),
+                (JinxEvent::FireRateBurst, 2),
+                (JinxEvent::SpawnCreatures, 2),
+            ],
+            min_damage: 5.0,
+            max_damage: 10.0,
+            bonuses: vec![
+                BonusType::SmallHealth,
+                BonusType::SmallExp,
+                BonusType::Ammo,
+                BonusType::SpeedBoost,
+                BonusType::DamageBoost,
+                BonusType::Shield,
+            ],
+            fire_rate_burst_duration: 3.0,
+            extra_creature: CreatureType::Zombie,
+            extra_creature_count: 3,
+        }
+    }
+}
+
+impl JinxConfig {
+    /// Seconds until the next event
+    pub fn roll_interval(&self, rng: &mut impl Rng) -> f32 {
+        if self.max_interval > self.min_interval {
+            rng.gen_range(self.min_interval..self.max_interval)
+        } else {
+            self.min_interval
+        }
+    }
+
+    /// Weighted pick from the event table; None when it is empty
+    pub fn roll_event(&self, rng: &mut impl Rng) -> Option<JinxEvent> {
+        let total: u32 = self.events.iter().map(|(_, weight)| weight).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.gen_range(0..total);
+        for (event, weight) in &self.events {
+            if roll < *weight {
+                return Some(*event);
+            }
+            roll -= weight;
+        }
+        None
+    }
+
+    /// Damage dealt by HurtPlayer
+    pub fn roll_damage(&self, rng: &mut impl Rng) -> f32 {
+        if self.max_damage > self.min_damage {
+            rng.gen_range(self.min_damage..=self.max_damage)
+        } else {
+            self.min_damage
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn jinx_table_picks_every_event() {
+        let config = JinxConfig::default();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(813);
+        let picked: HashSet<JinxEvent> = (0..200).filter_map(|_| config.roll_event(&mut rng)).collect();
+        for (event, _) in &config.events {
+            assert!(picked.contains(event), "{:?} never rolled", event);
+        }
+    }
+
+    #[test]
+    fn jinx_rolls_stay_in_range() {
+        let config = JinxConfig::default();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(813);
+        for _ in 0..100 {
+            let interval = config.roll_interval(&mut rng);
+            assert!((config.min_interval..=config.max_interval).contains(&interval));
+            let damage = config.roll_damage(&mut rng);
+            assert!((config.min_damage..=config.max_damage).contains(&damage));
+        }
+        let empty = JinxConfig { events: Vec::new(), ..default() };
+        assert_eq!(empty.roll_event(&mut rng), None);
+    }
+}
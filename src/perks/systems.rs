@@ -1,17 +1,52 @@
 //! Perk systems
 
 use bevy::prelude::*;
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::Rng;
 
-use super::components::{PerkBonuses, PerkId, PerkInventory};
+use super::components::{
+    AngryReloaderState, FinalRevengeSpent, FireCoughTimer, HotTemperedTimer, Infection, ManBombTimer,
+    JinxState, PassiveXp, PerkBonuses, PerkId, PerkInventory, PerkKey, PyrokineticTimer, RunnerMomentum, ANGRY_RELOADER_RING_SHOTS,
+    ANGRY_RELOADER_TRIGGER_PROGRESS, AURA_RADIUS, EVIL_EYES_BOSS_SPEED, EVIL_EYES_CONE, EVIL_EYES_LINGER,
+    EVIL_EYES_RANGE, FIRE_COUGH_INTERVAL, HOT_TEMPERED_INTERVAL, HOT_TEMPERED_RING_SHOTS, INFECTED_TINT,
+    MAN_BOMB_DAMAGE, MAN_BOMB_INTERVAL, MAN_BOMB_RADIUS, MAN_BOMB_STILL_TIME, MR_MELEE_KNOCKBACK, PLAGUE_DPS,
+    PLAGUE_DURATION, PLAGUE_MIN_SPREAD_DPS, PLAGUE_SPREAD_FALLOFF, PLAGUE_SPREAD_INTERVAL,
+    PLAGUE_SPREAD_RADIUS, POISON_BULLETS_DPS, POISON_BULLETS_DURATION, PYROKINETIC_BURN_DPS, PYROKINETIC_BURN_DURATION, PYROKINETIC_INTERVAL,
+    RADIOACTIVE_DPS,
+};
 use super::registry::PerkRegistry;
-use crate::player::components::{Health, MoveSpeed, Player};
-use crate::player::resources::PlayerConfig;
+use super::resources::{JinxConfig, JinxEvent, PendingLevelUps, PerkEffectConfig};
+use crate::audio::{PlaySoundEvent, SoundEffect};
+use crate::creatures::components::{Creature, CreatureHealth, FrozenStatus, Knockback, MarkedForDespawn, Stared};
+use crate::bonuses::SpawnBonusEvent;
+use crate::creatures::{CreatureGrid, CreatureTouchedPlayerEvent, SpawnCreatureEvent, StatusEffects, StatusKind};
+use crate::effects::{EffectType, ScreenShake, SpawnEffectEvent};
+use crate::player::components::{AimDirection, Experience, Health, Player, StationaryTimer};
+use crate::player::systems::{GrantXpEvent, PlayerDamageEvent, PlayerLevelUpEvent, XpSource};
+use crate::states::PlayingState;
+use crate::weapons::{
+    EquippedWeapon, ExplosionEvent, FireWeaponEvent, Projectile, ProjectileHitEvent, WeaponData, WeaponKey,
+    WeaponRegistry,
+};
+
+/// Experience granted by InstantWinner
+pub const INSTANT_WINNER_EXP: u32 = 2500;
+/// Share of current experience GrimDeal grants before killing the player
+pub const GRIM_DEAL_EXP_FRACTION: f32 = 0.18;
+/// Experience FatalLottery grants when it doesn't kill the player
+pub const FATAL_LOTTERY_EXP: u32 = 10_000;
+/// Levels InfernalContract grants
+pub const INFERNAL_CONTRACT_LEVELS: u32 = 3;
+/// Health InfernalContract leaves the player with
+pub const INFERNAL_CONTRACT_HEALTH: f32 = 0.1;
+/// Largest multiplier Bandage can roll for current health
+pub const BANDAGE_MAX_MULTIPLIER: u32 = 50;
 
 /// Event when a perk is selected
 #[derive(Event)]
 pub struct PerkSelectedEvent {
     pub player_entity: Entity,
-    pub perk_id: PerkId,
+    pub perk: PerkKey,
 }
 
 /// Sets up perk selection state
@@ -20,40 +55,942 @@ pub fn setup_perk_selection(_registry: Res<PerkRegistry>) {
     // This system could pre-calculate available perks based on player state
 }
 
+/// Queues a perk selection for every level gained, whether from kills, passive XP or instant perks
+pub fn queue_level_ups(mut events: EventReader<PlayerLevelUpEvent>, mut pending: ResMut<PendingLevelUps>) {
+    pending.0 += events.read().count() as u32;
+}
+
+/// Opens the perk select screen for the next pending level-up. Only runs during
+/// regular play, so level-ups in a wave transition or boss fight wait for it; each
+/// pick returns to play and the next queued selection opens right after
+pub fn open_perk_select(mut pending: ResMut<PendingLevelUps>, mut next_state: ResMut<NextState<PlayingState>>) {
+    if pending.0 > 0 {
+        pending.0 -= 1;
+        next_state.set(PlayingState::PerkSelect);
+    }
+}
+
+/// Drops level-ups left over from the last run
+pub fn reset_pending_level_ups(mut pending: ResMut<PendingLevelUps>) {
+    *pending = PendingLevelUps::default();
+}
+
 /// Applies perk effects each frame
 pub fn apply_perk_effects(
-    time: Res<Time>,
-    config: Res<PlayerConfig>,
+    registry: Res<PerkRegistry>,
     mut query: Query<
         (
             &PerkInventory,
             &mut PerkBonuses,
             &mut Health,
-            &mut MoveSpeed,
         ),
         With<Player>,
     >,
 ) {
-    for (inventory, mut bonuses, mut health, mut speed) in query.iter_mut() {
+    for (inventory, mut bonuses, mut health) in query.iter_mut() {
         // Recalculate bonuses
-        *bonuses = PerkBonuses::calculate(inventory);
+        *bonuses = registry.bonuses_for(inventory);
+
+        // Apply max health multiplier (ThickSkinned reduces to 2/3)
+        health.scale_max(bonuses.max_health_multiplier);
 
-        // Apply regeneration
-        if bonuses.regen_per_second > 0.0 {
-            let heal_amount = bonuses.regen_per_second * time.delta_seconds();
-            health.heal(heal_amount);
+        // Speed multiplier is applied with the other speed modifiers in apply_speed_boost
+    }
+}
+
+/// Heals (or drains) the player by the perks' combined health change per second
+pub fn player_regeneration(
+    time: Res<Time>,
+    config: Res<PerkEffectConfig>,
+    mut query: Query<(&PerkBonuses, &mut Health), With<Player>>,
+) {
+    for (bonuses, mut health) in query.iter_mut() {
+        if health.is_dead() {
+            continue;
         }
+        let delta = bonuses.health_per_second(config.death_clock_drain) * time.delta_seconds();
+        if delta > 0.0 {
+            health.heal(delta);
+        } else if delta < 0.0 {
+            health.damage(-delta);
+        }
+    }
+}
 
-        // Apply max health multiplier (ThickSkinned reduces to 2/3)
-        let adjusted_max = config.base_health * bonuses.max_health_multiplier;
-        if (health.max - adjusted_max).abs() > 0.01 {
-            let health_percent = health.percentage();
-            health.max = adjusted_max;
-            health.current = adjusted_max * health_percent;
+/// What a one-shot perk grants beyond its direct health change
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InstantPerkGrant {
+    /// Experience to grant through GrantXpEvent, so exp_multiplier applies to it
+    pub xp: u32,
+    /// Levels already added outright (InfernalContract)
+    pub levels: u32,
+}
+
+/// Applies a one-shot perk's effect to the picking player's health (and levels, for
+/// InfernalContract) and returns what it grants; perks without an instant effect do nothing.
+pub fn apply_instant_perk(
+    perk: PerkId,
+    exp: &mut Experience,
+    health: &mut Health,
+    rng: &mut impl Rng,
+) -> InstantPerkGrant {
+    let grant_xp = |xp| InstantPerkGrant { xp, levels: 0 };
+    match perk {
+        PerkId::InstantWinner => grant_xp(INSTANT_WINNER_EXP),
+        PerkId::GrimDeal => {
+            health.current = 0.0;
+            grant_xp((exp.current as f32 * GRIM_DEAL_EXP_FRACTION) as u32)
+        }
+        PerkId::FatalLottery => {
+            if rng.gen_bool(0.5) {
+                grant_xp(FATAL_LOTTERY_EXP)
+            } else {
+                health.current = 0.0;
+                InstantPerkGrant::default()
+            }
+        }
+        PerkId::InfernalContract => {
+            health.current = INFERNAL_CONTRACT_HEALTH.min(health.max);
+            exp.add_levels(INFERNAL_CONTRACT_LEVELS);
+            InstantPerkGrant {
+                xp: 0,
+                levels: INFERNAL_CONTRACT_LEVELS,
+            }
+        }
+        PerkId::Bandage => {
+            let multiplier = rng.gen_range(1..=BANDAGE_MAX_MULTIPLIER) as f32;
+            health.current = (health.current * multiplier).min(health.max);
+            InstantPerkGrant::default()
+        }
+        _ => InstantPerkGrant::default(),
+    }
+}
+
+/// Applies one-shot perks (InstantWinner, GrimDeal, ...) when they are picked.
+/// Deaths are left to check_player_death. Experience goes out as a GrantXpEvent, scaled
+/// like any other gain; levels added outright send their own level-up events.
+pub fn apply_instant_perk_effects(
+    mut events: EventReader<PerkSelectedEvent>,
+    mut query: Query<(&mut Experience, &mut Health), With<Player>>,
+    mut xp_events: EventWriter<GrantXpEvent>,
+    mut level_up_events: EventWriter<PlayerLevelUpEvent>,
+) {
+    let mut rng = rand::thread_rng();
+    for event in events.read() {
+        let PerkKey::Builtin(perk) = &event.perk else {
+            continue;
+        };
+        let Ok((mut exp, mut health)) = query.get_mut(event.player_entity) else {
+            continue;
+        };
+        let grant = apply_instant_perk(*perk, &mut exp, &mut health, &mut rng);
+        if grant.xp > 0 {
+            xp_events.send(GrantXpEvent {
+                player_entity: event.player_entity,
+                amount: grant.xp,
+                source: XpSource::Perk,
+            });
+        }
+        for new_level in (exp.level + 1 - grant.levels)..=exp.level {
+            level_up_events.send(PlayerLevelUpEvent {
+                player_entity: event.player_entity,
+                new_level,
+            });
+        }
+    }
+}
+
+/// Lifeline 50-50: half of the non-boss creatures on the field vanish. They are
+/// despawned without a death event, so they grant no XP, drops or kill credit.
+pub fn apply_lifeline_perk(
+    mut commands: Commands,
+    mut events: EventReader<PerkSelectedEvent>,
+    creature_query: Query<(Entity, &Creature, &Transform), Without<MarkedForDespawn>>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+) {
+    let picked = events
+        .read()
+        .any(|event| event.perk == PerkKey::Builtin(PerkId::Lifeline5050));
+    if !picked {
+        return;
+    }
+
+    let mut candidates: Vec<(Entity, Vec3)> = creature_query
+        .iter()
+        .filter(|(_, creature, _)| !creature.creature_type.is_boss())
+        .map(|(entity, _, transform)| (entity, transform.translation))
+        .collect();
+    candidates.shuffle(&mut rand::thread_rng());
+    let removed = candidates.len() / 2;
+    for (entity, position) in candidates.into_iter().take(removed) {
+        commands.entity(entity).insert(MarkedForDespawn);
+        effect_events.send(SpawnEffectEvent {
+            effect_type: EffectType::Vanish,
+            position,
+            count: 1,
+        });
+    }
+}
+
+/// Shots fired outward in `count` evenly spaced directions from `center`, starting along +X
+pub fn ring_fire_events(
+    shooter: Entity,
+    center: Vec3,
+    weapon_data: &WeaponData,
+    weapon: &WeaponKey,
+    count: u32,
+) -> Vec<FireWeaponEvent> {
+    (0..count)
+        .map(|i| {
+            let direction = Vec2::from_angle(i as f32 / count as f32 * std::f32::consts::TAU);
+            // Same muzzle distance as an aimed shot
+            let muzzle = direction * 20.0;
+            FireWeaponEvent {
+                shooter,
+                position: center + muzzle.extend(0.0),
+                direction,
+                weapon_id: weapon_data.id,
+                weapon: weapon.clone(),
+                target: None,
+            }
+        })
+        .collect()
+}
+
+/// HotTempered: every few seconds the player fires a free ring of their weapon's shots
+#[allow(clippy::type_complexity)]
+pub fn hot_tempered_ring(
+    mut commands: Commands,
+    time: Res<Time>,
+    registry: Res<WeaponRegistry>,
+    mut query: Query<
+        (Entity, &Transform, &PerkBonuses, &EquippedWeapon, Option<&mut HotTemperedTimer>),
+        With<Player>,
+    >,
+    mut fire_events: EventWriter<FireWeaponEvent>,
+) {
+    for (entity, transform, bonuses, weapon, timer) in query.iter_mut() {
+        if !bonuses.hot_tempered {
+            if timer.is_some() {
+                commands.entity(entity).remove::<HotTemperedTimer>();
+            }
+            continue;
+        }
+        let Some(mut timer) = timer else {
+            commands.entity(entity).insert(HotTemperedTimer::default());
+            continue;
+        };
+        timer.elapsed += time.delta_seconds();
+        if timer.elapsed < HOT_TEMPERED_INTERVAL {
+            continue;
+        }
+        timer.elapsed -= HOT_TEMPERED_INTERVAL;
+
+        let Some(weapon_data) = registry.get_key(&weapon.weapon) else {
+            continue;
+        };
+        fire_events.send_batch(ring_fire_events(
+            entity,
+            transform.translation,
+            weapon_data,
+            &weapon.weapon,
+            HOT_TEMPERED_RING_SHOTS,
+        ));
+    }
+}
+
+/// AngryReloader: a ring of the weapon's shots once each reload passes its midpoint
+#[allow(clippy::type_complexity)]
+pub fn angry_reloader_ring(
+    mut commands: Commands,
+    registry: Res<WeaponRegistry>,
+    mut query: Query<
+        (Entity, &Transform, &PerkBonuses, &EquippedWeapon, Option<&mut AngryReloaderState>),
+        With<Player>,
+    >,
+    mut fire_events: EventWriter<FireWeaponEvent>,
+) {
+    for (entity, transform, bonuses, weapon, state) in query.iter_mut() {
+        let mut state = match (bonuses.angry_reloader, state) {
+            (true, Some(state)) => state,
+            (true, None) => {
+                commands.entity(entity).insert(AngryReloaderState::default());
+                continue;
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<AngryReloaderState>();
+                continue;
+            }
+            (false, None) => continue,
+        };
+        let Some(progress) = weapon.reload_progress() else {
+            // Rearm for the next reload
+            state.ring_fired = false;
+            continue;
+        };
+        if state.ring_fired || progress < ANGRY_RELOADER_TRIGGER_PROGRESS {
+            continue;
+        }
+        state.ring_fired = true;
+
+        let Some(weapon_data) = registry.get_key(&weapon.weapon) else {
+            continue;
+        };
+        fire_events.send_batch(ring_fire_events(
+            entity,
+            transform.translation,
+            weapon_data,
+            &weapon.weapon,
+            ANGRY_RELOADER_RING_SHOTS,
+        ));
+    }
+}
+
+/// FireCough: every few seconds the player coughs out one free shot of their weapon
+/// along their aim, ignoring the fire cooldown
+#[allow(clippy::type_complexity)]
+pub fn fire_cough_shot(
+    mut commands: Commands,
+    time: Res<Time>,
+    registry: Res<WeaponRegistry>,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            &AimDirection,
+            &PerkBonuses,
+            &EquippedWeapon,
+            Option<&mut FireCoughTimer>,
+        ),
+        With<Player>,
+    >,
+    mut fire_events: EventWriter<FireWeaponEvent>,
+) {
+    for (entity, transform, aim, bonuses, weapon, timer) in query.iter_mut() {
+        match (bonuses.fire_cough, timer) {
+            (true, None) => {
+                commands.entity(entity).insert(FireCoughTimer::default());
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<FireCoughTimer>();
+            }
+            (true, Some(mut timer)) => {
+                timer.elapsed += time.delta_seconds();
+                if timer.elapsed < FIRE_COUGH_INTERVAL {
+                    continue;
+                }
+                timer.elapsed -= FIRE_COUGH_INTERVAL;
+
+                let Some(weapon_data) = registry.get_key(&weapon.weapon) else {
+                    continue;
+                };
+                let muzzle = aim.direction * 20.0;
+                fire_events.send(FireWeaponEvent {
+                    shooter: entity,
+                    position: transform.translation + muzzle.extend(0.0),
+                    direction: aim.direction,
+                    weapon_id: weapon_data.id,
+                    weapon: weapon.weapon.clone(),
+                    target: aim.target,
+                });
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+/// LongDistanceRunner: speed builds up the longer the player keeps moving and
+/// drains away quickly once they stop
+pub fn runner_momentum(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &Transform, &PerkBonuses, Option<&mut RunnerMomentum>), With<Player>>,
+) {
+    for (entity, transform, bonuses, momentum) in query.iter_mut() {
+        match (bonuses.runner_momentum_cap > 1.0, momentum) {
+            (true, Some(mut momentum)) => {
+                momentum.update(
+                    transform.translation.truncate(),
+                    bonuses.runner_momentum_cap,
+                    time.delta_seconds(),
+                );
+            }
+            (true, None) => {
+                commands.entity(entity).insert(RunnerMomentum::default());
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<RunnerMomentum>();
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+/// LeanMeanExpMachine: a steady trickle of XP, granted a whole point at a time
+pub fn passive_experience(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &PerkBonuses, Option<&mut PassiveXp>), With<Player>>,
+    mut xp_events: EventWriter<GrantXpEvent>,
+) {
+    for (entity, bonuses, passive) in query.iter_mut() {
+        match (bonuses.passive_xp_per_second > 0.0, passive) {
+            (true, Some(mut passive)) => {
+                passive.progress += bonuses.passive_xp_per_second * time.delta_seconds();
+                let amount = passive.progress.floor();
+                if amount >= 1.0 {
+                    passive.progress -= amount;
+                    xp_events.send(GrantXpEvent {
+                        player_entity: entity,
+                        amount: amount as u32,
+                        source: XpSource::Passive,
+                    });
+                }
+            }
+            (true, None) => {
+                commands.entity(entity).insert(PassiveXp::default());
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<PassiveXp>();
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+/// Jinxed: every few seconds something random happens, good or bad, rolled from
+/// the JinxConfig table
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn jinx_tick(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<JinxConfig>,
+    mut players: Query<(Entity, &Transform, &PerkBonuses, Option<&mut JinxState>), With<Player>>,
+    mut creatures: Query<(&Creature, &mut CreatureHealth), Without<MarkedForDespawn>>,
+    mut damage_events: EventWriter<PlayerDamageEvent>,
+    mut bonus_events: EventWriter<SpawnBonusEvent>,
+    mut spawn_events: EventWriter<SpawnCreatureEvent>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+) {
+    let mut rng = rand::thread_rng();
+    for (entity, transform, bonuses, state) in players.iter_mut() {
+        let mut state = match (bonuses.jinxed, state) {
+            (true, Some(state)) => state,
+            (true, None) => {
+                commands.entity(entity).insert(JinxState {
+                    next_event_in: config.roll_interval(&mut rng),
+                    fire_rate_burst: 0.0,
+                });
+                continue;
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<JinxState>();
+                continue;
+            }
+            (false, None) => continue,
+        };
+
+        state.fire_rate_burst = (state.fire_rate_burst - time.delta_seconds()).max(0.0);
+        state.next_event_in -= time.delta_seconds();
+        if state.next_event_in > 0.0 {
+            continue;
+        }
+        state.next_event_in = config.roll_interval(&mut rng);
+        let Some(event) = config.roll_event(&mut rng) else {
+            continue;
+        };
+
+        match event {
+            JinxEvent::KillCreature => {
+                let victim = creatures
+                    .iter_mut()
+                    .filter(|(creature, health)| !creature.creature_type.is_boss() && !health.is_dead())
+                    .map(|(_, health)| health)
+                    .choose(&mut rng);
+                let Some(mut health) = victim else {
+                    continue;
+                };
+                health.current = 0.0;
+            }
+            JinxEvent::HurtPlayer => {
+                damage_events.send(PlayerDamageEvent {
+                    player_entity: entity,
+                    damage: config.roll_damage(&mut rng),
+                    source: None,
+                    unavoidable: true,
+                });
+            }
+            JinxEvent::DropBonus => {
+                let Some(&bonus_type) = config.bonuses.choose(&mut rng) else {
+                    continue;
+                };
+                bonus_events.send(SpawnBonusEvent {
+                    bonus_type,
+                    position: transform.translation,
+                });
+            }
+            JinxEvent::FireRateBurst => {
+                state.fire_rate_burst = config.fire_rate_burst_duration;
+            }
+            JinxEvent::SpawnCreatures => {
+                spawn_events.send_batch((0..config.extra_creature_count).map(|_| SpawnCreatureEvent {
+                    creature_type: config.extra_creature,
+                    position: None,
+                    summoner: None,
+                }));
+            }
+        }
+        info!("Jinxed: {:?}", event);
+        effect_events.send(SpawnEffectEvent {
+            effect_type: EffectType::JinxFlash { good: event.is_good() },
+            position: transform.translation,
+            count: 1,
+        });
+    }
+}
+
+/// ManBomb: once the player has stood still for a moment, an ion ring pulses out
+/// from them every few seconds. Moving resets the countdown
+#[allow(clippy::type_complexity)]
+pub fn man_bomb_pulse(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<
+        (Entity, &Transform, &PerkBonuses, &StationaryTimer, Option<&mut ManBombTimer>),
+        With<Player>,
+    >,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for (entity, transform, bonuses, stationary, timer) in query.iter_mut() {
+        let mut timer = match (bonuses.man_bomb, timer) {
+            (true, Some(timer)) => timer,
+            (true, None) => {
+                commands.entity(entity).insert(ManBombTimer::default());
+                continue;
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<ManBombTimer>();
+                continue;
+            }
+            (false, None) => continue,
+        };
+        if stationary.seconds < MAN_BOMB_STILL_TIME {
+            timer.elapsed = 0.0;
+            continue;
+        }
+        timer.elapsed += time.delta_seconds();
+        if timer.elapsed < MAN_BOMB_INTERVAL {
+            continue;
+        }
+        timer.elapsed -= MAN_BOMB_INTERVAL;
+
+        let radius = MAN_BOMB_RADIUS * bonuses.ion_aoe_multiplier;
+        explosion_events.send(ExplosionEvent {
+            position: transform.translation,
+            radius,
+            damage: MAN_BOMB_DAMAGE * bonuses.ion_damage_multiplier,
+            spared: Vec::new(),
+            owner: entity,
+            self_damage: false,
+        });
+        effect_events.send(SpawnEffectEvent {
+            effect_type: EffectType::IonRing { radius },
+            position: transform.translation,
+            count: 1,
+        });
+        sound_events.send(PlaySoundEvent {
+            sound: SoundEffect::PlasmaFire,
+            position: Some(transform.translation.truncate()),
+        });
+    }
+}
+
+/// Radioactive and Pyrokinetic auras. Radioactive steadily damages every creature
+/// in range, more per pick; Pyrokinetic sets them burning every so often
+#[allow(clippy::type_complexity)]
+pub fn aura_damage_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    creature_grid: Res<CreatureGrid>,
+    mut player_query: Query<
+        (Entity, &Transform, &PerkBonuses, &PerkInventory, Option<&mut PyrokineticTimer>),
+        With<Player>,
+    >,
+    mut creature_query: Query<
+        (&Transform, &mut CreatureHealth, Option<&mut StatusEffects>),
+        (With<Creature>, Without<MarkedForDespawn>),
+    >,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, transform, bonuses, inventory, pyro_timer) in player_query.iter_mut() {
+        let ignite = match (bonuses.pyrokinetic_aura, pyro_timer) {
+            (true, Some(mut timer)) => {
+                timer.elapsed += delta;
+                let ignite = timer.elapsed >= PYROKINETIC_INTERVAL;
+                if ignite {
+                    timer.elapsed -= PYROKINETIC_INTERVAL;
+                }
+                ignite
+            }
+            (true, None) => {
+                commands.entity(entity).insert(PyrokineticTimer::default());
+                false
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<PyrokineticTimer>();
+                false
+            }
+            (false, None) => false,
+        };
+        let radioactive_dps = if bonuses.radioactive_aura {
+            RADIOACTIVE_DPS * inventory.get_count(PerkId::Radioactive).max(1) as f32
+        } else {
+            0.0
+        };
+        if radioactive_dps <= 0.0 && !ignite {
+            continue;
+        }
+
+        let center = transform.translation.truncate();
+        for (creature, _) in creature_grid.candidates(center, AURA_RADIUS) {
+            let Ok((creature_transform, mut health, status_effects)) = creature_query.get_mut(creature) else {
+                continue;
+            };
+            // Already dead this frame: its single death event is on the way
+            if health.is_dead() || center.distance(creature_transform.translation.truncate()) >= AURA_RADIUS {
+                continue;
+            }
+            health.damage(radioactive_dps * delta);
+            if ignite {
+                if let Some(mut status_effects) = status_effects {
+                    status_effects.apply(
+                        StatusKind::Burning,
+                        PYROKINETIC_BURN_DPS,
+                        PYROKINETIC_BURN_DURATION,
+                        Some(entity),
+                    );
+                }
+                effect_events.send(SpawnEffectEvent {
+                    effect_type: EffectType::Flame,
+                    position: creature_transform.translation,
+                    count: 1,
+                });
+            }
+        }
+    }
+}
+
+/// Who landed a hit. Hitscan beams report the shooter as the projectile
+fn hit_owner(hit: &ProjectileHitEvent, projectile_query: &Query<&Projectile>) -> Entity {
+    projectile_query.get(hit.projectile).map_or(hit.projectile, |projectile| projectile.owner)
+}
+
+/// Starts a Plaguebearer infection unless the creature already carries one.
+/// Frozen creatures remember their pre-freeze color so the tint unwinds cleanly.
+fn infect(
+    commands: &mut Commands,
+    creature: Entity,
+    status: &mut StatusEffects,
+    sprite: &mut Sprite,
+    frozen: Option<&FrozenStatus>,
+    dps: f32,
+    source: Option<Entity>,
+) {
+    if !status.apply(StatusKind::Infected, dps, PLAGUE_DURATION, source) {
+        return;
+    }
+    commands.entity(creature).insert(Infection {
+        dps,
+        source,
+        spread_elapsed: 0.0,
+        original_color: frozen.map_or(sprite.color, |frozen| frozen.original_color),
+    });
+    if frozen.is_none() {
+        sprite.color = INFECTED_TINT.with_alpha(sprite.color.alpha());
+    }
+}
+
+/// Plaguebearer: the player's hits infect creatures, and infected creatures pass
+/// a weaker infection to their neighbours every so often and once more as they die
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn plaguebearer_infection(
+    mut commands: Commands,
+    time: Res<Time>,
+    creature_grid: Res<CreatureGrid>,
+    mut hit_events: EventReader<ProjectileHitEvent>,
+    projectile_query: Query<&Projectile>,
+    player_query: Query<&PerkBonuses, With<Player>>,
+    mut infected_query: Query<
+        (
+            Entity,
+            &Transform,
+            &CreatureHealth,
+            &StatusEffects,
+            &mut Infection,
+            &mut Sprite,
+            Option<&mut FrozenStatus>,
+        ),
+        Without<MarkedForDespawn>,
+    >,
+    mut healthy_query: Query<
+        (&CreatureHealth, &mut StatusEffects, &mut Sprite, Option<&FrozenStatus>),
+        (With<Creature>, Without<Infection>, Without<MarkedForDespawn>),
+    >,
+) {
+    for hit in hit_events.read() {
+        let owner = hit_owner(hit, &projectile_query);
+        if !player_query.get(owner).is_ok_and(|bonuses| bonuses.plaguebearer) {
+            continue;
+        }
+        if let Ok((health, mut status, mut sprite, frozen)) = healthy_query.get_mut(hit.target) {
+            if !health.is_dead() {
+                infect(&mut commands, hit.target, &mut status, &mut sprite, frozen, PLAGUE_DPS, Some(owner));
+            }
+        }
+    }
+
+    let delta = time.delta_seconds();
+    let mut spreads = Vec::new();
+    for (entity, transform, health, status, mut infection, mut sprite, frozen) in infected_query.iter_mut() {
+        if !status.entries.iter().any(|entry| entry.kind == StatusKind::Infected) {
+            match frozen {
+                Some(mut frozen) => frozen.original_color = infection.original_color,
+                None => sprite.color = infection.original_color,
+            }
+            commands.entity(entity).remove::<Infection>();
+            continue;
+        }
+        // Re-tint once a freeze wears off
+        if frozen.is_none() {
+            sprite.color = INFECTED_TINT.with_alpha(sprite.color.alpha());
+        }
+
+        infection.spread_elapsed += delta;
+        let spread = health.is_dead() || infection.spread_elapsed >= PLAGUE_SPREAD_INTERVAL;
+        if spread {
+            infection.spread_elapsed = 0.0;
+            let dps = infection.dps * PLAGUE_SPREAD_FALLOFF;
+            if dps >= PLAGUE_MIN_SPREAD_DPS {
+                spreads.push((transform.translation.truncate(), dps, infection.source));
+            }
+        }
+    }
+
+    for (center, dps, source) in spreads {
+        for (creature, position) in creature_grid.candidates(center, PLAGUE_SPREAD_RADIUS) {
+            if center.distance(position) >= PLAGUE_SPREAD_RADIUS {
+                continue;
+            }
+            if let Ok((health, mut status, mut sprite, frozen)) = healthy_query.get_mut(creature) {
+                if !health.is_dead() {
+                    infect(&mut commands, creature, &mut status, &mut sprite, frozen, dps, source);
+                }
+            }
+        }
+    }
+}
+
+/// EvilEyes: holds the nearest creature on the player's aim line in place.
+/// Only one creature per player; a new target frees the old one at once,
+/// while looking away lets it linger for a moment first
+#[allow(clippy::type_complexity)]
+pub fn evil_eyes_stare(
+    mut commands: Commands,
+    time: Res<Time>,
+    creature_grid: Res<CreatureGrid>,
+    player_query: Query<(Entity, &Transform, &AimDirection, &PerkBonuses), With<Player>>,
+    creature_query: Query<&Creature, Without<MarkedForDespawn>>,
+    mut stared_query: Query<(Entity, &mut Stared)>,
+) {
+    let mut targets = Vec::new();
+    for (player, transform, aim, bonuses) in player_query.iter() {
+        if !bonuses.evil_eyes || aim.direction == Vec2::ZERO {
+            continue;
+        }
+        let center = transform.translation.truncate();
+        let target = creature_grid
+            .candidates(center, EVIL_EYES_RANGE)
+            .into_iter()
+            .filter(|&(creature, _)| creature_query.contains(creature))
+            .map(|(creature, position)| (creature, position - center))
+            .filter(|(_, offset)| {
+                offset.length() <= EVIL_EYES_RANGE && aim.direction.angle_between(*offset).abs() <= EVIL_EYES_CONE
+            })
+            .min_by(|(_, a), (_, b)| a.length_squared().total_cmp(&b.length_squared()));
+        if let Some((creature, _)) = target {
+            targets.push((player, creature));
+        }
+    }
+
+    for (creature, mut stared) in stared_query.iter_mut() {
+        if targets.contains(&(stared.by, creature)) {
+            stared.linger = EVIL_EYES_LINGER;
+            continue;
+        }
+        let still_staring = player_query
+            .get(stared.by)
+            .is_ok_and(|(_, _, _, bonuses)| bonuses.evil_eyes);
+        let looking_elsewhere = targets.iter().any(|&(player, _)| player == stared.by);
+        stared.linger -= time.delta_seconds();
+        if !still_staring || looking_elsewhere || stared.linger <= 0.0 {
+            commands.entity(creature).remove::<Stared>();
+        }
+    }
+
+    for (player, creature) in targets {
+        if stared_query.get(creature).is_ok_and(|(_, stared)| stared.by == player) {
+            continue;
+        }
+        let Ok(kind) = creature_query.get(creature) else {
+            continue;
+        };
+        commands.entity(creature).insert(Stared {
+            by: player,
+            linger: EVIL_EYES_LINGER,
+            speed_multiplier: if kind.creature_type.is_boss() { EVIL_EYES_BOSS_SPEED } else { 0.0 },
+        });
+    }
+}
+
+/// FinalRevenge: a dying player takes everything nearby with them. Runs before the
+/// death checks so the blast's kills are counted like any others
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn final_revenge_blast(
+    mut commands: Commands,
+    config: Res<PerkEffectConfig>,
+    creature_grid: Res<CreatureGrid>,
+    mut shake: ResMut<ScreenShake>,
+    player_query: Query<(Entity, &Transform, &Health, &PerkBonuses), (With<Player>, Without<FinalRevengeSpent>)>,
+    mut creature_query: Query<(&Transform, &mut CreatureHealth), (With<Creature>, Without<MarkedForDespawn>)>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    for (player, transform, health, bonuses) in player_query.iter() {
+        if !bonuses.final_revenge || !health.is_dead() {
+            continue;
+        }
+        commands.entity(player).insert(FinalRevengeSpent);
+
+        let center = transform.translation.truncate();
+        let radius = config.final_revenge_radius;
+        for (creature, _) in creature_grid.candidates(center, radius) {
+            let Ok((creature_transform, mut creature_health)) = creature_query.get_mut(creature) else {
+                continue;
+            };
+            let distance = center.distance(creature_transform.translation.truncate());
+            if distance < radius {
+                creature_health.damage(config.final_revenge_damage * (1.0 - distance / radius));
+            }
         }
 
-        // Apply speed multiplier
-        speed.0 = config.base_move_speed * bonuses.speed_multiplier;
+        effect_events.send(SpawnEffectEvent {
+            effect_type: EffectType::Explosion,
+            position: transform.translation,
+            count: 60,
+        });
+        shake.add(12.0, 0.6);
+        sound_events.send(PlaySoundEvent {
+            sound: SoundEffect::Explosion,
+            position: Some(center),
+        });
+    }
+}
+
+/// MrMelee: creatures that bite the player take counter damage and a small shove
+#[allow(clippy::type_complexity)]
+pub fn mr_melee_counter(
+    mut commands: Commands,
+    mut touch_events: EventReader<CreatureTouchedPlayerEvent>,
+    player_query: Query<(&Transform, &PerkBonuses), With<Player>>,
+    mut creature_query: Query<
+        (&Creature, &mut CreatureHealth, Option<&mut Knockback>),
+        (Without<MarkedForDespawn>, Without<Player>),
+    >,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+) {
+    for touch in touch_events.read() {
+        let Ok((player_transform, bonuses)) = player_query.get(touch.player) else {
+            continue;
+        };
+        if bonuses.melee_counter_damage <= 0.0 {
+            continue;
+        }
+        let Ok((creature, mut health, knockback)) = creature_query.get_mut(touch.creature) else {
+            continue;
+        };
+        health.damage(bonuses.melee_counter_damage);
+        effect_events.send(SpawnEffectEvent {
+            effect_type: EffectType::Slash,
+            position: touch.position,
+            count: 1,
+        });
+
+        let away = (touch.position - player_transform.translation).truncate().normalize_or_zero();
+        let shove = away * MR_MELEE_KNOCKBACK / creature.creature_type.mass();
+        if shove == Vec2::ZERO {
+            continue;
+        }
+        match knockback {
+            Some(mut knockback) => knockback.velocity += shove,
+            None => {
+                commands.entity(touch.creature).insert(Knockback { velocity: shove });
+            }
+        }
+    }
+}
+
+/// VeinsOfPoison and ToxicAvenger: creatures that bite the player are poisoned,
+/// with the stronger poison winning when both are held
+pub fn contact_poison(
+    mut touch_events: EventReader<CreatureTouchedPlayerEvent>,
+    player_query: Query<&PerkBonuses, With<Player>>,
+    mut creature_query: Query<&mut StatusEffects, (With<Creature>, Without<MarkedForDespawn>)>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
+) {
+    for touch in touch_events.read() {
+        let Some((dps, duration)) = player_query.get(touch.player).ok().and_then(PerkBonuses::contact_poison) else {
+            continue;
+        };
+        let Ok(mut status_effects) = creature_query.get_mut(touch.creature) else {
+            continue;
+        };
+        if status_effects.apply(StatusKind::Poisoned, dps, duration, Some(touch.player)) {
+            effect_events.send(SpawnEffectEvent {
+                effect_type: EffectType::PoisonSplash,
+                position: touch.position,
+                count: 1,
+            });
+        }
+    }
+}
+
+/// Rolls a PoisonBullets proc
+pub fn roll_poison(chance: f32, rng: &mut impl Rng) -> bool {
+    chance > 0.0 && rng.gen::<f32>() < chance
+}
+
+/// PoisonBullets: each hit has a chance to poison the creature, credited to the shooter
+pub fn poison_bullets(
+    mut hit_events: EventReader<ProjectileHitEvent>,
+    projectile_query: Query<&Projectile>,
+    player_query: Query<&PerkBonuses, With<Player>>,
+    mut creature_query: Query<&mut StatusEffects, (With<Creature>, Without<MarkedForDespawn>)>,
+) {
+    let mut rng = rand::thread_rng();
+    for hit in hit_events.read() {
+        let owner = hit_owner(hit, &projectile_query);
+        let Ok(bonuses) = player_query.get(owner) else {
+            continue;
+        };
+        if !roll_poison(bonuses.poison_chance, &mut rng) {
+            continue;
+        }
+        if let Ok(mut status_effects) = creature_query.get_mut(hit.target) {
+            status_effects.apply(StatusKind::Poisoned, POISON_BULLETS_DPS, POISON_BULLETS_DURATION, Some(owner));
+        }
     }
 }
 
@@ -68,7 +1005,7 @@ pub fn handle_perk_selection(
         if let Ok(inventory) = query.get(event.player_entity) {
             info!(
                 "Perk {:?} selected, player now has {} perks",
-                event.perk_id,
+                event.perk,
                 inventory.total_perks()
             );
         }
@@ -78,14 +1015,15 @@ pub fn handle_perk_selection(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::SeedableRng;
 
     #[test]
     fn perk_selected_event_can_be_created() {
         let event = PerkSelectedEvent {
             player_entity: Entity::PLACEHOLDER,
-            perk_id: PerkId::Regeneration,
+            perk: PerkId::Regeneration.into(),
         };
-        assert_eq!(event.perk_id, PerkId::Regeneration);
+        assert_eq!(event.perk, PerkKey::Builtin(PerkId::Regeneration));
     }
 
     #[test]
@@ -97,12 +1035,1043 @@ mod tests {
         assert!(bonuses.regen_per_second > 0.0);
     }
 
+    #[test]
+    fn regeneration_heals_at_the_perk_rate_up_to_max() {
+        use crate::player::resources::PlayerConfig;
+
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::Regeneration);
+        inventory.add_perk(PerkId::GreaterRegeneration);
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<PlayerConfig>()
+            .init_resource::<PerkRegistry>()
+            .init_resource::<PerkEffectConfig>()
+            .add_systems(Update, (apply_perk_effects, player_regeneration).chain());
+        let base_health = app.world().resource::<PlayerConfig>().base_health;
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), inventory, PerkBonuses::default(), Health { current: 10.0, ..Health::new(base_health) }))
+            .id();
+
+        // 2 (Regeneration) + 5 (Greater) per second
+        for _ in 0..2 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(500));
+            app.update();
+        }
+        let health = app.world().get::<Health>(player).unwrap();
+        assert!((health.current - 17.0).abs() < 0.01, "{}", health.current);
+
+        // Never past max
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs(1000));
+        app.update();
+        let health = app.world().get::<Health>(player).unwrap();
+        assert_eq!(health.current, health.max);
+    }
+
+    #[test]
+    fn death_clock_drains_through_regeneration() {
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::Regeneration);
+        inventory.add_perk(PerkId::DeathClock);
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<PerkRegistry>()
+            .init_resource::<PerkEffectConfig>()
+            .add_systems(Update, (apply_perk_effects, player_regeneration).chain());
+        let drain = app.world().resource::<PerkEffectConfig>().death_clock_drain;
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), inventory, PerkBonuses::default(), Health::new(100.0)))
+            .id();
+
+        // 2 (Regeneration) - 4 (DeathClock) per second
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs(1));
+        app.update();
+        let health = app.world().get::<Health>(player).unwrap();
+        assert!((health.current - (100.0 - drain + 2.0)).abs() < 0.01, "{}", health.current);
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs(1000));
+        app.update();
+        assert!(app.world().get::<Health>(player).unwrap().is_dead());
+    }
+
+    #[test]
+    fn thick_skinned_rescales_max_health_from_the_base() {
+        let mut app = App::new();
+        app.init_resource::<PerkRegistry>()
+            .add_systems(Update, apply_perk_effects);
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::ThickSkinned);
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), inventory, PerkBonuses::default(), Health::new(100.0)))
+            .id();
+        let health = |app: &App| {
+            let health = app.world().get::<Health>(player).unwrap();
+            (health.current, health.max)
+        };
+        let assert_health = |(current, max): (f32, f32), expected: (f32, f32)| {
+            assert!((current - expected.0).abs() < 0.01 && (max - expected.1).abs() < 0.01, "{current}/{max}");
+        };
+
+        app.update();
+        assert_health(health(&app), (66.67, 66.67));
+        // Steady from frame to frame
+        app.update();
+        assert_health(health(&app), (66.67, 66.67));
+
+        app.world_mut().get_mut::<Health>(player).unwrap().current = 33.33;
+        *app.world_mut().get_mut::<PerkInventory>(player).unwrap() = PerkInventory::new();
+        app.update();
+        assert_health(health(&app), (50.0, 100.0));
+
+        // Taken again: back to 2/3 of the base, not 2/3 of 2/3
+        app.world_mut().get_mut::<PerkInventory>(player).unwrap().add_perk(PerkId::ThickSkinned);
+        app.update();
+        app.update();
+        assert_health(health(&app), (33.33, 66.67));
+    }
+
+    #[test]
+    fn instant_perks_change_experience_and_health() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(785);
+
+        let mut exp = Experience { current: 50, ..Experience::new() };
+        let mut health = Health { current: 40.0, ..Health::new(100.0) };
+        let grant = apply_instant_perk(PerkId::GrimDeal, &mut exp, &mut health, &mut rng);
+        assert_eq!(grant, InstantPerkGrant { xp: 9, levels: 0 });
+        assert_eq!(exp.current, 50);
+        assert!(health.is_dead());
+
+        let mut exp = Experience::new();
+        let mut health = Health { current: 40.0, ..Health::new(100.0) };
+        let grant = apply_instant_perk(PerkId::InfernalContract, &mut exp, &mut health, &mut rng);
+        assert_eq!(grant, InstantPerkGrant { xp: 0, levels: 3 });
+        assert_eq!(exp.level, 4);
+        assert_eq!(health.current, INFERNAL_CONTRACT_HEALTH);
+
+        // Bandage never lowers health or overheals
+        for _ in 0..50 {
+            let mut health = Health { current: 3.0, ..Health::new(100.0) };
+            apply_instant_perk(PerkId::Bandage, &mut Experience::new(), &mut health, &mut rng);
+            assert!((3.0..=100.0).contains(&health.current));
+        }
+
+        // FatalLottery either pays out or kills
+        let (mut wins, mut deaths) = (0, 0);
+        for _ in 0..200 {
+            let mut exp = Experience::new();
+            let mut health = Health::new(100.0);
+            let grant = apply_instant_perk(PerkId::FatalLottery, &mut exp, &mut health, &mut rng);
+            if health.is_dead() {
+                deaths += 1;
+                assert_eq!(grant, InstantPerkGrant::default());
+            } else {
+                wins += 1;
+                assert_eq!(grant.xp, FATAL_LOTTERY_EXP);
+            }
+        }
+        assert!(wins > 60 && deaths > 60, "{} wins, {} deaths", wins, deaths);
+
+        // Ordinary perks have no instant effect
+        let mut exp = Experience::new();
+        let mut health = Health::new(100.0);
+        let grant = apply_instant_perk(PerkId::Regeneration, &mut exp, &mut health, &mut rng);
+        assert_eq!(grant, InstantPerkGrant::default());
+        assert_eq!((exp.current, health.current), (0, 100.0));
+    }
+
+    #[test]
+    fn instant_winner_xp_is_scaled_and_levels_up_once_per_level() {
+        use crate::player::resources::PlayerConfig;
+        use crate::player::systems::{apply_experience_grants, scaled_experience};
+
+        let mut app = App::new();
+        app.init_resource::<PlayerConfig>()
+            .add_event::<PerkSelectedEvent>()
+            .add_event::<GrantXpEvent>()
+            .add_event::<PlayerLevelUpEvent>()
+            .add_systems(Update, (apply_instant_perk_effects, apply_experience_grants).chain());
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::BloodyMess);
+        let bonuses = PerkBonuses::calculate(&inventory);
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), Experience::new(), Health::new(100.0), bonuses.clone()))
+            .id();
+        app.world_mut().send_event(PerkSelectedEvent {
+            player_entity: player,
+            perk: PerkId::InstantWinner.into(),
+        });
+        app.update();
+
+        let mut expected = Experience::new();
+        let scaled = scaled_experience(INSTANT_WINNER_EXP, &bonuses, &PlayerConfig::default());
+        assert!(scaled > INSTANT_WINNER_EXP);
+        expected.add_all(scaled);
+        let exp = app.world().get::<Experience>(player).unwrap().clone();
+        assert_eq!((exp.level, exp.current), (expected.level, expected.current));
+        let levels: Vec<u32> = app
+            .world_mut()
+            .resource_mut::<Events<PlayerLevelUpEvent>>()
+            .drain()
+            .map(|event| event.new_level)
+            .collect();
+        assert!(exp.level > 2);
+        assert_eq!(levels, (2..=exp.level).collect::<Vec<_>>());
+        assert_eq!(app.world().get::<Health>(player).unwrap().current, 100.0);
+    }
+
+    #[test]
+    fn lifeline_removes_half_the_creatures_without_killing_them() {
+        use crate::creatures::{CreatureBundle, CreatureDeathEvent, CreatureType};
+
+        let mut app = App::new();
+        app.add_event::<PerkSelectedEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<CreatureDeathEvent>()
+            .add_systems(Update, apply_lifeline_perk);
+        for i in 0..20 {
+            app.world_mut()
+                .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(i as f32 * 30.0, 0.0, 0.0)));
+        }
+        let boss = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::BossSpider, Vec3::ZERO))
+            .id();
+        app.world_mut().send_event(PerkSelectedEvent {
+            player_entity: Entity::PLACEHOLDER,
+            perk: PerkId::Lifeline5050.into(),
+        });
+        app.update();
+
+        let remaining = app
+            .world_mut()
+            .query_filtered::<&Creature, Without<MarkedForDespawn>>()
+            .iter(app.world())
+            .filter(|creature| !creature.creature_type.is_boss())
+            .count();
+        assert!((8..=12).contains(&remaining), "{} remain", remaining);
+        assert!(app.world().get::<MarkedForDespawn>(boss).is_none());
+        assert!(app.world().resource::<Events<CreatureDeathEvent>>().is_empty());
+        let puffs = app.world().resource::<Events<SpawnEffectEvent>>().len();
+        assert_eq!(puffs, 20 - remaining);
+    }
+
+    /// Asserts a ring has `count` shots from around `center`, evenly spaced around the circle
+    fn assert_even_ring(events: &[FireWeaponEvent], center: Vec3, count: usize) {
+        assert_eq!(events.len(), count);
+        let mut angles: Vec<f32> = events
+            .iter()
+            .map(|event| {
+                let offset = (event.position - center).truncate();
+                assert!(offset.normalize().dot(event.direction) > 0.999, "shot not fired outward");
+                event.direction.to_angle().rem_euclid(std::f32::consts::TAU)
+            })
+            .collect();
+        angles.sort_by(f32::total_cmp);
+        let step = std::f32::consts::TAU / count as f32;
+        for pair in angles.windows(2) {
+            assert!((pair[1] - pair[0] - step).abs() < 0.001, "uneven spacing: {:?}", angles);
+        }
+    }
+
+    #[test]
+    fn hot_tempered_fires_an_even_ring_of_the_equipped_weapon_every_interval() {
+        use crate::weapons::WeaponId;
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<WeaponRegistry>()
+            .add_event::<FireWeaponEvent>()
+            .add_systems(Update, hot_tempered_ring);
+        let center = Vec3::new(50.0, -20.0, 0.0);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::from_translation(center),
+                PerkBonuses {
+                    hot_tempered: true,
+                    ..default()
+                },
+                EquippedWeapon::new(WeaponId::Smg, Some(30)),
+            ))
+            .id();
+
+        let mut fired = Vec::new();
+        for _ in 0..90 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(100));
+            app.update();
+            fired.extend(app.world_mut().resource_mut::<Events<FireWeaponEvent>>().drain());
+        }
+
+        // Two full intervals in 9 seconds
+        assert_eq!(fired.len(), 2 * HOT_TEMPERED_RING_SHOTS as usize);
+        assert_even_ring(&fired[..HOT_TEMPERED_RING_SHOTS as usize], center, HOT_TEMPERED_RING_SHOTS as usize);
+        assert!(fired.iter().all(|event| event.weapon_id == WeaponId::Smg && event.shooter == player));
+        // Free shots: the clip is untouched
+        assert_eq!(app.world().get::<EquippedWeapon>(player).unwrap().ammo, Some(30));
+    }
+
+    #[test]
+    fn angry_reloader_fires_one_even_ring_per_reload_at_the_midpoint() {
+        use crate::weapons::WeaponId;
+
+        let mut app = App::new();
+        app.init_resource::<WeaponRegistry>()
+            .add_event::<FireWeaponEvent>()
+            .add_systems(Update, angry_reloader_ring);
+        let center = Vec3::new(-30.0, 10.0, 0.0);
+        let mut weapon = EquippedWeapon::new(WeaponId::AssaultRifle, Some(25));
+        weapon.ammo = Some(0);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::from_translation(center),
+                PerkBonuses {
+                    angry_reloader: true,
+                    ..default()
+                },
+                weapon,
+            ))
+            .id();
+        app.update();
+
+        let step_reload = |app: &mut App, timer: Option<f32>| {
+            let mut weapon = app.world_mut().get_mut::<EquippedWeapon>(player).unwrap();
+            match timer {
+                Some(timer) => {
+                    weapon.start_reload(2.0);
+                    weapon.reload_timer = timer;
+                }
+                None => weapon.finish_reload(),
+            }
+            app.update();
+            app.world_mut()
+                .resource_mut::<Events<FireWeaponEvent>>()
+                .drain()
+                .collect::<Vec<_>>()
+        };
+
+        assert!(step_reload(&mut app, Some(1.6)).is_empty());
+        let ring = step_reload(&mut app, Some(0.9));
+        assert_even_ring(&ring, center, ANGRY_RELOADER_RING_SHOTS as usize);
+        assert!(step_reload(&mut app, Some(0.2)).is_empty());
+
+        // The next reload gets its own ring
+        assert!(step_reload(&mut app, None).is_empty());
+        app.world_mut().get_mut::<EquippedWeapon>(player).unwrap().ammo = Some(0);
+        assert_eq!(step_reload(&mut app, Some(0.5)).len(), ANGRY_RELOADER_RING_SHOTS as usize);
+    }
+
+    #[test]
+    fn fire_cough_shoots_along_the_aim_while_the_perk_is_held() {
+        use crate::weapons::WeaponId;
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<WeaponRegistry>()
+            .add_event::<FireWeaponEvent>()
+            .add_systems(Update, fire_cough_shot);
+        let bonuses = PerkBonuses {
+            fire_cough: true,
+            ..default()
+        };
+        let mut weapon = EquippedWeapon::new(WeaponId::RocketLauncher, Some(5));
+        weapon.fire_cooldown = 100.0;
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), Transform::default(), AimDirection::from_angle(0.0), bonuses, weapon))
+            .id();
+
+        let fire = |app: &mut App, seconds: u32| {
+            let mut fired = Vec::new();
+            for _ in 0..seconds * 10 {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(std::time::Duration::from_millis(100));
+                app.update();
+                fired.extend(app.world_mut().resource_mut::<Events<FireWeaponEvent>>().drain());
+            }
+            fired
+        };
+
+        let fired = fire(&mut app, 7);
+        assert!(app.world().get::<FireCoughTimer>(player).is_some());
+        assert_eq!(fired.len(), 2);
+        assert!(fired
+            .iter()
+            .all(|event| event.weapon_id == WeaponId::RocketLauncher && event.direction.abs_diff_eq(Vec2::X, 0.001)));
+        // Free of ammo and cooldown
+        let weapon = app.world().get::<EquippedWeapon>(player).unwrap();
+        assert_eq!((weapon.ammo, weapon.fire_cooldown), (Some(5), 100.0));
+
+        // Perks reset: the timer goes and the coughing stops
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().fire_cough = false;
+        assert!(fire(&mut app, 7).is_empty());
+        assert!(app.world().get::<FireCoughTimer>(player).is_none());
+    }
+
+    #[test]
+    fn man_bomb_pulses_ion_rings_only_while_standing_still() {
+        use crate::player::systems::update_stationary_timers;
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .add_event::<ExplosionEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, (update_stationary_timers, man_bomb_pulse).chain());
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                StationaryTimer::default(),
+                PerkBonuses {
+                    man_bomb: true,
+                    ion_damage_multiplier: 1.2,
+                    ion_aoe_multiplier: 1.5,
+                    ..default()
+                },
+            ))
+            .id();
+
+        let run = |app: &mut App, seconds: u32, walking: bool| {
+            let mut rings = Vec::new();
+            for _ in 0..seconds * 10 {
+                if walking {
+                    app.world_mut().get_mut::<Transform>(player).unwrap().translation.x += 5.0;
+                }
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(std::time::Duration::from_millis(100));
+                app.update();
+                rings.extend(app.world_mut().resource_mut::<Events<ExplosionEvent>>().drain());
+            }
+            rings
+        };
+
+        // Still for 1s before the first 2s countdown: rings at ~3s and ~5s
+        let rings = run(&mut app, 6, false);
+        assert_eq!(rings.len(), 2);
+        assert_eq!(rings[0].radius, MAN_BOMB_RADIUS * 1.5);
+        assert_eq!(rings[0].damage, MAN_BOMB_DAMAGE * 1.2);
+        assert!(!rings[0].self_damage);
+        let effects = app.world().resource::<Events<SpawnEffectEvent>>();
+        assert!(effects
+            .get_reader()
+            .read(effects)
+            .all(|event| event.effect_type == EffectType::IonRing { radius: MAN_BOMB_RADIUS * 1.5 }));
+
+        // Walking never pulses, and the countdown starts over once stopped
+        assert!(run(&mut app, 6, true).is_empty());
+        assert!(run(&mut app, 2, false).is_empty());
+    }
+
+    #[test]
+    fn runner_momentum_builds_while_moving_and_resets_when_idle() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .add_systems(Update, runner_momentum);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                PerkBonuses {
+                    runner_momentum_cap: 1.9,
+                    ..default()
+                },
+            ))
+            .id();
+
+        let run = |app: &mut App, seconds: u32, walking: bool| {
+            for _ in 0..seconds * 10 {
+                if walking {
+                    app.world_mut().get_mut::<Transform>(player).unwrap().translation.x += 5.0;
+                }
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(std::time::Duration::from_millis(100));
+                app.update();
+            }
+            app.world().get::<RunnerMomentum>(player).unwrap().clone()
+        };
+
+        // Builds steadily while moving, toward the cap and never past it
+        let momentum = run(&mut app, 5, true);
+        assert!(momentum.multiplier > 1.3 && momentum.multiplier < 1.6);
+        assert_eq!(momentum.heading, Vec2::X);
+        assert_eq!(run(&mut app, 20, true).multiplier, 1.9);
+
+        // Gone within a second of standing still
+        assert_eq!(run(&mut app, 1, false).multiplier, 1.0);
+
+        // Losing the perk drops the momentum entirely
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().runner_momentum_cap = 1.0;
+        app.update();
+        assert!(app.world().get::<RunnerMomentum>(player).is_none());
+    }
+
+    fn aura_app(bonuses: PerkBonuses, inventory: PerkInventory) -> (App, Vec<Entity>) {
+        use crate::creatures::{rebuild_creature_grid, CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<CreatureGrid>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(Update, (rebuild_creature_grid, aura_damage_system).chain());
+        app.world_mut()
+            .spawn((Player::default(), Transform::default(), bonuses, inventory));
+        // Two inside the aura, one just outside
+        let creatures = [60.0, 110.0, 130.0]
+            .map(|x| {
+                app.world_mut()
+                    .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(x, 0.0, 0.0)))
+                    .id()
+            })
+            .to_vec();
+        (app, creatures)
+    }
+
+    fn run_seconds(app: &mut App, seconds: u32) {
+        for _ in 0..seconds * 10 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(100));
+            app.update();
+        }
+    }
+
+    #[test]
+    fn radioactive_aura_damages_creatures_in_range_per_pick() {
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::Radioactive);
+        inventory.add_perk(PerkId::Radioactive);
+        let bonuses = PerkBonuses {
+            radioactive_aura: true,
+            ..default()
+        };
+        let (mut app, creatures) = aura_app(bonuses, inventory);
+        let max = app.world().get::<CreatureHealth>(creatures[0]).unwrap().current;
+        run_seconds(&mut app, 1);
+
+        let lost: Vec<f32> = creatures
+            .iter()
+            .map(|&c| max - app.world().get::<CreatureHealth>(c).unwrap().current)
+            .collect();
+        // The first frame only has the grid built, so ~0.9s of two stacks
+        let expected = (max.min(2.0 * RADIOACTIVE_DPS * 1.0), 2.0 * RADIOACTIVE_DPS * 0.9);
+        assert!(lost[0] >= expected.1 - 0.01 && lost[0] <= expected.0 + 0.01, "{:?}", lost);
+        assert_eq!(lost[0], lost[1]);
+        assert_eq!(lost[2], 0.0);
+    }
+
+    #[test]
+    fn pyrokinetic_aura_periodically_ignites_creatures_in_range() {
+        let bonuses = PerkBonuses {
+            pyrokinetic_aura: true,
+            ..default()
+        };
+        let (mut app, creatures) = aura_app(bonuses, PerkInventory::new());
+        let burning = |app: &App, creature: Entity| {
+            app.world()
+                .get::<StatusEffects>(creature)
+                .is_some_and(|status| status.entries.iter().any(|entry| entry.kind == StatusKind::Burning))
+        };
+
+        run_seconds(&mut app, 1);
+        assert!(!burning(&app, creatures[0]));
+        run_seconds(&mut app, 1);
+        assert!(burning(&app, creatures[0]) && burning(&app, creatures[1]));
+        assert!(!burning(&app, creatures[2]));
+        // Pyrokinetic alone does no direct damage
+        let health = app.world().get::<CreatureHealth>(creatures[0]).unwrap();
+        assert_eq!(health.current, health.max);
+        let flames = app.world().resource::<Events<SpawnEffectEvent>>();
+        assert!(flames.get_reader().read(flames).all(|event| event.effect_type == EffectType::Flame));
+    }
+
+    #[test]
+    fn plaguebearer_hit_infects_and_spreads_through_a_cluster() {
+        use crate::creatures::{rebuild_creature_grid, CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<CreatureGrid>()
+            .add_event::<ProjectileHitEvent>()
+            .add_systems(Update, (rebuild_creature_grid, plaguebearer_infection).chain());
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                PerkBonuses {
+                    plaguebearer: true,
+                    ..default()
+                },
+            ))
+            .id();
+        let cluster = [(200.0, 0.0), (230.0, 0.0), (200.0, 30.0), (170.0, 0.0), (200.0, -30.0)].map(|(x, y)| {
+            app.world_mut()
+                .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(x, y, 0.0)))
+                .id()
+        });
+        let infected = |app: &App| cluster.map(|creature| app.world().get::<Infection>(creature).is_some());
+
+        app.world_mut().send_event(ProjectileHitEvent {
+            projectile: player,
+            target: cluster[0],
+            damage: 1.0,
+            position: Vec3::new(200.0, 0.0, 0.0),
+            was_crit: false,
+            instant_kill: false,
+        });
+        app.update();
+        assert_eq!(infected(&app), [true, false, false, false, false]);
+        assert_eq!(app.world().get::<Sprite>(cluster[0]).unwrap().color, INFECTED_TINT);
+
+        run_seconds(&mut app, 1);
+        let now_infected = infected(&app);
+        assert!(now_infected[1..].iter().any(|&infected| infected), "{:?}", now_infected);
+        let spread = app.world().get::<Infection>(cluster[1]).unwrap();
+        assert_eq!(spread.dps, PLAGUE_DPS * PLAGUE_SPREAD_FALLOFF);
+        assert_eq!(spread.source, Some(player));
+    }
+
+    #[test]
+    fn evil_eyes_holds_one_creature_on_the_aim_line() {
+        use crate::creatures::{rebuild_creature_grid, CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<CreatureGrid>()
+            .add_systems(Update, (rebuild_creature_grid, evil_eyes_stare).chain());
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                AimDirection::default(),
+                PerkBonuses {
+                    evil_eyes: true,
+                    ..default()
+                },
+            ))
+            .id();
+        let aim = |app: &mut App, direction: Vec2| {
+            app.world_mut().get_mut::<AimDirection>(player).unwrap().direction = direction;
+        };
+        let spawn = |app: &mut App, creature_type: CreatureType, x: f32, y: f32| {
+            app.world_mut()
+                .spawn(CreatureBundle::new(creature_type, Vec3::new(x, y, 0.0)))
+                .id()
+        };
+        let near = spawn(&mut app, CreatureType::Zombie, 100.0, 0.0);
+        let behind = spawn(&mut app, CreatureType::Zombie, 200.0, 0.0);
+        let above = spawn(&mut app, CreatureType::BossSpider, 0.0, 150.0);
+        let stared = |app: &App| {
+            [near, behind, above].map(|creature| app.world().get::<Stared>(creature).map(|s| s.speed_multiplier))
+        };
+
+        aim(&mut app, Vec2::X);
+        app.update();
+        assert_eq!(stared(&app), [Some(0.0), None, None]);
+
+        // Switching targets frees the old one straight away; bosses only slow
+        aim(&mut app, Vec2::Y);
+        app.update();
+        assert_eq!(stared(&app), [None, None, Some(EVIL_EYES_BOSS_SPEED)]);
+
+        // Looking at nothing lets the stare linger briefly
+        aim(&mut app, -Vec2::X);
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_millis(200));
+        app.update();
+        assert!(stared(&app)[2].is_some());
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_millis(200));
+        app.update();
+        assert_eq!(stared(&app), [None, None, None]);
+    }
+
+    #[test]
+    fn final_revenge_kills_creatures_near_the_dying_player() {
+        use crate::creatures::{check_creature_death, rebuild_creature_grid, CreatureBundle, CreatureDeathEvent, CreatureType};
+
+        let mut app = App::new();
+        app.init_resource::<CreatureGrid>()
+            .init_resource::<PerkEffectConfig>()
+            .init_resource::<ScreenShake>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_event::<CreatureDeathEvent>()
+            .add_systems(
+                Update,
+                (rebuild_creature_grid, final_revenge_blast, check_creature_death).chain(),
+            );
+        let mut health = Health::new(100.0);
+        health.damage(100.0);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                health,
+                PerkBonuses {
+                    final_revenge: true,
+                    ..default()
+                },
+            ))
+            .id();
+        let near = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(50.0, 0.0, 0.0)))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(400.0, 0.0, 0.0)))
+            .id();
+
+        app.update();
+        let deaths: Vec<Entity> = app
+            .world_mut()
+            .resource_mut::<Events<CreatureDeathEvent>>()
+            .drain()
+            .map(|event| event.entity)
+            .collect();
+        assert_eq!(deaths, vec![near]);
+        assert!(!app.world().get::<CreatureHealth>(far).unwrap().is_dead());
+        assert!(app.world().get::<FinalRevengeSpent>(player).is_some());
+        assert!(app.world().resource::<ScreenShake>().intensity > 0.0);
+
+        // Only goes off once
+        app.world_mut().get_mut::<CreatureHealth>(far).unwrap().current = 1.0;
+        app.world_mut().get_mut::<Transform>(far).unwrap().translation = Vec3::new(10.0, 0.0, 0.0);
+        app.update();
+        assert_eq!(app.world().get::<CreatureHealth>(far).unwrap().current, 1.0);
+    }
+
+    #[test]
+    fn mr_melee_counters_each_bite_per_pick() {
+        use crate::creatures::{creature_attack, CreatureBundle, CreatureType, WorldTimeScale};
+        use crate::player::systems::PlayerDamageEvent;
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<WorldTimeScale>()
+            .add_event::<PlayerDamageEvent>()
+            .add_event::<CreatureTouchedPlayerEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(Update, (creature_attack, mr_melee_counter).chain());
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::MrMelee);
+        inventory.add_perk(PerkId::MrMelee);
+        app.world_mut().spawn((
+            Player::default(),
+            Transform::default(),
+            PerkBonuses::calculate(&inventory),
+        ));
+        let creature = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(20.0, 0.0, 0.0)))
+            .insert(CreatureHealth::new(200.0))
+            .id();
+        let max = 200.0;
+
+        // Nothing to counter until the bite lands at the end of its windup
+        app.update();
+        assert_eq!(app.world().get::<CreatureHealth>(creature).unwrap().current, max);
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(CreatureType::Zombie.attack_windup()));
+        app.update();
+        assert_eq!(app.world().get::<CreatureHealth>(creature).unwrap().current, max - 50.0);
+        assert!(app.world().get::<Knockback>(creature).unwrap().velocity.x > 0.0);
+        let effects = app.world().resource::<Events<SpawnEffectEvent>>();
+        assert!(effects
+            .get_reader()
+            .read(effects)
+            .any(|effect| effect.effect_type == EffectType::Slash));
+
+        // The bite cooldown holds back both the attack and the counter
+        app.update();
+        assert_eq!(app.world().get::<CreatureHealth>(creature).unwrap().current, max - 50.0);
+    }
+
+    #[test]
+    fn toxic_avenger_outweighs_veins_of_poison() {
+        use crate::perks::components::{
+            TOXIC_AVENGER_DPS, TOXIC_AVENGER_DURATION, VEINS_OF_POISON_DPS, VEINS_OF_POISON_DURATION,
+        };
+
+        let mut inventory = PerkInventory::new();
+        assert_eq!(PerkBonuses::calculate(&inventory).contact_poison(), None);
+        inventory.add_perk(PerkId::VeinsOfPoison);
+        assert_eq!(
+            PerkBonuses::calculate(&inventory).contact_poison(),
+            Some((VEINS_OF_POISON_DPS, VEINS_OF_POISON_DURATION))
+        );
+        inventory.add_perk(PerkId::ToxicAvenger);
+        assert_eq!(
+            PerkBonuses::calculate(&inventory).contact_poison(),
+            Some((TOXIC_AVENGER_DPS, TOXIC_AVENGER_DURATION))
+        );
+    }
+
+    #[test]
+    fn creatures_biting_a_toxic_player_die_of_poison_for_full_xp() {
+        use crate::creatures::{
+            check_creature_death, creature_attack, tick_status_effects, CreatureBundle, CreatureDeathEvent,
+            CreatureType, ExperienceValue, StatusDamageEvent, WorldTimeScale,
+        };
+        use crate::perks::components::TOXIC_AVENGER_DPS;
+        use crate::player::systems::PlayerDamageEvent;
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<WorldTimeScale>()
+            .add_event::<PlayerDamageEvent>()
+            .add_event::<CreatureTouchedPlayerEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<StatusDamageEvent>()
+            .add_event::<CreatureDeathEvent>()
+            .add_systems(
+                Update,
+                (creature_attack, contact_poison, tick_status_effects, check_creature_death).chain(),
+            );
+        app.world_mut().spawn((
+            Player::default(),
+            Transform::default(),
+            PerkBonuses {
+                toxic_avenger: true,
+                ..default()
+            },
+        ));
+        let creature = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(20.0, 0.0, 0.0)))
+            .id();
+        let experience = app.world().get::<ExperienceValue>(creature).unwrap().0;
+
+        app.update();
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(CreatureType::Zombie.attack_windup()));
+        app.update();
+        let poison = app.world().get::<StatusEffects>(creature).unwrap().entries[0].clone();
+        assert_eq!((poison.kind, poison.dps), (StatusKind::Poisoned, TOXIC_AVENGER_DPS));
+        let effects = app.world().resource::<Events<SpawnEffectEvent>>();
+        assert!(effects
+            .get_reader()
+            .read(effects)
+            .any(|effect| effect.effect_type == EffectType::PoisonSplash));
+
+        let mut deaths = Vec::new();
+        for _ in 0..60 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(100));
+            app.update();
+            deaths.extend(
+                app.world_mut()
+                    .resource_mut::<Events<CreatureDeathEvent>>()
+                    .drain()
+                    .map(|event| (event.entity, event.experience, event.killed_by)),
+            );
+        }
+        assert_eq!(deaths, vec![(creature, experience, Some(StatusKind::Poisoned))]);
+    }
+
+    #[test]
+    fn poison_bullets_proc_about_one_hit_in_eight() {
+        use crate::creatures::{CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.add_event::<ProjectileHitEvent>()
+            .add_systems(Update, poison_bullets);
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::PoisonBullets);
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), PerkBonuses::calculate(&inventory)))
+            .id();
+
+        let mut poisoned = 0;
+        for _ in 0..400 {
+            let creature = app
+                .world_mut()
+                .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::ZERO))
+                .id();
+            app.world_mut().send_event(ProjectileHitEvent {
+                projectile: player,
+                target: creature,
+                damage: 100.0,
+                position: Vec3::ZERO,
+                was_crit: true,
+                instant_kill: false,
+            });
+            app.update();
+            if let Some(poison) = app.world().get::<StatusEffects>(creature).unwrap().entries.first() {
+                // A flat dose credited to the shooter, however hard the hit was
+                assert_eq!(poison.kind, StatusKind::Poisoned);
+                assert_eq!(poison.dps, POISON_BULLETS_DPS);
+                assert_eq!(poison.source, Some(player));
+                poisoned += 1;
+            }
+            app.world_mut().despawn(creature);
+        }
+        assert!((25..=80).contains(&poisoned), "{poisoned} of 400 hits poisoned");
+    }
+
+    #[test]
+    fn living_fortress_ramps_while_still_and_resets_on_moving() {
+        let bonuses = PerkBonuses {
+            living_fortress: true,
+            ..default()
+        };
+        let mut timer = StationaryTimer::default();
+        for _ in 0..=50 {
+            timer.update(Vec2::ZERO, 0.1);
+        }
+        assert!((bonuses.living_fortress_multiplier(timer.seconds) - 1.5).abs() < 1e-4);
+        assert_eq!(bonuses.living_fortress_multiplier(60.0), 2.0);
+        assert_eq!(PerkBonuses::default().living_fortress_multiplier(timer.seconds), 1.0);
+
+        timer.update(Vec2::new(5.0, 0.0), 0.1);
+        assert_eq!(bonuses.living_fortress_multiplier(timer.seconds), 1.0);
+    }
+
     #[test]
     fn perk_bonuses_apply_speed() {
         let mut inventory = PerkInventory::new();
         inventory.add_perk(PerkId::LongDistanceRunner);
 
         let bonuses = PerkBonuses::calculate(&inventory);
-        assert!(bonuses.speed_multiplier > 1.0);
+        assert!(bonuses.runner_momentum_cap > 1.0);
+    }
+
+    #[test]
+    fn queued_level_ups_open_one_perk_selection_each() {
+        use crate::states::GameState;
+
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(GameState::Playing)
+            .add_sub_state::<PlayingState>()
+            .add_event::<PlayerLevelUpEvent>()
+            .init_resource::<PendingLevelUps>()
+            .add_systems(Update, (queue_level_ups, open_perk_select.run_if(in_state(PlayingState::Active))).chain());
+        app.update();
+
+        for new_level in [2, 3] {
+            app.world_mut().send_event(PlayerLevelUpEvent { player_entity: Entity::PLACEHOLDER, new_level });
+        }
+        app.update();
+        app.update();
+        assert_eq!(*app.world().resource::<State<PlayingState>>().get(), PlayingState::PerkSelect);
+        assert_eq!(app.world().resource::<PendingLevelUps>().0, 1);
+
+        // Picking a perk returns to play, which opens the second selection
+        app.world_mut().resource_mut::<NextState<PlayingState>>().set(PlayingState::Active);
+        app.update();
+        app.update();
+        assert_eq!(*app.world().resource::<State<PlayingState>>().get(), PlayingState::PerkSelect);
+        assert_eq!(app.world().resource::<PendingLevelUps>().0, 0);
+
+        app.world_mut().resource_mut::<NextState<PlayingState>>().set(PlayingState::Active);
+        app.update();
+        app.update();
+        assert_eq!(*app.world().resource::<State<PlayingState>>().get(), PlayingState::Active);
+    }
+
+    #[test]
+    fn jinx_kill_spares_bosses() {
+        use crate::bonuses::SpawnBonusEvent;
+        use crate::creatures::components::{CreatureBundle, CreatureType};
+
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::Jinxed);
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .insert_resource(JinxConfig {
+                min_interval: 0.0,
+                max_interval: 0.0,
+                events: vec![(JinxEvent::KillCreature, 1)],
+                ..default()
+            })
+            .add_event::<PlayerDamageEvent>()
+            .add_event::<SpawnBonusEvent>()
+            .add_event::<SpawnCreatureEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(Update, jinx_tick);
+        let bonuses = PerkBonuses::calculate(&inventory);
+        let player = app.world_mut().spawn((Player::default(), Transform::default(), bonuses)).id();
+        let boss = app.world_mut().spawn(CreatureBundle::new(CreatureType::BossSpider, Vec3::ZERO)).id();
+        let zombie = app.world_mut().spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::ZERO)).id();
+
+        app.update();
+        assert!(app.world().get::<JinxState>(player).is_some());
+        app.world_mut().resource_mut::<Time>().advance_by(std::time::Duration::from_millis(100));
+        app.update();
+        assert!(app.world().get::<CreatureHealth>(zombie).unwrap().is_dead());
+        assert!(!app.world().get::<CreatureHealth>(boss).unwrap().is_dead());
+        let flashes = app.world().resource::<Events<SpawnEffectEvent>>();
+        assert!(flashes
+            .get_reader()
+            .read(flashes)
+            .any(|e| e.effect_type == EffectType::JinxFlash { good: true }));
+
+        // Losing the perk drops the state
+        *app.world_mut().get_mut::<PerkBonuses>(player).unwrap() = PerkBonuses::default();
+        app.update();
+        assert!(app.world().get::<JinxState>(player).is_none());
+    }
+
+    #[test]
+    fn lean_mean_exp_machine_trickles_experience() {
+        use crate::player::resources::PlayerConfig;
+        use crate::player::systems::apply_experience_grants;
+
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::LeanMeanExpMachine);
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<PlayerConfig>()
+            .add_event::<GrantXpEvent>()
+            .add_event::<PlayerLevelUpEvent>()
+            .add_systems(Update, (passive_experience, apply_experience_grants).chain());
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), Experience::new(), PerkBonuses::calculate(&inventory)))
+            .id();
+
+        app.update();
+        for _ in 0..10 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(250));
+            app.update();
+        }
+        // 4 XP per second for 2.5 seconds
+        assert_eq!(app.world().get::<Experience>(player).unwrap().current, 10);
     }
 }
@@ -0,0 +1,254 @@
+//! Crash-safe persistence
+//!
+//! Every file the game saves between runs goes through here. Writes land in a
+//! temp file that is synced and renamed over the target, so a crash leaves
+//! either the old or the new file, never half of one. Files carry a CRC32 of
+//! their payload; the previous good file is kept as `<file>.bak` and used when
+//! the main one fails its checksum or doesn't parse.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// First line of every saved file, followed by the payload's CRC32 in hex
+const CHECKSUM_PREFIX: &str = "crc32:";
+
+/// Outcome of [`load`]
+#[derive(Debug, PartialEq)]
+pub enum LoadResult<T> {
+    /// Nothing has been saved yet
+    Missing,
+    Loaded(T),
+    /// The main file was unusable and the backup was loaded instead
+    Recovered(T),
+    /// Neither file was usable; the main file was moved aside for recovery
+    Fresh { preserved: Option<PathBuf> },
+}
+
+impl<T> LoadResult<T> {
+    /// The loaded value, if there was one
+    pub fn into_value(self) -> Option<T> {
+        match self {
+            LoadResult::Loaded(value) | LoadResult::Recovered(value) => Some(value),
+            LoadResult::Missing | LoadResult::Fresh { .. } => None,
+        }
+    }
+}
+
+/// `path` with `suffix` appended to its file name
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+pub fn backup_path(path: &Path) -> PathBuf {
+    sibling(path, ".bak")
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    sibling(path, ".tmp")
+}
+
+/// Payload with its checksum line in front
+fn encode(payload: &str) -> String {
+    format!(
+        "{}{:08x}\n{}",
+        CHECKSUM_PREFIX,
+        crc32fast::hash(payload.as_bytes()),
+        payload
+    )
+}
+
+/// Checks the checksum and parses the payload. Files from before checksums
+/// were added have no checksum line and are parsed as they are.
+fn decode<T: DeserializeOwned>(text: &str) -> Result<T, String> {
+    let payload = match text.strip_prefix(CHECKSUM_PREFIX) {
+        Some(rest) => {
+            let (checksum, payload) = rest.split_once('\n').ok_or("missing payload")?;
+            let expected = u32::from_str_radix(checksum, 16).map_err(|_| "malformed checksum")?;
+            if crc32fast::hash(payload.as_bytes()) != expected {
+                return Err("checksum mismatch".into());
+            }
+            payload
+        }
+        None => text,
+    };
+    ron::from_str(payload).map_err(|err| err.to_string())
+}
+
+fn read<T: DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let bytes = fs::read(path).map_err(|err| err.to_string())?;
+    let text = String::from_utf8(bytes).map_err(|_| "not UTF-8".to_string())?;
+    decode(&text)
+}
+
+/// Writes `value` to `path` atomically, moving the previous good file to the backup first
+pub fn save<T: Serialize>(path: impl AsRef<Path>, value: &T) -> io::Result<()> {
+    let path = path.as_ref();
+    let payload = ron::to_string(value).map_err(io::Error::other)?;
+
+    // Only a file that still verifies is worth keeping as the backup
+    if let Ok(text) = fs::read_to_string(path) {
+        if decode::<ron::Value>(&text).is_ok() {
+            fs::copy(path, backup_path(path))?;
+        }
+    }
+
+    let temp = temp_path(path);
+    let mut file = File::create(&temp)?;
+    file.write_all(encode(&payload).as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&temp, path)
+}
+
+/// Loads `path`, falling back to its backup. When both fail the broken file is
+/// renamed to `<file>.corrupt-<unix time>` so a fresh start doesn't overwrite it.
+pub fn load<T: DeserializeOwned>(path: impl AsRef<Path>) -> LoadResult<T> {
+    let path = path.as_ref();
+    if !path.exists() && !backup_path(path).exists() {
+        return LoadResult::Missing;
+    }
+
+    let err = match read(path) {
+        Ok(value) => return LoadResult::Loaded(value),
+        Err(err) => err,
+    };
+    match read(&backup_path(path)) {
+        Ok(value) => {
+            warn!("{} is unreadable ({}), loaded the backup", path.display(), err);
+            LoadResult::Recovered(value)
+        }
+        Err(_) => {
+            let preserved = preserve_corrupt(path);
+            match &preserved {
+                Some(corrupt) => warn!(
+                    "{} is unreadable ({}) and has no usable backup; kept it as {} and starting fresh",
+                    path.display(),
+                    err,
+                    corrupt.display()
+                ),
+                None => warn!("{} has no usable backup; starting fresh", path.display()),
+            }
+            LoadResult::Fresh { preserved }
+        }
+    }
+}
+
+fn preserve_corrupt(path: &Path) -> Option<PathBuf> {
+    if !path.exists() {
+        return None;
+    }
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let corrupt = sibling(path, &format!(".corrupt-{}", timestamp));
+    fs::rename(path, &corrupt).ok().map(|_| corrupt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        kills: u64,
+        name: String,
+    }
+
+    fn record(kills: u64) -> Record {
+        Record {
+            kills,
+            name: "Pyro".into(),
+        }
+    }
+
+    /// Empty scratch directory for one test
+    fn scratch(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("crimson-persist-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn round_trips_and_keeps_the_previous_save_as_backup() {
+        let dir = scratch("round-trip");
+        let path = dir.join("profile.ron");
+        assert_eq!(load::<Record>(&path), LoadResult::Missing);
+
+        save(&path, &record(1)).unwrap();
+        save(&path, &record(2)).unwrap();
+        assert_eq!(load(&path), LoadResult::Loaded(record(2)));
+        assert_eq!(read::<Record>(&backup_path(&path)), Ok(record(1)));
+        // The temp file is renamed away, not left behind
+        assert!(!temp_path(&path).exists());
+    }
+
+    #[test]
+    fn truncated_file_falls_back_to_backup() {
+        let dir = scratch("truncate");
+        let path = dir.join("profile.ron");
+        save(&path, &record(1)).unwrap();
+        save(&path, &record(2)).unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        fs::write(&path, &text[..text.len() / 2]).unwrap();
+        assert_eq!(load(&path), LoadResult::Recovered(record(1)));
+    }
+
+    #[test]
+    fn bit_flip_fails_the_checksum() {
+        let dir = scratch("bit-flip");
+        let path = dir.join("profile.ron");
+        save(&path, &record(1)).unwrap();
+        save(&path, &record(12)).unwrap();
+
+        // Flip a digit so the payload still parses but no longer matches
+        let mut bytes = fs::read(&path).unwrap();
+        let digit = bytes.iter().rposition(|&b| b == b'2').unwrap();
+        bytes[digit] ^= 0x01;
+        fs::write(&path, bytes).unwrap();
+        assert_eq!(load(&path), LoadResult::Recovered(record(1)));
+    }
+
+    #[test]
+    fn corrupt_backup_is_never_rotated_in() {
+        let dir = scratch("rotate");
+        let path = dir.join("profile.ron");
+        save(&path, &record(1)).unwrap();
+        save(&path, &record(2)).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        fs::write(&path, &text[..text.len() - 3]).unwrap();
+
+        save(&path, &record(3)).unwrap();
+        assert_eq!(read::<Record>(&backup_path(&path)), Ok(record(1)));
+    }
+
+    #[test]
+    fn fresh_start_preserves_the_corrupt_file() {
+        let dir = scratch("fresh");
+        let path = dir.join("profile.ron");
+        fs::write(&path, "crc32:00000000\n(kills: 1").unwrap();
+
+        let LoadResult::Fresh { preserved: Some(corrupt) } = load::<Record>(&path) else {
+            panic!("expected a fresh start");
+        };
+        assert!(!path.exists());
+        assert_eq!(fs::read_to_string(corrupt).unwrap(), "crc32:00000000\n(kills: 1");
+    }
+
+    #[test]
+    fn files_from_before_checksums_still_load() {
+        let dir = scratch("legacy");
+        let path = dir.join("profile.ron");
+        fs::write(&path, "(kills: 42, name: \"Pyro\")").unwrap();
+        assert_eq!(load(&path), LoadResult::Loaded(record(42)));
+    }
+}
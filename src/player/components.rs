@@ -14,11 +14,28 @@ pub struct Player {
 pub struct Health {
     pub current: f32,
     pub max: f32,
+    /// Max health before perks (ThickSkinned) scale it
+    pub base_max: f32,
 }
 
 impl Health {
     pub fn new(max: f32) -> Self {
-        Self { current: max, max }
+        Self {
+            current: max,
+            max,
+            base_max: max,
+        }
+    }
+
+    /// Sets max health to `multiplier` times the base, keeping the same fraction
+    /// of it filled. Does nothing when already there, so it can run every frame
+    pub fn scale_max(&mut self, multiplier: f32) {
+        let max = self.base_max * multiplier;
+        if (self.max - max).abs() > 0.01 {
+            let fraction = self.percentage();
+            self.max = max;
+            self.current = max * fraction;
+        }
     }
 
     pub fn heal(&mut self, amount: f32) {
@@ -65,17 +82,35 @@ impl Experience {
         }
     }
 
-    /// Add experience and return true if leveled up
-    pub fn add(&mut self, amount: u32) -> bool {
+    /// Add experience, levelling up as many times as it covers. Returns levels gained
+    pub fn add_all(&mut self, amount: u32) -> u32 {
         self.current += amount;
-        if self.current >= self.to_next_level {
+        let mut levels = 0;
+        while self.current >= self.to_next_level {
+            self.level_up();
+            levels += 1;
+        }
+        levels
+    }
+
+    /// Level up `levels` times without touching the progress toward the next level
+    pub fn add_levels(&mut self, levels: u32) {
+        for _ in 0..levels {
+            self.current += self.to_next_level;
             self.level_up();
-            true
-        } else {
-            false
         }
     }
 
+    /// Take `amount` from the progress toward the next level. Returns false, taking
+    /// nothing, when there isn't that much
+    pub fn spend(&mut self, amount: u32) -> bool {
+        if self.current < amount {
+            return false;
+        }
+        self.current -= amount;
+        true
+    }
+
     fn level_up(&mut self) {
         self.current -= self.to_next_level;
         self.level += 1;
@@ -115,6 +150,8 @@ pub struct AimDirection {
     pub direction: Vec2,
     /// Angle in radians
     pub angle: f32,
+    /// World position under the cursor, when aiming with the mouse
+    pub target: Option<Vec2>,
 }
 
 impl AimDirection {
@@ -123,6 +160,7 @@ impl AimDirection {
         Self {
             direction: normalized,
             angle: normalized.y.atan2(normalized.x),
+            target: None,
         }
     }
 
@@ -130,7 +168,27 @@ impl AimDirection {
         Self {
             direction: Vec2::new(angle.cos(), angle.sin()),
             angle,
+            target: None,
+        }
+    }
+}
+
+/// How long the player has been standing still (ManBomb)
+#[derive(Component, Debug, Clone, Default)]
+pub struct StationaryTimer {
+    pub seconds: f32,
+    last_position: Option<Vec2>,
+}
+
+impl StationaryTimer {
+    /// Counts up while the position holds and resets on any movement
+    pub fn update(&mut self, position: Vec2, delta: f32) {
+        if self.last_position.is_some_and(|last| last.distance_squared(position) < 0.01) {
+            self.seconds += delta;
+        } else {
+            self.seconds = 0.0;
         }
+        self.last_position = Some(position);
     }
 }
 
@@ -139,6 +197,8 @@ impl AimDirection {
 pub struct Firing {
     pub is_firing: bool,
     pub cooldown_timer: f32,
+    /// is_firing as the reload system last saw it, so AnxiousLoader only counts fresh pulls
+    pub was_firing: bool,
 }
 
 impl Default for Firing {
@@ -146,6 +206,7 @@ impl Default for Firing {
         Self {
             is_firing: false,
             cooldown_timer: 0.0,
+            was_firing: false,
         }
     }
 }
@@ -242,7 +303,7 @@ mod tests {
     #[test]
     fn experience_add_accumulates() {
         let mut exp = Experience::new();
-        exp.add(50);
+        assert_eq!(exp.add_all(50), 0);
         assert_eq!(exp.current, 50);
         assert_eq!(exp.level, 1);
     }
@@ -250,19 +311,28 @@ mod tests {
     #[test]
     fn experience_level_up_on_threshold() {
         let mut exp = Experience::new();
-        let leveled_up = exp.add(100);
-        assert!(leveled_up);
+        assert_eq!(exp.add_all(100), 1);
         assert_eq!(exp.level, 2);
     }
 
     #[test]
     fn experience_carries_over_excess() {
         let mut exp = Experience::new();
-        exp.add(120); // 100 needed, 20 carries over
+        exp.add_all(120); // 100 needed, 20 carries over
         assert_eq!(exp.level, 2);
         assert_eq!(exp.current, 20);
     }
 
+    #[test]
+    fn large_experience_gains_cascade_through_levels() {
+        let mut exp = Experience::new();
+        // 100 + 120 + 144 to reach level 4, 36 left over
+        assert_eq!(exp.add_all(400), 3);
+        assert_eq!(exp.level, 4);
+        assert_eq!(exp.current, 36);
+        assert_eq!(exp.to_next_level, 172);
+    }
+
     #[test]
     fn aim_direction_from_angle() {
         let aim = AimDirection::from_angle(0.0);
@@ -270,6 +340,20 @@ mod tests {
         assert!(aim.direction.y.abs() < 0.001);
     }
 
+    #[test]
+    fn stationary_timer_resets_on_movement() {
+        let mut timer = StationaryTimer::default();
+        timer.update(Vec2::ZERO, 0.5);
+        timer.update(Vec2::ZERO, 0.5);
+        timer.update(Vec2::ZERO, 0.5);
+        assert_eq!(timer.seconds, 1.0);
+
+        timer.update(Vec2::new(3.0, 0.0), 0.5);
+        assert_eq!(timer.seconds, 0.0);
+        timer.update(Vec2::new(3.0, 0.0), 0.5);
+        assert_eq!(timer.seconds, 0.5);
+    }
+
     #[test]
     fn invincibility_ticks_down() {
         let mut inv = Invincibility::new(1.0);
@@ -12,6 +12,7 @@ pub use systems::*;
 
 use bevy::prelude::*;
 
+use crate::schedule::GameSet;
 use crate::states::GameState;
 
 /// Plugin for player-related functionality
@@ -22,21 +23,34 @@ impl Plugin for PlayerPlugin {
         app.init_resource::<PlayerConfig>()
             .init_resource::<PlayerInputMapping>()
             .add_event::<PlayerDamageEvent>()
+            .add_event::<PlayerHitBlockedEvent>()
+            .add_event::<PlayerHitLandedEvent>()
             .add_event::<PlayerDeathEvent>()
             .add_event::<PlayerLevelUpEvent>()
+            .add_event::<GrantXpEvent>()
             .add_systems(OnEnter(GameState::Playing), spawn_player)
             .add_systems(OnExit(GameState::Playing), despawn_players)
             .add_systems(
                 Update,
                 (
-                    player_movement,
-                    player_aim,
-                    player_shooting,
-                    apply_player_damage,
-                    check_player_death,
-                    update_player_experience,
-                    player_invincibility_timer,
-                    grant_experience_on_kill,
+                    (
+                        player_movement,
+                        player_aim,
+                        player_shooting,
+                        grant_alternate_weapon_slot,
+                        swap_weapons,
+                    )
+                        .in_set(GameSet::Input),
+                    (player_invincibility_timer, update_stationary_timers)
+                        .in_set(GameSet::Simulation),
+                    // Runs after pickups, so shields picked up this frame already count
+                    apply_player_damage
+                        .after(crate::weapons::apply_explosion_damage)
+                        .in_set(GameSet::DamageResolution),
+                    check_player_death.in_set(GameSet::DeathResolution),
+                    (grant_experience_on_kill, apply_experience_grants)
+                        .chain()
+                        .in_set(GameSet::Consequences),
                 )
                     .run_if(in_state(GameState::Playing)),
             );
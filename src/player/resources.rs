@@ -3,6 +3,8 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::start_kit::SurvivalStartKit;
+
 /// Configuration for player behavior
 #[derive(Resource, Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerConfig {
@@ -14,8 +16,10 @@ pub struct PlayerConfig {
     pub damage_invincibility_duration: f32,
     /// Invincibility duration after spawning
     pub spawn_invincibility_duration: f32,
-    /// Base experience per kill multiplier
+    /// Base multiplier on every experience grant
     pub exp_multiplier: f32,
+    /// What survival runs start with
+    pub start_kit: SurvivalStartKit,
 }
 
 impl Default for PlayerConfig {
@@ -26,6 +30,7 @@ impl Default for PlayerConfig {
             damage_invincibility_duration: 0.5,
             spawn_invincibility_duration: 2.0,
             exp_multiplier: 1.0,
+            start_kit: SurvivalStartKit::default(),
         }
     }
 }
@@ -40,6 +45,12 @@ pub struct PlayerInputMapping {
     pub fire: MouseButton,
     pub reload: KeyCode,
     pub use_item: KeyCode,
+    /// Swaps to the stowed weapon (AlternateWeapon perk)
+    pub swap_weapon: KeyCode,
+    /// Held to show the perk and weapon inspect panel
+    pub inspect: KeyCode,
+    /// Cycles the HUD layout mid-run
+    pub cycle_hud_layout: KeyCode,
 }
 
 impl Default for PlayerInputMapping {
@@ -52,6 +63,9 @@ impl Default for PlayerInputMapping {
             fire: MouseButton::Left,
             reload: KeyCode::KeyR,
             use_item: KeyCode::Space,
+            swap_weapon: KeyCode::KeyQ,
+            inspect: KeyCode::Tab,
+            cycle_hud_layout: KeyCode::F2,
         }
     }
 }
@@ -6,12 +6,16 @@ use rand::Rng;
 
 use super::components::*;
 use super::resources::*;
+use crate::assists::AssistedAim;
+use crate::audio::{PlaySoundEvent, SoundEffect};
 use crate::bonuses::ActiveBonusEffects;
 use crate::creatures::CreatureDeathEvent;
 use crate::items::CarriedItem;
-use crate::perks::{PerkBonuses, PerkInventory};
-use crate::states::{GameState, PlayingState};
-use crate::weapons::EquippedWeapon;
+use crate::perks::{PerkBonuses, PerkInventory, RunnerMomentum};
+use crate::render_layers::PLAYER;
+use crate::states::GameState;
+use crate::viewport::ArenaBounds;
+use crate::weapons::{AlternateWeaponSlot, EquippedWeapon, SpreadBloom, WeaponRegistry};
 
 /// Event fired when a player takes damage
 #[derive(Event)]
@@ -19,6 +23,24 @@ pub struct PlayerDamageEvent {
     pub player_entity: Entity,
     pub damage: f32,
     pub source: Option<Entity>,
+    /// Ignores invincibility, shield and dodge (self-inflicted damage)
+    pub unavoidable: bool,
+}
+
+/// Event fired when a hit is negated by invincibility or a shield
+#[derive(Event)]
+pub struct PlayerHitBlockedEvent {
+    pub player_entity: Entity,
+    pub source: Option<Entity>,
+}
+
+/// Event fired when a hit gets through and reduces health
+#[derive(Event)]
+pub struct PlayerHitLandedEvent {
+    pub player_entity: Entity,
+    pub source: Option<Entity>,
+    /// Health lost, after damage reduction
+    pub damage: f32,
 }
 
 /// Event fired when a player dies
@@ -34,6 +56,30 @@ pub struct PlayerLevelUpEvent {
     pub new_level: u32,
 }
 
+/// Where a grant of experience came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XpSource {
+    Kill,
+    Bonus,
+    Passive,
+    /// One-shot perks (InstantWinner, GrimDeal, FatalLottery)
+    Perk,
+}
+
+/// Experience for a player, before multipliers. Every XP gain goes through this
+/// so exp_multiplier applies the same way to all of them
+#[derive(Event, Debug, Clone)]
+pub struct GrantXpEvent {
+    pub player_entity: Entity,
+    pub amount: u32,
+    pub source: XpSource,
+}
+
+/// Experience a grant is worth after the perk and config multipliers
+pub fn scaled_experience(amount: u32, perk_bonuses: &PerkBonuses, config: &PlayerConfig) -> u32 {
+    (amount as f32 * perk_bonuses.exp_multiplier * config.exp_multiplier).round() as u32
+}
+
 /// Spawns the player entity when entering Playing state
 pub fn spawn_player(mut commands: Commands, config: Res<PlayerConfig>) {
     // Player index for multiplayer support (0 = first player)
@@ -63,12 +109,15 @@ pub fn spawn_player(mut commands: Commands, config: Res<PlayerConfig>) {
                     custom_size: Some(Vec2::new(32.0, 32.0)),
                     ..default()
                 },
-                transform: Transform::from_translation(Vec3::ZERO),
+                transform: Transform::from_translation(Vec3::new(0.0, 0.0, PLAYER)),
                 ..default()
             },
         },
         Invincibility::new(config.spawn_invincibility_duration),
+        AssistedAim::default(),
         EquippedWeapon::default(),
+        SpreadBloom::default(),
+        StationaryTimer::default(),
         // Perk system components
         PerkInventory::new(),
         PerkBonuses::default(),
@@ -86,14 +135,16 @@ pub fn despawn_players(mut commands: Commands, query: Query<Entity, With<Player>
     }
 }
 
-/// Handles player movement input
+/// Handles player movement input, keeping the player inside the arena.
+/// LongDistanceRunner momentum multiplies the move speed
 pub fn player_movement(
     keyboard: Res<ButtonInput<KeyCode>>,
     input_mapping: Res<PlayerInputMapping>,
     time: Res<Time>,
-    mut query: Query<(&mut Transform, &MoveSpeed), With<Player>>,
+    arena: Res<ArenaBounds>,
+    mut query: Query<(&mut Transform, &MoveSpeed, Option<&RunnerMomentum>), With<Player>>,
 ) {
-    for (mut transform, speed) in query.iter_mut() {
+    for (mut transform, speed, momentum) in query.iter_mut() {
         let mut direction = Vec2::ZERO;
 
         // Use input mapping for customizable keybindings, with arrow key fallbacks
@@ -112,8 +163,11 @@ pub fn player_movement(
 
         if direction != Vec2::ZERO {
             direction = direction.normalize();
-            transform.translation.x += direction.x * speed.0 * time.delta_seconds();
-            transform.translation.y += direction.y * speed.0 * time.delta_seconds();
+            let momentum = momentum.map_or(1.0, |momentum| momentum.multiplier);
+            let moved = transform.translation.truncate() + direction * speed.0 * momentum * time.delta_seconds();
+            let clamped = arena.clamp(moved);
+            transform.translation.x = clamped.x;
+            transform.translation.y = clamped.y;
         }
     }
 }
@@ -146,6 +200,7 @@ pub fn player_aim(
         if direction.length_squared() > 0.01 {
             *aim = AimDirection::from_direction(direction);
         }
+        aim.target = Some(world_position);
     }
 }
 
@@ -155,6 +210,7 @@ pub fn player_shooting(
     keyboard: Res<ButtonInput<KeyCode>>,
     input_mapping: Res<PlayerInputMapping>,
     time: Res<Time>,
+    weapon_registry: Res<WeaponRegistry>,
     mut query: Query<(&mut Firing, &mut EquippedWeapon), With<Player>>,
 ) {
     for (mut firing, mut weapon) in query.iter_mut() {
@@ -162,17 +218,50 @@ pub fn player_shooting(
         firing.is_firing = mouse.pressed(input_mapping.fire);
         firing.cooldown_timer = (firing.cooldown_timer - time.delta_seconds()).max(0.0);
 
-        // Handle reload input (2 second base reload time)
-        if keyboard.just_pressed(input_mapping.reload) && !weapon.is_reloading() {
-            weapon.start_reload(2.0);
+        // Manual reload tops up a partly used clip at the weapon's reload time
+        if keyboard.just_pressed(input_mapping.reload) {
+            if let Some(data) = weapon_registry.get_key(&weapon.weapon) {
+                weapon.start_reload(data.reload_time);
+            }
         }
         // Item use is handled by the items system (handle_item_use)
     }
 }
 
-/// Applies damage to players from damage events
-/// Integrates perk bonuses: damage_reduction reduces incoming damage, dodge_chance can avoid hits entirely
-/// Also respects ActiveBonusEffects: invincibility and shield
+/// Gives players who took AlternateWeapon their second (empty) slot
+#[allow(clippy::type_complexity)]
+pub fn grant_alternate_weapon_slot(
+    mut commands: Commands,
+    query: Query<(Entity, &PerkBonuses), (With<Player>, Without<AlternateWeaponSlot>)>,
+) {
+    for (entity, bonuses) in query.iter() {
+        if bonuses.alternate_weapon {
+            commands.entity(entity).insert(AlternateWeaponSlot::default());
+        }
+    }
+}
+
+/// Swaps the equipped and stowed weapons on the swap key
+pub fn swap_weapons(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<PlayerInputMapping>,
+    mut query: Query<(&mut EquippedWeapon, &mut AlternateWeaponSlot), With<Player>>,
+) {
+    if !keyboard.just_pressed(input_mapping.swap_weapon) {
+        return;
+    }
+    for (mut weapon, mut slot) in query.iter_mut() {
+        slot.swap(&mut weapon);
+    }
+}
+
+/// Applies damage to players from damage events.
+/// This is the only place player health is reduced, so invincibility and shields are
+/// checked when damage lands rather than when it was queued. Anything a hit carries
+/// besides damage (knockback, slows) belongs here too, after the block checks.
+/// Integrates perk bonuses: damage_taken_multiplier (ThickSkinned) scales incoming damage,
+/// reload_damage_multiplier (ToughReloader) too while reloading, and dodge_chance can avoid hits entirely
+#[allow(clippy::type_complexity)]
 pub fn apply_player_damage(
     mut events: EventReader<PlayerDamageEvent>,
     mut query: Query<
@@ -182,40 +271,64 @@ pub fn apply_player_damage(
             Option<&mut Invincibility>,
             &PerkBonuses,
             &ActiveBonusEffects,
+            Option<&EquippedWeapon>,
         ),
     >,
     config: Res<PlayerConfig>,
     mut commands: Commands,
+    mut blocked_events: EventWriter<PlayerHitBlockedEvent>,
+    mut landed_events: EventWriter<PlayerHitLandedEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
 ) {
     let mut rng = rand::thread_rng();
 
     for event in events.read() {
-        if let Ok((player, mut health, invincibility, perk_bonuses, bonus_effects)) =
+        if let Ok((player, mut health, invincibility, perk_bonuses, bonus_effects, weapon)) =
             query.get_mut(event.player_entity)
         {
-            // Skip if invincible (perk or pickup)
-            if let Some(inv) = &invincibility {
-                if inv.is_active() {
-                    continue;
-                }
-            }
-            if bonus_effects.has_invincibility() {
+            // DeathClock: immune to every hit, the drain is the only way to die
+            if perk_bonuses.death_clock {
+                blocked_events.send(PlayerHitBlockedEvent {
+                    player_entity: event.player_entity,
+                    source: event.source,
+                });
                 continue;
             }
 
-            // Shield absorbs damage completely
-            if bonus_effects.has_shield() {
-                continue;
-            }
+            if !event.unavoidable {
+                // Invincibility (perk, pickup or post-hit) and shields negate the whole hit
+                let invincible = invincibility.as_ref().is_some_and(|inv| inv.is_active())
+                    || bonus_effects.has_invincibility()
+                    || bonus_effects.has_shield();
+                if invincible {
+                    blocked_events.send(PlayerHitBlockedEvent {
+                        player_entity: event.player_entity,
+                        source: event.source,
+                    });
+                    continue;
+                }
 
-            // Dodge check - chance to completely avoid damage (Dodger perk)
-            if perk_bonuses.dodge_chance > 0.0 && rng.gen::<f32>() < perk_bonuses.dodge_chance {
-                continue; // Dodged!
+                // Dodge check - chance to completely avoid damage (Dodger perk)
+                if perk_bonuses.dodge_chance > 0.0 && rng.gen::<f32>() < perk_bonuses.dodge_chance {
+                    continue; // Dodged!
+                }
             }
 
-            // Apply damage reduction (ThickSkin perk)
-            let reduced_damage = event.damage * (1.0 - perk_bonuses.damage_reduction);
+            // Apply damage reduction (ThickSkinned, ToughReloader)
+            let mut reduced_damage = event.damage * perk_bonuses.damage_taken_multiplier;
+            if weapon.is_some_and(EquippedWeapon::is_reloading) {
+                reduced_damage *= perk_bonuses.reload_damage_multiplier;
+            }
             health.damage(reduced_damage);
+            landed_events.send(PlayerHitLandedEvent {
+                player_entity: event.player_entity,
+                source: event.source,
+                damage: reduced_damage,
+            });
+            sound_events.send(PlaySoundEvent {
+                sound: SoundEffect::PlayerHurt,
+                position: None,
+            });
 
             // Log damage for multiplayer support (uses player.index)
             info!("Player {} took {:.1} damage (reduced from {:.1})",
@@ -245,12 +358,11 @@ pub fn check_player_death(
     }
 }
 
-/// Updates player experience display (level ups are handled by grant_experience_on_kill)
-pub fn update_player_experience(
-    _query: Query<(Entity, &Experience), With<Player>>,
-) {
-    // Experience updates and level ups are handled by grant_experience_on_kill
-    // This system exists for potential future UI updates or experience decay mechanics
+/// Tracks how long each player has been standing still
+pub fn update_stationary_timers(time: Res<Time>, mut query: Query<(&Transform, &mut StationaryTimer), With<Player>>) {
+    for (transform, mut timer) in query.iter_mut() {
+        timer.update(transform.translation.truncate(), time.delta_seconds());
+    }
 }
 
 /// Ticks down invincibility timers
@@ -261,34 +373,292 @@ pub fn player_invincibility_timer(time: Res<Time>, mut query: Query<&mut Invinci
 }
 
 /// Grants experience to players when creatures die
-/// Applies exp_multiplier from perks (FastLearner)
 pub fn grant_experience_on_kill(
     mut death_events: EventReader<CreatureDeathEvent>,
-    mut player_query: Query<(Entity, &mut Experience, &PerkBonuses), With<Player>>,
-    mut level_up_events: EventWriter<PlayerLevelUpEvent>,
-    mut next_state: ResMut<NextState<PlayingState>>,
+    player_query: Query<Entity, With<Player>>,
+    mut xp_events: EventWriter<GrantXpEvent>,
 ) {
     for event in death_events.read() {
         // Grant experience to all players (for potential multiplayer support)
-        for (player_entity, mut exp, perk_bonuses) in player_query.iter_mut() {
-            // Apply exp multiplier from FastLearner perk
-            let exp_amount = (event.experience as f32 * perk_bonuses.exp_multiplier) as u32;
-            let leveled_up = exp.add(exp_amount);
-
-            if leveled_up {
-                level_up_events.send(PlayerLevelUpEvent {
-                    player_entity,
-                    new_level: exp.level,
-                });
-                next_state.set(PlayingState::PerkSelect);
-            }
+        for player_entity in player_query.iter() {
+            xp_events.send(GrantXpEvent {
+                player_entity,
+                amount: event.experience,
+                source: XpSource::Kill,
+            });
         }
     }
 }
 
+/// Adds granted experience, scaled by exp_multiplier (BloodyMess) and the config's
+/// base multiplier. Every level gained sends a PlayerLevelUpEvent, which the perks
+/// module queues up as perk selections
+pub fn apply_experience_grants(
+    mut xp_events: EventReader<GrantXpEvent>,
+    config: Res<PlayerConfig>,
+    mut player_query: Query<(&mut Experience, &PerkBonuses), With<Player>>,
+    mut level_up_events: EventWriter<PlayerLevelUpEvent>,
+) {
+    for event in xp_events.read() {
+        let Ok((mut exp, perk_bonuses)) = player_query.get_mut(event.player_entity) else {
+            continue;
+        };
+        let amount = scaled_experience(event.amount, perk_bonuses, &config);
+        debug!("Player gained {} XP ({:?})", amount, event.source);
+        let levels = exp.add_all(amount);
+        let first_level = exp.level - levels + 1;
+        level_up_events.send_batch((first_level..=exp.level).map(|new_level| PlayerLevelUpEvent {
+            player_entity: event.player_entity,
+            new_level,
+        }));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bonuses::{apply_bonus_effects, bonus_collection, Bonus, BonusCollectedEvent, BonusType};
+    use crate::creatures::{creature_attack, AIState, AttackStats, ContactDamage, Creature, CreatureType, WorldTimeScale};
+    use crate::effects::{spawn_whiff_effect, EffectType, SpawnEffectEvent};
+    use crate::weapons::WeaponRegistry;
+
+    fn damage_test_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<PlayerConfig>()
+            .add_event::<PlayerDamageEvent>()
+            .add_event::<PlayerHitBlockedEvent>()
+            .add_event::<PlayerHitLandedEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<crate::creatures::CreatureTouchedPlayerEvent>();
+        app
+    }
+
+    fn spawn_test_player(app: &mut App, effects: ActiveBonusEffects) -> Entity {
+        app.world_mut()
+            .spawn((
+                Player { index: 0 },
+                Health::new(100.0),
+                Experience::new(),
+                EquippedWeapon::default(),
+                PerkBonuses::default(),
+                effects,
+                Transform::default(),
+            ))
+            .id()
+    }
+
+    fn sent_sounds(app: &App) -> Vec<SoundEffect> {
+        let events = app.world().resource::<Events<PlaySoundEvent>>();
+        events.get_reader().read(events).map(|e| e.sound).collect()
+    }
+
+    #[test]
+    fn shield_picked_up_in_the_same_frame_blocks_damage() {
+        let mut app = damage_test_app();
+        app.init_resource::<WeaponRegistry>()
+            .add_event::<BonusCollectedEvent>()
+            .add_event::<crate::weapons::WeaponGrantedEvent>()
+            .add_event::<GrantXpEvent>()
+            // Registered before the pickup systems on purpose; ordering must come from `after`
+            .add_systems(Update, apply_player_damage.after(apply_bonus_effects))
+            .add_systems(Update, (bonus_collection, apply_bonus_effects).chain());
+
+        let player = spawn_test_player(&mut app, ActiveBonusEffects::default());
+        app.world_mut().spawn((
+            Bonus {
+                bonus_type: BonusType::Shield,
+            },
+            Transform::default(),
+        ));
+        app.world_mut().send_event(PlayerDamageEvent {
+            player_entity: player,
+            damage: 30.0,
+            source: None,
+            unavoidable: false,
+        });
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(player).unwrap().current, 100.0);
+        assert!(app.world().get::<ActiveBonusEffects>(player).unwrap().has_shield());
+    }
+
+    #[test]
+    fn thick_skinned_and_tough_reloader_scale_incoming_damage() {
+        let mut app = damage_test_app();
+        app.add_systems(Update, apply_player_damage);
+        let player = spawn_test_player(&mut app, ActiveBonusEffects::default());
+        *app.world_mut().get_mut::<PerkBonuses>(player).unwrap() = PerkBonuses {
+            damage_taken_multiplier: 2.0 / 3.0,
+            reload_damage_multiplier: 0.5,
+            ..default()
+        };
+        let hit = |app: &mut App| {
+            app.world_mut().send_event(PlayerDamageEvent {
+                player_entity: player,
+                damage: 30.0,
+                source: None,
+                unavoidable: true,
+            });
+            app.update();
+            app.world().get::<Health>(player).unwrap().current
+        };
+
+        assert!((hit(&mut app) - 80.0).abs() < 1e-3);
+        app.world_mut().get_mut::<EquippedWeapon>(player).unwrap().reload_timer = 1.0;
+        assert!((hit(&mut app) - 70.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn bloody_mess_scales_kill_experience() {
+        use crate::perks::PerkId;
+
+        let mut app = App::new();
+        app.init_resource::<PlayerConfig>()
+            .add_event::<CreatureDeathEvent>()
+            .add_event::<GrantXpEvent>()
+            .add_event::<PlayerLevelUpEvent>()
+            .add_systems(Update, (grant_experience_on_kill, apply_experience_grants).chain());
+        let mut inventory = PerkInventory::new();
+        inventory.add_perk(PerkId::BloodyMess);
+        let mut exp = Experience::new();
+        exp.to_next_level = 1000;
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), exp, PerkBonuses::calculate(&inventory)))
+            .id();
+
+        app.world_mut().send_event(CreatureDeathEvent {
+            entity: Entity::PLACEHOLDER,
+            creature_type: CreatureType::Zombie,
+            position: Vec3::ZERO,
+            experience: 100,
+            killed_by: None,
+        });
+        app.update();
+        assert_eq!(app.world().get::<Experience>(player).unwrap().current, 130);
+    }
+
+    #[test]
+    fn big_grants_send_a_level_up_per_level() {
+        let mut app = App::new();
+        app.init_resource::<PlayerConfig>()
+            .add_event::<GrantXpEvent>()
+            .add_event::<PlayerLevelUpEvent>()
+            .add_systems(Update, apply_experience_grants);
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), Experience::new(), PerkBonuses::default()))
+            .id();
+
+        // 100 + 120 to reach level 3
+        app.world_mut().send_event(GrantXpEvent {
+            player_entity: player,
+            amount: 230,
+            source: XpSource::Bonus,
+        });
+        app.update();
+        let events = app.world().resource::<Events<PlayerLevelUpEvent>>();
+        let levels: Vec<u32> = events.get_reader().read(events).map(|e| e.new_level).collect();
+        assert_eq!(levels, vec![2, 3]);
+    }
+
+    #[test]
+    fn death_clock_ignores_every_hit() {
+        let mut app = damage_test_app();
+        app.add_systems(Update, apply_player_damage);
+        let player = spawn_test_player(&mut app, ActiveBonusEffects::default());
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().death_clock = true;
+
+        for unavoidable in [false, true] {
+            app.world_mut().send_event(PlayerDamageEvent {
+                player_entity: player,
+                damage: 30.0,
+                source: None,
+                unavoidable,
+            });
+            app.update();
+        }
+        assert_eq!(app.world().get::<Health>(player).unwrap().current, 100.0);
+        assert!(app.world().get::<Invincibility>(player).is_none());
+    }
+
+    #[test]
+    fn giant_strike_into_fresh_shield_whiffs() {
+        let mut app = damage_test_app();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<WorldTimeScale>()
+            .add_systems(
+                Update,
+                (creature_attack, apply_player_damage, spawn_whiff_effect).chain(),
+            );
+
+        let shield = BonusType::Shield.duration().unwrap();
+        let player = spawn_test_player(
+            &mut app,
+            ActiveBonusEffects {
+                shield_timer: shield - 0.1,
+                ..default()
+            },
+        );
+        let giant = app
+            .world_mut()
+            .spawn((
+                Creature {
+                    creature_type: CreatureType::Giant,
+                },
+                AIState::default(),
+                ContactDamage(CreatureType::Giant.base_damage()),
+                AttackStats {
+                    windup: 0.0,
+                    ..AttackStats::for_type(CreatureType::Giant)
+                },
+                Transform::from_xyz(20.0, 0.0, 0.0),
+            ))
+            .id();
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(player).unwrap().current, 100.0);
+        assert!(app.world().get::<AIState>(giant).unwrap().attack_cooldown > 0.0);
+
+        let sounds = sent_sounds(&app);
+        assert!(sounds.contains(&SoundEffect::AttackWhiff));
+        assert!(!sounds.contains(&SoundEffect::PlayerHurt));
+
+        let effects = app.world().resource::<Events<SpawnEffectEvent>>();
+        let whiffs: Vec<Vec2> = effects
+            .get_reader()
+            .read(effects)
+            .filter(|e| e.effect_type == EffectType::Whiff)
+            .map(|e| e.position.truncate())
+            .collect();
+        assert_eq!(whiffs, vec![Vec2::new(20.0, 0.0)]);
+    }
+
+    #[test]
+    fn unavoidable_damage_ignores_shield() {
+        let mut app = damage_test_app();
+        app.add_systems(Update, apply_player_damage);
+
+        let player = spawn_test_player(
+            &mut app,
+            ActiveBonusEffects {
+                shield_timer: 10.0,
+                invincibility_timer: 10.0,
+                ..default()
+            },
+        );
+        app.world_mut().send_event(PlayerDamageEvent {
+            player_entity: player,
+            damage: 10.0,
+            source: None,
+            unavoidable: true,
+        });
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(player).unwrap().current, 90.0);
+        assert_eq!(sent_sounds(&app), vec![SoundEffect::PlayerHurt]);
+    }
 
     #[test]
     fn player_damage_event_can_be_created() {
@@ -296,6 +666,7 @@ mod tests {
             player_entity: Entity::PLACEHOLDER,
             damage: 10.0,
             source: None,
+            unavoidable: false,
         };
         assert_eq!(event.damage, 10.0);
     }
@@ -316,4 +687,45 @@ mod tests {
         };
         assert_eq!(event.new_level, 5);
     }
+
+    #[test]
+    fn alternate_weapon_stows_the_old_gun_and_swaps_on_key() {
+        use crate::weapons::{WeaponGrantedEvent, WeaponId};
+
+        let mut app = App::new();
+        app.init_resource::<WeaponRegistry>()
+            .init_resource::<PlayerInputMapping>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_event::<BonusCollectedEvent>()
+            .add_event::<WeaponGrantedEvent>()
+            .add_event::<GrantXpEvent>()
+            .add_systems(
+                Update,
+                (grant_alternate_weapon_slot, apply_bonus_effects, swap_weapons).chain(),
+            );
+        let player = spawn_test_player(&mut app, ActiveBonusEffects::default());
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().alternate_weapon = true;
+        app.update();
+        assert!(app.world().get::<AlternateWeaponSlot>(player).is_some());
+
+        app.world_mut().send_event(BonusCollectedEvent {
+            player_entity: player,
+            bonus_type: BonusType::WeaponPickup,
+            weapon: Some(WeaponId::Shotgun.into()),
+            amount: None,
+        });
+        app.update();
+        let stowed = app.world().get::<AlternateWeaponSlot>(player).unwrap().stowed.clone();
+        assert_eq!(stowed.unwrap().weapon, WeaponId::Pistol.into());
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyQ);
+        app.update();
+        assert_eq!(
+            app.world().get::<EquippedWeapon>(player).unwrap().weapon,
+            WeaponId::Pistol.into()
+        );
+    }
+
 }
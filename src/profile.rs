@@ -0,0 +1,221 @@
+//! Player profile
+//!
+//! Progress that outlives a single run: lifetime kills and the best rush score
+//! per loadout. Used to unlock rush loadouts.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::creatures::CreatureDeathEvent;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persist;
+use crate::quests::{QuestCompletionLog, QuestDatabase};
+use crate::rush::{RushState, UnlockProgress};
+use crate::start_kit::PityState;
+use crate::schedule::GameSet;
+use crate::states::GameState;
+
+#[cfg(not(target_arch = "wasm32"))]
+const PROFILE_FILE: &str = "profile.ron";
+
+/// Plugin for the persistent player profile
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerProfile>()
+            .add_systems(Startup, load_profile)
+            .add_systems(Update, save_profile)
+            .add_systems(OnExit(GameState::Playing), (record_rush_best, save_run_kills))
+            .add_systems(
+                Update,
+                count_lifetime_kills
+                    .run_if(in_state(GameState::Playing))
+                    .in_set(GameSet::Consequences),
+            );
+    }
+}
+
+/// Persistent progress across runs
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerProfile {
+    pub lifetime_kills: u64,
+    /// Best rush score keyed by loadout name
+    pub rush_best_scores: BTreeMap<String, u32>,
+    /// Unranked survival runs that ended in death
+    pub survival_deaths: u32,
+    /// Early-death streak for the survival start kit
+    pub pity: PityState,
+}
+
+impl PlayerProfile {
+    pub fn rush_best(&self, loadout: &str) -> Option<u32> {
+        self.rush_best_scores.get(loadout).copied()
+    }
+
+    /// Keeps the score if it beats the loadout's best. Returns true on a new best.
+    pub fn submit_rush_score(&mut self, loadout: &str, score: u32) -> bool {
+        if self.rush_best(loadout).is_some_and(|best| best >= score) {
+            return false;
+        }
+        self.rush_best_scores.insert(loadout.to_string(), score);
+        true
+    }
+
+    /// Progress for unlock checks; a chapter counts once all of its quests are completed
+    pub fn unlock_progress(&self, log: &QuestCompletionLog, db: &QuestDatabase) -> UnlockProgress {
+        let mut chapters: BTreeMap<u32, bool> = BTreeMap::new();
+        for quest in &db.quests {
+            *chapters.entry(quest.chapter).or_insert(true) &= log.is_completed(quest.id);
+        }
+        UnlockProgress {
+            completed_chapters: chapters
+                .into_iter()
+                .filter(|&(_, done)| done)
+                .map(|(chapter, _)| chapter)
+                .collect(),
+            lifetime_kills: self.lifetime_kills,
+        }
+    }
+}
+
+fn count_lifetime_kills(
+    mut death_events: EventReader<CreatureDeathEvent>,
+    mut profile: ResMut<PlayerProfile>,
+) {
+    let kills = death_events.read().count() as u64;
+    if kills > 0 {
+        // Saved once the run ends, not on every frame with a kill
+        profile.bypass_change_detection().lifetime_kills += kills;
+    }
+}
+
+/// Marks the profile changed so the kills counted during the run get saved
+fn save_run_kills(mut profile: ResMut<PlayerProfile>) {
+    profile.set_changed();
+}
+
+/// Stores the finished rush run's score as the loadout's best if it beats it
+fn record_rush_best(rush: Option<Res<RushState>>, mut profile: ResMut<PlayerProfile>) {
+    let Some(rush) = rush else { return };
+    if profile.submit_rush_score(&rush.loadout.name, rush.score) {
+        info!("New best for {}: {}", rush.loadout.name, rush.score);
+    }
+}
+
+/// Loads the saved profile on startup
+fn load_profile(mut profile: ResMut<PlayerProfile>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(loaded) = persist::load::<PlayerProfile>(PROFILE_FILE).into_value() {
+            *profile = loaded;
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &mut profile;
+}
+
+/// Persists the profile whenever it changes
+fn save_profile(profile: Res<PlayerProfile>) {
+    if !profile.is_changed() || profile.is_added() {
+        return;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(err) = persist::save(PROFILE_FILE, &*profile) {
+        warn!("Failed to save {}: {}", PROFILE_FILE, err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quests::QuestId;
+
+    #[test]
+    fn best_score_only_improves() {
+        let mut profile = PlayerProfile::default();
+        assert!(profile.submit_rush_score("Pyro", 1200));
+        assert!(!profile.submit_rush_score("Pyro", 900));
+        assert!(!profile.submit_rush_score("Pyro", 1200));
+        assert!(profile.submit_rush_score("Pyro", 1500));
+        assert_eq!(profile.rush_best("Pyro"), Some(1500));
+        assert_eq!(profile.rush_best("Sniper"), None);
+    }
+
+    #[test]
+    fn chapter_completes_only_when_every_quest_is_done() {
+        let db = QuestDatabase::new();
+        let chapter_one: Vec<QuestId> = db
+            .quests
+            .iter()
+            .filter(|q| q.chapter == 1)
+            .map(|q| q.id)
+            .collect();
+        let profile = PlayerProfile::default();
+
+        let mut log = QuestCompletionLog::default();
+        for id in &chapter_one[1..] {
+            log.mark_completed(*id);
+        }
+        assert!(profile.unlock_progress(&log, &db).completed_chapters.is_empty());
+
+        log.mark_completed(chapter_one[0]);
+        assert_eq!(profile.unlock_progress(&log, &db).completed_chapters, vec![1]);
+    }
+
+    #[derive(Resource, Default)]
+    struct Saves(u32);
+
+    /// Counts the frames save_profile would write on
+    fn count_saves(profile: Res<PlayerProfile>, mut saves: ResMut<Saves>) {
+        if profile.is_changed() && !profile.is_added() {
+            saves.0 += 1;
+        }
+    }
+
+    #[test]
+    fn kills_are_saved_when_the_run_ends() {
+        use crate::creatures::CreatureType;
+
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(GameState::Playing)
+            .init_resource::<PlayerProfile>()
+            .init_resource::<Saves>()
+            .add_event::<CreatureDeathEvent>()
+            .add_systems(OnExit(GameState::Playing), save_run_kills)
+            .add_systems(
+                Update,
+                (count_lifetime_kills.run_if(in_state(GameState::Playing)), count_saves).chain(),
+            );
+        app.update();
+
+        for _ in 0..2 {
+            app.world_mut().send_event(CreatureDeathEvent {
+                entity: Entity::PLACEHOLDER,
+                creature_type: CreatureType::Zombie,
+                position: Vec3::ZERO,
+                experience: 1,
+                killed_by: None,
+            });
+            app.update();
+        }
+        assert_eq!(app.world().resource::<PlayerProfile>().lifetime_kills, 2);
+        assert_eq!(app.world().resource::<Saves>().0, 0);
+
+        app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::MainMenu);
+        app.update();
+        assert_eq!(app.world().resource::<Saves>().0, 1);
+    }
+
+    #[test]
+    fn profile_without_scores_still_loads() {
+        let profile: PlayerProfile = ron::from_str("(lifetime_kills: 42)").unwrap();
+        assert_eq!(profile.lifetime_kills, 42);
+        assert!(profile.rush_best_scores.is_empty());
+    }
+}
@@ -0,0 +1,274 @@
+//! Chapter grouping for the quest select screen
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::database::{QuestDatabase, QuestId};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persist;
+
+#[cfg(not(target_arch = "wasm32"))]
+const COMPLETION_LOG_FILE: &str = "quest_log.ron";
+
+/// Record of completed quests and the most recently played one
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuestCompletionLog {
+    pub completed: Vec<QuestId>,
+    pub last_played: Option<QuestId>,
+}
+
+impl QuestCompletionLog {
+    pub fn is_completed(&self, id: QuestId) -> bool {
+        self.completed.contains(&id)
+    }
+
+    pub fn mark_completed(&mut self, id: QuestId) {
+        if !self.is_completed(id) {
+            self.completed.push(id);
+        }
+    }
+}
+
+/// Loads the saved quest completion log on startup
+pub fn load_completion_log(mut log: ResMut<QuestCompletionLog>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(loaded) = persist::load::<QuestCompletionLog>(COMPLETION_LOG_FILE).into_value() {
+            *log = loaded;
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &mut log;
+}
+
+/// Persists the quest completion log whenever it changes
+pub fn save_completion_log(log: Res<QuestCompletionLog>) {
+    if !log.is_changed() || log.is_added() {
+        return;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(err) = persist::save(COMPLETION_LOG_FILE, &*log) {
+        warn!("Failed to save {}: {}", COMPLETION_LOG_FILE, err);
+    }
+}
+
+/// Display name for a chapter number
+pub fn chapter_name(chapter: u32) -> &'static str {
+    match chapter {
+        1 => "The Landing",
+        2 => "Swarm Season",
+        3 => "Web of Death",
+        4 => "Alien Invasion",
+        _ => "Uncharted",
+    }
+}
+
+/// One quest row on the quest select screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestEntryView {
+    pub id: QuestId,
+    pub name: String,
+    pub unlocked: bool,
+    pub completed: bool,
+}
+
+/// One chapter header (plus its quests) on the quest select screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterView {
+    pub number: u32,
+    pub name: &'static str,
+    pub completed: usize,
+    pub total: usize,
+    /// False when no quest in the chapter is unlocked yet
+    pub unlocked: bool,
+    /// Shown instead of the chapter contents while locked
+    pub unlock_hint: Option<String>,
+    pub quests: Vec<QuestEntryView>,
+}
+
+/// View model for the whole quest select screen
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChapterSelectView {
+    pub chapters: Vec<ChapterView>,
+    /// (chapter index, quest index) highlighted on entry
+    pub initial_selection: (usize, usize),
+}
+
+impl ChapterSelectView {
+    pub fn quest_at(&self, chapter: usize, quest: usize) -> Option<&QuestEntryView> {
+        self.chapters.get(chapter)?.quests.get(quest)
+    }
+}
+
+/// Groups quests by chapter and computes per-chapter progress from the completion log.
+///
+/// Log entries for quests that are not in the database are ignored.
+pub fn build_chapter_select(db: &QuestDatabase, log: &QuestCompletionLog) -> ChapterSelectView {
+    let mut quests: Vec<_> = db.quests.iter().collect();
+    quests.sort_by_key(|q| (q.chapter, q.id));
+
+    let mut chapters: Vec<ChapterView> = Vec::new();
+    for quest in quests {
+        let unlocked = quest
+            .unlock_requirement
+            .is_none_or(|req| log.is_completed(req));
+        let entry = QuestEntryView {
+            id: quest.id,
+            name: quest.name.clone(),
+            unlocked,
+            completed: log.is_completed(quest.id),
+        };
+
+        if chapters.last().map(|c| c.number) != Some(quest.chapter) {
+            let hint = quest.unlock_requirement.map(|req| {
+                let name = db.get(req).map_or("an earlier quest", |q| q.name.as_str());
+                format!("Complete \"{}\" to unlock", name)
+            });
+            chapters.push(ChapterView {
+                number: quest.chapter,
+                name: chapter_name(quest.chapter),
+                completed: 0,
+                total: 0,
+                unlocked: false,
+                unlock_hint: hint,
+                quests: Vec::new(),
+            });
+        }
+
+        let chapter = chapters.last_mut().expect("chapter was just pushed");
+        chapter.total += 1;
+        if entry.completed {
+            chapter.completed += 1;
+        }
+        chapter.unlocked |= entry.unlocked;
+        chapter.quests.push(entry);
+    }
+
+    for chapter in &mut chapters {
+        if chapter.unlocked {
+            chapter.unlock_hint = None;
+        }
+    }
+
+    let initial_selection = log
+        .last_played
+        .and_then(|id| find_quest(&chapters, id))
+        .or_else(|| {
+            chapters.iter().enumerate().find_map(|(c, chapter)| {
+                chapter
+                    .quests
+                    .iter()
+                    .position(|q| q.unlocked && !q.completed)
+                    .map(|q| (c, q))
+            })
+        })
+        .unwrap_or((0, 0));
+
+    ChapterSelectView {
+        chapters,
+        initial_selection,
+    }
+}
+
+fn find_quest(chapters: &[ChapterView], id: QuestId) -> Option<(usize, usize)> {
+    chapters.iter().enumerate().find_map(|(c, chapter)| {
+        chapter.quests.iter().position(|q| q.id == id).map(|q| (c, q))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quests::database::QuestData;
+
+    #[test]
+    fn fresh_log_unlocks_only_first_chapter() {
+        let db = QuestDatabase::new();
+        let view = build_chapter_select(&db, &QuestCompletionLog::default());
+
+        assert!(view.chapters[0].unlocked);
+        assert!(view.chapters[1..].iter().all(|c| !c.unlocked));
+        assert_eq!(view.initial_selection, (0, 0));
+    }
+
+    #[test]
+    fn chapters_are_sorted_and_counted() {
+        let db = QuestDatabase::new();
+        let view = build_chapter_select(&db, &QuestCompletionLog::default());
+
+        let numbers: Vec<u32> = view.chapters.iter().map(|c| c.number).collect();
+        let mut sorted = numbers.clone();
+        sorted.sort();
+        assert_eq!(numbers, sorted);
+
+        let total: usize = view.chapters.iter().map(|c| c.total).sum();
+        assert_eq!(total, db.quests.len());
+    }
+
+    #[test]
+    fn locked_chapter_has_hint_and_no_unlocked_quests() {
+        let db = QuestDatabase::new();
+        let view = build_chapter_select(&db, &QuestCompletionLog::default());
+
+        let locked = &view.chapters[1];
+        assert!(!locked.unlocked);
+        assert!(locked.quests.iter().all(|q| !q.unlocked));
+        assert!(locked.unlock_hint.as_deref().unwrap().contains("Night Fall"));
+    }
+
+    #[test]
+    fn completion_unlocks_next_chapter() {
+        let db = QuestDatabase::new();
+        let log = QuestCompletionLog {
+            completed: vec![
+                QuestId::Q01LandHostile,
+                QuestId::Q02TheHunt,
+                QuestId::Q03NightFall,
+            ],
+            last_played: None,
+        };
+        let view = build_chapter_select(&db, &log);
+
+        assert_eq!(view.chapters[0].completed, view.chapters[0].total);
+        assert!(view.chapters[1].unlocked);
+        assert!(view.chapters[1].unlock_hint.is_none());
+        assert_eq!(view.initial_selection, (1, 0));
+    }
+
+    #[test]
+    fn unknown_quest_in_log_is_ignored() {
+        let db = QuestDatabase::new();
+        let log = QuestCompletionLog {
+            completed: vec![QuestId::Q04FirstBlood],
+            last_played: Some(QuestId::Q44Extermination),
+        };
+        let view = build_chapter_select(&db, &log);
+
+        assert!(view.chapters.iter().all(|c| c.completed == 0));
+        assert_eq!(view.initial_selection, (0, 0));
+    }
+
+    #[test]
+    fn last_played_is_preselected() {
+        let db = QuestDatabase::new();
+        let log = QuestCompletionLog {
+            completed: vec![QuestId::Q01LandHostile],
+            last_played: Some(QuestId::Q02TheHunt),
+        };
+        let view = build_chapter_select(&db, &log);
+
+        let (c, q) = view.initial_selection;
+        assert_eq!(view.quest_at(c, q).unwrap().id, QuestId::Q02TheHunt);
+    }
+
+    #[test]
+    fn all_locked_database_still_builds() {
+        let mut db = QuestDatabase::new();
+        db.quests.retain(|q: &QuestData| q.unlock_requirement.is_some());
+        let view = build_chapter_select(&db, &QuestCompletionLog::default());
+
+        assert!(view.chapters.iter().all(|c| !c.unlocked));
+        assert!(view.chapters.iter().all(|c| c.unlock_hint.is_some()));
+    }
+}
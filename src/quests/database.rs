@@ -28,11 +28,6 @@ impl QuestDatabase {
         self.quests.iter().find(|q| q.id == id)
     }
 
-    /// Get quest by numeric index (for quest selection menu)
-    pub fn get_by_index(&self, index: usize) -> Option<&QuestData> {
-        self.quests.get(index)
-    }
-
     fn register_all_quests(&mut self) {
         // Chapter 1: The Landing
         self.quests.push(QuestData {
@@ -44,6 +39,7 @@ impl QuestDatabase {
             waves: vec![
                 WaveData {
                     spawn_delay: 0.0,
+                    intro_text: None,
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::Zombie,
@@ -59,6 +55,10 @@ impl QuestDatabase {
                 },
                 WaveData {
                     spawn_delay: 5.0,
+                    intro_text: Some(
+                        "The landing zone is ours, for now. Something big is stirring under the sand, and the first wave was only the noise that woke it."
+                            .into(),
+                    ),
                     spawns: vec![SpawnEntry {
                         creature: CreatureType::Zombie,
                         count: 20,
@@ -68,6 +68,10 @@ impl QuestDatabase {
             ],
             time_limit: None,
             unlock_requirement: None,
+            outro_text: Some(
+                "The beachhead holds. Command wants the spider nests to the north cleared before the next drop."
+                    .into(),
+            ),
         });
 
         self.quests.push(QuestData {
@@ -78,6 +82,7 @@ impl QuestDatabase {
             waves: vec![
                 WaveData {
                     spawn_delay: 0.0,
+                    intro_text: None,
                     spawns: vec![SpawnEntry {
                         creature: CreatureType::Spider,
                         count: 30,
@@ -86,6 +91,10 @@ impl QuestDatabase {
                 },
                 WaveData {
                     spawn_delay: 3.0,
+                    intro_text: Some(
+                        "The nests were deeper than the scans showed. Whatever is crawling up from them is not a spider we have a name for."
+                            .into(),
+                    ),
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::Spider,
@@ -102,6 +111,10 @@ impl QuestDatabase {
             ],
             time_limit: None,
             unlock_requirement: Some(QuestId::Q01LandHostile),
+            outro_text: Some(
+                "The nests burn behind you. Your radio picks up nothing but static as the sun starts to set."
+                    .into(),
+            ),
         });
 
         self.quests.push(QuestData {
@@ -112,6 +125,7 @@ impl QuestDatabase {
             waves: vec![
                 WaveData {
                     spawn_delay: 0.0,
+                    intro_text: None,
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::Zombie,
@@ -127,6 +141,10 @@ impl QuestDatabase {
                 },
                 WaveData {
                     spawn_delay: 5.0,
+                    intro_text: Some(
+                        "Night brings the pack hunters. You hear them long before you see them, circling just past the light."
+                            .into(),
+                    ),
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::Dog,
@@ -143,6 +161,10 @@ impl QuestDatabase {
             ],
             time_limit: None,
             unlock_requirement: Some(QuestId::Q02TheHunt),
+            outro_text: Some(
+                "Dawn finds you alone on a field of husks. Chapter one of a long war is written."
+                    .into(),
+            ),
         });
 
         // Chapter 2: Deep Trouble
@@ -154,6 +176,7 @@ impl QuestDatabase {
             waves: vec![
                 WaveData {
                     spawn_delay: 0.0,
+                    intro_text: None,
                     spawns: vec![SpawnEntry {
                         creature: CreatureType::Spider,
                         count: 50,
@@ -162,6 +185,7 @@ impl QuestDatabase {
                 },
                 WaveData {
                     spawn_delay: 3.0,
+                    intro_text: None,
                     spawns: vec![SpawnEntry {
                         creature: CreatureType::Beetle,
                         count: 40,
@@ -170,6 +194,7 @@ impl QuestDatabase {
                 },
                 WaveData {
                     spawn_delay: 3.0,
+                    intro_text: None,
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::Spider,
@@ -186,6 +211,7 @@ impl QuestDatabase {
             ],
             time_limit: None,
             unlock_requirement: Some(QuestId::Q03NightFall),
+            outro_text: None,
         });
 
         self.quests.push(QuestData {
@@ -196,6 +222,7 @@ impl QuestDatabase {
             waves: vec![
                 WaveData {
                     spawn_delay: 0.0,
+                    intro_text: None,
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::Giant,
@@ -211,6 +238,7 @@ impl QuestDatabase {
                 },
                 WaveData {
                     spawn_delay: 5.0,
+                    intro_text: None,
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::Giant,
@@ -227,6 +255,7 @@ impl QuestDatabase {
             ],
             time_limit: None,
             unlock_requirement: Some(QuestId::Q10Swarm),
+            outro_text: None,
         });
 
         // Chapter 3: The Hive
@@ -238,6 +267,7 @@ impl QuestDatabase {
             waves: vec![
                 WaveData {
                     spawn_delay: 0.0,
+                    intro_text: None,
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::AlienSpider,
@@ -253,6 +283,7 @@ impl QuestDatabase {
                 },
                 WaveData {
                     spawn_delay: 5.0,
+                    intro_text: None,
                     spawns: vec![SpawnEntry {
                         creature: CreatureType::GiantSpider,
                         count: 3,
@@ -262,6 +293,7 @@ impl QuestDatabase {
             ],
             time_limit: None,
             unlock_requirement: Some(QuestId::Q11GiantProblem),
+            outro_text: None,
         });
 
         // Boss quest
@@ -273,6 +305,7 @@ impl QuestDatabase {
             waves: vec![
                 WaveData {
                     spawn_delay: 0.0,
+                    intro_text: None,
                     spawns: vec![SpawnEntry {
                         creature: CreatureType::Spider,
                         count: 20,
@@ -281,6 +314,10 @@ impl QuestDatabase {
                 },
                 WaveData {
                     spawn_delay: 5.0,
+                    intro_text: Some(
+                        "The ground splits open. She is bigger than the reports said, and every brood in the valley answers her call."
+                            .into(),
+                    ),
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::BossSpider,
@@ -297,6 +334,10 @@ impl QuestDatabase {
             ],
             time_limit: None,
             unlock_requirement: Some(QuestId::Q20Infestation),
+            outro_text: Some(
+                "The Queen is dead. Her brood scatters into the dark, leaderless, and for the first time since landing the valley is quiet."
+                    .into(),
+            ),
         });
 
         // Additional quests (abbreviated - full game has 53)
@@ -309,6 +350,7 @@ impl QuestDatabase {
             waves: vec![
                 WaveData {
                     spawn_delay: 0.0,
+                    intro_text: None,
                     spawns: vec![
                         SpawnEntry {
                             creature: CreatureType::AlienShooter,
@@ -324,6 +366,7 @@ impl QuestDatabase {
                 },
                 WaveData {
                     spawn_delay: 8.0,
+                    intro_text: None,
                     spawns: vec![SpawnEntry {
                         creature: CreatureType::BossAlien,
                         count: 1,
@@ -333,12 +376,13 @@ impl QuestDatabase {
             ],
             time_limit: None,
             unlock_requirement: Some(QuestId::Q30QueenSpider),
+            outro_text: None,
         });
     }
 }
 
 /// Unique identifier for each quest
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum QuestId {
     // Chapter 1
     Q01LandHostile,
@@ -396,6 +440,9 @@ pub struct QuestData {
     pub waves: Vec<WaveData>,
     pub time_limit: Option<f32>,
     pub unlock_requirement: Option<QuestId>,
+    /// Shown on the victory screen above the stats
+    #[serde(default)]
+    pub outro_text: Option<String>,
 }
 
 /// Data for a wave within a quest
@@ -403,6 +450,9 @@ pub struct QuestData {
 pub struct WaveData {
     /// Delay before this wave starts (after previous wave)
     pub spawn_delay: f32,
+    /// Narrative shown on the transition into this wave
+    #[serde(default)]
+    pub intro_text: Option<String>,
     /// Creatures to spawn in this wave
     pub spawns: Vec<SpawnEntry>,
 }
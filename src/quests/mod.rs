@@ -5,13 +5,16 @@
 pub mod database;
 pub mod systems;
 pub mod builders;
+pub mod chapters;
 
+pub use chapters::*;
 pub use database::*;
 pub use systems::*;
 
 use bevy::prelude::*;
 
-use crate::states::GameState;
+use crate::schedule::GameSet;
+use crate::states::{GameMode, GameState};
 
 /// Plugin for quest-related functionality
 pub struct QuestsPlugin;
@@ -21,28 +24,38 @@ impl Plugin for QuestsPlugin {
         app.init_resource::<QuestDatabase>()
             .init_resource::<ActiveQuest>()
             .init_resource::<QuestProgress>()
+            .init_resource::<QuestCompletionLog>()
             .init_resource::<DelayedSpawns>()
             .add_event::<QuestCompletedEvent>()
+            .add_event::<WaveStartedEvent>()
             .add_event::<WaveCompletedEvent>()
+            .add_systems(Startup, load_completion_log)
+            .add_systems(Update, save_completion_log)
             .add_systems(
                 OnEnter(GameState::Playing),
-                start_active_quest.run_if(quest_is_active),
+                start_active_quest
+                    .run_if(resource_equals(GameMode::Quest))
+                    .run_if(quest_is_active),
             )
             .add_systems(OnExit(GameState::Playing), cleanup_quest_state)
             .add_systems(
                 Update,
                 (
-                    update_quest_progress,
-                    spawn_wave_creatures,
-                    update_quest_builder,
-                    track_quest_kills,
-                    check_wave_completion,
-                    check_quest_completion,
-                    handle_wave_completion,
-                    handle_quest_completion,
+                    (update_quest_progress, spawn_wave_creatures, update_quest_builder)
+                        .chain()
+                        .in_set(GameSet::Simulation),
+                    (
+                        track_quest_kills,
+                        check_wave_completion,
+                        check_quest_completion,
+                        handle_wave_completion,
+                        handle_quest_completion,
+                    )
+                        .chain()
+                        .in_set(GameSet::Consequences),
                 )
-                    .chain()
                     .run_if(in_state(GameState::Playing))
+                    .run_if(resource_equals(GameMode::Quest))
                     .run_if(quest_is_active),
             );
     }
@@ -3,9 +3,12 @@
 use bevy::prelude::*;
 
 use super::builders::QuestBuilder;
+use super::chapters::QuestCompletionLog;
 use super::database::{QuestDatabase, QuestId};
-use crate::creatures::components::{Creature, CreatureType, MarkedForDespawn};
-use crate::creatures::systems::{CreatureDeathEvent, SpawnCreatureEvent};
+use crate::creatures::components::{
+    Creature, CreatureType, MarkedForDespawn, SpawnWarning, WorldTimeScale,
+};
+use crate::creatures::systems::{CreatureDeathEvent, PendingCreatureSpawns, SpawnCreatureEvent};
 use crate::states::{trigger_boss_encounter, trigger_wave_transition, GameState, PlayingState};
 
 /// Currently active quest
@@ -75,6 +78,14 @@ pub struct QuestCompletedEvent {
     pub kills: u32,
 }
 
+/// Event fired when a wave starts spawning
+#[derive(Event)]
+// Only the event timeline reads it so far
+#[cfg_attr(not(feature = "debug-tools"), allow(dead_code))]
+pub struct WaveStartedEvent {
+    pub wave_index: usize,
+}
+
 /// Event fired when a wave is completed
 #[derive(Event)]
 pub struct WaveCompletedEvent {
@@ -183,13 +194,18 @@ pub fn start_active_quest(
     active_quest: Res<ActiveQuest>,
     quest_db: Res<QuestDatabase>,
     mut progress: ResMut<QuestProgress>,
+    mut completion_log: ResMut<QuestCompletionLog>,
+    mut wave_started: EventWriter<WaveStartedEvent>,
 ) {
     progress.reset();
 
     if let Some(quest_id) = active_quest.quest_id {
+        completion_log.last_played = Some(quest_id);
+
         if let Some(quest_data) = quest_db.get(quest_id) {
             if let Some(first_wave) = quest_data.waves.first() {
                 progress.start_wave(first_wave);
+                wave_started.send(WaveStartedEvent { wave_index: 0 });
             }
 
             // Create a quest builder for advanced spawning logic
@@ -223,6 +239,7 @@ pub fn update_quest_progress(time: Res<Time>, mut progress: ResMut<QuestProgress
 /// Spawns creatures for the current wave
 pub fn spawn_wave_creatures(
     time: Res<Time>,
+    time_scale: Res<WorldTimeScale>,
     active_quest: Res<ActiveQuest>,
     quest_db: Res<QuestDatabase>,
     mut progress: ResMut<QuestProgress>,
@@ -257,13 +274,14 @@ pub fn spawn_wave_creatures(
         }
 
         // Update timer
-        progress.spawn_timers[i] -= time.delta_seconds();
+        progress.spawn_timers[i] -= time_scale.delta(&time);
 
         // Spawn if timer is ready
         if progress.spawn_timers[i] <= 0.0 {
             spawn_events.send(SpawnCreatureEvent {
                 creature_type: spawn_entry.creature,
                 position: None, // Let spawner choose position
+                summoner: None,
             });
 
             progress.spawned_in_wave[i] += 1;
@@ -303,6 +321,7 @@ pub fn update_quest_builder(
         spawn_events.send(SpawnCreatureEvent {
             creature_type: cmd.creature_type,
             position: cmd.position,
+            summoner: None,
         });
     }
 
@@ -324,6 +343,7 @@ pub fn update_quest_builder(
             spawn_events.send(SpawnCreatureEvent {
                 creature_type: cmd.creature_type,
                 position: cmd.position,
+                summoner: None,
             });
         }
     }
@@ -334,14 +354,33 @@ pub fn update_quest_builder(
     }
 }
 
+/// Creatures and spawn warnings still in the world, alive or about to be
+type LivingOrHatching = (Or<(With<Creature>, With<SpawnWarning>)>, Without<MarkedForDespawn>);
+
+/// Counts creatures that are alive or still on their way in: spawn warnings that haven't
+/// hatched, spawn requests not yet given a warning, and delayed builder spawns
+fn creatures_remaining(
+    creatures: &Query<Entity, LivingOrHatching>,
+    spawn_events: &mut EventReader<SpawnCreatureEvent>,
+    pending: &PendingCreatureSpawns,
+    delayed: &DelayedSpawns,
+) -> usize {
+    creatures.iter().count() + spawn_events.read().count() + pending.0.len() + delayed.commands.len()
+}
+
 /// Checks if the current wave is complete
+#[allow(clippy::too_many_arguments)]
 pub fn check_wave_completion(
     mut commands: Commands,
     active_quest: Res<ActiveQuest>,
     quest_db: Res<QuestDatabase>,
     mut progress: ResMut<QuestProgress>,
-    creatures: Query<Entity, (With<Creature>, Without<MarkedForDespawn>)>,
+    creatures: Query<Entity, LivingOrHatching>,
+    mut spawn_events: EventReader<SpawnCreatureEvent>,
+    pending: Res<PendingCreatureSpawns>,
+    delayed: Res<DelayedSpawns>,
     mut wave_events: EventWriter<WaveCompletedEvent>,
+    mut wave_started: EventWriter<WaveStartedEvent>,
     mut next_playing_state: ResMut<NextState<PlayingState>>,
 ) {
     if progress.wave_complete {
@@ -371,8 +410,8 @@ pub fn check_wave_completion(
         return;
     }
 
-    // Check if all creatures are dead
-    let creatures_alive = creatures.iter().count();
+    // Check if all creatures are dead, counting those still hatching
+    let creatures_alive = creatures_remaining(&creatures, &mut spawn_events, &pending, &delayed);
 
     if creatures_alive == 0 {
         progress.wave_complete = true;
@@ -414,6 +453,7 @@ pub fn check_wave_completion(
                         &mut commands,
                         &mut next_playing_state,
                         next_wave_index as u32 + 1,
+                        next_wave.intro_text.clone(),
                     );
                 }
             }
@@ -421,17 +461,24 @@ pub fn check_wave_completion(
             progress.advance_wave();
             if let Some(next_wave) = quest_data.waves.get(progress.current_wave) {
                 progress.start_wave(next_wave);
+                wave_started.send(WaveStartedEvent {
+                    wave_index: progress.current_wave,
+                });
             }
         }
     }
 }
 
 /// Checks if the quest is complete
+#[allow(clippy::too_many_arguments)]
 pub fn check_quest_completion(
     active_quest: Res<ActiveQuest>,
     quest_db: Res<QuestDatabase>,
     progress: Res<QuestProgress>,
-    creatures: Query<Entity, (With<Creature>, Without<MarkedForDespawn>)>,
+    creatures: Query<Entity, LivingOrHatching>,
+    mut spawn_events: EventReader<SpawnCreatureEvent>,
+    pending: Res<PendingCreatureSpawns>,
+    delayed: Res<DelayedSpawns>,
     mut quest_events: EventWriter<QuestCompletedEvent>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
@@ -453,7 +500,7 @@ pub fn check_quest_completion(
         return;
     }
 
-    let creatures_alive = creatures.iter().count();
+    let creatures_alive = creatures_remaining(&creatures, &mut spawn_events, &pending, &delayed);
     if creatures_alive > 0 {
         return;
     }
@@ -468,53 +515,6 @@ pub fn check_quest_completion(
     next_state.set(GameState::Victory);
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn active_quest_can_be_created() {
-        let quest = ActiveQuest::new(QuestId::Q01LandHostile);
-        assert_eq!(quest.quest_id, Some(QuestId::Q01LandHostile));
-    }
-
-    #[test]
-    fn quest_progress_reset_clears_all() {
-        let mut progress = QuestProgress {
-            current_wave: 5,
-            wave_time: 100.0,
-            total_time: 500.0,
-            kills: 50,
-            ..default()
-        };
-
-        progress.reset();
-
-        assert_eq!(progress.current_wave, 0);
-        assert_eq!(progress.wave_time, 0.0);
-        assert_eq!(progress.kills, 0);
-    }
-
-    #[test]
-    fn quest_progress_advance_wave() {
-        let mut progress = QuestProgress::default();
-        progress.advance_wave();
-        assert_eq!(progress.current_wave, 1);
-        progress.advance_wave();
-        assert_eq!(progress.current_wave, 2);
-    }
-
-    #[test]
-    fn quest_completed_event_can_be_created() {
-        let event = QuestCompletedEvent {
-            quest_id: QuestId::Q01LandHostile,
-            time: 120.5,
-            kills: 100,
-        };
-        assert_eq!(event.kills, 100);
-    }
-}
-
 /// Run condition: only run if a quest is active
 pub fn quest_is_active(active_quest: Res<ActiveQuest>) -> bool {
     active_quest.quest_id.is_some()
@@ -567,8 +567,11 @@ pub fn handle_wave_completion(
 pub fn handle_quest_completion(
     mut quest_events: EventReader<QuestCompletedEvent>,
     quest_db: Res<QuestDatabase>,
+    mut completion_log: ResMut<QuestCompletionLog>,
 ) {
     for event in quest_events.read() {
+        completion_log.mark_completed(event.quest_id);
+
         // Use all fields from the event
         let quest_name = quest_db
             .get(event.quest_id)
@@ -581,3 +584,134 @@ pub fn handle_quest_completion(
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn active_quest_can_be_created() {
+        let quest = ActiveQuest::new(QuestId::Q01LandHostile);
+        assert_eq!(quest.quest_id, Some(QuestId::Q01LandHostile));
+    }
+
+    #[test]
+    fn quest_progress_reset_clears_all() {
+        let mut progress = QuestProgress {
+            current_wave: 5,
+            wave_time: 100.0,
+            total_time: 500.0,
+            kills: 50,
+            ..default()
+        };
+
+        progress.reset();
+
+        assert_eq!(progress.current_wave, 0);
+        assert_eq!(progress.wave_time, 0.0);
+        assert_eq!(progress.kills, 0);
+    }
+
+    #[test]
+    fn quest_progress_advance_wave() {
+        let mut progress = QuestProgress::default();
+        progress.advance_wave();
+        assert_eq!(progress.current_wave, 1);
+        progress.advance_wave();
+        assert_eq!(progress.current_wave, 2);
+    }
+
+    #[test]
+    fn one_creature_wave_waits_for_its_creature_to_die() {
+        use super::super::database::{QuestData, SpawnEntry, WaveData};
+        use crate::audio::PlaySoundEvent;
+        use crate::creatures::spawner::CreatureRegistry;
+        use crate::creatures::systems::{handle_creature_spawns, hatch_spawn_warnings};
+        use crate::viewport::{ArenaBounds, ViewportInfo};
+        use std::time::Duration;
+
+        let quest = QuestData {
+            id: QuestId::Q01LandHostile,
+            chapter: 1,
+            name: "Test".into(),
+            description: String::new(),
+            waves: vec![WaveData {
+                spawn_delay: 0.0,
+                intro_text: None,
+                spawns: vec![SpawnEntry {
+                    creature: CreatureType::Zombie,
+                    count: 1,
+                    interval: 1.0,
+                }],
+            }],
+            time_limit: None,
+            unlock_requirement: None,
+            outro_text: None,
+        };
+        let mut progress = QuestProgress::default();
+        progress.start_wave(&quest.waves[0]);
+
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(GameState::Playing)
+            .add_sub_state::<PlayingState>()
+            .insert_resource(Time::<()>::default())
+            .init_resource::<WorldTimeScale>()
+            .init_resource::<ViewportInfo>()
+            .init_resource::<ArenaBounds>()
+            .init_resource::<PendingCreatureSpawns>()
+            .init_resource::<DelayedSpawns>()
+            .insert_resource(CreatureRegistry::new())
+            .insert_resource(QuestDatabase { quests: vec![quest] })
+            .insert_resource(ActiveQuest::new(QuestId::Q01LandHostile))
+            .insert_resource(progress)
+            .add_event::<SpawnCreatureEvent>()
+            .add_event::<PlaySoundEvent>()
+            .add_event::<WaveStartedEvent>()
+            .add_event::<WaveCompletedEvent>()
+            .add_event::<QuestCompletedEvent>()
+            .add_systems(
+                Update,
+                (
+                    spawn_wave_creatures,
+                    handle_creature_spawns,
+                    hatch_spawn_warnings,
+                    check_wave_completion,
+                    check_quest_completion,
+                )
+                    .chain(),
+            );
+        app.world_mut().spawn((crate::player::components::Player::default(), Transform::default()));
+
+        // The whole wave has been sent, but its creature is still a spawn warning
+        app.update();
+        assert_eq!(app.world().resource::<QuestProgress>().spawned_in_wave, vec![1]);
+        assert!(!app.world().resource::<QuestProgress>().wave_complete);
+
+        // Hatched and alive
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(crate::creatures::components::SPAWN_WARNING_TIME + 0.1));
+        app.update();
+        let creature = app
+            .world_mut()
+            .query_filtered::<Entity, With<Creature>>()
+            .single(app.world());
+        assert!(!app.world().resource::<QuestProgress>().wave_complete);
+
+        app.world_mut().despawn(creature);
+        app.update();
+        assert!(app.world().resource::<QuestProgress>().wave_complete);
+        assert_eq!(app.world().resource::<Events<QuestCompletedEvent>>().len(), 1);
+    }
+
+    #[test]
+    fn quest_completed_event_can_be_created() {
+        let event = QuestCompletedEvent {
+            quest_id: QuestId::Q01LandHostile,
+            time: 120.5,
+            kills: 100,
+        };
+        assert_eq!(event.kills, 100);
+    }
+}
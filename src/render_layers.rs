@@ -0,0 +1,109 @@
+//! Render layers
+//!
+//! Z values for every kind of world-space sprite. Spawns take their x/y from
+//! wherever they come from (events often carry a full Vec3) and their z from here.
+
+use bevy::prelude::*;
+
+/// Arena floor
+// Nothing draws the floor yet
+#[allow(dead_code)]
+pub const GROUND: f32 = 0.0;
+/// Blood stains and scorch marks, below everything that moves
+pub const DECALS: f32 = 1.0;
+/// Bonuses and item pickups
+pub const PICKUPS: f32 = 2.0;
+pub const CREATURES: f32 = 3.0;
+pub const PLAYER: f32 = 4.0;
+pub const PROJECTILES: f32 = 5.0;
+/// Blood, impacts and status puffs
+pub const EFFECTS_LOW: f32 = 6.0;
+/// Explosions, flashes and pickup or level-up bursts
+pub const EFFECTS_HIGH: f32 = 7.0;
+/// Full-arena overlays (darkness) and world-space indicators like health bars
+pub const WORLD_OVERLAY: f32 = 8.0;
+/// Floating text such as pickup labels
+pub const WORLD_TEXT: f32 = 9.0;
+
+/// Rush ghost draws just under the live player
+pub const GHOST_OFFSET: f32 = -0.5;
+/// Health bar fill sits this far above its background
+pub const HEALTH_BAR_FILL_OFFSET: f32 = 0.1;
+
+/// `position` moved onto `layer`, keeping x and y
+pub fn on_layer(position: Vec3, layer: f32) -> Vec3 {
+    position.truncate().extend(layer)
+}
+
+/// Local z for a child that should render at `layer` under a parent at `parent_layer`
+pub fn child_offset(layer: f32, parent_layer: f32) -> f32 {
+    layer - parent_layer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bonuses::{BonusBundle, BonusType};
+    use crate::creatures::{CreatureBundle, CreatureType};
+    use crate::effects::ParticleBundle;
+    use crate::items::{ItemPickupBundle, ItemType};
+    use crate::weapons::{ProjectileBundle, WeaponId};
+
+    #[test]
+    fn layers_are_strictly_ordered() {
+        let layers = [
+            GROUND,
+            DECALS,
+            PICKUPS,
+            CREATURES,
+            PLAYER,
+            PROJECTILES,
+            EFFECTS_LOW,
+            EFFECTS_HIGH,
+            WORLD_OVERLAY,
+            WORLD_TEXT,
+        ];
+        assert!(layers.windows(2).all(|pair| pair[0] < pair[1]));
+        // Offsets must stay inside the gap to the neighbouring layer
+        const {
+            assert!(PLAYER + GHOST_OFFSET > CREATURES);
+            assert!(WORLD_OVERLAY + HEALTH_BAR_FILL_OFFSET < WORLD_TEXT);
+        }
+    }
+
+    #[test]
+    fn on_layer_keeps_xy_and_replaces_z() {
+        assert_eq!(on_layer(Vec3::new(3.0, -4.0, 42.0), PROJECTILES), Vec3::new(3.0, -4.0, PROJECTILES));
+        assert_eq!(child_offset(WORLD_TEXT, PICKUPS) + PICKUPS, WORLD_TEXT);
+    }
+
+    #[test]
+    fn spawns_normalize_inherited_z() {
+        // Event positions copied from other entities carry their z along
+        let inherited = Vec3::new(10.0, 20.0, 37.0);
+        let projectile = ProjectileBundle::new(
+            WeaponId::Pistol,
+            10.0,
+            Entity::PLACEHOLDER,
+            inherited,
+            Vec2::X,
+            800.0,
+            1.0,
+            Color::WHITE,
+            4.0,
+        );
+        let creature = CreatureBundle::new(CreatureType::Zombie, inherited);
+        let bonus = BonusBundle::new(BonusType::Shield, inherited);
+        let item = ItemPickupBundle::new(ItemType::Nuke, inherited);
+        let blood = ParticleBundle::blood(inherited, Vec2::ZERO);
+
+        let projectile_z = projectile.sprite.transform.translation.z;
+        assert_eq!(projectile.sprite.transform.translation.truncate(), inherited.truncate());
+        assert_eq!(projectile_z, PROJECTILES);
+        assert!(projectile_z > creature.sprite.transform.translation.z);
+        assert!(creature.sprite.transform.translation.z > bonus.sprite.transform.translation.z);
+        assert_eq!(bonus.sprite.transform.translation.z, item.sprite.transform.translation.z);
+        assert!(projectile_z > DECALS);
+        assert_eq!(blood.sprite.transform.translation.z, EFFECTS_LOW);
+    }
+}
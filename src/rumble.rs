@@ -0,0 +1,417 @@
+//! Controller rumble
+//!
+//! Gameplay events are turned into [`RumbleEvent`]s, which merge into a single
+//! active rumble sent to every connected gamepad. Overlapping requests don't
+//! queue up behind each other: the strongest sets the intensity and the
+//! longest sets how long it lasts.
+
+use std::time::Duration;
+
+use bevy::input::gamepad::{
+    GamepadConnection, GamepadConnectionEvent, GamepadRumbleIntensity, GamepadRumbleRequest,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::audio::HordeSurgeEvent;
+use crate::last_stand::LastStandPushEvent;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persist;
+use crate::player::{Health, Player, PlayerHitLandedEvent};
+use crate::schedule::GameSet;
+use crate::states::{gameplay_frozen, GameState};
+use crate::weapons::{ExplosionEvent, FireWeaponEvent, WeaponRegistry};
+
+/// A hit costing this fraction of max health rumbles at full strength
+pub const DAMAGE_FULL_RUMBLE_FRACTION: f32 = 0.3;
+pub const DAMAGE_RUMBLE_DURATION: f32 = 0.25;
+/// Explosions further than this from the player aren't felt
+pub const EXPLOSION_RUMBLE_RANGE: f32 = 450.0;
+/// Strength of an explosion right on top of the player
+pub const EXPLOSION_RUMBLE: f32 = 0.8;
+pub const EXPLOSION_RUMBLE_DURATION: f32 = 0.35;
+/// Length of the pulse from a weapon with recoil
+pub const RECOIL_RUMBLE_DURATION: f32 = 0.08;
+pub const BOSS_RUMBLE: RumbleEvent = RumbleEvent {
+    intensity: 0.7,
+    duration: 1.0,
+};
+pub const PUSH_RUMBLE: RumbleEvent = RumbleEvent {
+    intensity: 0.6,
+    duration: 0.3,
+};
+/// Step of the intensity option on the main menu
+pub const RUMBLE_SETTING_STEP: f32 = 0.25;
+
+#[cfg(not(target_arch = "wasm32"))]
+const SETTINGS_FILE: &str = "rumble.ron";
+
+/// Plugin for controller rumble
+pub struct RumblePlugin;
+
+impl Plugin for RumblePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RumbleSettings>()
+            .init_resource::<ActiveRumble>()
+            .add_event::<RumbleEvent>()
+            .add_systems(Startup, load_rumble_settings)
+            .add_systems(Update, (save_rumble_settings, stop_rumble_on_disconnect))
+            .add_systems(OnExit(GameState::Playing), stop_rumble)
+            .add_systems(
+                Update,
+                (rumble_from_gameplay.run_if(not(gameplay_frozen)), apply_rumble)
+                    .chain()
+                    .in_set(GameSet::Presentation)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Request to rumble the controller
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct RumbleEvent {
+    /// 0-1, before the player's intensity setting
+    pub intensity: f32,
+    /// Seconds
+    pub duration: f32,
+}
+
+/// Player option scaling all rumble (persisted; 0 disables it)
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RumbleSettings {
+    pub intensity: f32,
+}
+
+impl Default for RumbleSettings {
+    fn default() -> Self {
+        Self { intensity: 1.0 }
+    }
+}
+
+impl RumbleSettings {
+    /// Next step of the menu option, wrapping from full back to off
+    pub fn step_up(&mut self) {
+        self.intensity = if self.intensity >= 1.0 {
+            0.0
+        } else {
+            (self.intensity + RUMBLE_SETTING_STEP).min(1.0)
+        };
+    }
+
+    pub fn step_down(&mut self) {
+        self.intensity = (self.intensity - RUMBLE_SETTING_STEP).max(0.0);
+    }
+}
+
+/// The rumble currently playing on the controllers
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ActiveRumble {
+    pub intensity: f32,
+    /// Seconds left
+    pub remaining: f32,
+}
+
+impl ActiveRumble {
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0.0 && self.intensity > 0.0
+    }
+}
+
+/// Folds a new request into the active rumble: the stronger intensity wins
+/// and the rumble lasts until the longer of the two ends
+pub fn merge_rumble(current: ActiveRumble, request: RumbleEvent) -> ActiveRumble {
+    let intensity = request.intensity.clamp(0.0, 1.0);
+    let duration = request.duration.max(0.0);
+    if !current.is_active() {
+        return ActiveRumble {
+            intensity,
+            remaining: duration,
+        };
+    }
+    ActiveRumble {
+        intensity: current.intensity.max(intensity),
+        remaining: current.remaining.max(duration),
+    }
+}
+
+/// Rumble strength for a hit of `damage` against `max_health`
+pub fn damage_rumble(damage: f32, max_health: f32) -> f32 {
+    if max_health <= 0.0 {
+        return 1.0;
+    }
+    (damage / max_health / DAMAGE_FULL_RUMBLE_FRACTION).clamp(0.0, 1.0)
+}
+
+/// Rumble strength of an explosion `distance` away from the player
+pub fn explosion_rumble(distance: f32) -> f32 {
+    EXPLOSION_RUMBLE * (1.0 - distance / EXPLOSION_RUMBLE_RANGE).clamp(0.0, 1.0)
+}
+
+/// Turns hits, explosions, heavy shots, boss entrances and the last-stand push into rumble
+#[allow(clippy::too_many_arguments)]
+pub fn rumble_from_gameplay(
+    player_query: Query<(&Transform, &Health), With<Player>>,
+    weapon_registry: Res<WeaponRegistry>,
+    mut hits: EventReader<PlayerHitLandedEvent>,
+    mut explosions: EventReader<ExplosionEvent>,
+    mut shots: EventReader<FireWeaponEvent>,
+    mut surges: EventReader<HordeSurgeEvent>,
+    mut pushes: EventReader<LastStandPushEvent>,
+    mut rumble_events: EventWriter<RumbleEvent>,
+) {
+    for hit in hits.read() {
+        let Ok((_, health)) = player_query.get(hit.player_entity) else {
+            continue;
+        };
+        rumble_events.send(RumbleEvent {
+            intensity: damage_rumble(hit.damage, health.max),
+            duration: DAMAGE_RUMBLE_DURATION,
+        });
+    }
+
+    // Felt by whichever player is closest
+    for explosion in explosions.read() {
+        let center = explosion.position.truncate();
+        let nearest = player_query
+            .iter()
+            .map(|(transform, _)| transform.translation.truncate().distance(center))
+            .fold(f32::MAX, f32::min);
+        let intensity = explosion_rumble(nearest);
+        if intensity > 0.0 {
+            rumble_events.send(RumbleEvent {
+                intensity,
+                duration: EXPLOSION_RUMBLE_DURATION,
+            });
+        }
+    }
+
+    for shot in shots.read() {
+        let recoil = weapon_registry.get_key(&shot.weapon).map_or(0.0, |data| data.recoil);
+        if recoil > 0.0 {
+            rumble_events.send(RumbleEvent {
+                intensity: recoil,
+                duration: RECOIL_RUMBLE_DURATION,
+            });
+        }
+    }
+
+    if surges.read().any(|surge| surge.boss) {
+        rumble_events.send(BOSS_RUMBLE);
+    }
+
+    if pushes.read().count() > 0 {
+        rumble_events.send(PUSH_RUMBLE);
+    }
+}
+
+/// Motor strengths for a rumble; the heavy motor carries it, the light one adds buzz
+fn motor_intensity(intensity: f32) -> GamepadRumbleIntensity {
+    GamepadRumbleIntensity {
+        strong_motor: intensity,
+        weak_motor: intensity * 0.5,
+    }
+}
+
+/// Merges this frame's requests, scaled by the player's setting, and restarts
+/// the controller rumble whenever the merged result grows
+pub fn apply_rumble(
+    time: Res<Time>,
+    settings: Res<RumbleSettings>,
+    gamepads: Res<Gamepads>,
+    mut rumble_events: EventReader<RumbleEvent>,
+    mut active: ResMut<ActiveRumble>,
+    mut requests: EventWriter<GamepadRumbleRequest>,
+) {
+    active.remaining = (active.remaining - time.delta_seconds()).max(0.0);
+    if settings.intensity <= 0.0 {
+        rumble_events.clear();
+        return;
+    }
+
+    let before = *active;
+    for event in rumble_events.read() {
+        *active = merge_rumble(
+            *active,
+            RumbleEvent {
+                intensity: event.intensity * settings.intensity,
+                duration: event.duration,
+            },
+        );
+    }
+    if *active == before || !active.is_active() {
+        return;
+    }
+
+    // Gamepad rumbles add up, so replace the old one rather than stacking on it
+    for gamepad in gamepads.iter() {
+        requests.send(GamepadRumbleRequest::Stop { gamepad });
+        requests.send(GamepadRumbleRequest::Add {
+            gamepad,
+            intensity: motor_intensity(active.intensity),
+            duration: Duration::from_secs_f32(active.remaining),
+        });
+    }
+}
+
+/// Cuts the rumble when leaving gameplay (pausing included)
+pub fn stop_rumble(
+    gamepads: Res<Gamepads>,
+    mut active: ResMut<ActiveRumble>,
+    mut requests: EventWriter<GamepadRumbleRequest>,
+) {
+    *active = ActiveRumble::default();
+    for gamepad in gamepads.iter() {
+        requests.send(GamepadRumbleRequest::Stop { gamepad });
+    }
+}
+
+/// Stops a controller that disconnects so it doesn't keep buzzing on reconnect
+pub fn stop_rumble_on_disconnect(
+    mut connections: EventReader<GamepadConnectionEvent>,
+    mut requests: EventWriter<GamepadRumbleRequest>,
+) {
+    for event in connections.read() {
+        if event.connection == GamepadConnection::Disconnected {
+            requests.send(GamepadRumbleRequest::Stop {
+                gamepad: event.gamepad,
+            });
+        }
+    }
+}
+
+/// Loads the rumble option on startup
+fn load_rumble_settings(mut settings: ResMut<RumbleSettings>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(loaded) = persist::load::<RumbleSettings>(SETTINGS_FILE).into_value() {
+            *settings = loaded;
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &mut settings;
+}
+
+/// Persists the rumble option whenever it changes
+fn save_rumble_settings(settings: Res<RumbleSettings>) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(err) = persist::save(SETTINGS_FILE, &*settings) {
+        warn!("Failed to save {}: {}", SETTINGS_FILE, err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::input::gamepad::GamepadInfo;
+    use bevy::input::InputPlugin;
+    use bevy::state::app::StatesPlugin;
+
+    use crate::states::PlayingState;
+
+    #[test]
+    fn overlapping_rumbles_keep_the_strongest_and_longest() {
+        let quiet = ActiveRumble::default();
+        let first = merge_rumble(quiet, RumbleEvent { intensity: 0.4, duration: 1.0 });
+        assert_eq!(first, ActiveRumble { intensity: 0.4, remaining: 1.0 });
+
+        // A short strong pulse raises the intensity without cutting the rumble short
+        let second = merge_rumble(first, RumbleEvent { intensity: 0.9, duration: 0.1 });
+        assert_eq!(second, ActiveRumble { intensity: 0.9, remaining: 1.0 });
+
+        // A long weak one extends it without weakening it
+        let third = merge_rumble(second, RumbleEvent { intensity: 0.2, duration: 2.0 });
+        assert_eq!(third, ActiveRumble { intensity: 0.9, remaining: 2.0 });
+
+        // Requests are clamped, and an expired rumble doesn't carry over
+        let expired = ActiveRumble { intensity: 1.0, remaining: 0.0 };
+        let fresh = merge_rumble(expired, RumbleEvent { intensity: 3.0, duration: 0.5 });
+        assert_eq!(fresh, ActiveRumble { intensity: 1.0, remaining: 0.5 });
+    }
+
+    #[test]
+    fn hits_and_explosions_scale_with_severity() {
+        assert_eq!(damage_rumble(0.0, 100.0), 0.0);
+        assert!((damage_rumble(15.0, 100.0) - 0.5).abs() < 1e-6);
+        assert_eq!(damage_rumble(80.0, 100.0), 1.0);
+
+        assert_eq!(explosion_rumble(0.0), EXPLOSION_RUMBLE);
+        assert!(explosion_rumble(EXPLOSION_RUMBLE_RANGE / 2.0) < EXPLOSION_RUMBLE);
+        assert_eq!(explosion_rumble(EXPLOSION_RUMBLE_RANGE + 1.0), 0.0);
+    }
+
+    fn rumble_app(state: GameState, intensity: f32) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, InputPlugin))
+            .insert_resource(Time::<()>::default())
+            .insert_state(state)
+            .add_sub_state::<PlayingState>()
+            .init_resource::<WeaponRegistry>()
+            .insert_resource(RumbleSettings { intensity })
+            .init_resource::<ActiveRumble>()
+            .add_event::<RumbleEvent>()
+            .add_event::<PlayerHitLandedEvent>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<FireWeaponEvent>()
+            .add_event::<HordeSurgeEvent>()
+            .add_event::<LastStandPushEvent>()
+            .add_systems(
+                Update,
+                (rumble_from_gameplay.run_if(not(gameplay_frozen)), apply_rumble)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+        app.world_mut().send_event(GamepadConnectionEvent::new(
+            Gamepad::new(0),
+            GamepadConnection::Connected(GamepadInfo { name: "Pad".into() }),
+        ));
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), Transform::default(), Health::new(100.0)))
+            .id();
+        app.update();
+        (app, player)
+    }
+
+    /// Motor strengths of the rumbles requested by a 15-damage hit
+    fn rumble_from_hit(app: &mut App, player: Entity) -> Vec<f32> {
+        app.world_mut().send_event(PlayerHitLandedEvent {
+            player_entity: player,
+            source: None,
+            damage: 15.0,
+        });
+        app.update();
+        app.world_mut()
+            .resource_mut::<Events<GamepadRumbleRequest>>()
+            .drain()
+            .filter_map(|request| match request {
+                GamepadRumbleRequest::Add { intensity, .. } => Some(intensity.strong_motor),
+                GamepadRumbleRequest::Stop { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn no_rumble_while_paused() {
+        let (mut app, player) = rumble_app(GameState::Paused, 1.0);
+        assert!(rumble_from_hit(&mut app, player).is_empty());
+        assert!(!app.world().resource::<ActiveRumble>().is_active());
+    }
+
+    #[test]
+    fn intensity_setting_scales_the_rumble() {
+        for (setting, expected) in [(1.0, 0.5), (0.5, 0.25)] {
+            let (mut app, player) = rumble_app(GameState::Playing, setting);
+            let emitted = rumble_from_hit(&mut app, player);
+            assert_eq!(emitted.len(), 1);
+            assert!((emitted[0] - expected).abs() < 1e-5, "{:?} at {}", emitted, setting);
+        }
+
+        let (mut app, player) = rumble_app(GameState::Playing, 0.0);
+        assert!(rumble_from_hit(&mut app, player).is_empty());
+    }
+}
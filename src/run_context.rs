@@ -0,0 +1,229 @@
+//! Run context
+//!
+//! Describes how the current run was set up (mode, modifiers, seed, assists,
+//! debug tools) so end screens can show a shareable one-line banner.
+
+use bevy::prelude::*;
+
+use crate::assists::RunAssists;
+use crate::quests::{ActiveQuest, QuestDatabase};
+use crate::rush::RushState;
+use crate::states::GameState;
+
+/// Plugin for run context tracking
+pub struct RunContextPlugin;
+
+impl Plugin for RunContextPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunContext>()
+            .add_event::<DebugToolUsedEvent>()
+            .add_systems(OnEnter(GameState::Playing), start_run_context)
+            .add_systems(
+                Update,
+                (
+                    track_assist_flag.run_if(in_state(GameState::Playing)),
+                    track_debug_tools,
+                ),
+            );
+    }
+}
+
+/// Sent by debug tools (timeline dump, and any sandbox or console) whenever they are used
+#[derive(Event)]
+// Only the debug-tools feature sends it
+#[cfg_attr(not(feature = "debug-tools"), allow(dead_code))]
+pub struct DebugToolUsedEvent;
+
+/// Game mode of a run, as shown in the banner
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum RunMode {
+    #[default]
+    Survival,
+    Rush { loadout: String },
+    Quest { name: String },
+}
+
+impl RunMode {
+    pub fn label(&self) -> String {
+        match self {
+            RunMode::Survival => "Survival".into(),
+            RunMode::Rush { loadout } => format!("Rush ({})", loadout),
+            RunMode::Quest { name } => format!("Quest: {}", name),
+        }
+    }
+}
+
+/// Setup of the current (or just finished) run. Kept after the run ends for the end screens.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct RunContext {
+    pub mode: RunMode,
+    pub difficulty: Option<String>,
+    pub mutators: Vec<String>,
+    /// Only set for seeded runs
+    pub seed: Option<u64>,
+    pub assists_used: bool,
+    /// Sticky for the run: once set it stays set until the next run starts
+    debug_used: bool,
+}
+
+impl RunContext {
+    pub fn new(mode: RunMode) -> Self {
+        Self {
+            mode,
+            ..default()
+        }
+    }
+
+    pub fn mark_debug_used(&mut self) {
+        self.debug_used = true;
+    }
+}
+
+/// Formats a run context as e.g. "v0.3.1 | Survival | Nightmare | Frenzy+Famine | seed 8F3A21 | assists off"
+pub fn run_banner_string(ctx: &RunContext) -> String {
+    let mut parts = vec![format!("v{}", env!("CARGO_PKG_VERSION")), ctx.mode.label()];
+    if let Some(difficulty) = &ctx.difficulty {
+        parts.push(difficulty.clone());
+    }
+    if !ctx.mutators.is_empty() {
+        parts.push(ctx.mutators.join("+"));
+    }
+    if let Some(seed) = ctx.seed {
+        parts.push(format!("seed {:06X}", seed));
+    }
+    parts.push(format!("assists {}", if ctx.assists_used { "on" } else { "off" }));
+    if ctx.debug_used {
+        parts.push("DEBUG".into());
+    }
+    parts.join(" | ")
+}
+
+/// Assembles the run context when a run starts
+fn start_run_context(
+    mut context: ResMut<RunContext>,
+    rush: Option<Res<RushState>>,
+    active_quest: Option<Res<ActiveQuest>>,
+    quest_db: Option<Res<QuestDatabase>>,
+) {
+    let quest_name = active_quest
+        .and_then(|q| q.quest_id)
+        .and_then(|id| quest_db.as_ref()?.get(id).map(|q| q.name.clone()));
+
+    let mode = if let Some(rush) = rush {
+        RunMode::Rush {
+            loadout: rush.loadout.name.clone(),
+        }
+    } else if let Some(name) = quest_name {
+        RunMode::Quest { name }
+    } else {
+        RunMode::Survival
+    };
+    *context = RunContext::new(mode);
+}
+
+/// Records that assists were active at some point during the run
+fn track_assist_flag(run_assists: Res<RunAssists>, mut context: ResMut<RunContext>) {
+    if run_assists.used && !context.assists_used {
+        context.assists_used = true;
+    }
+}
+
+/// Sets the sticky debug flag whenever a debug tool is used
+fn track_debug_tools(mut events: EventReader<DebugToolUsedEvent>, mut context: ResMut<RunContext>) {
+    if events.read().count() > 0 {
+        context.mark_debug_used();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version() -> String {
+        format!("v{}", env!("CARGO_PKG_VERSION"))
+    }
+
+    #[test]
+    fn minimal_banner_has_version_mode_and_assists() {
+        let ctx = RunContext::new(RunMode::Survival);
+        assert_eq!(
+            run_banner_string(&ctx),
+            format!("{} | Survival | assists off", version())
+        );
+    }
+
+    #[test]
+    fn full_banner_lists_every_field_in_order() {
+        let mut ctx = RunContext {
+            mode: RunMode::Survival,
+            difficulty: Some("Nightmare".into()),
+            mutators: vec!["Frenzy".into(), "Famine".into()],
+            seed: Some(0x8F3A21),
+            assists_used: true,
+            ..default()
+        };
+        ctx.mark_debug_used();
+        assert_eq!(
+            run_banner_string(&ctx),
+            format!(
+                "{} | Survival | Nightmare | Frenzy+Famine | seed 8F3A21 | assists on | DEBUG",
+                version()
+            )
+        );
+    }
+
+    #[test]
+    fn small_seeds_are_zero_padded() {
+        let ctx = RunContext {
+            seed: Some(0x2A),
+            ..RunContext::new(RunMode::Rush {
+                loadout: "Heavy".into(),
+            })
+        };
+        let banner = run_banner_string(&ctx);
+        assert!(banner.contains("Rush (Heavy)"));
+        assert!(banner.contains("seed 00002A"));
+    }
+
+    #[test]
+    fn quest_mode_uses_quest_name() {
+        let ctx = RunContext::new(RunMode::Quest {
+            name: "Land Hostile".into(),
+        });
+        assert!(run_banner_string(&ctx).contains("Quest: Land Hostile"));
+    }
+
+    #[test]
+    fn debug_flag_is_sticky_until_next_run() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(GameState::MainMenu)
+            .init_resource::<RunAssists>()
+            .add_plugins(RunContextPlugin);
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.world_mut().send_event(DebugToolUsedEvent);
+        app.update();
+
+        // Stays set through the rest of the run and onto the end screen
+        for _ in 0..3 {
+            app.update();
+        }
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::GameOver);
+        app.update();
+        assert!(app.world().resource::<RunContext>().debug_used);
+        assert!(run_banner_string(app.world().resource::<RunContext>()).ends_with("DEBUG"));
+
+        // A new run starts clean
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        assert!(!app.world().resource::<RunContext>().debug_used);
+    }
+}
@@ -5,10 +5,12 @@
 
 use bevy::prelude::*;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-use crate::creatures::{CreatureType, SpawnCreatureEvent};
+use crate::creatures::{CreatureRegistry, CreatureType, SpawnCreatureEvent, WorldTimeScale};
 use crate::perks::components::{PerkBonuses, PerkId, PerkInventory};
-use crate::states::GameState;
+use crate::schedule::GameSet;
+use crate::states::{GameMode, GameState};
 use crate::weapons::components::WeaponId;
 
 /// Plugin for rush mode functionality
@@ -16,20 +18,26 @@ pub struct RushPlugin;
 
 impl Plugin for RushPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<RushScoreEvent>()
-            .add_systems(OnEnter(GameState::Playing), setup_rush_mode)
+        app.init_resource::<RushLoadouts>()
+            .add_event::<RushScoreEvent>()
+            .add_systems(
+                OnEnter(GameState::Playing),
+                setup_rush_mode.run_if(resource_equals(GameMode::Rush)),
+            )
             .add_systems(OnExit(GameState::Playing), cleanup_rush_mode)
             .add_systems(
                 Update,
                 (
-                    update_rush_timer,
-                    spawn_rush_creatures,
-                    handle_rush_kills,
-                    track_rush_score,
-                    handle_rush_round_end,
+                    (update_rush_timer, spawn_rush_creatures)
+                        .chain()
+                        .in_set(GameSet::Simulation),
+                    // The time bonus is scored before the round-end transition applies
+                    (handle_rush_kills, handle_rush_round_end, track_rush_score)
+                        .chain()
+                        .in_set(GameSet::Consequences),
                 )
-                    .chain()
                     .run_if(in_state(GameState::Playing))
+                    .run_if(resource_equals(GameMode::Rush))
                     .run_if(resource_exists::<RushState>),
             );
     }
@@ -51,8 +59,11 @@ pub enum ScoreSource {
     TimeBonus,
 }
 
+/// Loadout table shipped with the game
+const LOADOUTS_RON: &str = include_str!("../assets/data/rush_loadouts.ron");
+
 /// Rush mode loadout configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RushLoadout {
     /// Starting weapon
     pub weapon: WeaponId,
@@ -60,6 +71,56 @@ pub struct RushLoadout {
     pub perks: Vec<PerkId>,
     /// Loadout name for display
     pub name: String,
+    /// Progress needed before the loadout can be picked (None = always available)
+    #[serde(default)]
+    pub unlock: Option<LoadoutUnlock>,
+}
+
+impl RushLoadout {
+    /// Why the loadout cannot be picked yet, or None if it is unlocked
+    pub fn lock_reason(&self, progress: &UnlockProgress) -> Option<String> {
+        self.unlock
+            .filter(|unlock| !unlock.is_met(progress))
+            .map(|unlock| unlock.describe(progress))
+    }
+}
+
+/// Profile condition that unlocks a loadout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoadoutUnlock {
+    /// Every quest in the chapter completed
+    CompleteChapter(u32),
+    /// Creatures killed across all runs
+    LifetimeKills(u64),
+}
+
+impl LoadoutUnlock {
+    pub fn is_met(&self, progress: &UnlockProgress) -> bool {
+        match *self {
+            LoadoutUnlock::CompleteChapter(chapter) => progress.completed_chapters.contains(&chapter),
+            LoadoutUnlock::LifetimeKills(kills) => progress.lifetime_kills >= kills,
+        }
+    }
+
+    /// Lock reason for the loadout select line
+    pub fn describe(&self, progress: &UnlockProgress) -> String {
+        match *self {
+            LoadoutUnlock::CompleteChapter(chapter) => format!("Complete chapter {}", chapter),
+            LoadoutUnlock::LifetimeKills(kills) => format!(
+                "Reach {} lifetime kills ({}/{})",
+                kills,
+                progress.lifetime_kills.min(kills),
+                kills
+            ),
+        }
+    }
+}
+
+/// Profile progress that loadout unlocks are checked against
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnlockProgress {
+    pub completed_chapters: Vec<u32>,
+    pub lifetime_kills: u64,
 }
 
 impl Default for RushLoadout {
@@ -72,61 +133,43 @@ impl Default for RushLoadout {
                 PerkId::BarrelGreaser,
             ],
             name: "Assault".to_string(),
+            unlock: None,
         }
     }
 }
 
-/// Available rush loadouts
-pub fn available_loadouts() -> Vec<RushLoadout> {
-    vec![
-        RushLoadout {
-            weapon: WeaponId::AssaultRifle,
-            perks: vec![
-                PerkId::Fastshot,
-                PerkId::Sharpshooter,
-                PerkId::BarrelGreaser,
-            ],
-            name: "Assault".to_string(),
-        },
-        RushLoadout {
-            weapon: WeaponId::Shotgun,
-            perks: vec![
-                PerkId::Fastloader,
-                PerkId::Sharpshooter,
-                PerkId::ThickSkinned,
-            ],
-            name: "Shotgunner".to_string(),
-        },
-        RushLoadout {
-            weapon: WeaponId::Minigun,
-            perks: vec![
-                PerkId::Fastshot,
-                PerkId::BarrelGreaser,
-                PerkId::ThickSkinned,
-            ],
-            name: "Heavy".to_string(),
-        },
-        RushLoadout {
-            weapon: WeaponId::RocketLauncher,
-            perks: vec![
-                PerkId::Pyromaniac,
-                PerkId::Fastloader,
-                PerkId::ThickSkinned,
-            ],
-            name: "Demolition".to_string(),
-        },
-        RushLoadout {
-            weapon: WeaponId::PlasmaRifle,
-            perks: vec![
-                PerkId::Fastshot,
-                PerkId::Sharpshooter,
-                PerkId::Highlander,
-            ],
-            name: "Plasma".to_string(),
-        },
-    ]
+/// Parses the shipped loadout table, falling back to the default loadout
+fn parse_loadouts() -> Vec<RushLoadout> {
+    match ron::from_str::<Vec<RushLoadout>>(LOADOUTS_RON) {
+        Ok(loadouts) if !loadouts.is_empty() => loadouts,
+        Ok(_) => vec![RushLoadout::default()],
+        Err(err) => {
+            warn!("Ignoring invalid rush loadout table: {}", err);
+            vec![RushLoadout::default()]
+        }
+    }
 }
 
+/// Available rush loadouts, in menu order; parsed once when the plugin is built
+#[derive(Resource, Debug, Clone)]
+pub struct RushLoadouts(pub Vec<RushLoadout>);
+
+impl Default for RushLoadouts {
+    fn default() -> Self {
+        Self(parse_loadouts())
+    }
+}
+
+impl RushLoadouts {
+    /// Loadout at a menu index, wrapping around
+    pub fn get(&self, index: usize) -> &RushLoadout {
+        &self.0[index % self.0.len()]
+    }
+}
+
+/// Length of a rush round in seconds
+pub const RUSH_ROUND_DURATION: f32 = 120.0;
+
 /// Resource tracking rush mode state
 #[derive(Resource, Debug)]
 pub struct RushState {
@@ -182,30 +225,6 @@ impl RushState {
         }
     }
 
-    /// Get base score for a creature type
-    pub fn creature_score(creature_type: CreatureType) -> u32 {
-        match creature_type {
-            CreatureType::Zombie => 10,
-            CreatureType::Spider => 15,
-            CreatureType::Lizard => 20,
-            CreatureType::Beetle => 15,
-            CreatureType::AlienSpider => 35,
-            CreatureType::Giant => 100,
-            CreatureType::Necromancer => 80,
-            CreatureType::GiantSpider => 120,
-            CreatureType::Dog => 25,
-            CreatureType::Runner => 30,
-            CreatureType::AlienShooter => 40,
-            CreatureType::Turret => 50,
-            CreatureType::Ghost => 45,
-            CreatureType::Exploder => 35,
-            CreatureType::Splitter => 40,
-            CreatureType::BossSpider => 500,
-            CreatureType::BossAlien => 800,
-            CreatureType::BossNest => 1000,
-        }
-    }
-
     /// Calculate time bonus at end of round
     pub fn calculate_time_bonus(&self) -> u32 {
         // No time bonus if round ended naturally
@@ -261,9 +280,51 @@ impl RushState {
     }
 }
 
+#[cfg(test)]
+impl RushState {
+    /// Kill scores, streak multipliers and loadouts
+    pub fn snapshot_repr() -> crate::snapshot::SnapshotTable {
+        use crate::snapshot::SnapshotTable;
+
+        let registry = CreatureRegistry::new();
+        let mut scores = SnapshotTable::new();
+        for creature_type in CreatureType::all() {
+            scores.int(&format!("{:?}", creature_type), registry.score(*creature_type));
+        }
+
+        let mut streaks = SnapshotTable::new();
+        for streak in [0, 5, 10, 20, 50] {
+            let state = Self {
+                kill_streak: streak,
+                ..default()
+            };
+            streaks.float(&format!("{:02}", streak), state.streak_multiplier());
+        }
+
+        let mut loadouts = SnapshotTable::new();
+        for loadout in parse_loadouts() {
+            let perks: Vec<String> = loadout.perks.iter().map(|p| format!("{:?}", p)).collect();
+            let mut entry = SnapshotTable::new();
+            entry
+                .ident("weapon", loadout.weapon)
+                .text("perks", &perks.join(", "))
+                .ident("unlock", loadout.unlock);
+            loadouts.table(&loadout.name, entry);
+        }
+
+        let mut table = SnapshotTable::new();
+        table
+            .table("creature_scores", scores)
+            .table("streak_multipliers", streaks)
+            .table("loadouts", loadouts)
+            .float("spawn_interval", Self::default().spawn_interval());
+        table
+    }
+}
+
 impl Default for RushState {
     fn default() -> Self {
-        Self::new(120.0, RushLoadout::default()) // 2 minute rounds
+        Self::new(RUSH_ROUND_DURATION, RushLoadout::default())
     }
 }
 
@@ -302,13 +363,13 @@ fn cleanup_rush_mode(mut commands: Commands) {
 }
 
 /// Updates the rush timer and checks for round end
-fn update_rush_timer(time: Res<Time>, mut rush: ResMut<RushState>) {
+fn update_rush_timer(time: Res<Time>, time_scale: Res<WorldTimeScale>, mut rush: ResMut<RushState>) {
     if rush.round_over {
         return;
     }
 
     rush.time_remaining -= time.delta_seconds();
-    rush.spawn_timer += time.delta_seconds();
+    rush.spawn_timer += time_scale.delta(&time);
 
     // Update streak timer
     rush.streak_timer += time.delta_seconds();
@@ -347,6 +408,7 @@ fn spawn_rush_creatures(
             spawn_events.send(SpawnCreatureEvent {
                 creature_type,
                 position: None,
+                summoner: None,
             });
         }
     }
@@ -390,6 +452,7 @@ fn track_rush_score(
 /// Handles creature deaths in Rush mode - sends score events
 fn handle_rush_kills(
     rush: Option<Res<RushState>>,
+    registry: Res<CreatureRegistry>,
     mut death_events: EventReader<crate::creatures::systems::CreatureDeathEvent>,
     mut score_events: EventWriter<RushScoreEvent>,
 ) {
@@ -399,8 +462,7 @@ fn handle_rush_kills(
     }
 
     for event in death_events.read() {
-        // Use RushState::creature_score to get base points
-        let base_score = RushState::creature_score(event.creature_type);
+        let base_score = registry.score(event.creature_type);
         score_events.send(RushScoreEvent {
             points: base_score,
             source: ScoreSource::Kill(event.creature_type),
@@ -483,17 +545,80 @@ mod tests {
 
     #[test]
     fn creature_scores_vary() {
-        assert!(RushState::creature_score(CreatureType::Giant) > RushState::creature_score(CreatureType::Zombie));
-        assert!(RushState::creature_score(CreatureType::BossNest) > RushState::creature_score(CreatureType::Giant));
+        let registry = CreatureRegistry::new();
+        assert!(registry.score(CreatureType::Giant) > registry.score(CreatureType::Zombie));
+        assert!(registry.score(CreatureType::BossNest) > registry.score(CreatureType::Giant));
     }
 
     #[test]
     fn loadouts_have_three_perks() {
-        for loadout in available_loadouts() {
+        for loadout in parse_loadouts() {
             assert_eq!(loadout.perks.len(), 3);
         }
     }
 
+    #[test]
+    fn loadout_table_references_known_weapons_and_perks() {
+        let loadouts: Vec<RushLoadout> = ron::from_str(LOADOUTS_RON).expect("loadout table parses");
+        assert!(loadouts.len() >= 10);
+
+        let weapons = crate::weapons::WeaponRegistry::new();
+        let perks = crate::perks::PerkRegistry::new();
+        let mut names = std::collections::HashSet::new();
+        for loadout in &loadouts {
+            assert!(names.insert(loadout.name.as_str()), "duplicate loadout {}", loadout.name);
+            assert!(weapons.get(loadout.weapon).is_some(), "{}: unknown weapon", loadout.name);
+            for perk in &loadout.perks {
+                assert!(perks.get(*perk).is_some(), "{}: unknown perk {:?}", loadout.name, perk);
+            }
+        }
+    }
+
+    #[test]
+    fn loadout_unlocks_are_reachable_from_profile() {
+        let db = crate::quests::QuestDatabase::new();
+        let locked: Vec<_> = parse_loadouts().into_iter().filter_map(|l| l.unlock).collect();
+        assert!(!locked.is_empty());
+        for unlock in locked {
+            match unlock {
+                LoadoutUnlock::CompleteChapter(chapter) => {
+                    assert!(db.quests.iter().any(|q| q.chapter == chapter), "no chapter {}", chapter);
+                }
+                LoadoutUnlock::LifetimeKills(kills) => assert!(kills > 0),
+            }
+        }
+    }
+
+    #[test]
+    fn lock_evaluation_against_profiles() {
+        let fresh = UnlockProgress::default();
+        let veteran = UnlockProgress {
+            completed_chapters: vec![1, 2],
+            lifetime_kills: 5000,
+        };
+        let almost = UnlockProgress {
+            completed_chapters: vec![1, 3],
+            lifetime_kills: 4999,
+        };
+
+        let chapter = LoadoutUnlock::CompleteChapter(2);
+        let kills = LoadoutUnlock::LifetimeKills(5000);
+        assert!(!chapter.is_met(&fresh) && !kills.is_met(&fresh));
+        assert!(!chapter.is_met(&almost) && !kills.is_met(&almost));
+        assert!(chapter.is_met(&veteran) && kills.is_met(&veteran));
+
+        let loadout = RushLoadout {
+            unlock: Some(kills),
+            ..RushLoadout::default()
+        };
+        assert_eq!(
+            loadout.lock_reason(&almost).as_deref(),
+            Some("Reach 5000 lifetime kills (4999/5000)")
+        );
+        assert_eq!(loadout.lock_reason(&veteran), None);
+        assert_eq!(RushLoadout::default().lock_reason(&fresh), None);
+    }
+
     #[test]
     fn apply_loadout_adds_perks() {
         let loadout = RushLoadout::default();
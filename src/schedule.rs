@@ -0,0 +1,251 @@
+//! Gameplay frame ordering
+//!
+//! Every gameplay system in `Update` belongs to one [`GameSet`], and the sets
+//! run in a fixed order configured only here:
+//!
+//! 1. [`GameSet::Input`]: read devices, aim, queue shots and item use.
+//! 2. [`GameSet::Simulation`]: spawn, move, fire, collect pickups.
+//! 3. [`GameSet::DamageResolution`]: projectile, explosion, status and contact
+//!    damage lands on health.
+//! 4. [`GameSet::DeathResolution`]: anything at zero health sends its death
+//!    event and stops being targetable.
+//! 5. [`GameSet::Consequences`]: drops, XP, score and run stats, read from
+//!    this frame's death events.
+//! 6. [`GameSet::Presentation`]: effects, audio, HUD, rumble.
+//!
+//! The contract: a creature killed during a frame is credited in that same
+//! frame, before any state change requested by that frame is applied. Systems
+//! that read death events belong in `Consequences`, never earlier, so a kill
+//! on the last frame of a run still yields its drop, XP and score exactly once.
+//! Ordering *within* a set stays with the owning plugin's `.chain()`.
+//!
+//! `Input`, `Simulation` and `DamageResolution` are held while [`gameplay_frozen`]
+//! (perk selection, a boss intro), so nothing moves, fires or gets hurt behind a menu.
+
+use bevy::prelude::*;
+
+use crate::states::gameplay_frozen;
+
+/// Phases of a gameplay frame, in execution order
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameSet {
+    Input,
+    Simulation,
+    DamageResolution,
+    DeathResolution,
+    Consequences,
+    Presentation,
+}
+
+/// Plugin that orders the [`GameSet`]s
+pub struct GameSchedulePlugin;
+
+impl Plugin for GameSchedulePlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            (
+                GameSet::Input,
+                GameSet::Simulation,
+                GameSet::DamageResolution,
+                GameSet::DeathResolution,
+                GameSet::Consequences,
+                GameSet::Presentation,
+            )
+                .chain(),
+        )
+        .configure_sets(
+            Update,
+            (GameSet::Input, GameSet::Simulation, GameSet::DamageResolution).run_if(not(gameplay_frozen)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::input::InputPlugin;
+    use bevy::state::app::StatesPlugin;
+
+    use crate::assists::{AimAssistSettings, RunAssists};
+    use crate::audio::{HordeSurgeEvent, PlaySoundEvent};
+    use crate::bonuses::{Bonus, BonusDropChance, BonusesPlugin};
+    use crate::creatures::{CreatureBundle, CreatureHealth, CreatureRegistry, CreatureType, CreaturesPlugin};
+    use crate::effects::{ScreenShake, SpawnEffectEvent};
+    use crate::items::{CarriedItem, ItemType, ItemsPlugin};
+    use crate::player::{Experience, Health, Player, PlayerPlugin};
+    use crate::rush::{RushPlugin, RushState};
+    use crate::states::{GameMode, GameState, PlayingState};
+    use crate::viewport::ViewportPlugin;
+    use crate::weapons::WeaponsPlugin;
+
+    #[derive(Resource, Default)]
+    struct Visited(Vec<GameSet>);
+
+    fn probe(set: GameSet) -> impl FnMut(ResMut<Visited>) {
+        move |mut visited: ResMut<Visited>| visited.0.push(set)
+    }
+
+    #[test]
+    fn sets_run_in_frame_order() {
+        let order = [
+            GameSet::Input,
+            GameSet::Simulation,
+            GameSet::DamageResolution,
+            GameSet::DeathResolution,
+            GameSet::Consequences,
+            GameSet::Presentation,
+        ];
+        let mut app = App::new();
+        app.add_plugins(GameSchedulePlugin).init_resource::<Visited>();
+        // Registered back to front so only the set configuration can order them
+        for set in order.into_iter().rev() {
+            app.add_systems(Update, probe(set).in_set(set));
+        }
+
+        app.update();
+        app.update();
+        let expected: Vec<GameSet> = order.iter().chain(order.iter()).copied().collect();
+        assert_eq!(app.world().resource::<Visited>().0, expected);
+    }
+
+    fn gameplay_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, InputPlugin))
+            .insert_resource(Time::<()>::default())
+            .insert_state(GameState::Playing)
+            .add_sub_state::<PlayingState>()
+            .insert_resource(GameMode::Rush)
+            .init_resource::<AimAssistSettings>()
+            .init_resource::<RunAssists>()
+            .init_resource::<ScreenShake>()
+            .add_event::<PlaySoundEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<HordeSurgeEvent>()
+            .add_plugins((
+                GameSchedulePlugin,
+                ViewportPlugin,
+                PlayerPlugin,
+                CreaturesPlugin,
+                WeaponsPlugin,
+                BonusesPlugin,
+                ItemsPlugin,
+                RushPlugin,
+            ));
+        app
+    }
+
+    #[test]
+    fn kill_on_the_last_frame_of_a_run_is_credited_once() {
+        let mut app = gameplay_app();
+        app.insert_resource(BonusDropChance(1.0))
+            .insert_resource(RushState::default());
+        app.update();
+
+        let zombie = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(400.0, 0.0, 0.0)))
+            .id();
+        let mut health = app.world_mut().get_mut::<CreatureHealth>(zombie).unwrap();
+        let current = health.current;
+        health.damage(current);
+        // The round runs out in the same frame the kill lands
+        app.world_mut().resource_mut::<RushState>().time_remaining = 0.0;
+        app.update();
+
+        let experience = app
+            .world_mut()
+            .query_filtered::<&Experience, With<Player>>()
+            .single(app.world())
+            .current;
+        let bonuses = app
+            .world_mut()
+            .query_filtered::<(), With<Bonus>>()
+            .iter(app.world())
+            .count();
+        let score = app.world().resource::<RushState>().score;
+        assert_eq!(experience, CreatureType::Zombie.experience_value());
+        assert_eq!(bonuses, 1);
+        assert_eq!(score, CreatureRegistry::new().score(CreatureType::Zombie));
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::GameOver)
+        ));
+
+        // The transition itself only applies on the next frame
+        app.update();
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::GameOver);
+    }
+
+    #[test]
+    fn perk_select_holds_creatures_and_damage_but_keeps_the_play_field() {
+        let mut app = gameplay_app();
+        app.insert_resource(RushState::default());
+        app.update();
+
+        let biter = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(20.0, 0.0, 0.0)))
+            .id();
+        let walker = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::new(300.0, 0.0, 0.0)))
+            .id();
+        app.world_mut().resource_mut::<NextState<PlayingState>>().set(PlayingState::PerkSelect);
+        app.update();
+        assert_eq!(*app.world().resource::<State<PlayingState>>().get(), PlayingState::PerkSelect);
+
+        let health = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<&Health, With<Player>>()
+                .single(app.world())
+                .current
+        };
+        let full_health = health(&mut app);
+        for _ in 0..30 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(0.1));
+            app.update();
+        }
+        let walker_x = app.world().get::<Transform>(walker).unwrap().translation.x;
+        assert_eq!(walker_x, 300.0);
+        assert_eq!(health(&mut app), full_health);
+
+        // Picking a perk resumes the same fight
+        app.world_mut().resource_mut::<NextState<PlayingState>>().set(PlayingState::Active);
+        app.update();
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Playing);
+        assert!(app.world().get_entity(biter).is_some());
+        assert!(app.world().get_entity(walker).is_some());
+    }
+
+    #[test]
+    fn perk_select_ignores_movement_and_item_use() {
+        let mut app = gameplay_app();
+        app.insert_resource(RushState::default());
+        app.update();
+
+        let player = app
+            .world_mut()
+            .query_filtered::<Entity, With<Player>>()
+            .single(app.world());
+        app.world_mut().get_mut::<CarriedItem>(player).unwrap().set_item(ItemType::Nuke);
+        app.world_mut().resource_mut::<NextState<PlayingState>>().set(PlayingState::PerkSelect);
+        app.update();
+        let start = app.world().get::<Transform>(player).unwrap().translation;
+
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(KeyCode::Space);
+        keyboard.press(KeyCode::KeyW);
+        for _ in 0..10 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(0.1));
+            app.update();
+        }
+
+        assert_eq!(app.world().get::<Transform>(player).unwrap().translation, start);
+        assert_eq!(app.world().get::<CarriedItem>(player).unwrap().item, Some(ItemType::Nuke));
+    }
+}
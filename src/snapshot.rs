@@ -0,0 +1,298 @@
+//! Balance snapshots
+//!
+//! Serializes every balance table (weapons, perk bonuses, creatures, scores,
+//! difficulty curves) into one canonical text and checks it against a golden
+//! file, so each balance change lands as an explicit snapshot update.
+//!
+//! Regenerate the golden file with `BLESS_SNAPSHOTS=1 cargo test balance`.
+
+use std::collections::BTreeMap;
+
+/// Path of the checked-in balance snapshot
+pub const BALANCE_SNAPSHOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/balance.ron");
+/// Set to regenerate snapshots instead of comparing against them
+pub const BLESS_ENV: &str = "BLESS_SNAPSHOTS";
+
+/// Unchanged lines shown around each change in a diff
+const DIFF_CONTEXT: usize = 2;
+
+/// Formats a float with fixed precision so rounding noise does not show up as a change
+pub fn format_float(value: f32) -> String {
+    if value.is_nan() {
+        return "NaN".into();
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { "inf".into() } else { "-inf".into() };
+    }
+    let mut text = format!("{:.4}", value);
+    while text.ends_with('0') && !text.ends_with(".0") {
+        text.pop();
+    }
+    if text == "-0.0" {
+        text = "0.0".into();
+    }
+    text
+}
+
+/// A value in a snapshot table
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotValue {
+    /// Already formatted scalar
+    Scalar(String),
+    Table(SnapshotTable),
+}
+
+/// Map with sorted keys, rendered as RON
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SnapshotTable {
+    entries: BTreeMap<String, SnapshotValue>,
+}
+
+impl SnapshotTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn float(&mut self, key: &str, value: f32) -> &mut Self {
+        self.scalar(key, format_float(value))
+    }
+
+    pub fn int(&mut self, key: &str, value: impl Into<i64>) -> &mut Self {
+        self.scalar(key, value.into().to_string())
+    }
+
+    pub fn flag(&mut self, key: &str, value: bool) -> &mut Self {
+        self.scalar(key, value.to_string())
+    }
+
+    pub fn text(&mut self, key: &str, value: &str) -> &mut Self {
+        self.scalar(key, format!("{:?}", value))
+    }
+
+    /// Enum variants and other identifiers, written without quotes
+    pub fn ident(&mut self, key: &str, value: impl std::fmt::Debug) -> &mut Self {
+        self.scalar(key, format!("{:?}", value))
+    }
+
+    pub fn table(&mut self, key: &str, table: SnapshotTable) -> &mut Self {
+        self.entries.insert(key.to_string(), SnapshotValue::Table(table));
+        self
+    }
+
+    fn scalar(&mut self, key: &str, value: String) -> &mut Self {
+        self.entries.insert(key.to_string(), SnapshotValue::Scalar(value));
+        self
+    }
+
+    /// Entries whose value differs from `baseline`
+    pub fn changed_from(&self, baseline: &SnapshotTable) -> SnapshotTable {
+        let entries = self
+            .entries
+            .iter()
+            .filter(|(key, value)| baseline.entries.get(*key) != Some(*value))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        SnapshotTable { entries }
+    }
+
+    /// Canonical text: one entry per line, keys sorted, four-space indent
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        self.render_into(&mut out, 0);
+        out.push('\n');
+        out
+    }
+
+    fn render_into(&self, out: &mut String, depth: usize) {
+        if self.entries.is_empty() {
+            out.push_str("{}");
+            return;
+        }
+        out.push_str("{\n");
+        for (key, value) in &self.entries {
+            out.push_str(&"    ".repeat(depth + 1));
+            out.push_str(&format!("{:?}: ", key));
+            match value {
+                SnapshotValue::Scalar(text) => out.push_str(text),
+                SnapshotValue::Table(table) => table.render_into(out, depth + 1),
+            }
+            out.push_str(",\n");
+        }
+        out.push_str(&"    ".repeat(depth));
+        out.push('}');
+    }
+}
+
+/// Line diff of two snapshots ("-" expected, "+" actual) with a little context.
+/// Empty when they match.
+pub fn line_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // Longest common subsequence table, filled from the end
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    // (marker, expected line number, text)
+    let mut ops: Vec<(char, usize, &str)> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push((' ', i + 1, old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push(('-', i + 1, old[i]));
+            i += 1;
+        } else {
+            ops.push(('+', i + 1, new[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..ops.len()).filter(|&k| ops[k].0 != ' ').collect();
+    let mut out = String::new();
+    let mut last_shown: Option<usize> = None;
+    for &k in &changed {
+        let start = k.saturating_sub(DIFF_CONTEXT);
+        let end = (k + DIFF_CONTEXT).min(ops.len() - 1);
+        let from = match last_shown {
+            Some(shown) if shown >= start => shown + 1,
+            Some(_) => {
+                out.push_str("...\n");
+                start
+            }
+            None => start,
+        };
+        for (marker, line, text) in ops.iter().take(end + 1).skip(from) {
+            out.push_str(&format!("{}{:>5} | {}\n", marker, line, text));
+        }
+        last_shown = Some(last_shown.map_or(end, |shown| shown.max(end)));
+    }
+    out
+}
+
+/// Compares `actual` with the golden file at `path`, or rewrites it when blessing
+pub fn check_snapshot(path: &str, actual: &str) {
+    if std::env::var_os(BLESS_ENV).is_some() {
+        if let Some(dir) = std::path::Path::new(path).parent() {
+            std::fs::create_dir_all(dir).expect("create snapshot directory");
+        }
+        std::fs::write(path, actual).expect("write snapshot");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(path).unwrap_or_default();
+    let diff = line_diff(&expected, actual);
+    assert!(
+        diff.is_empty(),
+        "snapshot {} is out of date (- snapshot, + current):\n{}\nIf the change is intended, rerun with {}=1 and commit the updated file.",
+        path,
+        diff,
+        BLESS_ENV
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::creatures::CreatureRegistry;
+    use crate::perks::PerkRegistry;
+    use crate::rush::RushState;
+    use crate::survival::SurvivalState;
+    use crate::weapons::WeaponRegistry;
+
+    /// Every balance table in one canonical document
+    fn balance_snapshot() -> String {
+        let mut root = SnapshotTable::new();
+        root.table("weapons", WeaponRegistry::new().snapshot_repr())
+            .table("perks", PerkRegistry::new().snapshot_repr())
+            .table("creatures", CreatureRegistry::new().snapshot_repr())
+            .table("rush", RushState::snapshot_repr())
+            .table("survival", SurvivalState::snapshot_repr());
+        root.render()
+    }
+
+    #[test]
+    fn balance_tables_match_snapshot() {
+        check_snapshot(BALANCE_SNAPSHOT, &balance_snapshot());
+    }
+
+    #[test]
+    fn balance_snapshot_is_deterministic() {
+        assert_eq!(balance_snapshot(), balance_snapshot());
+    }
+
+    #[test]
+    fn floats_use_fixed_precision() {
+        assert_eq!(format_float(10.0), "10.0");
+        assert_eq!(format_float(0.88), "0.88");
+        assert_eq!(format_float(1.0 * 0.88 * 1.0000001), "0.88");
+        assert_eq!(format_float(2.0 / 3.0), "0.6667");
+        assert_eq!(format_float(-0.00001), "0.0");
+        assert_eq!(format_float(-1.5), "-1.5");
+        assert_eq!(format_float(f32::INFINITY), "inf");
+    }
+
+    #[test]
+    fn keys_render_sorted_regardless_of_insert_order() {
+        let mut a = SnapshotTable::new();
+        a.int("b", 2).float("a", 1.0);
+        let mut b = SnapshotTable::new();
+        b.float("a", 1.0).int("b", 2);
+        assert_eq!(a.render(), b.render());
+        assert_eq!(a.render(), "{\n    \"a\": 1.0,\n    \"b\": 2,\n}\n");
+    }
+
+    #[test]
+    fn nested_tables_indent_and_empty_tables_stay_inline() {
+        let mut inner = SnapshotTable::new();
+        inner.ident("kind", Some(3)).text("name", "Pistol");
+        let mut root = SnapshotTable::new();
+        root.table("empty", SnapshotTable::new()).table("inner", inner);
+        assert_eq!(
+            root.render(),
+            "{\n    \"empty\": {},\n    \"inner\": {\n        \"kind\": Some(3),\n        \"name\": \"Pistol\",\n    },\n}\n"
+        );
+    }
+
+    #[test]
+    fn changed_from_keeps_only_differences() {
+        let mut base = SnapshotTable::new();
+        base.float("speed", 1.0).flag("unstoppable", false);
+        let mut perk = SnapshotTable::new();
+        perk.float("speed", 1.2).flag("unstoppable", false);
+        let mut expected = SnapshotTable::new();
+        expected.float("speed", 1.2);
+        assert_eq!(perk.changed_from(&base), expected);
+    }
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert!(line_diff("a\nb\nc\n", "a\nb\nc\n").is_empty());
+
+        let diff = line_diff("a\nb\nc\n", "a\nB\nc\n");
+        assert!(diff.contains("-    2 | b"));
+        assert!(diff.contains("+    3 | B"));
+        assert!(diff.find("-    2 | b") < diff.find("+    3 | B"));
+        assert!(diff.contains("     1 | a"));
+    }
+
+    #[test]
+    fn diff_elides_distant_unchanged_lines() {
+        let expected: String = (0..20).map(|n| format!("line {}\n", n)).collect();
+        let actual = expected.replace("line 1\n", "line one\n").replace("line 18\n", "line eighteen\n");
+        let diff = line_diff(&expected, &actual);
+        assert!(diff.contains("...\n"));
+        assert!(!diff.contains("line 10"));
+        assert!(diff.contains("+") && diff.contains("line eighteen"));
+    }
+}
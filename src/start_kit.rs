@@ -0,0 +1,264 @@
+//! Survival start kit
+//!
+//! A gentler start for new players: fresh profiles begin survival carrying a
+//! Nuke, and dying early twice in a row arms a one-tier weapon bump until a run
+//! finally lasts five minutes. Never applies to ranked runs.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::assists::RunAssists;
+use crate::items::{CarriedItem, ItemType};
+use crate::player::{spawn_player, Player, PlayerConfig, PlayerDeathEvent};
+use crate::profile::PlayerProfile;
+use crate::quests::ActiveQuest;
+use crate::rush::RushState;
+use crate::schedule::GameSet;
+use crate::states::GameState;
+use crate::survival::SurvivalState;
+use crate::weapons::{EquippedWeapon, WeaponId, WeaponRegistry};
+
+/// Deaths before this many seconds count as early
+pub const EARLY_DEATH_SECONDS: f32 = 180.0;
+/// Early deaths in a row that arm the weapon bump
+pub const EARLY_DEATHS_FOR_PITY: u8 = 2;
+/// A run lasting this long disarms the weapon bump
+pub const PITY_RESET_SECONDS: f32 = 300.0;
+
+/// Plugin for the survival start kit
+pub struct StartKitPlugin;
+
+impl Plugin for StartKitPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::Playing),
+            apply_survival_start_kit
+                .after(spawn_player)
+                .after(crate::assists::reset_run_assists),
+        )
+        .add_systems(
+            Update,
+            record_survival_death
+                .run_if(in_state(GameState::Playing))
+                .in_set(GameSet::Consequences),
+        );
+    }
+}
+
+/// What a survival run starts with (part of [`PlayerConfig`])
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurvivalStartKit {
+    /// Carried item granted to new profiles
+    pub starting_item: Option<ItemType>,
+    /// Profiles with fewer survival deaths than this still count as new
+    pub new_profile_deaths: u32,
+    /// Weapon replacing the Pistol while the pity bump is armed; None disables the bump
+    pub pity_weapon: Option<WeaponId>,
+}
+
+impl Default for SurvivalStartKit {
+    fn default() -> Self {
+        Self {
+            starting_item: Some(ItemType::Nuke),
+            new_profile_deaths: 5,
+            pity_weapon: Some(WeaponId::Smg),
+        }
+    }
+}
+
+/// Early-death streak and whether the next runs get the weapon bump
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PityState {
+    pub early_deaths: u8,
+    pub armed: bool,
+}
+
+impl PityState {
+    /// Advances the state with the length of a finished survival run
+    pub fn record_run(&mut self, seconds: f32) {
+        if seconds >= PITY_RESET_SECONDS {
+            *self = PityState::default();
+        } else if seconds < EARLY_DEATH_SECONDS {
+            self.early_deaths = (self.early_deaths + 1).min(EARLY_DEATHS_FOR_PITY);
+            if self.early_deaths >= EARLY_DEATHS_FOR_PITY {
+                self.armed = true;
+            }
+        } else {
+            // A middling run ends the streak but keeps an armed bump
+            self.early_deaths = 0;
+        }
+    }
+}
+
+/// Survival runs are the ones without a rush loadout or an active quest
+fn is_survival_run(rush: Option<&RushState>, active_quest: Option<&ActiveQuest>) -> bool {
+    rush.is_none() && active_quest.is_none_or(|quest| quest.quest_id.is_none())
+}
+
+/// Hands out the start kit once the player has spawned
+#[allow(clippy::too_many_arguments)]
+fn apply_survival_start_kit(
+    config: Res<PlayerConfig>,
+    profile: Res<PlayerProfile>,
+    run_assists: Res<RunAssists>,
+    rush: Option<Res<RushState>>,
+    active_quest: Option<Res<ActiveQuest>>,
+    weapon_registry: Res<WeaponRegistry>,
+    mut player_query: Query<(&mut CarriedItem, &mut EquippedWeapon), With<Player>>,
+) {
+    if run_assists.ranked || !is_survival_run(rush.as_deref(), active_quest.as_deref()) {
+        return;
+    }
+    let kit = &config.start_kit;
+
+    for (mut carried, mut weapon) in player_query.iter_mut() {
+        if profile.survival_deaths < kit.new_profile_deaths {
+            if let Some(item) = kit.starting_item {
+                carried.set_item(item);
+            }
+        }
+        if let (true, Some(pity_weapon)) = (profile.pity.armed, kit.pity_weapon) {
+            let ammo = weapon_registry
+                .get(pity_weapon)
+                .and_then(|data| data.ammo_capacity);
+            *weapon = EquippedWeapon::new(pity_weapon, ammo);
+            info!("Early deaths: starting with {:?}", pity_weapon);
+        }
+    }
+}
+
+/// Feeds survival deaths into the profile's pity state
+fn record_survival_death(
+    mut death_events: EventReader<PlayerDeathEvent>,
+    survival: Option<Res<SurvivalState>>,
+    run_assists: Res<RunAssists>,
+    rush: Option<Res<RushState>>,
+    active_quest: Option<Res<ActiveQuest>>,
+    mut profile: ResMut<PlayerProfile>,
+) {
+    if death_events.read().count() == 0 {
+        return;
+    }
+    let Some(survival) = survival else { return };
+    if run_assists.ranked || !is_survival_run(rush.as_deref(), active_quest.as_deref()) {
+        return;
+    }
+    profile.survival_deaths += 1;
+    profile.pity.record_run(survival.game_time);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quests::QuestId;
+
+    fn after_runs(runs: &[f32]) -> PityState {
+        let mut pity = PityState::default();
+        for &seconds in runs {
+            pity.record_run(seconds);
+        }
+        pity
+    }
+
+    #[test]
+    fn one_early_death_does_not_arm() {
+        assert_eq!(
+            after_runs(&[60.0]),
+            PityState {
+                early_deaths: 1,
+                armed: false
+            }
+        );
+    }
+
+    #[test]
+    fn two_early_deaths_in_a_row_arm() {
+        assert!(after_runs(&[60.0, 179.0]).armed);
+    }
+
+    #[test]
+    fn middling_run_breaks_the_streak() {
+        assert!(!after_runs(&[60.0, 200.0, 60.0]).armed);
+        assert_eq!(after_runs(&[60.0, 200.0]).early_deaths, 0);
+    }
+
+    #[test]
+    fn armed_bump_survives_middling_and_early_runs() {
+        assert!(after_runs(&[60.0, 60.0, 200.0]).armed);
+        assert!(after_runs(&[60.0, 60.0, 30.0, 30.0]).armed);
+    }
+
+    #[test]
+    fn five_minute_run_resets_everything() {
+        assert_eq!(after_runs(&[60.0, 60.0, 300.0]), PityState::default());
+        assert_eq!(after_runs(&[60.0, 300.0]), PityState::default());
+    }
+
+    #[test]
+    fn boundaries_are_exact() {
+        assert_eq!(after_runs(&[EARLY_DEATH_SECONDS]).early_deaths, 0);
+        assert_eq!(after_runs(&[EARLY_DEATH_SECONDS - 0.01]).early_deaths, 1);
+        assert!(after_runs(&[60.0, 60.0, PITY_RESET_SECONDS - 0.01]).armed);
+    }
+
+    fn start_app(profile: PlayerProfile) -> App {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(GameState::MainMenu)
+            .add_plugins(StartKitPlugin)
+            .add_systems(OnEnter(GameState::Playing), spawn_player)
+            .init_resource::<PlayerConfig>()
+            .insert_resource(profile)
+            .init_resource::<RunAssists>()
+            .init_resource::<ActiveQuest>()
+            .init_resource::<WeaponRegistry>()
+            .add_event::<PlayerDeathEvent>();
+        app
+    }
+
+    fn start_run(app: &mut App) -> (Option<ItemType>, EquippedWeapon) {
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        let (carried, weapon) = app
+            .world_mut()
+            .query_filtered::<(&CarriedItem, &EquippedWeapon), With<Player>>()
+            .single(app.world());
+        (carried.item, weapon.clone())
+    }
+
+    #[test]
+    fn two_early_deaths_start_the_next_run_with_the_smg() {
+        let mut profile = PlayerProfile {
+            survival_deaths: 2,
+            ..default()
+        };
+        profile.pity.record_run(90.0);
+        profile.pity.record_run(150.0);
+
+        let mut app = start_app(profile);
+        let (item, weapon) = start_run(&mut app);
+        assert_eq!(weapon.weapon, WeaponId::Smg.into());
+        assert_eq!(item, Some(ItemType::Nuke));
+    }
+
+    #[test]
+    fn kit_is_skipped_for_ranked_and_quest_runs() {
+        let mut profile = PlayerProfile::default();
+        profile.pity.armed = true;
+
+        let mut app = start_app(profile.clone());
+        app.world_mut().resource_mut::<RunAssists>().ranked = true;
+        let (item, weapon) = start_run(&mut app);
+        assert_eq!(item, None);
+        assert_eq!(weapon.weapon, WeaponId::Pistol.into());
+
+        let mut app = start_app(profile);
+        app.world_mut().resource_mut::<ActiveQuest>().quest_id = Some(QuestId::Q01LandHostile);
+        let (item, _) = start_run(&mut app);
+        assert_eq!(item, None);
+    }
+}
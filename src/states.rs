@@ -35,10 +35,24 @@ pub enum PlayingState {
     WaveTransition,
     /// Boss encounter
     BossEncounter,
-    /// Perk selection screen (on level up)
+    /// Perk selection screen (on level up). A sub-state so the play field survives;
+    /// the Input, Simulation and DamageResolution sets are held by gameplay_frozen while choosing
     PerkSelect,
 }
 
+/// Which mode the next (or current) run plays, chosen from the menus.
+/// Each mode's plugin only sets up and runs while its mode is selected.
+#[derive(Resource, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum GameMode {
+    /// A quest picked on the quest select screen
+    Quest,
+    /// Endless survival
+    #[default]
+    Survival,
+    /// Timed round with a fixed loadout
+    Rush,
+}
+
 /// Plugin for game state management
 pub struct GameStatePlugin;
 
@@ -46,6 +60,7 @@ impl Plugin for GameStatePlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>()
             .add_sub_state::<PlayingState>()
+            .init_resource::<GameMode>()
             .insert_resource(LoadingState::default())
             .add_systems(OnEnter(GameState::Loading), start_loading)
             .add_systems(
@@ -54,8 +69,6 @@ impl Plugin for GameStatePlugin {
             )
             .add_systems(OnEnter(GameState::MainMenu), setup_main_menu_state)
             .add_systems(OnExit(GameState::MainMenu), cleanup_main_menu_state)
-            .add_systems(OnEnter(GameState::QuestSelect), setup_quest_select)
-            .add_systems(OnExit(GameState::QuestSelect), cleanup_quest_select)
             .add_systems(OnEnter(GameState::Playing), setup_playing_state)
             .add_systems(OnExit(GameState::Playing), cleanup_playing_state)
             .add_systems(
@@ -110,17 +123,61 @@ impl LoadingState {
     }
 }
 
+/// Wave transition length without intro text
+pub const WAVE_TRANSITION_DURATION: f32 = 3.0;
+/// Seconds the typewriter takes to reveal a wave's intro text
+pub const INTRO_REVEAL_DURATION: f32 = 1.5;
+/// Reading time added per character of intro text
+const READING_SECONDS_PER_CHAR: f32 = 0.04;
+/// Cap on a transition stretched by intro text
+pub const MAX_WAVE_TRANSITION_DURATION: f32 = 8.0;
+/// Ends a wave transition early
+pub const WAVE_TRANSITION_SKIP_KEY: KeyCode = KeyCode::Enter;
+
+/// How long a wave transition lasts: longer when there is intro text to read, up to a cap
+pub fn wave_transition_duration(intro_text: Option<&str>) -> f32 {
+    match intro_text.filter(|text| !text.is_empty()) {
+        Some(text) => (WAVE_TRANSITION_DURATION
+            + INTRO_REVEAL_DURATION
+            + text.chars().count() as f32 * READING_SECONDS_PER_CHAR)
+            .min(MAX_WAVE_TRANSITION_DURATION),
+        None => WAVE_TRANSITION_DURATION,
+    }
+}
+
 /// Resource for wave transition state
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct WaveTransitionState {
     /// Timer for transition duration
     pub timer: f32,
+    /// Seconds until the next wave starts
+    pub duration: f32,
     /// Next wave number
     pub next_wave: u32,
+    /// Narrative shown under the wave header
+    pub intro_text: Option<String>,
     /// Whether transition is complete
     pub complete: bool,
 }
 
+impl Default for WaveTransitionState {
+    fn default() -> Self {
+        Self::new(0, None)
+    }
+}
+
+impl WaveTransitionState {
+    pub fn new(next_wave: u32, intro_text: Option<String>) -> Self {
+        Self {
+            timer: 0.0,
+            duration: wave_transition_duration(intro_text.as_deref()),
+            next_wave,
+            intro_text,
+            complete: false,
+        }
+    }
+}
+
 /// Resource for boss encounter state
 #[derive(Resource)]
 pub struct BossEncounterState {
@@ -181,20 +238,6 @@ fn cleanup_main_menu_state() {
     info!("Leaving main menu");
 }
 
-fn setup_quest_select(quest_db: Res<crate::quests::QuestDatabase>) {
-    info!("Entering quest selection");
-    // Display available quests using get_by_index
-    for i in 0..10 {
-        if let Some(quest) = quest_db.get_by_index(i) {
-            info!("  Quest {}: {} - {}", i + 1, quest.name, quest.description);
-        }
-    }
-}
-
-fn cleanup_quest_select() {
-    info!("Leaving quest selection");
-}
-
 fn setup_playing_state() {
     info!("Starting gameplay");
 }
@@ -223,7 +266,8 @@ fn handle_unpause_input(
 
 // Wave transition systems
 fn on_wave_transition_enter(mut commands: Commands) {
-    commands.insert_resource(WaveTransitionState::default());
+    // Keeps the state set up by trigger_wave_transition
+    commands.init_resource::<WaveTransitionState>();
     info!("Wave transition started");
 }
 
@@ -234,15 +278,16 @@ fn on_wave_transition_exit(mut commands: Commands) {
 
 fn update_wave_transition(
     time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
     mut transition: ResMut<WaveTransitionState>,
     mut next_state: ResMut<NextState<PlayingState>>,
 ) {
     transition.timer += time.delta_seconds();
+    if keyboard.just_pressed(WAVE_TRANSITION_SKIP_KEY) {
+        transition.timer = transition.duration;
+    }
 
-    // 3 second transition between waves
-    const TRANSITION_DURATION: f32 = 3.0;
-
-    if transition.timer >= TRANSITION_DURATION && !transition.complete {
+    if transition.timer >= transition.duration && !transition.complete {
         transition.complete = true;
         next_state.set(PlayingState::Active);
         info!("Wave {} starting!", transition.next_wave);
@@ -297,6 +342,19 @@ pub fn update_boss_encounter(
     }
 }
 
+/// Run condition: true while an overlay (perk selection, boss intro) has gameplay frozen.
+/// Player buff timers must not tick while this holds.
+pub fn gameplay_frozen(
+    playing_state: Option<Res<State<PlayingState>>>,
+    boss_state: Option<Res<BossEncounterState>>,
+) -> bool {
+    match playing_state.map(|s| *s.get()) {
+        Some(PlayingState::PerkSelect) => true,
+        Some(PlayingState::BossEncounter) => boss_state.is_some_and(|b| !b.intro_complete),
+        _ => false,
+    }
+}
+
 /// Resource for pending boss encounter (set before transitioning)
 #[derive(Resource)]
 pub struct PendingBossEncounter {
@@ -308,12 +366,9 @@ pub fn trigger_wave_transition(
     commands: &mut Commands,
     next_state: &mut ResMut<NextState<PlayingState>>,
     wave_number: u32,
+    intro_text: Option<String>,
 ) {
-    commands.insert_resource(WaveTransitionState {
-        timer: 0.0,
-        next_wave: wave_number,
-        complete: false,
-    });
+    commands.insert_resource(WaveTransitionState::new(wave_number, intro_text));
     next_state.set(PlayingState::WaveTransition);
     info!("Triggering transition to wave {}", wave_number);
 }
@@ -364,6 +419,76 @@ mod tests {
         assert!(!state.complete);
     }
 
+    #[test]
+    fn intro_text_stretches_the_transition_up_to_a_cap() {
+        assert_eq!(wave_transition_duration(None), WAVE_TRANSITION_DURATION);
+        assert_eq!(wave_transition_duration(Some("")), WAVE_TRANSITION_DURATION);
+
+        let short = wave_transition_duration(Some("They are coming."));
+        assert!(short > WAVE_TRANSITION_DURATION + INTRO_REVEAL_DURATION);
+        let long_text = "word ".repeat(200);
+        assert_eq!(wave_transition_duration(Some(&long_text)), MAX_WAVE_TRANSITION_DURATION);
+        assert!(short < MAX_WAVE_TRANSITION_DURATION);
+    }
+
+    fn transition_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .insert_resource(Time::<()>::default())
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_plugins(GameStatePlugin);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app
+    }
+
+    fn start_transition(app: &mut App, intro_text: Option<&str>) {
+        app.insert_resource(WaveTransitionState::new(2, intro_text.map(String::from)));
+        app.world_mut()
+            .resource_mut::<NextState<PlayingState>>()
+            .set(PlayingState::WaveTransition);
+        app.update();
+    }
+
+    /// Seconds (in 0.1s steps) until the transition hands back to active play
+    fn seconds_in_transition(app: &mut App, skip_after: Option<u32>) -> f32 {
+        for step in 1..=200 {
+            if Some(step) == skip_after {
+                app.world_mut()
+                    .resource_mut::<ButtonInput<KeyCode>>()
+                    .press(WAVE_TRANSITION_SKIP_KEY);
+            }
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(100));
+            app.update();
+            if *app.world().resource::<State<PlayingState>>().get() == PlayingState::Active {
+                return step as f32 * 0.1;
+            }
+        }
+        panic!("wave transition never ended");
+    }
+
+    #[test]
+    fn wave_with_intro_text_holds_the_transition_longer() {
+        let mut app = transition_app();
+        start_transition(&mut app, None);
+        let plain = seconds_in_transition(&mut app, None);
+
+        let mut app = transition_app();
+        start_transition(&mut app, Some("The ground splits open and the brood answers."));
+        let with_text = seconds_in_transition(&mut app, None);
+        assert!(with_text > plain + 1.0, "{} vs {}", with_text, plain);
+
+        // Skipping still ends it right away
+        let mut app = transition_app();
+        start_transition(&mut app, Some("The ground splits open and the brood answers."));
+        let skipped = seconds_in_transition(&mut app, Some(5));
+        assert!(skipped <= 0.6, "skipped after {}", skipped);
+    }
+
     #[test]
     fn game_states_are_distinct() {
         let states = [
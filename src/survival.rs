@@ -6,32 +6,41 @@
 use bevy::prelude::*;
 use rand::Rng;
 
+use crate::audio::HordeSurgeEvent;
 use crate::bonuses::{BonusType, SpawnBonusEvent};
-use crate::creatures::{CreatureDeathEvent, CreatureRegistry, CreatureType, SpawnCreatureEvent};
+use crate::creatures::{CreatureDeathEvent, CreatureRegistry, CreatureType, SpawnCreatureEvent, WorldTimeScale};
 use crate::items::{ItemType, spawn_item_at};
 use crate::player::components::{Experience, Player};
 use crate::quests::ActiveQuestBuilder;
-use crate::states::GameState;
+use crate::schedule::GameSet;
+use crate::states::{GameMode, GameState};
 
 /// Plugin for survival mode functionality
 pub struct SurvivalPlugin;
 
 impl Plugin for SurvivalPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Playing), setup_survival_mode)
+        app.add_systems(
+            OnEnter(GameState::Playing),
+            setup_survival_mode.run_if(resource_equals(GameMode::Survival)),
+        )
             .add_systems(OnExit(GameState::Playing), cleanup_survival_mode)
             .add_systems(
                 Update,
                 (
-                    update_survival_mode,
-                    spawn_survival_creatures,
-                    trigger_survival_swarms,
-                    spawn_survival_bonuses,
-                    spawn_survival_items,
-                    track_survival_kills,
+                    (
+                        update_survival_mode,
+                        spawn_survival_creatures,
+                        trigger_survival_swarms,
+                        spawn_survival_bonuses,
+                        spawn_survival_items,
+                    )
+                        .chain()
+                        .in_set(GameSet::Simulation),
+                    track_survival_kills.in_set(GameSet::Consequences),
                 )
-                    .chain()
-                    .run_if(in_state(GameState::Playing)),
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(resource_equals(GameMode::Survival)),
             );
     }
 }
@@ -96,63 +105,17 @@ impl SurvivalState {
         1.0 + (self.total_exp as f32 / 1000.0) * 0.5
     }
 
-    /// Get available creature types for current difficulty
-    pub fn available_creatures(&self) -> Vec<CreatureType> {
-        let mut creatures = vec![CreatureType::Zombie];
-
-        if self.game_time > 10.0 {
-            creatures.push(CreatureType::Spider);
-        }
-        if self.game_time > 20.0 {
-            creatures.push(CreatureType::Beetle);
-        }
-        if self.game_time > 30.0 {
-            creatures.push(CreatureType::Runner);
-        }
-        if self.game_time > 45.0 {
-            creatures.push(CreatureType::Dog);
-        }
-        if self.game_time > 60.0 {
-            creatures.push(CreatureType::Lizard);
-        }
-        if self.game_time > 75.0 {
-            creatures.push(CreatureType::Ghost);
-        }
-        if self.game_time > 90.0 {
-            creatures.push(CreatureType::AlienSpider);
-        }
-        if self.game_time > 105.0 {
-            creatures.push(CreatureType::Exploder);
-        }
-        if self.game_time > 120.0 {
-            creatures.push(CreatureType::AlienShooter);
-        }
-        if self.game_time > 135.0 {
-            creatures.push(CreatureType::Necromancer);
-        }
-        if self.game_time > 150.0 {
-            creatures.push(CreatureType::GiantSpider);
-        }
-        if self.game_time > 165.0 {
-            creatures.push(CreatureType::Splitter);
-        }
-        if self.game_time > 180.0 {
-            creatures.push(CreatureType::Giant);
-        }
-        // Boss creatures spawn at later stages
-        if self.game_time > 240.0 {
-            creatures.push(CreatureType::BossSpider);
-        }
-        if self.game_time > 300.0 {
-            creatures.push(CreatureType::BossAlien);
-        }
-
-        creatures
+    /// Creature types unlocked so far this run, earliest unlock first
+    pub fn available_creatures(&self, registry: &CreatureRegistry) -> Vec<CreatureType> {
+        registry.unlocked_for_survival(self.game_time)
     }
 
     /// Pick a random creature type weighted by difficulty
-    pub fn pick_creature(&self) -> CreatureType {
-        let available = self.available_creatures();
+    pub fn pick_creature(&self, registry: &CreatureRegistry) -> CreatureType {
+        let available = self.available_creatures(registry);
+        if available.is_empty() {
+            return CreatureType::Zombie;
+        }
         let mut rng = rand::thread_rng();
 
         // Higher difficulty = more chance of later creatures
@@ -182,6 +145,38 @@ impl SurvivalState {
     }
 }
 
+#[cfg(test)]
+impl SurvivalState {
+    /// Difficulty and spawn interval curves sampled at fixed points
+    pub fn snapshot_repr() -> crate::snapshot::SnapshotTable {
+        use crate::snapshot::SnapshotTable;
+
+        let mut difficulty = SnapshotTable::new();
+        for total_exp in [0u32, 1000, 5000, 10000, 50000] {
+            let state = Self {
+                total_exp,
+                ..default()
+            };
+            difficulty.float(&format!("{:05}", total_exp), state.calculate_difficulty());
+        }
+
+        let mut spawn_interval = SnapshotTable::new();
+        for game_time in [0u32, 60, 120, 300, 600] {
+            let state = Self {
+                game_time: game_time as f32,
+                ..default()
+            };
+            spawn_interval.float(&format!("{:03}", game_time), state.spawn_interval());
+        }
+
+        let mut table = SnapshotTable::new();
+        table
+            .table("difficulty_by_exp", difficulty)
+            .table("spawn_interval_by_time", spawn_interval);
+        table
+    }
+}
+
 /// Sets up survival mode when entering Playing state
 fn setup_survival_mode(mut commands: Commands) {
     commands.insert_resource(SurvivalState::default());
@@ -195,14 +190,16 @@ fn cleanup_survival_mode(mut commands: Commands) {
 /// Updates survival mode timers and difficulty
 fn update_survival_mode(
     time: Res<Time>,
+    time_scale: Res<WorldTimeScale>,
     mut survival: ResMut<SurvivalState>,
     player_query: Query<&Experience, With<Player>>,
 ) {
     survival.game_time += time.delta_seconds();
-    survival.spawn_timer += time.delta_seconds();
+    // Spawns keep pace with the slowed world
+    survival.spawn_timer += time_scale.delta(&time);
     survival.weapon_drop_timer += time.delta_seconds();
     survival.item_drop_timer += time.delta_seconds();
-    survival.swarm_timer += time.delta_seconds();
+    survival.swarm_timer += time_scale.delta(&time);
 
     // Update total exp from player
     if let Ok(exp) = player_query.get_single() {
@@ -238,11 +235,12 @@ fn spawn_survival_creatures(
             let creature_type = if let Some(ct) = creature_registry.pick_random_for_wave(effective_wave) {
                 ct
             } else {
-                survival.pick_creature()
+                survival.pick_creature(&creature_registry)
             };
             spawn_events.send(SpawnCreatureEvent {
                 creature_type,
                 position: None, // Let spawner pick position
+                summoner: None,
             });
         }
     }
@@ -253,8 +251,10 @@ fn trigger_survival_swarms(
     mut commands: Commands,
     time: Res<Time>,
     mut survival: ResMut<SurvivalState>,
+    creature_registry: Res<CreatureRegistry>,
     mut swarm: Option<ResMut<SurvivalSwarm>>,
     mut spawn_events: EventWriter<SpawnCreatureEvent>,
+    mut surge_events: EventWriter<HordeSurgeEvent>,
 ) {
     const SWARM_INTERVAL: f32 = 60.0; // Swarm every minute
 
@@ -263,7 +263,7 @@ fn trigger_survival_swarms(
         survival.swarm_timer = 0.0;
 
         // Choose swarm type based on game time and difficulty
-        let creature = survival.pick_creature();
+        let creature = survival.pick_creature(&creature_registry);
         let mut rng = rand::thread_rng();
 
         let builder = if survival.game_time > 180.0 && rng.gen_bool(0.3) {
@@ -275,12 +275,14 @@ fn trigger_survival_swarms(
             };
             let minion_count = (5 + survival.difficulty as u32).min(12);
             info!("Survival BOSS wave triggered: {:?} with {} minions", boss, minion_count);
+            surge_events.send(HordeSurgeEvent { boss: true });
             ActiveQuestBuilder::boss_wave(creature, minion_count, boss)
         } else if survival.game_time > 90.0 && rng.gen_bool(0.5) {
             // Timed wave after 1.5 minutes (50% chance)
             let wave_size = (8 + survival.difficulty as u32 * 2).min(20);
             let creatures: Vec<_> = std::iter::repeat_n(creature, wave_size as usize).collect();
             info!("Survival timed wave triggered: {} {:?}", wave_size, creature);
+            surge_events.send(HordeSurgeEvent { boss: false });
             ActiveQuestBuilder::timed_wave(creatures, 0.3)
         } else {
             // Regular swarm
@@ -290,6 +292,7 @@ fn trigger_survival_swarms(
                 "Survival swarm triggered: {:?} x{} bursts of {}",
                 creature, bursts, per_burst
             );
+            surge_events.send(HordeSurgeEvent { boss: false });
             ActiveQuestBuilder::swarm(creature, bursts, per_burst)
         };
 
@@ -320,6 +323,7 @@ fn trigger_survival_swarms(
             spawn_events.send(SpawnCreatureEvent {
                 creature_type: cmd.creature_type,
                 position: pos,
+                summoner: None,
             });
         }
 
@@ -436,12 +440,54 @@ mod tests {
 
     #[test]
     fn more_creatures_available_over_time() {
+        let registry = CreatureRegistry::new();
         let mut state = SurvivalState::default();
-        let initial = state.available_creatures().len();
+        let initial = state.available_creatures(&registry);
+        assert_eq!(initial, vec![CreatureType::Zombie]);
 
         state.game_time = 120.0;
-        let after_2_min = state.available_creatures().len();
+        let after_2_min = state.available_creatures(&registry);
 
-        assert!(after_2_min > initial);
+        assert!(after_2_min.len() > initial.len());
+        assert_eq!(after_2_min[1], CreatureType::Spider);
+        assert!(!after_2_min.contains(&CreatureType::BossSpider));
+    }
+
+    fn mode_app(mode: GameMode) -> App {
+        use crate::quests::{ActiveQuest, QuestId, QuestsPlugin};
+        use crate::rush::{RushLoadout, RushPlugin, RushState};
+        use crate::states::GameStatePlugin;
+
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .add_plugins((GameStatePlugin, SurvivalPlugin, RushPlugin, QuestsPlugin));
+        // What the menus do when the mode is picked
+        app.insert_resource(mode);
+        match mode {
+            GameMode::Quest => app.insert_resource(ActiveQuest::new(QuestId::Q01LandHostile)),
+            GameMode::Rush => app.insert_resource(RushState::new(120.0, RushLoadout::default())),
+            GameMode::Survival => &mut app,
+        };
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        // Only the transition: the modes' Update systems need the whole game around them
+        app.world_mut().run_schedule(StateTransition);
+        app
+    }
+
+    #[test]
+    fn only_the_selected_mode_sets_up_its_state() {
+        use crate::quests::ActiveQuestBuilder;
+        use crate::rush::RushState;
+
+        for mode in [GameMode::Quest, GameMode::Survival, GameMode::Rush] {
+            let app = mode_app(mode);
+            let world = app.world();
+            assert_eq!(*world.resource::<State<GameState>>().get(), GameState::Playing);
+            assert_eq!(world.contains_resource::<ActiveQuestBuilder>(), mode == GameMode::Quest, "{:?}", mode);
+            assert_eq!(world.contains_resource::<SurvivalState>(), mode == GameMode::Survival, "{:?}", mode);
+            assert_eq!(world.contains_resource::<RushState>(), mode == GameMode::Rush, "{:?}", mode);
+        }
     }
 }
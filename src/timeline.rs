@@ -0,0 +1,515 @@
+//! Event timeline (debug-tools feature)
+//!
+//! Keeps the last few hundred gameplay events in a ring buffer so odd runs
+//! ("the wave never completed", "score jumped by 30000") can be replayed as a
+//! log. F8 dumps the buffer to a JSON lines file; a panic hook tries the same.
+//! Recording only copies event fields; nothing is formatted until a dump.
+
+use std::collections::VecDeque;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use bevy::core::FrameCount;
+use bevy::ecs::schedule::SystemConfigs;
+use bevy::prelude::*;
+use bevy::state::state::StateTransitionEvent;
+use serde_json::{json, Value};
+
+use crate::bonuses::{BonusCollectedEvent, BonusType};
+use crate::creatures::{CreatureDeathEvent, CreatureType, StatusKind};
+use crate::items::{ItemPickedUpEvent, ItemType};
+use crate::perks::{PerkKey, PerkSelectedEvent};
+use crate::player::PlayerDamageEvent;
+use crate::quests::{QuestCompletedEvent, QuestId, WaveCompletedEvent, WaveStartedEvent};
+use crate::run_context::DebugToolUsedEvent;
+use crate::rush::{RushScoreEvent, ScoreSource};
+use crate::states::{GameState, PlayingState};
+use crate::weapons::WeaponKey;
+
+/// Entries kept before the oldest is evicted
+pub const TIMELINE_CAPACITY: usize = 500;
+
+/// Plugin for the event timeline
+pub struct TimelinePlugin;
+
+impl Plugin for TimelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventTimeline>()
+            .add_systems(Startup, install_panic_dump)
+            .add_systems(Update, (recorders(), dump_timeline_on_key));
+    }
+}
+
+/// One recorded gameplay event
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimelineEvent {
+    GameState(GameState),
+    /// None when leaving Playing
+    PlayingState(Option<PlayingState>),
+    WaveStarted {
+        wave_index: usize,
+    },
+    WaveCompleted {
+        wave_index: usize,
+    },
+    QuestCompleted {
+        quest_id: QuestId,
+        kills: u32,
+    },
+    CreatureDied {
+        creature_type: CreatureType,
+        /// Status effect that landed the killing blow; None for direct hits
+        killed_by: Option<StatusKind>,
+    },
+    PlayerDamaged {
+        damage: f32,
+        source: Option<Entity>,
+    },
+    BonusCollected {
+        bonus_type: BonusType,
+        weapon: Option<WeaponKey>,
+    },
+    ItemPickedUp {
+        item_type: ItemType,
+    },
+    PerkAcquired {
+        perk: PerkKey,
+    },
+    RushScore {
+        points: u32,
+        source: ScoreSource,
+    },
+}
+
+impl TimelineEvent {
+    fn to_json(&self) -> Value {
+        match self {
+            TimelineEvent::GameState(state) => {
+                json!({ "kind": "GameState", "state": format!("{:?}", state) })
+            }
+            TimelineEvent::PlayingState(state) => json!({
+                "kind": "PlayingState",
+                "state": state.map(|state| format!("{:?}", state)),
+            }),
+            TimelineEvent::WaveStarted { wave_index } => {
+                json!({ "kind": "WaveStarted", "wave_index": wave_index })
+            }
+            TimelineEvent::WaveCompleted { wave_index } => {
+                json!({ "kind": "WaveCompleted", "wave_index": wave_index })
+            }
+            TimelineEvent::QuestCompleted { quest_id, kills } => json!({
+                "kind": "QuestCompleted",
+                "quest": format!("{:?}", quest_id),
+                "kills": kills,
+            }),
+            TimelineEvent::CreatureDied {
+                creature_type,
+                killed_by,
+            } => json!({
+                "kind": "CreatureDied",
+                "creature": format!("{:?}", creature_type),
+                "killed_by": killed_by.map(|kind| format!("{:?}", kind)),
+            }),
+            TimelineEvent::PlayerDamaged { damage, source } => json!({
+                "kind": "PlayerDamaged",
+                "damage": damage,
+                "source": source.map(|entity| format!("{:?}", entity)),
+            }),
+            TimelineEvent::BonusCollected { bonus_type, weapon } => json!({
+                "kind": "BonusCollected",
+                "bonus": format!("{:?}", bonus_type),
+                "weapon": weapon.as_ref().map(|weapon| format!("{:?}", weapon)),
+            }),
+            TimelineEvent::ItemPickedUp { item_type } => {
+                json!({ "kind": "ItemPickedUp", "item": format!("{:?}", item_type) })
+            }
+            TimelineEvent::PerkAcquired { perk } => {
+                json!({ "kind": "PerkAcquired", "perk": format!("{:?}", perk) })
+            }
+            TimelineEvent::RushScore { points, source } => json!({
+                "kind": "RushScore",
+                "points": points,
+                "source": format!("{:?}", source),
+            }),
+        }
+    }
+}
+
+/// A timeline event stamped with game time and frame number
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineEntry {
+    pub time: f32,
+    pub frame: u32,
+    pub event: TimelineEvent,
+}
+
+impl TimelineEntry {
+    /// One JSON object, e.g. `{"time":1.5,"frame":90,"kind":"WaveStarted","wave_index":0}`
+    pub fn to_json_line(&self) -> String {
+        let mut value = self.event.to_json();
+        value["time"] = json!(self.time);
+        value["frame"] = json!(self.frame);
+        value.to_string()
+    }
+}
+
+/// Fixed-size ring buffer of timeline entries, oldest first
+#[derive(Debug)]
+pub struct TimelineBuffer {
+    entries: VecDeque<TimelineEntry>,
+    capacity: usize,
+}
+
+impl Default for TimelineBuffer {
+    fn default() -> Self {
+        Self::with_capacity(TIMELINE_CAPACITY)
+    }
+}
+
+impl TimelineBuffer {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Appends an entry, evicting the oldest once full
+    pub fn push(&mut self, entry: TimelineEntry) {
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Writes every entry as one JSON line, oldest first
+    pub fn write_json_lines(&self, out: &mut impl Write) -> std::io::Result<()> {
+        for entry in &self.entries {
+            writeln!(out, "{}", entry.to_json_line())?;
+        }
+        Ok(())
+    }
+}
+
+/// Recent gameplay events. Shared with the panic hook, hence the mutex.
+#[derive(Resource, Clone, Default)]
+pub struct EventTimeline(Arc<Mutex<TimelineBuffer>>);
+
+impl EventTimeline {
+    /// Stamps and stores a batch of events; takes the lock only if there is something to store
+    fn record(&self, time: &Time, frame: &FrameCount, events: impl IntoIterator<Item = TimelineEvent>) {
+        let mut events = events.into_iter().peekable();
+        if events.peek().is_none() {
+            return;
+        }
+        let Ok(mut buffer) = self.0.lock() else {
+            return;
+        };
+        for event in events {
+            buffer.push(TimelineEntry {
+                time: time.elapsed_seconds(),
+                frame: frame.0,
+                event,
+            });
+        }
+    }
+
+    /// Writes the timeline to `timeline-<unix seconds>.jsonl` in the working directory
+    #[cfg(not(target_arch = "wasm32"))]
+    fn dump_to_file(buffer: &TimelineBuffer) -> std::io::Result<String> {
+        let stamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        let path = format!("timeline-{}.jsonl", stamp);
+        let mut file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+        buffer.write_json_lines(&mut file)?;
+        file.flush()?;
+        Ok(path)
+    }
+}
+
+/// Every recorder system, as one set so tests can run them without the plugin
+fn recorders() -> SystemConfigs {
+    (
+        record_state_transitions,
+        record_progress_events,
+        record_combat_events,
+        record_pickup_events,
+    )
+        .into_configs()
+}
+
+fn record_state_transitions(
+    timeline: Res<EventTimeline>,
+    time: Res<Time>,
+    frame: Res<FrameCount>,
+    mut game_states: EventReader<StateTransitionEvent<GameState>>,
+    mut playing_states: EventReader<StateTransitionEvent<PlayingState>>,
+) {
+    let game = game_states
+        .read()
+        .filter_map(|transition| transition.entered)
+        .map(TimelineEvent::GameState);
+    let playing = playing_states
+        .read()
+        .map(|transition| TimelineEvent::PlayingState(transition.entered));
+    timeline.record(&time, &frame, game.chain(playing));
+}
+
+/// Waves, quests and rush score
+fn record_progress_events(
+    timeline: Res<EventTimeline>,
+    time: Res<Time>,
+    frame: Res<FrameCount>,
+    mut wave_started: EventReader<WaveStartedEvent>,
+    mut wave_completed: EventReader<WaveCompletedEvent>,
+    mut quest_completed: EventReader<QuestCompletedEvent>,
+    mut rush_score: EventReader<RushScoreEvent>,
+) {
+    let events = wave_started
+        .read()
+        .map(|event| TimelineEvent::WaveStarted {
+            wave_index: event.wave_index,
+        })
+        .chain(wave_completed.read().map(|event| TimelineEvent::WaveCompleted {
+            wave_index: event.wave_index,
+        }))
+        .chain(quest_completed.read().map(|event| TimelineEvent::QuestCompleted {
+            quest_id: event.quest_id,
+            kills: event.kills,
+        }))
+        .chain(rush_score.read().map(|event| TimelineEvent::RushScore {
+            points: event.points,
+            source: event.source,
+        }));
+    timeline.record(&time, &frame, events);
+}
+
+fn record_combat_events(
+    timeline: Res<EventTimeline>,
+    time: Res<Time>,
+    frame: Res<FrameCount>,
+    mut creature_deaths: EventReader<CreatureDeathEvent>,
+    mut player_damage: EventReader<PlayerDamageEvent>,
+) {
+    let events = creature_deaths
+        .read()
+        .map(|event| TimelineEvent::CreatureDied {
+            creature_type: event.creature_type,
+            killed_by: event.killed_by,
+        })
+        .chain(player_damage.read().map(|event| TimelineEvent::PlayerDamaged {
+            damage: event.damage,
+            source: event.source,
+        }));
+    timeline.record(&time, &frame, events);
+}
+
+fn record_pickup_events(
+    timeline: Res<EventTimeline>,
+    time: Res<Time>,
+    frame: Res<FrameCount>,
+    mut bonuses: EventReader<BonusCollectedEvent>,
+    mut items: EventReader<ItemPickedUpEvent>,
+    mut perks: EventReader<PerkSelectedEvent>,
+) {
+    let events = bonuses
+        .read()
+        .map(|event| TimelineEvent::BonusCollected {
+            bonus_type: event.bonus_type,
+            weapon: event.weapon.clone(),
+        })
+        .chain(items.read().map(|event| TimelineEvent::ItemPickedUp {
+            item_type: event.item_type,
+        }))
+        .chain(perks.read().map(|event| TimelineEvent::PerkAcquired {
+            perk: event.perk.clone(),
+        }));
+    timeline.record(&time, &frame, events);
+}
+
+/// F8 writes the timeline to disk, and flags the run as debugged
+fn dump_timeline_on_key(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    timeline: Res<EventTimeline>,
+    mut debug_events: EventWriter<DebugToolUsedEvent>,
+) {
+    if !keyboard.just_pressed(KeyCode::F8) {
+        return;
+    }
+    debug_events.send(DebugToolUsedEvent);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let Ok(buffer) = timeline.0.lock() else {
+            return;
+        };
+        match EventTimeline::dump_to_file(&buffer) {
+            Ok(path) => info!("Event timeline written to {}", path),
+            Err(err) => warn!("Failed to write event timeline: {}", err),
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &timeline;
+}
+
+/// Chains a panic hook that dumps the timeline before the default report.
+/// Best effort: skipped if a recorder holds the lock at the time of the panic.
+fn install_panic_dump(timeline: Res<EventTimeline>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let timeline = timeline.clone();
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if let Ok(buffer) = timeline.0.try_lock() {
+                if let Ok(path) = EventTimeline::dump_to_file(&buffer) {
+                    eprintln!("Event timeline written to {}", path);
+                }
+            }
+            previous(info);
+        }));
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &timeline;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn entry(frame: u32) -> TimelineEntry {
+        TimelineEntry {
+            time: frame as f32 / 60.0,
+            frame,
+            event: TimelineEvent::WaveStarted { wave_index: 0 },
+        }
+    }
+
+    #[test]
+    fn full_buffer_evicts_oldest() {
+        let mut buffer = TimelineBuffer::with_capacity(3);
+        for frame in 0..5 {
+            buffer.push(entry(frame));
+        }
+        let frames: Vec<u32> = buffer.entries.iter().map(|entry| entry.frame).collect();
+        assert_eq!(frames, vec![2, 3, 4]);
+
+        let mut buffer = TimelineBuffer::default();
+        for frame in 0..TIMELINE_CAPACITY as u32 + 1 {
+            buffer.push(entry(frame));
+        }
+        assert_eq!(buffer.entries.len(), TIMELINE_CAPACITY);
+        assert_eq!(buffer.entries[0].frame, 1);
+    }
+
+    #[test]
+    fn entries_serialize_as_flat_json_objects() {
+        let entry = TimelineEntry {
+            time: 1.5,
+            frame: 90,
+            event: TimelineEvent::CreatureDied {
+                creature_type: CreatureType::Zombie,
+                killed_by: Some(StatusKind::Burning),
+            },
+        };
+        let value: Value = serde_json::from_str(&entry.to_json_line()).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "time": 1.5,
+                "frame": 90,
+                "kind": "CreatureDied",
+                "creature": "Zombie",
+                "killed_by": "Burning",
+            })
+        );
+
+        let line = TimelineEntry {
+            time: 0.0,
+            frame: 0,
+            event: TimelineEvent::PlayingState(None),
+        }
+        .to_json_line();
+        assert!(!line.contains('\n'));
+        assert_eq!(serde_json::from_str::<Value>(&line).unwrap()["state"], Value::Null);
+    }
+
+    #[test]
+    fn scripted_events_dump_in_order_with_timestamps() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<FrameCount>()
+            .init_resource::<EventTimeline>()
+            .add_event::<StateTransitionEvent<GameState>>()
+            .add_event::<StateTransitionEvent<PlayingState>>()
+            .add_event::<WaveStartedEvent>()
+            .add_event::<WaveCompletedEvent>()
+            .add_event::<QuestCompletedEvent>()
+            .add_event::<RushScoreEvent>()
+            .add_event::<CreatureDeathEvent>()
+            .add_event::<PlayerDamageEvent>()
+            .add_event::<BonusCollectedEvent>()
+            .add_event::<ItemPickedUpEvent>()
+            .add_event::<PerkSelectedEvent>()
+            .add_systems(Update, recorders());
+
+        let step = |app: &mut App, frame: u32| {
+            app.world_mut().resource_mut::<FrameCount>().0 = frame;
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(500));
+            app.update();
+        };
+
+        app.world_mut().send_event(WaveStartedEvent { wave_index: 0 });
+        step(&mut app, 10);
+        app.world_mut().send_event(CreatureDeathEvent {
+            entity: Entity::PLACEHOLDER,
+            creature_type: CreatureType::Spider,
+            position: Vec3::ZERO,
+            experience: 10,
+            killed_by: None,
+        });
+        app.world_mut().send_event(PlayerDamageEvent {
+            player_entity: Entity::PLACEHOLDER,
+            damage: 12.0,
+            source: None,
+            unavoidable: false,
+        });
+        step(&mut app, 11);
+        app.world_mut().send_event(WaveCompletedEvent { wave_index: 0 });
+        step(&mut app, 12);
+
+        let timeline = app.world().resource::<EventTimeline>().clone();
+        let mut dump = Vec::new();
+        timeline.0.lock().unwrap().write_json_lines(&mut dump).unwrap();
+        let lines: Vec<Value> = String::from_utf8(dump)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        let summary: Vec<(&str, u64, f64)> = lines
+            .iter()
+            .map(|line| {
+                (
+                    line["kind"].as_str().unwrap(),
+                    line["frame"].as_u64().unwrap(),
+                    line["time"].as_f64().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("WaveStarted", 10, 0.5),
+                ("CreatureDied", 11, 1.0),
+                ("PlayerDamaged", 11, 1.0),
+                ("WaveCompleted", 12, 1.5),
+            ]
+        );
+        assert_eq!(lines[2]["damage"], 12.0);
+    }
+}
@@ -2,14 +2,20 @@
 
 use bevy::prelude::*;
 
-use crate::creatures::{Creature, CreatureHealth};
+use super::{HudBannerText, HudElement, HudLayout, HudSettings};
+use crate::assists::RunAssists;
+use crate::bonuses::{ActiveBonusEffects, BonusCollectedEvent, BonusType};
+use crate::creatures::{Creature, CreatureHealth, CreatureType};
 use crate::items::CarriedItem;
-use crate::perks::PerkInventory;
-use crate::player::{Experience, Health, Invincibility, Player};
+use crate::last_stand::{last_stand_allowed, LastStand, LastStandSettings};
+use crate::perks::{PerkBonuses, PerkInventory, PerkKey, PerkRegistry};
+use crate::player::{Experience, Health, Invincibility, Player, PlayerInputMapping, StationaryTimer};
 use crate::quests::{ActiveQuest, QuestProgress};
+use crate::render_layers::{HEALTH_BAR_FILL_OFFSET, WORLD_OVERLAY};
 use crate::rush::RushState;
+use crate::states::BossEncounterState;
 use crate::survival::SurvivalState;
-use crate::weapons::EquippedWeapon;
+use crate::weapons::{AlternateWeaponSlot, EquippedWeapon, OutOfAmmoEvent};
 
 /// Marker for HUD root
 #[derive(Component)]
@@ -19,6 +25,22 @@ pub struct HudRoot;
 #[derive(Component)]
 pub struct HealthBar;
 
+/// Regular health bar fill
+const HEALTH_BAR_COLOR: Color = Color::srgb(0.8, 0.1, 0.1);
+/// Pulses per second of the DeathClock health bar (radians)
+const DEATH_CLOCK_PULSE_RATE: f32 = 6.0;
+
+/// Bottom-right row of owned perk badges; hovering it expands the badges to names
+#[derive(Component)]
+pub struct PerkBadgeList;
+
+/// One owned perk in the badge list
+#[derive(Component, Debug)]
+pub struct PerkBadge {
+    pub key: PerkKey,
+    pub count: u8,
+}
+
 /// Marker for health text
 #[derive(Component)]
 pub struct HealthText;
@@ -35,10 +57,22 @@ pub struct LevelText;
 #[derive(Component)]
 pub struct AmmoText;
 
+/// Marker for the heat bar beside the ammo text, shown for weapons that build heat
+#[derive(Component)]
+pub struct HeatBar;
+
+/// Marker for the heat bar fill
+#[derive(Component)]
+pub struct HeatBarFill;
+
 /// Marker for weapon name text
 #[derive(Component)]
 pub struct WeaponText;
 
+/// Marker for the dimmed name of the stowed weapon (AlternateWeapon perk)
+#[derive(Component)]
+pub struct StowedWeaponText;
+
 /// Marker for kill counter text
 #[derive(Component)]
 pub struct KillCounterText;
@@ -63,6 +97,14 @@ pub struct InvincibilityIndicator;
 #[derive(Component)]
 pub struct CarriedItemText;
 
+/// Marker for the active bonus effects panel (remaining time per effect)
+#[derive(Component)]
+pub struct ActiveEffectsText;
+
+/// Marker for the last-stand push cooldown icon
+#[derive(Component)]
+pub struct LastStandIcon;
+
 /// Marker for creature health bar (world-space sprite)
 #[derive(Component)]
 pub struct CreatureHealthBar {
@@ -74,8 +116,44 @@ pub struct CreatureHealthBar {
 #[derive(Component)]
 pub struct CreatureHealthBarBackground;
 
-/// Sets up the HUD
-pub fn setup_hud(mut commands: Commands) {
+/// Most boss health bars shown at once; further bosses wait for a free slot
+pub const MAX_BOSS_BARS: usize = 2;
+
+/// Seconds a boss health bar flashes white after the boss takes damage
+pub const BOSS_BAR_FLASH_DURATION: f32 = 0.15;
+
+/// Boss health bar fill
+const BOSS_BAR_COLOR: Color = Color::srgb(0.75, 0.1, 0.15);
+
+/// Wide health bar across the top of the screen for one boss
+#[derive(Component)]
+pub struct BossHealthBarRoot {
+    pub boss: Entity,
+}
+
+/// Fill of a boss health bar
+#[derive(Component, Debug)]
+pub struct BossHealthBarFill {
+    /// Health seen last frame, to catch new damage
+    pub last_health: f32,
+    /// Seconds left on the damage flash
+    pub flash: f32,
+}
+
+/// Sets up the HUD in the player's chosen layout
+pub fn setup_hud(mut commands: Commands, settings: Res<HudSettings>) {
+    spawn_hud(&mut commands, settings.layout);
+}
+
+/// Spawns a HUD root with the elements `layout` shows
+pub fn spawn_hud(commands: &mut Commands, layout: HudLayout) {
+    // Minimal drops the bar backdrops so only the numbers float over the arena
+    let bar_background = if layout == HudLayout::Minimal {
+        Color::NONE
+    } else {
+        Color::srgba(0.0, 0.0, 0.0, 0.5)
+    };
+
     commands
         .spawn((
             HudRoot,
@@ -91,290 +169,445 @@ pub fn setup_hud(mut commands: Commands) {
             },
         ))
         .with_children(|parent| {
-            // Top bar (health, exp)
+            if layout == HudLayout::Flipped {
+                spawn_weapon_bar(parent, layout, bar_background);
+                spawn_status_bar(parent, layout, bar_background);
+            } else {
+                spawn_status_bar(parent, layout, bar_background);
+                spawn_weapon_bar(parent, layout, bar_background);
+            }
+
+            // Filled in by update_hud_perk_badges; clear of both bars in every layout
+            if layout.shows(HudElement::PerkBadges) {
+                parent.spawn((
+                    PerkBadgeList,
+                    Interaction::default(),
+                    NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            right: Val::Px(10.0),
+                            bottom: Val::Px(70.0),
+                            max_width: Val::Percent(50.0),
+                            flex_direction: FlexDirection::Row,
+                            flex_wrap: FlexWrap::Wrap,
+                            justify_content: JustifyContent::FlexEnd,
+                            column_gap: Val::Px(4.0),
+                            row_gap: Val::Px(4.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                ));
+            }
+
+            if layout.shows(HudElement::Banner) {
+                parent.spawn((
+                    HudBannerText,
+                    TextBundle {
+                        visibility: Visibility::Hidden,
+                        ..TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 32.0,
+                                color: Color::srgb(1.0, 0.85, 0.3),
+                                ..default()
+                            },
+                        )
+                        .with_style(Style {
+                            position_type: PositionType::Absolute,
+                            top: Val::Percent(20.0),
+                            width: Val::Percent(100.0),
+                            ..default()
+                        })
+                        .with_text_justify(JustifyText::Center)
+                    },
+                ));
+            }
+        });
+}
+
+/// Health, timer and level bar (top in the classic layout)
+fn spawn_status_bar(parent: &mut ChildBuilder, layout: HudLayout, background: Color) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Px(60.0),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: BackgroundColor(background),
+            ..default()
+        })
+        .with_children(|parent| {
+            // Health section
             parent
                 .spawn(NodeBundle {
                     style: Style {
-                        width: Val::Percent(100.0),
-                        height: Val::Px(60.0),
-                        flex_direction: FlexDirection::Row,
-                        justify_content: JustifyContent::SpaceBetween,
-                        padding: UiRect::all(Val::Px(10.0)),
+                        flex_direction: FlexDirection::Column,
                         ..default()
                     },
-                    background_color: BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
                     ..default()
                 })
                 .with_children(|parent| {
-                    // Health section
-                    parent
-                        .spawn(NodeBundle {
-                            style: Style {
-                                flex_direction: FlexDirection::Column,
+                    if layout.shows(HudElement::HealthText) {
+                        parent.spawn((
+                            HealthText,
+                            TextBundle::from_section(
+                                "Health: 100/100",
+                                TextStyle {
+                                    font_size: 20.0,
+                                    color: Color::WHITE,
+                                    ..default()
+                                },
+                            ),
+                        ));
+                    }
+
+                    if layout.shows(HudElement::HealthBar) {
+                        // Health bar background
+                        parent
+                            .spawn(NodeBundle {
+                                style: Style {
+                                    width: Val::Px(200.0),
+                                    height: Val::Px(20.0),
+                                    ..default()
+                                },
+                                background_color: BackgroundColor(Color::srgb(0.3, 0.0, 0.0)),
                                 ..default()
-                            },
-                            ..default()
-                        })
-                        .with_children(|parent| {
-                            parent.spawn((
-                                HealthText,
-                                TextBundle::from_section(
-                                    "Health: 100/100",
-                                    TextStyle {
-                                        font_size: 20.0,
-                                        color: Color::WHITE,
+                            })
+                            .with_children(|parent| {
+                                // Health bar fill
+                                parent.spawn((
+                                    HealthBar,
+                                    NodeBundle {
+                                        style: Style {
+                                            width: Val::Percent(100.0),
+                                            height: Val::Percent(100.0),
+                                            ..default()
+                                        },
+                                        background_color: BackgroundColor(HEALTH_BAR_COLOR),
                                         ..default()
                                     },
-                                ),
-                            ));
+                                ));
+                            });
+                    }
+                });
 
-                            // Health bar background
-                            parent
-                                .spawn(NodeBundle {
-                                    style: Style {
-                                        width: Val::Px(200.0),
-                                        height: Val::Px(20.0),
-                                        ..default()
-                                    },
-                                    background_color: BackgroundColor(Color::srgb(0.3, 0.0, 0.0)),
+            // Center stats section
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    if layout.shows(HudElement::GameTimer) {
+                        parent.spawn((
+                            GameTimerText,
+                            TextBundle::from_section(
+                                "0:00",
+                                TextStyle {
+                                    font_size: 28.0,
+                                    color: Color::WHITE,
                                     ..default()
-                                })
-                                .with_children(|parent| {
-                                    // Health bar fill
-                                    parent.spawn((
-                                        HealthBar,
-                                        NodeBundle {
-                                            style: Style {
-                                                width: Val::Percent(100.0),
-                                                height: Val::Percent(100.0),
-                                                ..default()
-                                            },
-                                            background_color: BackgroundColor(Color::srgb(
-                                                0.8, 0.1, 0.1,
-                                            )),
-                                            ..default()
-                                        },
-                                    ));
-                                });
-                        });
+                                },
+                            ),
+                        ));
+                    }
 
-                    // Center stats section
-                    parent
-                        .spawn(NodeBundle {
-                            style: Style {
-                                flex_direction: FlexDirection::Column,
-                                align_items: AlignItems::Center,
+                    if layout.shows(HudElement::WaveProgress) {
+                        parent.spawn((
+                            WaveProgressText,
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font_size: 16.0,
+                                    color: Color::srgb(0.8, 0.8, 0.5),
+                                    ..default()
+                                },
+                            ),
+                        ));
+                    }
+                });
+
+            // Level/XP section
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::End,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    if layout.shows(HudElement::Level) {
+                        parent.spawn((
+                            LevelText,
+                            TextBundle::from_section(
+                                "Level 1",
+                                TextStyle {
+                                    font_size: 20.0,
+                                    color: Color::srgb(0.5, 0.8, 1.0),
+                                    ..default()
+                                },
+                            ),
+                        ));
+                    }
+
+                    if layout.shows(HudElement::ExperienceBar) {
+                        // XP bar background
+                        parent
+                            .spawn(NodeBundle {
+                                style: Style {
+                                    width: Val::Px(200.0),
+                                    height: Val::Px(10.0),
+                                    ..default()
+                                },
+                                background_color: BackgroundColor(Color::srgb(0.1, 0.1, 0.3)),
                                 ..default()
-                            },
-                            ..default()
-                        })
-                        .with_children(|parent| {
-                            // Game timer
-                            parent.spawn((
-                                GameTimerText,
-                                TextBundle::from_section(
-                                    "0:00",
-                                    TextStyle {
-                                        font_size: 28.0,
-                                        color: Color::WHITE,
-                                        ..default()
-                                    },
-                                ),
-                            ));
-
-                            // Wave/progress text
-                            parent.spawn((
-                                WaveProgressText,
-                                TextBundle::from_section(
-                                    "",
-                                    TextStyle {
-                                        font_size: 16.0,
-                                        color: Color::srgb(0.8, 0.8, 0.5),
+                            })
+                            .with_children(|parent| {
+                                // XP bar fill
+                                parent.spawn((
+                                    ExperienceBar,
+                                    NodeBundle {
+                                        style: Style {
+                                            width: Val::Percent(0.0),
+                                            height: Val::Percent(100.0),
+                                            ..default()
+                                        },
+                                        background_color: BackgroundColor(Color::srgb(0.3, 0.5, 1.0)),
                                         ..default()
                                     },
-                                ),
-                            ));
-                        });
+                                ));
+                            });
+                    }
+                });
+        });
+}
 
-                    // Level/XP section
-                    parent
-                        .spawn(NodeBundle {
-                            style: Style {
-                                flex_direction: FlexDirection::Column,
-                                align_items: AlignItems::End,
+/// Kills, weapon and pickup bar (bottom in the classic layout)
+fn spawn_weapon_bar(parent: &mut ChildBuilder, layout: HudLayout, background: Color) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Px(50.0),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: BackgroundColor(background),
+            ..default()
+        })
+        .with_children(|parent| {
+            // Kill counter (left side); an empty node keeps the weapon centered without it
+            if layout.shows(HudElement::KillCounter) {
+                parent.spawn((
+                    KillCounterText,
+                    TextBundle::from_section(
+                        "Kills: 0",
+                        TextStyle {
+                            font_size: 20.0,
+                            color: Color::srgb(1.0, 0.5, 0.5),
+                            ..default()
+                        },
+                    ),
+                ));
+            } else {
+                parent.spawn(NodeBundle::default());
+            }
+
+            // Weapon section (center)
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|parent| {
+                    if layout.shows(HudElement::Weapon) {
+                        parent.spawn((
+                            WeaponText,
+                            TextBundle::from_section(
+                                "Pistol",
+                                TextStyle {
+                                    font_size: 24.0,
+                                    color: Color::srgb(1.0, 0.8, 0.3),
+                                    ..default()
+                                },
+                            ),
+                        ));
+
+                        parent.spawn(TextBundle::from_section(
+                            " - ",
+                            TextStyle {
+                                font_size: 24.0,
+                                color: Color::WHITE,
                                 ..default()
                             },
-                            ..default()
-                        })
-                        .with_children(|parent| {
-                            parent.spawn((
-                                LevelText,
-                                TextBundle::from_section(
-                                    "Level 1",
-                                    TextStyle {
-                                        font_size: 20.0,
-                                        color: Color::srgb(0.5, 0.8, 1.0),
-                                        ..default()
-                                    },
-                                ),
-                            ));
+                        ));
+                    }
+
+                    if layout.shows(HudElement::Ammo) {
+                        parent.spawn((
+                            AmmoText,
+                            TextBundle::from_section(
+                                "∞",
+                                TextStyle {
+                                    font_size: 24.0,
+                                    color: Color::WHITE,
+                                    ..default()
+                                },
+                            ),
+                        ));
 
-                            // XP bar background
-                            parent
-                                .spawn(NodeBundle {
+                        // Heat bar background
+                        parent
+                            .spawn((
+                                HeatBar,
+                                NodeBundle {
                                     style: Style {
-                                        width: Val::Px(200.0),
-                                        height: Val::Px(10.0),
+                                        display: Display::None,
+                                        width: Val::Px(60.0),
+                                        height: Val::Px(8.0),
+                                        margin: UiRect::left(Val::Px(8.0)),
+                                        align_self: AlignSelf::Center,
                                         ..default()
                                     },
-                                    background_color: BackgroundColor(Color::srgb(0.1, 0.1, 0.3)),
+                                    background_color: BackgroundColor(Color::srgb(0.2, 0.1, 0.0)),
                                     ..default()
-                                })
-                                .with_children(|parent| {
-                                    // XP bar fill
-                                    parent.spawn((
-                                        ExperienceBar,
-                                        NodeBundle {
-                                            style: Style {
-                                                width: Val::Percent(0.0),
-                                                height: Val::Percent(100.0),
-                                                ..default()
-                                            },
-                                            background_color: BackgroundColor(Color::srgb(
-                                                0.3, 0.5, 1.0,
-                                            )),
+                                },
+                            ))
+                            .with_children(|parent| {
+                                // Heat bar fill
+                                parent.spawn((
+                                    HeatBarFill,
+                                    NodeBundle {
+                                        style: Style {
+                                            width: Val::Percent(0.0),
+                                            height: Val::Percent(100.0),
                                             ..default()
                                         },
-                                    ));
-                                });
-                        });
+                                        background_color: BackgroundColor(Color::srgb(1.0, 0.6, 0.1)),
+                                        ..default()
+                                    },
+                                ));
+                            });
+                    }
+
+                    if layout.shows(HudElement::StowedWeapon) {
+                        parent.spawn((
+                            StowedWeaponText,
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font_size: 18.0,
+                                    color: Color::srgba(1.0, 1.0, 1.0, 0.4),
+                                    ..default()
+                                },
+                            ),
+                        ));
+                    }
                 });
 
-            // Bottom bar (weapon, ammo, kills, perks)
+            // Right side: carried item, perk count and power-up indicators
             parent
                 .spawn(NodeBundle {
                     style: Style {
-                        width: Val::Percent(100.0),
-                        height: Val::Px(50.0),
                         flex_direction: FlexDirection::Row,
-                        justify_content: JustifyContent::SpaceBetween,
                         align_items: AlignItems::Center,
-                        padding: UiRect::all(Val::Px(10.0)),
+                        column_gap: Val::Px(15.0),
                         ..default()
                     },
-                    background_color: BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
                     ..default()
                 })
                 .with_children(|parent| {
-                    // Kill counter (left side)
-                    parent.spawn((
-                        KillCounterText,
-                        TextBundle::from_section(
-                            "Kills: 0",
-                            TextStyle {
-                                font_size: 20.0,
-                                color: Color::srgb(1.0, 0.5, 0.5),
-                                ..default()
-                            },
-                        ),
-                    ));
+                    if layout.shows(HudElement::CarriedItem) {
+                        parent.spawn((
+                            CarriedItemText,
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font_size: 18.0,
+                                    color: Color::srgb(1.0, 0.8, 0.2),
+                                    ..default()
+                                },
+                            ),
+                        ));
+                    }
 
-                    // Weapon section (center)
-                    parent
-                        .spawn(NodeBundle {
-                            style: Style {
-                                flex_direction: FlexDirection::Row,
-                                align_items: AlignItems::Center,
-                                ..default()
-                            },
-                            ..default()
-                        })
-                        .with_children(|parent| {
-                            parent.spawn((
-                                WeaponText,
-                                TextBundle::from_section(
-                                    "Pistol",
-                                    TextStyle {
-                                        font_size: 24.0,
-                                        color: Color::srgb(1.0, 0.8, 0.3),
-                                        ..default()
-                                    },
-                                ),
-                            ));
+                    // Active bonus effects with remaining time
+                    if layout.shows(HudElement::ActiveEffects) {
+                        parent.spawn((
+                            ActiveEffectsText,
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font_size: 18.0,
+                                    color: Color::srgb(0.5, 0.9, 1.0),
+                                    ..default()
+                                },
+                            ),
+                        ));
+                    }
 
-                            parent.spawn(TextBundle::from_section(
-                                " - ",
+                    // Invincibility indicator (empty while inactive)
+                    if layout.shows(HudElement::Invincibility) {
+                        parent.spawn((
+                            InvincibilityIndicator,
+                            TextBundle::from_section(
+                                "",
                                 TextStyle {
-                                    font_size: 24.0,
-                                    color: Color::WHITE,
+                                    font_size: 18.0,
+                                    color: Color::srgb(1.0, 1.0, 0.3),
                                     ..default()
                                 },
-                            ));
-
-                            parent.spawn((
-                                AmmoText,
-                                TextBundle::from_section(
-                                    "∞",
-                                    TextStyle {
-                                        font_size: 24.0,
-                                        color: Color::WHITE,
-                                        ..default()
-                                    },
-                                ),
-                            ));
-                        });
+                            ),
+                        ));
+                    }
 
-                    // Right side: carried item, perk count and power-up indicators
-                    parent
-                        .spawn(NodeBundle {
-                            style: Style {
-                                flex_direction: FlexDirection::Row,
-                                align_items: AlignItems::Center,
-                                column_gap: Val::Px(15.0),
+                    // Last-stand push: lit when ready, grey while recovering
+                    if layout.shows(HudElement::LastStandIcon) {
+                        parent.spawn((
+                            LastStandIcon,
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Px(12.0),
+                                    height: Val::Px(12.0),
+                                    ..default()
+                                },
+                                background_color: BackgroundColor(Color::srgb(0.9, 0.9, 1.0)),
                                 ..default()
                             },
-                            ..default()
-                        })
-                        .with_children(|parent| {
-                            // Carried item display
-                            parent.spawn((
-                                CarriedItemText,
-                                TextBundle::from_section(
-                                    "",
-                                    TextStyle {
-                                        font_size: 18.0,
-                                        color: Color::srgb(1.0, 0.8, 0.2),
-                                        ..default()
-                                    },
-                                ),
-                            ));
-
-                            // Invincibility indicator (hidden by default)
-                            parent.spawn((
-                                InvincibilityIndicator,
-                                TextBundle::from_section(
-                                    "",
-                                    TextStyle {
-                                        font_size: 18.0,
-                                        color: Color::srgb(1.0, 1.0, 0.3),
-                                        ..default()
-                                    },
-                                ),
-                            ));
-
-                            // Perk count
-                            parent.spawn((
-                                PerkCountText,
-                                TextBundle::from_section(
-                                    "Perks: 0",
-                                    TextStyle {
-                                        font_size: 20.0,
-                                        color: Color::srgb(0.6, 0.9, 0.6),
-                                        ..default()
-                                    },
-                                ),
-                            ));
-                        });
+                        ));
+                    }
+
+                    if layout.shows(HudElement::PerkCount) {
+                        parent.spawn((
+                            PerkCountText,
+                            TextBundle::from_section(
+                                "Perks: 0",
+                                TextStyle {
+                                    font_size: 20.0,
+                                    color: Color::srgb(0.6, 0.9, 0.6),
+                                    ..default()
+                                },
+                            ),
+                        ));
+                    }
                 });
         });
 }
@@ -386,6 +619,69 @@ pub fn cleanup_hud(mut commands: Commands, query: Query<Entity, With<HudRoot>>)
     }
 }
 
+/// Seconds the ammo readout shows "OUT OF AMMO" after a weapon runs dry
+pub const OUT_OF_AMMO_WARNING_DURATION: f32 = 1.5;
+
+/// Time left on the out-of-ammo warning
+#[derive(Resource, Debug, Default)]
+pub struct OutOfAmmoWarning {
+    pub remaining: f32,
+}
+
+/// Starts the out-of-ammo warning when the HUD's player runs dry and counts it down
+pub fn track_out_of_ammo_warning(
+    time: Res<Time>,
+    player_query: Query<Entity, With<Player>>,
+    mut out_of_ammo_events: EventReader<OutOfAmmoEvent>,
+    mut warning: ResMut<OutOfAmmoWarning>,
+) {
+    warning.remaining = (warning.remaining - time.delta_seconds()).max(0.0);
+    let hud_player = player_query.get_single().ok();
+    if out_of_ammo_events
+        .read()
+        .any(|event| Some(event.player_entity) == hud_player)
+    {
+        warning.remaining = OUT_OF_AMMO_WARNING_DURATION;
+    }
+}
+
+/// Clears a warning left over from the previous run
+pub fn reset_out_of_ammo_warning(
+    mut warning: ResMut<OutOfAmmoWarning>,
+    mut refill_flash: ResMut<AmmoRefillFlash>,
+) {
+    *warning = OutOfAmmoWarning::default();
+    *refill_flash = AmmoRefillFlash::default();
+}
+
+/// Seconds the ammo readout flashes green after an Ammo bonus refills it
+pub const AMMO_REFILL_FLASH_DURATION: f32 = 0.6;
+
+/// Time left on the ammo refill flash
+#[derive(Resource, Debug, Default)]
+pub struct AmmoRefillFlash {
+    pub remaining: f32,
+}
+
+/// Starts the refill flash when the HUD's player tops up a limited-ammo weapon and counts it down
+pub fn track_ammo_refill_flash(
+    time: Res<Time>,
+    player_query: Query<(Entity, &EquippedWeapon), With<Player>>,
+    mut bonus_events: EventReader<BonusCollectedEvent>,
+    mut flash: ResMut<AmmoRefillFlash>,
+) {
+    flash.remaining = (flash.remaining - time.delta_seconds()).max(0.0);
+    let Ok((hud_player, weapon)) = player_query.get_single() else {
+        bonus_events.clear();
+        return;
+    };
+    if bonus_events.read().any(|event| {
+        event.player_entity == hud_player && event.bonus_type == BonusType::Ammo && weapon.ammo.is_some()
+    }) {
+        flash.remaining = AMMO_REFILL_FLASH_DURATION;
+    }
+}
+
 /// Updates basic HUD elements (health, XP, level, weapon)
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn update_hud(
@@ -421,6 +717,8 @@ pub fn update_hud(
         ),
     >,
     weapon_registry: Res<crate::weapons::registry::WeaponRegistry>,
+    out_of_ammo: Res<OutOfAmmoWarning>,
+    refill_flash: Res<AmmoRefillFlash>,
 ) {
     let Ok((health, experience, weapon)) = player_query.get_single() else {
         return;
@@ -450,7 +748,7 @@ pub fn update_hud(
 
     // Update weapon name
     if let Ok(mut text) = weapon_text_query.get_single_mut() {
-        if let Some(weapon_data) = weapon_registry.get(weapon.weapon_id) {
+        if let Some(weapon_data) = weapon_registry.get_key(&weapon.weapon) {
             text.sections[0].value = weapon_data.name.clone();
         }
     }
@@ -458,12 +756,19 @@ pub fn update_hud(
     // Update ammo text - use has_ammo() to check and color accordingly
     if let Ok(mut text) = ammo_text_query.get_single_mut() {
         let has_ammo = weapon.has_ammo();
-        text.sections[0].value = match weapon.ammo {
-            Some(ammo) => format!("{}", ammo),
-            None => "∞".into(),
+        text.sections[0].value = if out_of_ammo.remaining > 0.0 {
+            "OUT OF AMMO".into()
+        } else {
+            ammo_label(weapon)
         };
-        // Red text when out of ammo
-        text.sections[0].style.color = if has_ammo {
+        // Yellow while reloading, red text when out of ammo, green just after a refill
+        text.sections[0].style.color = if out_of_ammo.remaining > 0.0 {
+            Color::srgb(1.0, 0.3, 0.3)
+        } else if refill_flash.remaining > 0.0 {
+            Color::srgb(0.4, 1.0, 0.4)
+        } else if weapon.is_reloading() {
+            Color::srgb(1.0, 0.9, 0.3)
+        } else if has_ammo {
             Color::WHITE
         } else {
             Color::srgb(1.0, 0.3, 0.3)
@@ -471,6 +776,27 @@ pub fn update_hud(
     }
 }
 
+/// Ammo count, or a progress bar like "RELOAD [####------]" while reloading.
+/// A spinning weapon adds its wind-up, like "150 >>>--".
+pub fn ammo_label(weapon: &EquippedWeapon) -> String {
+    const BAR_WIDTH: usize = 10;
+    const SPIN_WIDTH: usize = 5;
+
+    if let Some(progress) = weapon.reload_progress() {
+        let filled = ((progress * BAR_WIDTH as f32) as usize).min(BAR_WIDTH);
+        return format!("RELOAD [{}{}]", "#".repeat(filled), "-".repeat(BAR_WIDTH - filled));
+    }
+    let count = match weapon.ammo {
+        Some(ammo) => format!("{}", ammo),
+        None => "∞".into(),
+    };
+    if weapon.spin <= 0.0 {
+        return count;
+    }
+    let spun = ((weapon.spin * SPIN_WIDTH as f32).ceil() as usize).min(SPIN_WIDTH);
+    format!("{} {}{}", count, ">".repeat(spun), "-".repeat(SPIN_WIDTH - spun))
+}
+
 /// Updates perk count, invincibility indicator, and carried item
 #[allow(clippy::type_complexity)]
 pub fn update_hud_perks(
@@ -518,6 +844,216 @@ pub fn update_hud_perks(
     }
 }
 
+/// Badge text: the perk's initials, or its full name when expanded, and the stack count
+pub fn perk_badge_label(name: &str, count: u8, expanded: bool) -> String {
+    let mut label = if expanded {
+        name.to_string()
+    } else {
+        let words: Vec<&str> = name.split_whitespace().collect();
+        match words.as_slice() {
+            [word] => word.chars().take(2).collect(),
+            words => words.iter().filter_map(|word| word.chars().next()).take(3).collect(),
+        }
+    };
+    if count > 1 {
+        label.push_str(&format!(" x{}", count));
+    }
+    label
+}
+
+/// Keeps the perk badges in step with the inventory. Only runs the diff when the
+/// inventory changes, the list is new (HUD rebuilt) or it expands or collapses;
+/// badges are added, relabelled or removed one by one rather than rebuilt
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_hud_perk_badges(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<PlayerInputMapping>,
+    perk_registry: Res<PerkRegistry>,
+    player_query: Query<Ref<PerkInventory>, With<Player>>,
+    list_query: Query<(Entity, Ref<PerkBadgeList>, &Interaction)>,
+    mut badges: Query<(Entity, &mut PerkBadge, &mut Text)>,
+    mut was_expanded: Local<bool>,
+) {
+    let (Ok((list, list_ref, interaction)), Ok(inventory)) = (list_query.get_single(), player_query.get_single())
+    else {
+        return;
+    };
+    let expanded = *interaction != Interaction::None || keyboard.pressed(input_mapping.inspect);
+    if !inventory.is_changed() && !list_ref.is_added() && expanded == *was_expanded {
+        return;
+    }
+    *was_expanded = expanded;
+
+    let owned = inventory.owned();
+    let mut shown = Vec::new();
+    for (entity, mut badge, mut text) in badges.iter_mut() {
+        let owned_count = owned.iter().find(|(key, _)| *key == badge.key).map(|&(_, count)| count);
+        match (owned_count, perk_registry.offer(&badge.key)) {
+            (Some(count), Some(offer)) => {
+                badge.count = count;
+                text.sections[0].value = perk_badge_label(&offer.name, count, expanded);
+                shown.push(badge.key.clone());
+            }
+            _ => commands.entity(entity).despawn_recursive(),
+        }
+    }
+
+    for (key, count) in owned {
+        if shown.contains(&key) {
+            continue;
+        }
+        let Some(offer) = perk_registry.offer(&key) else {
+            continue;
+        };
+        let badge = commands
+            .spawn((
+                TextBundle {
+                    style: Style {
+                        padding: UiRect::axes(Val::Px(4.0), Val::Px(1.0)),
+                        ..default()
+                    },
+                    background_color: BackgroundColor(offer.rarity.color()),
+                    ..TextBundle::from_section(
+                        perk_badge_label(&offer.name, count, expanded),
+                        TextStyle {
+                            font_size: 14.0,
+                            color: Color::BLACK,
+                            ..default()
+                        },
+                    )
+                },
+                PerkBadge { key, count },
+            ))
+            .id();
+        commands.entity(list).add_child(badge);
+    }
+}
+
+/// Updates the active bonus effects panel, along with how charged LivingFortress is
+#[allow(clippy::type_complexity)]
+pub fn update_hud_active_effects(
+    player_query: Query<(&ActiveBonusEffects, Option<&PerkBonuses>, Option<&StationaryTimer>), With<Player>>,
+    mut text_query: Query<&mut Text, With<ActiveEffectsText>>,
+) {
+    let Ok((effects, perk_bonuses, stationary)) = player_query.get_single() else {
+        return;
+    };
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+
+    let mut entries: Vec<String> = effects
+        .active_timers()
+        .iter()
+        .map(|(name, remaining)| format!("{} {:.1}s", name, remaining))
+        .collect();
+    let fortress = perk_bonuses.map_or(1.0, |bonuses| {
+        bonuses.living_fortress_multiplier(stationary.map_or(0.0, |timer| timer.seconds))
+    });
+    if fortress > 1.0 {
+        entries.push(format!("Fortress +{:.0}%", (fortress - 1.0) * 100.0));
+    }
+    text.sections[0].value = entries.join("  ");
+}
+
+/// Greys out the last-stand icon while the push recovers, hides it when disabled
+pub fn update_hud_last_stand(
+    last_stand: Res<LastStand>,
+    settings: Res<LastStandSettings>,
+    run_assists: Res<RunAssists>,
+    mut icon_query: Query<(&mut BackgroundColor, &mut Visibility), With<LastStandIcon>>,
+) {
+    let Ok((mut color, mut visibility)) = icon_query.get_single_mut() else {
+        return;
+    };
+    *visibility = if last_stand_allowed(&settings, &run_assists) {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    color.0 = if last_stand.is_ready() {
+        Color::srgb(0.9, 0.9, 1.0)
+    } else {
+        // Brightens back up as the cooldown recovers
+        let shade = 0.2 + 0.3 * last_stand.recovery();
+        Color::srgb(shade, shade, shade)
+    };
+}
+
+/// Shows the stowed weapon's name next to the equipped one, empty without a second weapon
+pub fn update_hud_stowed_weapon(
+    player_query: Query<&AlternateWeaponSlot, With<Player>>,
+    mut text_query: Query<&mut Text, With<StowedWeaponText>>,
+    weapon_registry: Res<crate::weapons::registry::WeaponRegistry>,
+) {
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+    let stowed = player_query
+        .get_single()
+        .ok()
+        .and_then(|slot| slot.stowed.as_ref())
+        .and_then(|stowed| weapon_registry.get_key(&stowed.weapon));
+    text.sections[0].value = match stowed {
+        Some(data) => format!("  [{}]", data.name),
+        None => String::new(),
+    };
+}
+
+/// Health bar fill color; DeathClock pulses it purple while health drains
+pub fn health_bar_color(death_clock: bool, elapsed: f32) -> Color {
+    if !death_clock {
+        return HEALTH_BAR_COLOR;
+    }
+    let pulse = 0.5 + 0.5 * (elapsed * DEATH_CLOCK_PULSE_RATE).sin();
+    Color::srgb(0.4 + 0.4 * pulse, 0.05, 0.5 + 0.4 * pulse)
+}
+
+/// Recolors the health bar for DeathClock
+pub fn update_hud_health_bar_color(
+    time: Res<Time>,
+    player_query: Query<&PerkBonuses, With<Player>>,
+    mut bar_query: Query<&mut BackgroundColor, With<HealthBar>>,
+) {
+    let Ok(mut color) = bar_query.get_single_mut() else {
+        return;
+    };
+    let death_clock = player_query.get_single().is_ok_and(|bonuses| bonuses.death_clock);
+    color.0 = health_bar_color(death_clock, time.elapsed_seconds());
+}
+
+/// Shows the heat bar for weapons that build heat; it turns red while overheated
+#[allow(clippy::type_complexity)]
+pub fn update_hud_heat_bar(
+    player_query: Query<&EquippedWeapon, With<Player>>,
+    weapon_registry: Res<crate::weapons::registry::WeaponRegistry>,
+    mut bar_query: Query<&mut Style, (With<HeatBar>, Without<HeatBarFill>)>,
+    mut fill_query: Query<(&mut Style, &mut BackgroundColor), With<HeatBarFill>>,
+) {
+    let Ok(mut bar_style) = bar_query.get_single_mut() else {
+        return;
+    };
+    let weapon = player_query.get_single().ok().filter(|weapon| {
+        weapon_registry
+            .get_key(&weapon.weapon)
+            .is_some_and(|data| data.builds_heat())
+    });
+    let Some(weapon) = weapon else {
+        bar_style.display = Display::None;
+        return;
+    };
+    bar_style.display = Display::Flex;
+    if let Ok((mut fill_style, mut fill_color)) = fill_query.get_single_mut() {
+        fill_style.width = Val::Percent(weapon.heat * 100.0);
+        fill_color.0 = if weapon.overheated {
+            Color::srgb(1.0, 0.2, 0.1)
+        } else {
+            Color::srgb(1.0, 0.6, 0.1)
+        };
+    }
+}
+
 /// Updates game mode specific HUD elements (timer, kills, wave)
 #[allow(clippy::type_complexity)]
 pub fn update_hud_game_mode(
@@ -603,13 +1139,162 @@ pub fn update_hud_game_mode(
     }
 }
 
-/// Spawns health bars above damaged creatures
+/// Name shown above a boss's health bar when no encounter names it
+pub fn boss_display_name(creature_type: CreatureType) -> &'static str {
+    match creature_type {
+        CreatureType::BossSpider => "Brood Mother",
+        CreatureType::BossAlien => "Alien Overlord",
+        CreatureType::BossNest => "The Nest",
+        _ => "Boss",
+    }
+}
+
+/// Spawns a boss health bar in `slot` (0 is the top one)
+fn spawn_boss_health_bar(commands: &mut Commands, boss: Entity, name: &str, health: &CreatureHealth, slot: usize) {
+    commands
+        .spawn((
+            BossHealthBarRoot { boss },
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: boss_bar_top(slot),
+                    left: Val::Percent(25.0),
+                    width: Val::Percent(50.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                name,
+                TextStyle {
+                    font_size: 18.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(12.0),
+                        ..default()
+                    },
+                    background_color: BackgroundColor(Color::srgba(0.1, 0.0, 0.0, 0.8)),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        BossHealthBarFill {
+                            last_health: health.current,
+                            flash: 0.0,
+                        },
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(health.percentage() * 100.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            background_color: BackgroundColor(BOSS_BAR_COLOR),
+                            ..default()
+                        },
+                    ));
+                });
+        });
+}
+
+/// Offset from the top of the screen of the boss bar in `slot`, clear of the status bar
+fn boss_bar_top(slot: usize) -> Val {
+    Val::Px(70.0 + 36.0 * slot as f32)
+}
+
+/// Shows a health bar for each living boss, up to MAX_BOSS_BARS stacked at the top of
+/// the screen. During a boss encounter the first bar carries the encounter's name.
+/// Bars flash when their boss takes damage and go away when it dies
+#[allow(clippy::type_complexity)]
+pub fn update_boss_health_bars(
+    mut commands: Commands,
+    time: Res<Time>,
+    boss_state: Option<Res<BossEncounterState>>,
+    bosses: Query<(Entity, &Creature, &CreatureHealth)>,
+    mut roots: Query<(Entity, &BossHealthBarRoot, &mut Style, &Children)>,
+    bar_children: Query<&Children, Without<BossHealthBarRoot>>,
+    mut fills: Query<(&mut BossHealthBarFill, &mut Style, &mut BackgroundColor), Without<BossHealthBarRoot>>,
+) {
+    let mut living: Vec<(Entity, CreatureType, &CreatureHealth)> = bosses
+        .iter()
+        .filter(|(_, creature, health)| creature.creature_type.is_boss() && !health.is_dead())
+        .map(|(entity, creature, health)| (entity, creature.creature_type, health))
+        .collect();
+    living.sort_by_key(|(entity, _, _)| *entity);
+    living.truncate(MAX_BOSS_BARS);
+
+    let mut shown = Vec::new();
+    for (root_entity, root, mut style, children) in roots.iter_mut() {
+        let Some(slot) = living.iter().position(|(boss, _, _)| *boss == root.boss) else {
+            commands.entity(root_entity).despawn_recursive();
+            continue;
+        };
+        shown.push(root.boss);
+        style.top = boss_bar_top(slot);
+
+        let health = living[slot].2;
+        let fill_entities = children
+            .iter()
+            .filter_map(|child| bar_children.get(*child).ok())
+            .flat_map(|grandchildren| grandchildren.iter());
+        for &entity in fill_entities {
+            let Ok((mut fill, mut fill_style, mut color)) = fills.get_mut(entity) else {
+                continue;
+            };
+            fill.flash = (fill.flash - time.delta_seconds()).max(0.0);
+            if health.current < fill.last_health {
+                fill.flash = BOSS_BAR_FLASH_DURATION;
+            }
+            fill.last_health = health.current;
+            fill_style.width = Val::Percent(health.percentage() * 100.0);
+            color.0 = if fill.flash > 0.0 { Color::WHITE } else { BOSS_BAR_COLOR };
+        }
+    }
+
+    for (slot, (boss, creature_type, health)) in living.iter().enumerate() {
+        if shown.contains(boss) {
+            continue;
+        }
+        let name = match &boss_state {
+            Some(state) if slot == 0 => state.boss_name.as_str(),
+            _ => boss_display_name(*creature_type),
+        };
+        spawn_boss_health_bar(&mut commands, *boss, name, health, slot);
+    }
+}
+
+/// Removes every boss health bar (boss encounter or run over)
+pub fn cleanup_boss_health_bars(mut commands: Commands, query: Query<Entity, With<BossHealthBarRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// True when any player has a perk that shows creature health (Doctor, MonsterVision)
+fn creature_health_visible(players: &Query<&PerkBonuses, With<Player>>) -> bool {
+    players.iter().any(|bonuses| bonuses.show_creature_health)
+}
+
+/// Spawns health bars above damaged creatures while a player can see creature health
 #[allow(clippy::type_complexity)]
 pub fn spawn_creature_health_bars(
     mut commands: Commands,
+    players: Query<&PerkBonuses, With<Player>>,
     creatures: Query<(Entity, &CreatureHealth), (With<Creature>, Without<CreatureHealthBar>)>,
     existing_bars: Query<&CreatureHealthBar>,
 ) {
+    if !creature_health_visible(&players) {
+        return;
+    }
     for (entity, health) in creatures.iter() {
         // Only spawn health bar if creature has taken damage
         if health.current < health.max {
@@ -626,7 +1311,7 @@ pub fn spawn_creature_health_bars(
                             custom_size: Some(Vec2::new(32.0, 4.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(Vec3::new(0.0, 20.0, 10.0)),
+                        transform: Transform::from_translation(Vec3::new(0.0, 20.0, WORLD_OVERLAY)),
                         ..default()
                     },
                 ));
@@ -640,7 +1325,11 @@ pub fn spawn_creature_health_bars(
                             custom_size: Some(Vec2::new(32.0 * health.percentage(), 4.0)),
                             ..default()
                         },
-                        transform: Transform::from_translation(Vec3::new(0.0, 20.0, 11.0)),
+                        transform: Transform::from_translation(Vec3::new(
+                            0.0,
+                            20.0,
+                            WORLD_OVERLAY + HEALTH_BAR_FILL_OFFSET,
+                        )),
                         ..default()
                     },
                 ));
@@ -698,15 +1387,17 @@ pub fn update_creature_health_bars(
     }
 }
 
-/// Cleans up health bars when creatures die
+/// Cleans up health bars when creatures die, or all of them once no player can see creature health
 pub fn cleanup_creature_health_bars(
     mut commands: Commands,
+    players: Query<&PerkBonuses, With<Player>>,
     creatures: Query<Entity, With<Creature>>,
     health_bars: Query<(Entity, &CreatureHealthBar)>,
 ) {
+    let visible = creature_health_visible(&players);
     for (bar_entity, bar) in health_bars.iter() {
         // If the creature no longer exists, despawn the health bar
-        if creatures.get(bar.creature).is_err() {
+        if !visible || creatures.get(bar.creature).is_err() {
             commands.entity(bar_entity).despawn_recursive();
         }
     }
@@ -721,6 +1412,68 @@ mod tests {
         let _root = HudRoot;
     }
 
+    #[test]
+    fn perk_badges_show_initials_and_stacks() {
+        assert_eq!(perk_badge_label("Regeneration", 1, false), "Re");
+        assert_eq!(perk_badge_label("Regeneration", 3, false), "Re x3");
+        assert_eq!(perk_badge_label("Death Clock", 1, false), "DC");
+        assert_eq!(perk_badge_label("Death Clock", 2, true), "Death Clock x2");
+    }
+
+    #[test]
+    fn perk_badges_follow_the_inventory_without_rebuilding() {
+        use crate::perks::PerkId;
+
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<PlayerInputMapping>()
+            .init_resource::<PerkRegistry>()
+            .add_systems(Update, update_hud_perk_badges);
+        app.world_mut().spawn((PerkBadgeList, Interaction::default(), NodeBundle::default()));
+        let player = app.world_mut().spawn((Player::default(), PerkInventory::new())).id();
+        let badges = |app: &mut App| {
+            let mut badges: Vec<(Entity, PerkKey, u8)> = app
+                .world_mut()
+                .query::<(Entity, &PerkBadge)>()
+                .iter(app.world())
+                .map(|(entity, badge)| (entity, badge.key.clone(), badge.count))
+                .collect();
+            badges.sort_by_key(|(_, _, count)| *count);
+            badges
+        };
+        app.update();
+        assert!(badges(&mut app).is_empty());
+
+        let add = |app: &mut App, perk: PerkId| {
+            app.world_mut().get_mut::<PerkInventory>(player).unwrap().add_perk(perk);
+            app.update();
+        };
+        add(&mut app, PerkId::Regeneration);
+        add(&mut app, PerkId::BloodyMess);
+        let before = badges(&mut app);
+        assert_eq!(before.len(), 2);
+
+        // A second stack relabels the existing badge
+        add(&mut app, PerkId::Regeneration);
+        let after = badges(&mut app);
+        let regen = after.iter().find(|(_, key, _)| *key == PerkKey::Builtin(PerkId::Regeneration)).unwrap();
+        assert_eq!(regen.2, 2);
+        assert!(before.iter().any(|(entity, _, _)| *entity == regen.0));
+
+        *app.world_mut().get_mut::<PerkInventory>(player).unwrap() = PerkInventory::new();
+        app.update();
+        assert!(badges(&mut app).is_empty());
+    }
+
+    #[test]
+    fn death_clock_health_bar_pulses() {
+        assert_eq!(health_bar_color(false, 1.0), HEALTH_BAR_COLOR);
+        let low = health_bar_color(true, -std::f32::consts::FRAC_PI_2 / DEATH_CLOCK_PULSE_RATE);
+        let high = health_bar_color(true, std::f32::consts::FRAC_PI_2 / DEATH_CLOCK_PULSE_RATE);
+        assert_ne!(low, high);
+        assert_ne!(low, HEALTH_BAR_COLOR);
+    }
+
     #[test]
     fn creature_health_bar_tracks_entity() {
         let bar = CreatureHealthBar {
@@ -728,4 +1481,217 @@ mod tests {
         };
         assert_eq!(bar.creature, Entity::PLACEHOLDER);
     }
+
+    #[test]
+    fn boss_health_bars_follow_living_bosses_up_to_two() {
+        use crate::creatures::CreatureBundle;
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .add_systems(Update, update_boss_health_bars);
+        app.world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::ZERO));
+        let bars = |app: &mut App| {
+            let mut bars: Vec<Entity> = app
+                .world_mut()
+                .query::<&BossHealthBarRoot>()
+                .iter(app.world())
+                .map(|root| root.boss)
+                .collect();
+            bars.sort();
+            bars
+        };
+        let fill = |app: &mut App| {
+            let mut fills = app
+                .world_mut()
+                .query::<(&BossHealthBarFill, &Style, &BackgroundColor)>();
+            let (fill, style, color) = fills.iter(app.world()).next().unwrap();
+            (fill.flash > 0.0, style.width, color.0)
+        };
+
+        app.update();
+        assert!(bars(&mut app).is_empty());
+
+        let nest = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::BossNest, Vec3::ZERO))
+            .id();
+        app.update();
+        assert_eq!(bars(&mut app), vec![nest]);
+        assert_eq!(fill(&mut app), (false, Val::Percent(100.0), BOSS_BAR_COLOR));
+
+        // Damage shrinks the fill and flashes it
+        app.world_mut().get_mut::<CreatureHealth>(nest).unwrap().damage(250.0);
+        app.update();
+        assert_eq!(fill(&mut app), (true, Val::Percent(75.0), Color::WHITE));
+
+        // A third boss waits for a free slot
+        let spiders: Vec<Entity> = (0..2)
+            .map(|_| {
+                app.world_mut()
+                    .spawn(CreatureBundle::new(CreatureType::BossSpider, Vec3::ZERO))
+                    .id()
+            })
+            .collect();
+        app.update();
+        assert_eq!(bars(&mut app), vec![nest, spiders[0]]);
+
+        app.world_mut().despawn(nest);
+        app.update();
+        assert_eq!(bars(&mut app), spiders);
+
+        for &spider in &spiders {
+            let mut health = app.world_mut().get_mut::<CreatureHealth>(spider).unwrap();
+            let current = health.current;
+            health.damage(current);
+        }
+        app.update();
+        assert!(bars(&mut app).is_empty());
+    }
+
+    #[test]
+    fn creature_health_bars_need_doctor_or_monster_vision() {
+        use crate::creatures::{CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.add_systems(
+            Update,
+            (spawn_creature_health_bars, cleanup_creature_health_bars).chain(),
+        );
+        let player = app.world_mut().spawn((Player::default(), PerkBonuses::default())).id();
+        let creature = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Zombie, Vec3::ZERO))
+            .id();
+        app.world_mut().get_mut::<CreatureHealth>(creature).unwrap().damage(10.0);
+        let bars = |app: &mut App| {
+            app.world_mut()
+                .query::<&CreatureHealthBar>()
+                .iter(app.world())
+                .filter(|bar| bar.creature == creature)
+                .count()
+        };
+
+        app.update();
+        assert_eq!(bars(&mut app), 0);
+
+        // Picked up mid-run: the creature damaged earlier gets its bar now
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().show_creature_health = true;
+        app.update();
+        app.update();
+        assert_eq!(bars(&mut app), 2);
+
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().show_creature_health = false;
+        app.update();
+        app.update();
+        assert_eq!(bars(&mut app), 0);
+    }
+
+    #[test]
+    fn ammo_label_shows_reload_progress() {
+        let mut weapon = EquippedWeapon::new(crate::weapons::WeaponId::Shotgun, Some(12));
+        assert_eq!(ammo_label(&weapon), "12");
+
+        weapon.ammo = Some(0);
+        weapon.start_reload(2.0);
+        assert_eq!(ammo_label(&weapon), "RELOAD [----------]");
+        weapon.reload_timer = 0.8;
+        assert_eq!(ammo_label(&weapon), "RELOAD [######----]");
+
+        assert_eq!(ammo_label(&EquippedWeapon::default()), "∞");
+    }
+
+    #[test]
+    fn ammo_label_shows_spin_up() {
+        let mut weapon = EquippedWeapon::new(crate::weapons::WeaponId::Minigun, Some(150));
+        weapon.spin = 0.5;
+        assert_eq!(ammo_label(&weapon), "150 >>>--");
+        weapon.spin = 1.0;
+        assert_eq!(ammo_label(&weapon), "150 >>>>>");
+    }
+
+    #[test]
+    fn running_dry_flashes_the_out_of_ammo_warning() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<OutOfAmmoWarning>()
+            .add_event::<OutOfAmmoEvent>()
+            .add_systems(Update, track_out_of_ammo_warning);
+        let player = app.world_mut().spawn(Player::default()).id();
+        app.world_mut().send_event(OutOfAmmoEvent {
+            player_entity: player,
+            position: Vec3::ZERO,
+        });
+        app.update();
+        assert_eq!(app.world().resource::<OutOfAmmoWarning>().remaining, OUT_OF_AMMO_WARNING_DURATION);
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(OUT_OF_AMMO_WARNING_DURATION));
+        app.update();
+        assert_eq!(app.world().resource::<OutOfAmmoWarning>().remaining, 0.0);
+    }
+
+    #[test]
+    fn ammo_refill_flashes_only_for_limited_weapons() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<AmmoRefillFlash>()
+            .add_event::<BonusCollectedEvent>()
+            .add_systems(Update, track_ammo_refill_flash);
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), EquippedWeapon::default()))
+            .id();
+        let collect = |app: &mut App| {
+            app.world_mut().send_event(BonusCollectedEvent {
+                player_entity: player,
+                bonus_type: BonusType::Ammo,
+                weapon: None,
+                amount: Some(10),
+            });
+            app.update();
+        };
+
+        // The Pistol converts the bonus to XP, so no flash
+        collect(&mut app);
+        assert_eq!(app.world().resource::<AmmoRefillFlash>().remaining, 0.0);
+
+        *app.world_mut().get_mut::<EquippedWeapon>(player).unwrap() =
+            EquippedWeapon::new(crate::weapons::WeaponId::Shotgun, Some(3));
+        collect(&mut app);
+        assert_eq!(app.world().resource::<AmmoRefillFlash>().remaining, AMMO_REFILL_FLASH_DURATION);
+    }
+
+    #[test]
+    fn heat_bar_shows_only_for_weapons_that_build_heat() {
+        let mut app = App::new();
+        app.init_resource::<crate::weapons::registry::WeaponRegistry>()
+            .add_systems(Update, update_hud_heat_bar);
+        let bar = app
+            .world_mut()
+            .spawn((HeatBar, Style::default()))
+            .id();
+        let fill = app
+            .world_mut()
+            .spawn((HeatBarFill, Style::default(), BackgroundColor::default()))
+            .id();
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), EquippedWeapon::default()))
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<Style>(bar).unwrap().display, Display::None);
+
+        let mut plasma = EquippedWeapon::new(crate::weapons::WeaponId::PlasmaRifle, Some(150));
+        plasma.add_heat(1.0);
+        *app.world_mut().get_mut::<EquippedWeapon>(player).unwrap() = plasma;
+        app.update();
+        assert_eq!(app.world().get::<Style>(bar).unwrap().display, Display::Flex);
+        assert_eq!(app.world().get::<Style>(fill).unwrap().width, Val::Percent(100.0));
+        assert_eq!(
+            app.world().get::<BackgroundColor>(fill).unwrap().0,
+            Color::srgb(1.0, 0.2, 0.1)
+        );
+    }
 }
@@ -0,0 +1,72 @@
+//! HUD banner
+//!
+//! A short centered message for moments the Minimal HUD has no persistent
+//! element for: level-ups and boss waves.
+
+use bevy::prelude::*;
+
+use crate::audio::HordeSurgeEvent;
+use crate::player::PlayerLevelUpEvent;
+
+/// Seconds a banner message stays up
+pub const BANNER_DURATION: f32 = 2.5;
+
+/// Marker for the banner text (only spawned by layouts that use it)
+#[derive(Component)]
+pub struct HudBannerText;
+
+/// Message currently on the banner
+#[derive(Resource, Debug, Default)]
+pub struct HudBanner {
+    pub message: String,
+    pub remaining: f32,
+}
+
+impl HudBanner {
+    /// Replaces whatever is showing
+    pub fn show(&mut self, message: impl Into<String>) {
+        self.message = message.into();
+        self.remaining = BANNER_DURATION;
+    }
+
+    pub fn is_showing(&self) -> bool {
+        self.remaining > 0.0
+    }
+}
+
+/// Posts banner messages for level-ups and boss waves
+pub fn queue_hud_banners(
+    mut levelups: EventReader<PlayerLevelUpEvent>,
+    mut surges: EventReader<HordeSurgeEvent>,
+    mut banner: ResMut<HudBanner>,
+) {
+    for event in levelups.read() {
+        banner.show(format!("LEVEL {}", event.new_level));
+    }
+    // A boss warning outranks a level-up in the same frame
+    if surges.read().any(|surge| surge.boss) {
+        banner.show("BOSS INCOMING");
+    }
+}
+
+/// Counts the banner down and mirrors it into the banner text
+pub fn update_hud_banner(
+    time: Res<Time>,
+    mut banner: ResMut<HudBanner>,
+    mut text_query: Query<(&mut Text, &mut Visibility), With<HudBannerText>>,
+) {
+    banner.remaining = (banner.remaining - time.delta_seconds()).max(0.0);
+    for (mut text, mut visibility) in text_query.iter_mut() {
+        if banner.is_showing() {
+            text.sections[0].value.clone_from(&banner.message);
+            *visibility = Visibility::Inherited;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+/// Clears any message left over from the previous run
+pub fn reset_hud_banner(mut banner: ResMut<HudBanner>) {
+    *banner = HudBanner::default();
+}
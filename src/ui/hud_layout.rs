@@ -0,0 +1,252 @@
+//! HUD layout option
+//!
+//! Classic keeps status on top and the weapon bar below, Flipped swaps the two
+//! bars, Minimal keeps only the health bar, ammo and timer. Which HUD elements
+//! each layout spawns is the table in [`HudLayout::elements`]; changing the
+//! layout mid-run rebuilds the HUD in place.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{spawn_hud, HudRoot};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persist;
+use crate::player::PlayerInputMapping;
+
+#[cfg(not(target_arch = "wasm32"))]
+const SETTINGS_FILE: &str = "hud.ron";
+
+/// Arrangement of the in-game HUD
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HudLayout {
+    /// Health and level on top, weapon and kills at the bottom
+    #[default]
+    Classic,
+    /// Classic with the top and bottom bars swapped
+    Flipped,
+    /// Health bar, ammo and timer only
+    Minimal,
+}
+
+/// One HUD element, named after its marker component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HudElement {
+    HealthText,
+    HealthBar,
+    GameTimer,
+    WaveProgress,
+    Level,
+    ExperienceBar,
+    KillCounter,
+    Weapon,
+    Ammo,
+    StowedWeapon,
+    CarriedItem,
+    ActiveEffects,
+    Invincibility,
+    LastStandIcon,
+    PerkCount,
+    /// Owned perks as small rarity-colored badges
+    PerkBadges,
+    /// Transient messages for what the hidden elements would have shown
+    Banner,
+}
+
+const FULL_HUD: &[HudElement] = &[
+    HudElement::HealthText,
+    HudElement::HealthBar,
+    HudElement::GameTimer,
+    HudElement::WaveProgress,
+    HudElement::Level,
+    HudElement::ExperienceBar,
+    HudElement::KillCounter,
+    HudElement::Weapon,
+    HudElement::Ammo,
+    HudElement::StowedWeapon,
+    HudElement::CarriedItem,
+    HudElement::ActiveEffects,
+    HudElement::Invincibility,
+    HudElement::LastStandIcon,
+    HudElement::PerkCount,
+    HudElement::PerkBadges,
+];
+
+const MINIMAL_HUD: &[HudElement] = &[
+    HudElement::HealthBar,
+    HudElement::GameTimer,
+    HudElement::Ammo,
+    HudElement::Banner,
+];
+
+impl HudLayout {
+    /// Elements this layout spawns
+    pub fn elements(self) -> &'static [HudElement] {
+        match self {
+            HudLayout::Classic | HudLayout::Flipped => FULL_HUD,
+            HudLayout::Minimal => MINIMAL_HUD,
+        }
+    }
+
+    pub fn shows(self, element: HudElement) -> bool {
+        self.elements().contains(&element)
+    }
+
+    /// Layout after this one when cycling through the option
+    pub fn next(self) -> Self {
+        match self {
+            HudLayout::Classic => HudLayout::Flipped,
+            HudLayout::Flipped => HudLayout::Minimal,
+            HudLayout::Minimal => HudLayout::Classic,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HudLayout::Classic => "Classic",
+            HudLayout::Flipped => "Flipped",
+            HudLayout::Minimal => "Minimal",
+        }
+    }
+}
+
+/// Player HUD options (persisted)
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HudSettings {
+    pub layout: HudLayout,
+}
+
+/// Loads the HUD options on startup
+pub fn load_hud_settings(mut settings: ResMut<HudSettings>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(loaded) = persist::load::<HudSettings>(SETTINGS_FILE).into_value() {
+            *settings = loaded;
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &mut settings;
+}
+
+/// Persists the HUD options whenever they change
+pub fn save_hud_settings(settings: Res<HudSettings>) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(err) = persist::save(SETTINGS_FILE, &*settings) {
+        warn!("Failed to save {}: {}", SETTINGS_FILE, err);
+    }
+}
+
+/// Cycles the layout from in game
+pub fn handle_hud_layout_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<PlayerInputMapping>,
+    mut settings: ResMut<HudSettings>,
+) {
+    if keyboard.just_pressed(input_mapping.cycle_hud_layout) {
+        settings.layout = settings.layout.next();
+    }
+}
+
+/// Tears down and respawns the HUD when the layout changes during a run
+pub fn rebuild_hud_on_layout_change(
+    mut commands: Commands,
+    settings: Res<HudSettings>,
+    roots: Query<Entity, With<HudRoot>>,
+) {
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+    for entity in roots.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    spawn_hud(&mut commands, settings.layout);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::*;
+
+    /// Elements present in the world, found by their marker components
+    fn spawned_elements(world: &mut World) -> Vec<HudElement> {
+        fn count<T: Component>(world: &mut World) -> usize {
+            world.query_filtered::<(), With<T>>().iter(world).count()
+        }
+        let counts = [
+            (HudElement::HealthText, count::<HealthText>(world)),
+            (HudElement::HealthBar, count::<HealthBar>(world)),
+            (HudElement::GameTimer, count::<GameTimerText>(world)),
+            (HudElement::WaveProgress, count::<WaveProgressText>(world)),
+            (HudElement::Level, count::<LevelText>(world)),
+            (HudElement::ExperienceBar, count::<ExperienceBar>(world)),
+            (HudElement::KillCounter, count::<KillCounterText>(world)),
+            (HudElement::Weapon, count::<WeaponText>(world)),
+            (HudElement::Ammo, count::<AmmoText>(world)),
+            (HudElement::StowedWeapon, count::<StowedWeaponText>(world)),
+            (HudElement::CarriedItem, count::<CarriedItemText>(world)),
+            (HudElement::ActiveEffects, count::<ActiveEffectsText>(world)),
+            (HudElement::Invincibility, count::<InvincibilityIndicator>(world)),
+            (HudElement::LastStandIcon, count::<LastStandIcon>(world)),
+            (HudElement::PerkCount, count::<PerkCountText>(world)),
+            (HudElement::PerkBadges, count::<PerkBadgeList>(world)),
+            (HudElement::Banner, count::<HudBannerText>(world)),
+        ];
+        for (element, n) in counts {
+            assert!(n <= 1, "{:?} spawned {} times", element, n);
+        }
+        counts.into_iter().filter(|&(_, n)| n == 1).map(|(e, _)| e).collect()
+    }
+
+    fn hud_app(layout: HudLayout) -> App {
+        let mut app = App::new();
+        app.insert_resource(HudSettings { layout })
+            .add_systems(Startup, setup_hud)
+            .add_systems(Update, rebuild_hud_on_layout_change);
+        app.update();
+        app
+    }
+
+    #[test]
+    fn each_layout_spawns_exactly_its_elements() {
+        for layout in [HudLayout::Classic, HudLayout::Flipped, HudLayout::Minimal] {
+            let mut app = hud_app(layout);
+            assert_eq!(spawned_elements(app.world_mut()), layout.elements(), "{:?}", layout);
+        }
+    }
+
+    #[test]
+    fn changing_layout_rebuilds_a_single_hud() {
+        let mut app = hud_app(HudLayout::Classic);
+        app.update();
+
+        app.world_mut().resource_mut::<HudSettings>().layout = HudLayout::Minimal;
+        app.update();
+        let roots = app
+            .world_mut()
+            .query_filtered::<(), With<HudRoot>>()
+            .iter(app.world())
+            .count();
+        assert_eq!(roots, 1);
+        assert_eq!(spawned_elements(app.world_mut()), HudLayout::Minimal.elements());
+
+        app.world_mut().resource_mut::<HudSettings>().layout = HudLayout::Flipped;
+        app.update();
+        assert_eq!(spawned_elements(app.world_mut()), HudLayout::Flipped.elements());
+    }
+
+    #[test]
+    fn cycling_visits_every_layout() {
+        let mut layout = HudLayout::default();
+        let mut seen = vec![layout];
+        for _ in 0..2 {
+            layout = layout.next();
+            seen.push(layout);
+        }
+        assert_eq!(seen, [HudLayout::Classic, HudLayout::Flipped, HudLayout::Minimal]);
+        assert_eq!(layout.next(), HudLayout::Classic);
+    }
+}
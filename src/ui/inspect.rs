@@ -0,0 +1,287 @@
+//! Inspect panel
+//!
+//! Holding the inspect key (Tab) during a run overlays the player's perks with
+//! their current values and the equipped weapon's perk-modified stats. The run
+//! keeps going underneath; releasing the key hides the panel.
+
+use bevy::prelude::*;
+
+use crate::perks::{PerkBonuses, PerkEffectConfig, PerkId, PerkInventory, PerkKey, PerkRegistry};
+use crate::player::{Player, PlayerInputMapping};
+use crate::weapons::{EquippedWeapon, WeaponData, WeaponRegistry};
+
+/// Marker for the inspect panel root
+#[derive(Component)]
+pub struct InspectPanel;
+
+/// Marker for the inspect panel's text
+#[derive(Component)]
+pub struct InspectPanelText;
+
+/// Weapon stats after perk bonuses, matching what firing and reloading use
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EffectiveStats {
+    /// Damage per projectile
+    pub damage: f32,
+    pub projectiles_per_shot: u32,
+    /// Shots per second
+    pub fire_rate: f32,
+    /// Spread in radians
+    pub spread: f32,
+    /// Seconds for a full reload
+    pub reload_time: f32,
+    /// Distance a projectile travels before expiring
+    pub range: f32,
+}
+
+impl EffectiveStats {
+    pub fn damage_per_second(&self) -> f32 {
+        self.damage * self.projectiles_per_shot as f32 * self.fire_rate
+    }
+}
+
+/// `weapon`'s stats with `bonuses` applied the way the weapon systems apply them
+pub fn effective_weapon_stats(weapon: &WeaponData, bonuses: &PerkBonuses) -> EffectiveStats {
+    let reload_speed = bonuses.reload_speed_multiplier.max(f32::EPSILON);
+    EffectiveStats {
+        damage: weapon.damage * bonuses.damage_multiplier,
+        projectiles_per_shot: weapon.projectiles_per_shot,
+        fire_rate: 1.0 / (weapon.fire_cooldown() / bonuses.fire_rate_multiplier),
+        spread: weapon.spread * (1.0 - bonuses.accuracy_bonus.min(0.9)),
+        reload_time: weapon.reload_time / reload_speed,
+        range: weapon.projectile_speed * weapon.projectile_lifetime * bonuses.range_multiplier,
+    }
+}
+
+/// Current value of a perk's effect, for perks whose description doesn't say it
+pub fn perk_value(perk: PerkId, bonuses: &PerkBonuses, config: &PerkEffectConfig) -> Option<String> {
+    match perk {
+        PerkId::Regeneration | PerkId::GreaterRegeneration => {
+            Some(format!("{:.1} hp/s", bonuses.regen_per_second))
+        }
+        PerkId::DeathClock => Some(format!("{:.1} hp/s", bonuses.health_per_second(config.death_clock_drain))),
+        PerkId::Dodger | PerkId::Ninja => Some(format!("Dodge {:.0}%", bonuses.dodge_chance * 100.0)),
+        PerkId::LongDistanceRunner => Some(format!("Top speed x{:.1}", bonuses.runner_momentum_cap)),
+        PerkId::BloodyMess => Some(format!("XP x{:.1}", bonuses.exp_multiplier)),
+        PerkId::UraniumFilledBullets | PerkId::Doctor | PerkId::BarrelGreaser => {
+            Some(format!("Damage x{:.2}", bonuses.damage_multiplier))
+        }
+        PerkId::Fastshot => Some(format!("Fire rate x{:.2}", bonuses.fire_rate_multiplier)),
+        PerkId::ThickSkinned => Some(format!(
+            "Damage taken {:.0}%",
+            bonuses.damage_taken_multiplier * 100.0
+        )),
+        PerkId::Highlander => Some(format!(
+            "Instant kill {:.0}%",
+            bonuses.instant_kill_chance * 100.0
+        )),
+        PerkId::PoisonBullets => Some(format!("Poison {:.1}%", bonuses.poison_chance * 100.0)),
+        _ => None,
+    }
+}
+
+/// One panel line for a perk, e.g. "Regeneration x3 — 6.0 hp/s".
+/// Falls back to the registry description when there's no value to show.
+pub fn perk_line(name: &str, description: &str, count: u8, value: Option<String>) -> String {
+    let name = if count > 1 {
+        format!("{} x{}", name, count)
+    } else {
+        name.to_string()
+    };
+    format!("{} — {}", name, value.as_deref().unwrap_or(description))
+}
+
+/// Weapon section of the panel
+fn weapon_lines(weapon: &WeaponData, stats: &EffectiveStats) -> String {
+    let damage = if stats.projectiles_per_shot > 1 {
+        format!("{:.0} x{}", stats.damage, stats.projectiles_per_shot)
+    } else {
+        format!("{:.0}", stats.damage)
+    };
+    format!(
+        "{}\nDamage {} | {:.1} shots/s | DPS {:.0}\nReload {:.2}s | Spread {:.1}° | Range {:.0}",
+        weapon.name,
+        damage,
+        stats.fire_rate,
+        stats.damage_per_second(),
+        stats.reload_time,
+        stats.spread.to_degrees(),
+        stats.range,
+    )
+}
+
+/// Full panel text for a player
+fn panel_text(
+    inventory: &PerkInventory,
+    bonuses: &PerkBonuses,
+    weapon: Option<&WeaponData>,
+    perk_registry: &PerkRegistry,
+    perk_config: &PerkEffectConfig,
+) -> String {
+    let mut lines = Vec::new();
+    if let Some(weapon) = weapon {
+        lines.push(weapon_lines(weapon, &effective_weapon_stats(weapon, bonuses)));
+        lines.push(String::new());
+    }
+
+    let mut any_perk = false;
+    for (key, count) in inventory.owned() {
+        let Some(offer) = perk_registry.offer(&key) else {
+            continue;
+        };
+        let value = match key {
+            PerkKey::Builtin(id) => perk_value(id, bonuses, perk_config),
+            PerkKey::Custom(_) => None,
+        };
+        lines.push(perk_line(&offer.name, &offer.description, count, value));
+        any_perk = true;
+    }
+    if !any_perk {
+        lines.push("No perks yet".into());
+    }
+    lines.join("\n")
+}
+
+/// Shows the inspect panel while the inspect key is held and hides it on release
+#[allow(clippy::too_many_arguments)]
+pub fn update_inspect_panel(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<PlayerInputMapping>,
+    player_query: Query<(&PerkInventory, &PerkBonuses, &EquippedWeapon), With<Player>>,
+    perk_registry: Res<PerkRegistry>,
+    perk_config: Res<PerkEffectConfig>,
+    weapon_registry: Res<WeaponRegistry>,
+    panel_query: Query<Entity, With<InspectPanel>>,
+    mut text_query: Query<&mut Text, With<InspectPanelText>>,
+) {
+    let player = player_query.get_single().ok();
+    let (true, Some((inventory, bonuses, weapon))) =
+        (keyboard.pressed(input_mapping.inspect), player)
+    else {
+        for entity in panel_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    let text = panel_text(
+        inventory,
+        bonuses,
+        weapon_registry.get_key(&weapon.weapon),
+        &perk_registry,
+        &perk_config,
+    );
+    if let Ok(mut panel_text) = text_query.get_single_mut() {
+        panel_text.sections[0].value = text;
+        return;
+    }
+    if !panel_query.is_empty() {
+        return;
+    }
+
+    // Right-hand side, clear of the player in the middle of the screen
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(10.0),
+                    top: Val::Percent(20.0),
+                    max_width: Val::Px(420.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                background_color: Color::srgba(0.0, 0.0, 0.0, 0.55).into(),
+                ..default()
+            },
+            InspectPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(text, super::text_style(16.0, Color::srgba(1.0, 1.0, 1.0, 0.9))),
+                InspectPanelText,
+            ));
+        });
+}
+
+/// Removes the inspect panel when leaving the run
+pub fn cleanup_inspect_panel(mut commands: Commands, query: Query<Entity, With<InspectPanel>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weapons::WeaponId;
+
+    fn bonuses_for(perks: &[PerkId]) -> PerkBonuses {
+        let mut inventory = PerkInventory::default();
+        for &perk in perks {
+            inventory.add_perk(perk);
+        }
+        PerkBonuses::calculate(&inventory)
+    }
+
+    #[test]
+    fn effective_stats_apply_damage_and_fire_rate_perks() {
+        let registry = WeaponRegistry::default();
+        let pistol = registry.get(WeaponId::Pistol).unwrap();
+
+        let base = effective_weapon_stats(pistol, &PerkBonuses::default());
+        assert_eq!(base.damage, pistol.damage);
+        assert!((base.fire_rate - pistol.fire_rate).abs() < 1e-4);
+        assert_eq!(base.reload_time, pistol.reload_time);
+
+        let boosted = effective_weapon_stats(
+            pistol,
+            &bonuses_for(&[PerkId::UraniumFilledBullets, PerkId::Fastshot]),
+        );
+        assert_eq!(boosted.damage, pistol.damage * 2.0);
+        assert!((boosted.fire_rate - pistol.fire_rate / 0.88).abs() < 1e-3);
+        assert!(boosted.damage_per_second() > base.damage_per_second() * 2.0);
+    }
+
+    #[test]
+    fn stacked_regeneration_shows_its_rate() {
+        let bonuses = bonuses_for(&[PerkId::Regeneration, PerkId::Regeneration, PerkId::Regeneration]);
+        let line = perk_line(
+            "Regeneration",
+            "Slowly regenerate health over time.",
+            3,
+            perk_value(PerkId::Regeneration, &bonuses, &PerkEffectConfig::default()),
+        );
+        assert_eq!(line, "Regeneration x3 — 6.0 hp/s");
+    }
+
+    #[test]
+    fn dodge_perks_show_the_active_chance() {
+        let bonuses = bonuses_for(&[PerkId::Dodger, PerkId::Ninja]);
+        let config = PerkEffectConfig::default();
+        // Ninja overrides Dodger, so both report the same chance
+        assert_eq!(perk_value(PerkId::Dodger, &bonuses, &config).as_deref(), Some("Dodge 33%"));
+        assert_eq!(perk_value(PerkId::Ninja, &bonuses, &config).as_deref(), Some("Dodge 33%"));
+    }
+
+    #[test]
+    fn death_clock_shows_the_configured_drain() {
+        let bonuses = bonuses_for(&[PerkId::DeathClock]);
+        let config = PerkEffectConfig {
+            death_clock_drain: 6.0,
+            ..default()
+        };
+        assert_eq!(perk_value(PerkId::DeathClock, &bonuses, &config).as_deref(), Some("-6.0 hp/s"));
+    }
+
+    #[test]
+    fn perks_without_a_value_show_their_description() {
+        let bonuses = bonuses_for(&[PerkId::MonsterVision]);
+        assert_eq!(perk_value(PerkId::MonsterVision, &bonuses, &PerkEffectConfig::default()), None);
+        assert_eq!(
+            perk_line("Monster Vision", "See creatures clearly.", 1, None),
+            "Monster Vision — See creatures clearly."
+        );
+    }
+}
@@ -1,21 +1,119 @@
 //! Menu screens
 
 use bevy::prelude::*;
-
-use super::{centered_text, text_style, GameOverUi, MainMenuUi, PauseMenuUi, StateUi, VictoryUi};
+use bevy::window::PrimaryWindow;
+
+use super::{
+    centered_text, narrative_wrap_width, text_style, window_width, GameOverUi, HudSettings, MainMenuUi,
+    PauseMenuUi, StateUi, VictoryUi,
+};
+use crate::assists::AimAssistSettings;
+use crate::last_stand::LastStandSettings;
+use crate::rumble::RumbleSettings;
 use crate::audio::{PlaySoundEvent, SoundEffect};
-use crate::quests::database::QuestId;
+use crate::profile::PlayerProfile;
 use crate::quests::systems::{ActiveQuest, QuestProgress};
-use crate::rush::RushState;
-use crate::states::GameState;
+use crate::quests::{QuestCompletionLog, QuestDatabase};
+use crate::run_context::{run_banner_string, RunContext};
+use crate::rush::{RushLoadout, RushLoadouts, RushState, UnlockProgress, RUSH_ROUND_DURATION};
+use crate::states::{GameMode, GameState};
 use crate::survival::SurvivalState;
 
 /// Marker for stats text on end screens
 #[derive(Component)]
 pub struct EndScreenStats;
 
+/// Marker for the run settings banner at the bottom of end screens
+#[derive(Component)]
+pub struct RunBannerText;
+
+/// Marker for the aim assist line on the main menu
+#[derive(Component)]
+pub struct AimAssistMenuText;
+
+/// Marker for the last-stand push option on the main menu
+#[derive(Component)]
+pub struct LastStandMenuText;
+
+/// Marker for the rumble intensity option on the main menu
+#[derive(Component)]
+pub struct RumbleMenuText;
+
+/// Marker for the HUD layout option on the main menu
+#[derive(Component)]
+pub struct HudLayoutMenuText;
+
+/// Marker for the rush loadout line on the main menu
+#[derive(Component)]
+pub struct RushLoadoutMenuText;
+
+/// Rush loadout highlighted on the main menu (index into [`RushLoadouts`])
+#[derive(Resource, Debug, Default)]
+pub struct RushLoadoutSelection {
+    pub index: usize,
+}
+
+fn rush_loadout_label(loadout: &RushLoadout, progress: &UnlockProgress, best: Option<u32>) -> String {
+    let perks: Vec<String> = loadout.perks.iter().map(|p| format!("{:?}", p)).collect();
+    let mut label = format!(
+        "[<- / ->] Rush loadout: {} ({:?} + {})",
+        loadout.name,
+        loadout.weapon,
+        perks.join(", ")
+    );
+    if let Some(reason) = loadout.lock_reason(progress) {
+        label.push_str(&format!("   LOCKED: {}", reason));
+    } else if let Some(best) = best {
+        label.push_str(&format!("   Best: {}", best));
+    }
+    label
+}
+
+fn last_stand_label(settings: &LastStandSettings) -> String {
+    format!(
+        "[L] Last-stand push: {}",
+        if settings.enabled { "on" } else { "off" }
+    )
+}
+
+fn rumble_label(settings: &RumbleSettings) -> String {
+    if settings.intensity <= 0.0 {
+        "[- / =] Rumble: off".into()
+    } else {
+        format!("[- / =] Rumble: {:.0}%", settings.intensity * 100.0)
+    }
+}
+
+fn hud_layout_label(settings: &HudSettings) -> String {
+    format!("[H] HUD layout: {}", settings.layout.name())
+}
+
+fn aim_assist_label(settings: &AimAssistSettings) -> String {
+    format!(
+        "[ / ] Aim assist: {}%   [T] Sticky target: {}",
+        settings.strength_percent,
+        if settings.sticky_target { "on" } else { "off" }
+    )
+}
+
 /// Sets up the main menu
-pub fn setup_main_menu(mut commands: Commands) {
+#[allow(clippy::too_many_arguments)]
+pub fn setup_main_menu(
+    mut commands: Commands,
+    assist_settings: Res<AimAssistSettings>,
+    last_stand_settings: Res<LastStandSettings>,
+    hud_settings: Res<HudSettings>,
+    rumble_settings: Res<RumbleSettings>,
+    selection: Res<RushLoadoutSelection>,
+    loadouts: Res<RushLoadouts>,
+    profile: Res<PlayerProfile>,
+    completion_log: Res<QuestCompletionLog>,
+    quest_db: Res<QuestDatabase>,
+) {
+    let loadout = loadouts.get(selection.index);
+    let progress = profile.unlock_progress(&completion_log, &quest_db);
+    let loadout_label = rush_loadout_label(loadout, &progress, profile.rush_best(&loadout.name));
+
     commands
         .spawn((
             MainMenuUi,
@@ -67,6 +165,11 @@ pub fn setup_main_menu(mut commands: Commands) {
                 text_style(24.0, Color::srgb(0.9, 0.7, 0.7)),
             ));
 
+            parent.spawn((
+                RushLoadoutMenuText,
+                TextBundle::from_section(loadout_label, text_style(18.0, Color::srgb(0.8, 0.6, 0.6))),
+            ));
+
             parent.spawn(NodeBundle {
                 style: Style {
                     height: Val::Px(20.0),
@@ -75,6 +178,38 @@ pub fn setup_main_menu(mut commands: Commands) {
                 ..default()
             });
 
+            parent.spawn((
+                AimAssistMenuText,
+                TextBundle::from_section(
+                    aim_assist_label(&assist_settings),
+                    text_style(18.0, Color::srgb(0.6, 0.6, 0.8)),
+                ),
+            ));
+
+            parent.spawn((
+                LastStandMenuText,
+                TextBundle::from_section(
+                    last_stand_label(&last_stand_settings),
+                    text_style(18.0, Color::srgb(0.6, 0.6, 0.8)),
+                ),
+            ));
+
+            parent.spawn((
+                HudLayoutMenuText,
+                TextBundle::from_section(
+                    hud_layout_label(&hud_settings),
+                    text_style(18.0, Color::srgb(0.6, 0.6, 0.8)),
+                ),
+            ));
+
+            parent.spawn((
+                RumbleMenuText,
+                TextBundle::from_section(
+                    rumble_label(&rumble_settings),
+                    text_style(18.0, Color::srgb(0.6, 0.6, 0.8)),
+                ),
+            ));
+
             parent.spawn(TextBundle::from_section(
                 "[ESC] Quit",
                 text_style(20.0, Color::srgb(0.5, 0.5, 0.5)),
@@ -104,21 +239,20 @@ pub fn cleanup_main_menu(mut commands: Commands, query: Query<Entity, With<MainM
 
 /// Handles main menu input
 pub fn handle_main_menu_input(
-    mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut active_quest: ResMut<ActiveQuest>,
+    mut game_mode: ResMut<GameMode>,
     mut exit: EventWriter<AppExit>,
     mut sound_events: EventWriter<PlaySoundEvent>,
 ) {
     if keyboard.just_pressed(KeyCode::Enter) {
-        // Start quest mode with first quest using ActiveQuest::new
+        // Quest mode goes through the chapter select screen
         sound_events.send(PlaySoundEvent {
             sound: SoundEffect::MenuSelect,
             position: None,
         });
-        *active_quest = ActiveQuest::new(QuestId::Q01LandHostile);
-        next_state.set(GameState::Playing);
+        next_state.set(GameState::QuestSelect);
     }
 
     if keyboard.just_pressed(KeyCode::KeyS) {
@@ -128,10 +262,63 @@ pub fn handle_main_menu_input(
             position: None,
         });
         active_quest.quest_id = None;
+        *game_mode = GameMode::Survival;
         next_state.set(GameState::Playing);
     }
 
+    if keyboard.just_pressed(KeyCode::Escape) {
+        sound_events.send(PlaySoundEvent {
+            sound: SoundEffect::MenuBack,
+            position: None,
+        });
+        exit.send(AppExit::Success);
+    }
+}
+
+/// Cycles the rush loadout and starts rush mode with it (locked loadouts refuse to start)
+#[allow(clippy::too_many_arguments)]
+pub fn handle_rush_loadout_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut selection: ResMut<RushLoadoutSelection>,
+    loadouts: Res<RushLoadouts>,
+    profile: Res<PlayerProfile>,
+    completion_log: Res<QuestCompletionLog>,
+    quest_db: Res<QuestDatabase>,
+    mut active_quest: ResMut<ActiveQuest>,
+    mut game_mode: ResMut<GameMode>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    mut text_query: Query<&mut Text, With<RushLoadoutMenuText>>,
+) {
+    let count = loadouts.0.len();
+    if keyboard.just_pressed(KeyCode::ArrowRight) {
+        selection.index = (selection.index + 1) % count;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        selection.index = (selection.index + count - 1) % count;
+    }
+
+    let loadout = loadouts.get(selection.index);
+    let progress = profile.unlock_progress(&completion_log, &quest_db);
+
+    if selection.is_changed() {
+        for mut text in text_query.iter_mut() {
+            text.sections[0].value =
+                rush_loadout_label(loadout, &progress, profile.rush_best(&loadout.name));
+        }
+    }
+
     if keyboard.just_pressed(KeyCode::KeyR) {
+        if let Some(reason) = loadout.lock_reason(&progress) {
+            info!("Rush loadout {} is locked: {}", loadout.name, reason);
+            sound_events.send(PlaySoundEvent {
+                sound: SoundEffect::MenuBack,
+                position: None,
+            });
+            return;
+        }
+
         // Rush mode - 2 minute timed challenge
         sound_events.send(PlaySoundEvent {
             sound: SoundEffect::MenuSelect,
@@ -139,25 +326,83 @@ pub fn handle_main_menu_input(
         });
         active_quest.quest_id = None;
 
-        // Select a loadout from available_loadouts (use first one for now)
-        // In a full implementation, this would go to a loadout selection screen
-        let loadouts = crate::rush::available_loadouts();
-        let selected_loadout = loadouts.into_iter().next().unwrap_or_default();
-
-        // Log the loadout selection
         info!("Starting Rush mode with loadout: {} (weapon: {:?}, perks: {:?})",
-            selected_loadout.name, selected_loadout.weapon, selected_loadout.perks);
+            loadout.name, loadout.weapon, loadout.perks);
 
-        commands.insert_resource(RushState::new(120.0, selected_loadout));
+        commands.insert_resource(RushState::new(RUSH_ROUND_DURATION, loadout.clone()));
+        *game_mode = GameMode::Rush;
         next_state.set(GameState::Playing);
     }
+}
 
-    if keyboard.just_pressed(KeyCode::Escape) {
-        sound_events.send(PlaySoundEvent {
-            sound: SoundEffect::MenuBack,
-            position: None,
-        });
-        exit.send(AppExit::Success);
+/// Adjusts aim assist settings from the main menu
+pub fn handle_aim_assist_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<AimAssistSettings>,
+    mut text_query: Query<&mut Text, With<AimAssistMenuText>>,
+) {
+    const STRENGTH_STEP: i16 = 10;
+
+    if keyboard.just_pressed(KeyCode::BracketLeft) {
+        settings.adjust_strength(-STRENGTH_STEP);
+    }
+    if keyboard.just_pressed(KeyCode::BracketRight) {
+        settings.adjust_strength(STRENGTH_STEP);
+    }
+    if keyboard.just_pressed(KeyCode::KeyT) {
+        settings.sticky_target = !settings.sticky_target;
+    }
+
+    if settings.is_changed() {
+        for mut text in text_query.iter_mut() {
+            text.sections[0].value = aim_assist_label(&settings);
+        }
+    }
+}
+
+/// Toggles the last-stand push option from the main menu
+pub fn handle_last_stand_option_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<LastStandSettings>,
+    mut text_query: Query<&mut Text, With<LastStandMenuText>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyL) {
+        settings.enabled = !settings.enabled;
+        for mut text in text_query.iter_mut() {
+            text.sections[0].value = last_stand_label(&settings);
+        }
+    }
+}
+
+/// Cycles the HUD layout from the main menu
+pub fn handle_hud_layout_option_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<HudSettings>,
+    mut text_query: Query<&mut Text, With<HudLayoutMenuText>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyH) {
+        settings.layout = settings.layout.next();
+        for mut text in text_query.iter_mut() {
+            text.sections[0].value = hud_layout_label(&settings);
+        }
+    }
+}
+
+/// Steps the rumble intensity from the main menu
+pub fn handle_rumble_option_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<RumbleSettings>,
+    mut text_query: Query<&mut Text, With<RumbleMenuText>>,
+) {
+    if keyboard.just_pressed(KeyCode::Equal) {
+        settings.step_up();
+    } else if keyboard.just_pressed(KeyCode::Minus) {
+        settings.step_down();
+    } else {
+        return;
+    }
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = rumble_label(&settings);
     }
 }
 
@@ -236,6 +481,7 @@ pub fn setup_game_over(
     survival_state: Option<Res<SurvivalState>>,
     rush_state: Option<Res<RushState>>,
     quest_progress: Option<Res<QuestProgress>>,
+    run_context: Option<Res<RunContext>>,
 ) {
     // Gather stats from the current game mode
     let (time_str, kills_str, extra_str) = if let Some(ref rush) = rush_state {
@@ -337,9 +583,29 @@ pub fn setup_game_over(
                 "[ESC] Return to Menu",
                 text_style(20.0, Color::srgb(0.6, 0.6, 0.6)),
             ));
+
+            if let Some(ref context) = run_context {
+                spawn_run_banner(parent, context);
+            }
         });
 }
 
+/// Small footer with the run's mode and settings, for screenshots and bug reports
+fn spawn_run_banner(parent: &mut ChildBuilder, context: &RunContext) {
+    parent.spawn((
+        RunBannerText,
+        TextBundle::from_section(
+            run_banner_string(context),
+            text_style(14.0, Color::srgb(0.5, 0.5, 0.5)),
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(12.0),
+            ..default()
+        }),
+    ));
+}
+
 /// Cleans up the game over screen
 pub fn cleanup_game_over(mut commands: Commands, query: Query<Entity, With<GameOverUi>>) {
     for entity in query.iter() {
@@ -349,7 +615,11 @@ pub fn cleanup_game_over(mut commands: Commands, query: Query<Entity, With<GameO
 
 /// Handles game over input
 pub fn handle_game_over_input(
+    mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
+    game_mode: Res<GameMode>,
+    selection: Res<RushLoadoutSelection>,
+    loadouts: Res<RushLoadouts>,
     mut next_state: ResMut<NextState<GameState>>,
     mut sound_events: EventWriter<PlaySoundEvent>,
 ) {
@@ -358,6 +628,11 @@ pub fn handle_game_over_input(
             sound: SoundEffect::MenuSelect,
             position: None,
         });
+        // The finished round took its RushState with it; a retry starts a fresh one
+        if *game_mode == GameMode::Rush {
+            let loadout = loadouts.get(selection.index).clone();
+            commands.insert_resource(RushState::new(RUSH_ROUND_DURATION, loadout));
+        }
         next_state.set(GameState::Playing);
     }
 
@@ -371,11 +646,25 @@ pub fn handle_game_over_input(
 }
 
 /// Sets up the victory screen
+#[allow(clippy::too_many_arguments)]
 pub fn setup_victory(
     mut commands: Commands,
     quest_progress: Option<Res<QuestProgress>>,
     rush_state: Option<Res<RushState>>,
+    run_context: Option<Res<RunContext>>,
+    active_quest: Option<Res<ActiveQuest>>,
+    quest_db: Option<Res<QuestDatabase>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
 ) {
+    // Quest epilogue, shown between the title and the stats
+    let outro = match (&rush_state, &active_quest, &quest_db) {
+        (None, Some(active), Some(db)) => active
+            .quest_id
+            .and_then(|id| db.get(id))
+            .and_then(|quest| quest.outro_text.clone()),
+        _ => None,
+    };
+
     // Gather stats
     let (title, time_str, kills_str, extra_str) = if let Some(ref rush) = rush_state {
         let mins = rush.round_duration as u32 / 60;
@@ -433,6 +722,18 @@ pub fn setup_victory(
                 ..default()
             });
 
+            if let Some(outro) = outro {
+                parent.spawn(
+                    TextBundle::from_section(outro, text_style(22.0, Color::srgb(0.85, 0.95, 0.85)))
+                        .with_text_justify(JustifyText::Center)
+                        .with_style(Style {
+                            max_width: Val::Px(narrative_wrap_width(window_width(&windows))),
+                            margin: UiRect::bottom(Val::Px(24.0)),
+                            ..default()
+                        }),
+                );
+            }
+
             // Stats
             if !time_str.is_empty() {
                 parent.spawn((
@@ -473,6 +774,10 @@ pub fn setup_victory(
                 "[ESC] Return to Menu",
                 text_style(20.0, Color::srgb(0.6, 0.6, 0.6)),
             ));
+
+            if let Some(ref context) = run_context {
+                spawn_run_banner(parent, context);
+            }
         });
 }
 
@@ -515,4 +820,42 @@ mod tests {
     fn main_menu_ui_is_component() {
         let _ui = MainMenuUi;
     }
+
+    #[test]
+    fn loadout_label_shows_lock_reason_or_best_score() {
+        let loadouts = RushLoadouts::default();
+        let poisoner = loadouts.0.iter().find(|l| l.name == "Poisoner").unwrap();
+        let progress = UnlockProgress::default();
+        assert!(rush_loadout_label(poisoner, &progress, Some(900)).ends_with("LOCKED: Complete chapter 2"));
+
+        let unlocked = UnlockProgress {
+            completed_chapters: vec![2],
+            ..default()
+        };
+        assert!(rush_loadout_label(poisoner, &unlocked, Some(900)).ends_with("Best: 900"));
+        assert!(!rush_loadout_label(poisoner, &unlocked, None).contains("Best"));
+    }
+
+    #[test]
+    fn rush_retry_restarts_with_the_same_loadout() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(GameState::GameOver)
+            .insert_resource(GameMode::Rush)
+            .insert_resource(RushLoadoutSelection { index: 1 })
+            .init_resource::<RushLoadouts>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_event::<PlaySoundEvent>()
+            .add_systems(Update, handle_game_over_input);
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Enter);
+        app.update();
+
+        let rush = app.world().get_resource::<RushState>().expect("retry starts a new round");
+        assert_eq!(rush.loadout.name, RushLoadouts::default().0[1].name);
+        assert_eq!(rush.time_remaining, RUSH_ROUND_DURATION);
+        assert!(matches!(
+            app.world().resource::<NextState<GameState>>(),
+            NextState::Pending(GameState::Playing)
+        ));
+    }
 }
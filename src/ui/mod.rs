@@ -3,15 +3,30 @@
 //! Handles all user interface elements: menus, HUD, and overlays.
 
 mod hud;
+mod hud_banner;
+mod hud_layout;
+mod inspect;
 mod menus;
 mod perk_select;
+mod pickup_labels;
+mod quest_select;
+mod typewriter;
+mod wave_transition;
 
 pub use hud::*;
+pub use hud_banner::*;
+pub use hud_layout::*;
+pub use inspect::*;
 pub use menus::*;
 pub use perk_select::*;
+pub use pickup_labels::*;
+pub use quest_select::*;
+pub use typewriter::*;
+pub use wave_transition::*;
 
 use bevy::prelude::*;
 
+use crate::schedule::GameSet;
 use crate::states::{GameState, PlayingState};
 
 /// Plugin for UI functionality
@@ -19,29 +34,90 @@ pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
-        app
+        app.init_resource::<RushLoadoutSelection>()
+            .init_resource::<HudSettings>()
+            .init_resource::<HudBanner>()
+            .init_resource::<OutOfAmmoWarning>()
+            .init_resource::<AmmoRefillFlash>()
+            .add_systems(Startup, load_hud_settings)
+            .add_systems(Update, save_hud_settings)
             // Main menu
             .add_systems(OnEnter(GameState::MainMenu), setup_main_menu)
             .add_systems(OnExit(GameState::MainMenu), cleanup_main_menu)
             .add_systems(
                 Update,
-                handle_main_menu_input.run_if(in_state(GameState::MainMenu)),
+                (
+                    handle_main_menu_input,
+                    handle_rush_loadout_input,
+                    handle_aim_assist_input,
+                    handle_last_stand_option_input,
+                    handle_hud_layout_option_input,
+                    handle_rumble_option_input,
+                )
+                    .run_if(in_state(GameState::MainMenu)),
+            )
+            // Quest select
+            .add_systems(OnEnter(GameState::QuestSelect), setup_quest_select)
+            .add_systems(OnExit(GameState::QuestSelect), cleanup_quest_select)
+            .add_systems(
+                Update,
+                handle_quest_select_input.run_if(in_state(GameState::QuestSelect)),
             )
             // HUD
-            .add_systems(OnEnter(GameState::Playing), setup_hud)
-            .add_systems(OnExit(GameState::Playing), (cleanup_hud, cleanup_creature_health_bars))
+            .add_systems(
+                OnEnter(GameState::Playing),
+                (setup_hud, reset_hud_banner, reset_out_of_ammo_warning),
+            )
+            .add_systems(
+                OnExit(GameState::Playing),
+                (
+                    cleanup_hud,
+                    cleanup_creature_health_bars,
+                    cleanup_boss_health_bars,
+                    cleanup_inspect_panel,
+                ),
+            )
+            .add_systems(OnExit(PlayingState::BossEncounter), cleanup_boss_health_bars)
             .add_systems(
                 Update,
                 (
+                    track_out_of_ammo_warning.before(update_hud),
+                    track_ammo_refill_flash.before(update_hud),
                     update_hud,
+                    update_hud_health_bar_color,
                     update_hud_perks,
+                    update_hud_perk_badges,
+                    update_hud_active_effects,
                     update_hud_game_mode,
+                    update_hud_last_stand,
+                    update_hud_stowed_weapon,
+                    update_hud_heat_bar,
                     spawn_creature_health_bars,
                     update_creature_health_bars,
                     cleanup_creature_health_bars,
+                    update_boss_health_bars,
+                    update_pickup_labels,
+                    update_inspect_panel,
+                )
+                    .in_set(GameSet::Presentation)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    handle_hud_layout_input,
+                    rebuild_hud_on_layout_change,
+                    queue_hud_banners,
+                    update_hud_banner,
                 )
+                    .chain()
+                    .in_set(GameSet::Presentation)
                     .run_if(in_state(GameState::Playing)),
             )
+            // Wave transition overlay
+            .add_systems(OnEnter(PlayingState::WaveTransition), setup_wave_transition)
+            .add_systems(OnExit(PlayingState::WaveTransition), cleanup_wave_transition)
+            .add_systems(Update, update_typewriters)
             // Pause menu
             .add_systems(OnEnter(GameState::Paused), setup_pause_menu)
             .add_systems(OnExit(GameState::Paused), cleanup_pause_menu)
@@ -54,7 +130,9 @@ impl Plugin for UiPlugin {
             .add_systems(OnExit(PlayingState::PerkSelect), cleanup_perk_select)
             .add_systems(
                 Update,
-                handle_perk_select_input.run_if(in_state(PlayingState::PerkSelect)),
+                (handle_perk_reroll_input, handle_perk_select_input)
+                    .chain()
+                    .run_if(in_state(PlayingState::PerkSelect)),
             )
             // Game over
             .add_systems(OnEnter(GameState::GameOver), setup_game_over)
@@ -2,8 +2,11 @@
 
 use bevy::prelude::*;
 
-use crate::perks::{PerkBonuses, PerkId, PerkInventory, PerkData, PerkRegistry, PerkSelectedEvent};
-use crate::player::Player;
+use crate::audio::{PlaySoundEvent, SoundEffect};
+use crate::perks::{
+    PerkBonuses, PerkId, PerkInventory, PerkKey, PerkOffer, PerkRegistry, PerkSelectedEvent,
+};
+use crate::player::{Experience, Player};
 use crate::states::PlayingState;
 
 /// Gets perks the player already has for display
@@ -15,6 +18,48 @@ pub fn get_player_perks(inventory: &PerkInventory) -> Vec<PerkId> {
         .collect()
 }
 
+/// Keys picking the perk choices in order; also caps how many are offered
+const PERK_SELECT_KEYS: [KeyCode; 7] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+];
+
+/// Rerolls the offered perks
+const PERK_REROLL_KEY: KeyCode = KeyCode::KeyR;
+/// Rerolls allowed per selection
+pub const PERK_REROLLS: u32 = 2;
+/// Share of the XP needed for the next level a reroll costs
+pub const PERK_REROLL_COST_FRACTION: f32 = 0.25;
+
+/// XP a reroll costs at the player's current level
+pub fn perk_reroll_cost(exp: &Experience) -> u32 {
+    (exp.to_next_level as f32 * PERK_REROLL_COST_FRACTION).round() as u32
+}
+
+/// Reroll button text: the key, cost and rerolls left, or why it can't be used
+fn perk_reroll_label(rerolls_left: u32, cost: u32, affordable: bool) -> String {
+    if rerolls_left == 0 {
+        "No rerolls left".into()
+    } else if !affordable {
+        format!("Reroll needs {} XP", cost)
+    } else {
+        format!("R: Reroll for {} XP ({} left)", cost, rerolls_left)
+    }
+}
+
+fn perk_reroll_color(usable: bool) -> Color {
+    if usable {
+        Color::srgb(0.25, 0.2, 0.35)
+    } else {
+        Color::srgb(0.12, 0.12, 0.12)
+    }
+}
+
 /// Marker for perk selection UI
 #[derive(Component)]
 pub struct PerkSelectUi;
@@ -22,31 +67,56 @@ pub struct PerkSelectUi;
 /// Marker for individual perk buttons
 #[derive(Component)]
 pub struct PerkButton {
-    pub perk_id: PerkId,
+    pub perk: PerkKey,
     pub index: usize,
 }
 
+/// Holds the perk buttons, so a reroll can swap them out
+#[derive(Component)]
+pub struct PerkButtonList;
+
+/// Reroll button
+#[derive(Component)]
+pub struct PerkRerollButton;
+
+/// Text on the reroll button
+#[derive(Component)]
+pub struct PerkRerollText;
+
 /// Resource to track current perk selection
 #[derive(Resource, Default, Clone)]
 pub struct PerkSelectionState {
-    pub available_perks: Vec<PerkId>,
+    pub available_perks: Vec<PerkKey>,
     pub selected_index: usize,
+    pub rerolls_left: u32,
 }
 
 /// Sets up the perk selection screen
 pub fn setup_perk_select(
     mut commands: Commands,
     perk_registry: Res<PerkRegistry>,
-    player_query: Query<&PerkInventory, With<Player>>,
+    player_query: Query<(&PerkInventory, &PerkBonuses, &Experience), With<Player>>,
     mut selection_state: Local<PerkSelectionState>,
 ) {
-    // Get random perks to choose from
-    let perks = perk_registry.get_random_selection(4);
-    selection_state.available_perks = perks.iter().map(|p| p.id).collect();
-    selection_state.selected_index = 0;
-
     // Get player's current perks
-    let player_inventory = player_query.get_single().ok();
+    let player_inventory = player_query.get_single().ok().map(|(inventory, _, _)| inventory);
+
+    // Get random perks to choose from; PerkExpert and PerkMaster widen the choice
+    let choices = player_query
+        .get_single()
+        .map_or(PerkBonuses::default().perk_choices, |(_, bonuses, _)| bonuses.perk_choices)
+        .min(PERK_SELECT_KEYS.len());
+    let perks = perk_registry.get_random_selection(
+        player_inventory.unwrap_or(&PerkInventory::new()),
+        choices,
+        &mut rand::thread_rng(),
+    );
+    selection_state.available_perks = perks.iter().map(|p| p.key.clone()).collect();
+    selection_state.selected_index = 0;
+    selection_state.rerolls_left = PERK_REROLLS;
+    let (reroll_cost, reroll_affordable) = player_query
+        .get_single()
+        .map_or((0, false), |(_, _, exp)| (perk_reroll_cost(exp), exp.current >= perk_reroll_cost(exp)));
 
     commands
         .spawn((
@@ -84,16 +154,49 @@ pub fn setup_perk_select(
             });
 
             // Perk buttons
-            for (i, perk_data) in perks.iter().enumerate() {
-                // Get current level for this perk using PerkRegistry.get()
-                let current_level = player_inventory
-                    .map(|inv| inv.get_count(perk_data.id))
-                    .unwrap_or(0);
-
-                // Verify perk data using PerkRegistry.get() for consistency
-                let verified_perk = perk_registry.get(perk_data.id).unwrap_or(perk_data);
-                spawn_perk_button(parent, verified_perk, i, current_level);
-            }
+            parent
+                .spawn((
+                    PerkButtonList,
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        ..default()
+                    },
+                ))
+                .with_children(|parent| {
+                    spawn_perk_buttons(parent, &perks, player_inventory);
+                });
+
+            // Reroll, greyed out once it can't be used
+            parent
+                .spawn((
+                    PerkRerollButton,
+                    ButtonBundle {
+                        style: Style {
+                            margin: UiRect::top(Val::Px(10.0)),
+                            padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                            ..default()
+                        },
+                        background_color: BackgroundColor(perk_reroll_color(reroll_affordable)),
+                        ..default()
+                    },
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        PerkRerollText,
+                        TextBundle::from_section(
+                            perk_reroll_label(PERK_REROLLS, reroll_cost, reroll_affordable),
+                            TextStyle {
+                                font_size: 18.0,
+                                color: Color::srgb(0.8, 0.7, 1.0),
+                                ..default()
+                            },
+                        ),
+                    ));
+                });
 
             parent.spawn(NodeBundle {
                 style: Style {
@@ -118,7 +221,7 @@ pub fn setup_perk_select(
 
             // Instructions
             parent.spawn(TextBundle::from_section(
-                "Press 1-4 or click to select",
+                format!("Press 1-{} or click to select", perks.len()),
                 TextStyle {
                     font_size: 18.0,
                     color: Color::srgb(0.5, 0.5, 0.5),
@@ -130,7 +233,16 @@ pub fn setup_perk_select(
     commands.insert_resource(selection_state.clone());
 }
 
-fn spawn_perk_button(parent: &mut ChildBuilder, perk: &PerkData, index: usize, current_level: u8) {
+/// Spawns a button for each offered perk
+fn spawn_perk_buttons(parent: &mut ChildBuilder, perks: &[PerkOffer], inventory: Option<&PerkInventory>) {
+    for (i, offer) in perks.iter().enumerate() {
+        let current_level = inventory.map(|inv| inv.count(&offer.key)).unwrap_or(0);
+        spawn_perk_button(parent, offer, i, current_level, perks.len() > 4);
+    }
+}
+
+/// Spawns one perk choice; `compact` shortens it to fit the longer PerkExpert/PerkMaster lists
+fn spawn_perk_button(parent: &mut ChildBuilder, perk: &PerkOffer, index: usize, current_level: u8, compact: bool) {
     // Highlight color if player already has this perk
     let bg_color = if current_level > 0 {
         Color::srgb(0.2, 0.25, 0.2) // Slightly green tint
@@ -141,13 +253,13 @@ fn spawn_perk_button(parent: &mut ChildBuilder, perk: &PerkData, index: usize, c
     parent
         .spawn((
             PerkButton {
-                perk_id: perk.id,
+                perk: perk.key.clone(),
                 index,
             },
             ButtonBundle {
                 style: Style {
                     width: Val::Px(400.0),
-                    height: Val::Px(80.0),
+                    height: Val::Px(if compact { 64.0 } else { 80.0 }),
                     margin: UiRect::all(Val::Px(5.0)),
                     padding: UiRect::all(Val::Px(10.0)),
                     flex_direction: FlexDirection::Column,
@@ -198,9 +310,66 @@ pub fn cleanup_perk_select(
     commands.remove_resource::<PerkSelectionState>();
 }
 
+/// Rerolls the offered perks on R or a click of the reroll button, for a share of the
+/// XP toward the next level. Limited to PERK_REROLLS per selection
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn handle_perk_reroll_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    perk_registry: Res<PerkRegistry>,
+    selection_state: Option<ResMut<PerkSelectionState>>,
+    mut player_query: Query<(&PerkInventory, &mut Experience), With<Player>>,
+    clicks: Query<&Interaction, (With<PerkRerollButton>, Changed<Interaction>)>,
+    mut reroll_button: Query<&mut BackgroundColor, With<PerkRerollButton>>,
+    list_query: Query<Entity, With<PerkButtonList>>,
+    mut text_query: Query<&mut Text, With<PerkRerollText>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let (Some(mut selection_state), Ok((inventory, mut exp))) = (selection_state, player_query.get_single_mut())
+    else {
+        return;
+    };
+    // Only the frame the press starts, so holding the button rerolls once
+    let clicked = clicks.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if !keyboard.just_pressed(PERK_REROLL_KEY) && !clicked {
+        return;
+    }
+    let cost = perk_reroll_cost(&exp);
+    if selection_state.rerolls_left == 0 || !exp.spend(cost) {
+        return;
+    }
+    selection_state.rerolls_left -= 1;
+
+    let perks = perk_registry.get_random_selection(
+        inventory,
+        selection_state.available_perks.len(),
+        &mut rand::thread_rng(),
+    );
+    selection_state.available_perks = perks.iter().map(|p| p.key.clone()).collect();
+    selection_state.selected_index = 0;
+    for list in list_query.iter() {
+        commands.entity(list).despawn_descendants().with_children(|parent| {
+            spawn_perk_buttons(parent, &perks, Some(inventory));
+        });
+    }
+
+    let usable = selection_state.rerolls_left > 0 && exp.current >= cost;
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = perk_reroll_label(selection_state.rerolls_left, cost, exp.current >= cost);
+    }
+    for mut color in reroll_button.iter_mut() {
+        color.0 = perk_reroll_color(usable);
+    }
+    sound_events.send(PlaySoundEvent {
+        sound: SoundEffect::MenuSelect,
+        position: None,
+    });
+}
+
 /// Handles perk selection input
 pub fn handle_perk_select_input(
     keyboard: Res<ButtonInput<KeyCode>>,
+    perk_registry: Res<PerkRegistry>,
     selection_state: Option<Res<PerkSelectionState>>,
     mut player_query: Query<(Entity, &mut PerkInventory, &mut PerkBonuses), With<Player>>,
     button_query: Query<(&Interaction, &PerkButton), Changed<Interaction>>,
@@ -216,28 +385,18 @@ pub fn handle_perk_select_input(
     };
 
     // Number key selection
-    let selected = if keyboard.just_pressed(KeyCode::Digit1) {
-        Some(0)
-    } else if keyboard.just_pressed(KeyCode::Digit2) {
-        Some(1)
-    } else if keyboard.just_pressed(KeyCode::Digit3) {
-        Some(2)
-    } else if keyboard.just_pressed(KeyCode::Digit4) {
-        Some(3)
-    } else {
-        None
-    };
+    let selected = PERK_SELECT_KEYS.iter().position(|&key| keyboard.just_pressed(key));
 
     if let Some(index) = selected {
-        if let Some(&perk_id) = selection_state.available_perks.get(index) {
+        if let Some(perk) = selection_state.available_perks.get(index) {
             // Apply perk directly to avoid event timing issues
-            inventory.add_perk(perk_id);
-            *bonuses = PerkBonuses::calculate(&inventory);
-            info!("Perk {:?} applied to player", perk_id);
+            inventory.add(perk);
+            *bonuses = perk_registry.bonuses_for(&inventory);
+            info!("Perk {:?} applied to player", perk);
 
             perk_events.send(PerkSelectedEvent {
                 player_entity,
-                perk_id,
+                perk: perk.clone(),
             });
             next_state.set(PlayingState::Active);
             return;
@@ -250,13 +409,13 @@ pub fn handle_perk_select_input(
             info!("Perk {} selected via mouse click", button.index + 1);
 
             // Apply perk directly to avoid event timing issues
-            inventory.add_perk(button.perk_id);
-            *bonuses = PerkBonuses::calculate(&inventory);
-            info!("Perk {:?} applied to player", button.perk_id);
+            inventory.add(&button.perk);
+            *bonuses = perk_registry.bonuses_for(&inventory);
+            info!("Perk {:?} applied to player", button.perk);
 
             perk_events.send(PerkSelectedEvent {
                 player_entity,
-                perk_id: button.perk_id,
+                perk: button.perk.clone(),
             });
             next_state.set(PlayingState::Active);
             return;
@@ -278,7 +437,7 @@ mod tests {
     #[test]
     fn perk_button_stores_data() {
         let button = PerkButton {
-            perk_id: PerkId::Regeneration,
+            perk: PerkId::Regeneration.into(),
             index: 2,
         };
         assert_eq!(button.index, 2);
@@ -288,12 +447,12 @@ mod tests {
     fn get_player_perks_returns_owned_perks() {
         let mut inventory = PerkInventory::new();
         inventory.add_perk(PerkId::Regeneration);
-        inventory.add_perk(PerkId::SpeedBoost);
+        inventory.add_perk(PerkId::LongDistanceRunner);
 
         let owned = get_player_perks(&inventory);
         assert!(owned.contains(&PerkId::Regeneration));
-        assert!(owned.contains(&PerkId::SpeedBoost));
-        assert!(!owned.contains(&PerkId::CriticalHit));
+        assert!(owned.contains(&PerkId::LongDistanceRunner));
+        assert!(!owned.contains(&PerkId::Sharpshooter));
     }
 
     #[test]
@@ -303,4 +462,114 @@ mod tests {
         assert!(perk.is_some());
         assert_eq!(perk.unwrap().id, PerkId::Regeneration);
     }
+
+    #[test]
+    fn reroll_spends_xp_and_runs_out() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<PerkRegistry>()
+            .add_event::<PlaySoundEvent>()
+            .insert_resource(PerkSelectionState {
+                available_perks: vec![PerkId::Regeneration.into(); 3],
+                selected_index: 0,
+                rerolls_left: PERK_REROLLS,
+            })
+            .add_systems(Update, handle_perk_reroll_input);
+        app.world_mut().spawn((PerkButtonList, NodeBundle::default()));
+        let mut exp = Experience::new();
+        exp.current = 99;
+        let player = app.world_mut().spawn((Player::default(), PerkInventory::new(), exp)).id();
+        let reroll = |app: &mut App| {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release(PERK_REROLL_KEY);
+            keyboard.clear();
+            keyboard.press(PERK_REROLL_KEY);
+            app.update();
+            let state = app.world().resource::<PerkSelectionState>();
+            (app.world().get::<Experience>(player).unwrap().current, state.rerolls_left)
+        };
+
+        // 25% of the 100 XP to the next level each
+        assert_eq!(reroll(&mut app), (74, 1));
+        assert_eq!(app.world().resource::<PerkSelectionState>().available_perks.len(), 3);
+        let sounds = app.world().resource::<Events<PlaySoundEvent>>();
+        assert_eq!(sounds.get_reader().read(sounds).count(), 1);
+        assert_eq!(reroll(&mut app), (49, 0));
+        assert_eq!(reroll(&mut app), (49, 0));
+
+        // Not enough XP
+        app.world_mut().resource_mut::<PerkSelectionState>().rerolls_left = 1;
+        app.world_mut().get_mut::<Experience>(player).unwrap().current = 10;
+        assert_eq!(reroll(&mut app), (10, 1));
+    }
+
+    #[test]
+    fn holding_the_reroll_button_rerolls_once() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<PerkRegistry>()
+            .add_event::<PlaySoundEvent>()
+            .insert_resource(PerkSelectionState {
+                available_perks: vec![PerkId::Regeneration.into(); 3],
+                selected_index: 0,
+                rerolls_left: PERK_REROLLS,
+            })
+            .add_systems(Update, handle_perk_reroll_input);
+        app.world_mut().spawn((PerkButtonList, NodeBundle::default()));
+        let mut exp = Experience::new();
+        exp.current = 99;
+        app.world_mut().spawn((Player::default(), PerkInventory::new(), exp));
+        app.world_mut()
+            .spawn((PerkRerollButton, Interaction::Pressed, BackgroundColor::default()));
+
+        // Pressed on the first frame and still held on the second
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<PerkSelectionState>().rerolls_left, PERK_REROLLS - 1);
+    }
+
+    #[test]
+    fn rerolling_with_the_keyboard_does_not_reload() {
+        use bevy::state::app::StatesPlugin;
+
+        use crate::player::components::Firing;
+        use crate::player::resources::PlayerInputMapping;
+        use crate::player::systems::player_shooting;
+        use crate::schedule::{GameSchedulePlugin, GameSet};
+        use crate::states::{GameState, PlayingState};
+        use crate::weapons::{EquippedWeapon, WeaponId, WeaponRegistry};
+
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, GameSchedulePlugin))
+            .insert_state(GameState::Playing)
+            .add_sub_state::<PlayingState>()
+            .insert_resource(Time::<()>::default())
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<PlayerInputMapping>()
+            .init_resource::<WeaponRegistry>()
+            .init_resource::<PerkRegistry>()
+            .add_event::<PlaySoundEvent>()
+            .insert_resource(PerkSelectionState {
+                available_perks: vec![PerkId::Regeneration.into(); 3],
+                selected_index: 0,
+                rerolls_left: PERK_REROLLS,
+            })
+            .add_systems(Update, (player_shooting.in_set(GameSet::Input), handle_perk_reroll_input));
+        let mut exp = Experience::new();
+        exp.current = 99;
+        let mut weapon = EquippedWeapon::new(WeaponId::Shotgun, Some(12));
+        weapon.consume_ammo();
+        let player = app
+            .world_mut()
+            .spawn((Player::default(), PerkInventory::new(), exp, Firing::default(), weapon))
+            .id();
+        app.world_mut().resource_mut::<NextState<PlayingState>>().set(PlayingState::PerkSelect);
+        app.update();
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(PERK_REROLL_KEY);
+        app.update();
+        assert_eq!(app.world().resource::<PerkSelectionState>().rerolls_left, PERK_REROLLS - 1);
+        assert!(!app.world().get::<EquippedWeapon>(player).unwrap().is_reloading());
+    }
 }
@@ -0,0 +1,313 @@
+//! In-world pickup labels
+//!
+//! Floating name tags over the weapon drops and items closest to the player,
+//! so a drop can be read before walking over it.
+
+use bevy::prelude::*;
+
+use crate::bonuses::WeaponDrop;
+use crate::items::ItemPickup;
+use crate::player::Player;
+use crate::render_layers::{child_offset, PICKUPS, WORLD_TEXT};
+use crate::states::{gameplay_frozen, BossEncounterState, PlayingState};
+use crate::weapons::{WeaponData, WeaponRegistry};
+
+/// Labels show for pickups within this distance of the player
+pub const LABEL_RADIUS: f32 = 80.0;
+/// Labels are fully opaque up to this distance and fade out beyond it
+pub const LABEL_FADE_START: f32 = 40.0;
+/// Most labels shown at once
+pub const MAX_LABELS: usize = 3;
+
+/// Fire rate (shots per second) at or below which a weapon is tagged SLOW
+const SLOW_FIRE_RATE: f32 = 2.0;
+/// Fire rate at or above which a weapon is tagged FAST
+const FAST_FIRE_RATE: f32 = 10.0;
+
+/// Floating label attached (as a child) to a pickup
+#[derive(Component, Debug)]
+pub struct PickupLabel {
+    pub pickup: Entity,
+}
+
+/// The `max` pickups nearest to `player` within `radius`, nearest first
+pub fn nearest_pickups(
+    player: Vec2,
+    pickups: &[(Entity, Vec2)],
+    radius: f32,
+    max: usize,
+) -> Vec<(Entity, f32)> {
+    let mut in_range: Vec<(Entity, f32)> = pickups
+        .iter()
+        .map(|&(entity, position)| (entity, player.distance(position)))
+        .filter(|&(_, distance)| distance <= radius)
+        .collect();
+    in_range.sort_by(|a, b| a.1.total_cmp(&b.1));
+    in_range.truncate(max);
+    in_range
+}
+
+/// Label opacity for a pickup at `distance`: 1 up close, falling linearly to 0 at the label radius
+pub fn label_alpha(distance: f32) -> f32 {
+    if distance <= LABEL_FADE_START {
+        1.0
+    } else {
+        (1.0 - (distance - LABEL_FADE_START) / (LABEL_RADIUS - LABEL_FADE_START)).clamp(0.0, 1.0)
+    }
+}
+
+/// Short stat line for a weapon drop, e.g. "DMG 80 | SLOW | AOE"
+pub fn weapon_stat_hint(weapon: &WeaponData) -> String {
+    let mut parts = vec![format!(
+        "DMG {:.0}",
+        weapon.damage * weapon.projectiles_per_shot as f32
+    )];
+    if weapon.fire_rate <= SLOW_FIRE_RATE {
+        parts.push("SLOW".into());
+    } else if weapon.fire_rate >= FAST_FIRE_RATE {
+        parts.push("FAST".into());
+    }
+    if weapon.pierce_count > 0 {
+        parts.push("PIERCE".into());
+    }
+    if weapon.is_explosive() {
+        parts.push("AOE".into());
+    }
+    if weapon.homing {
+        parts.push("HOMING".into());
+    }
+    parts.join(" | ")
+}
+
+/// Shows labels on the nearest pickups, fades them with distance and removes
+/// them when they fall out of range. Hidden entirely while gameplay is frozen.
+#[allow(clippy::too_many_arguments)]
+pub fn update_pickup_labels(
+    mut commands: Commands,
+    playing_state: Option<Res<State<PlayingState>>>,
+    boss_state: Option<Res<BossEncounterState>>,
+    player_query: Query<&Transform, With<Player>>,
+    weapon_drops: Query<(Entity, &Transform, &WeaponDrop)>,
+    items: Query<(Entity, &Transform, &ItemPickup)>,
+    mut labels: Query<(Entity, &PickupLabel, &mut Text)>,
+    weapon_registry: Res<WeaponRegistry>,
+) {
+    let player = player_query.get_single().ok().map(|t| t.translation.truncate());
+    let (Some(player), false) = (player, gameplay_frozen(playing_state, boss_state)) else {
+        for (entity, _, _) in labels.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let pickups: Vec<(Entity, Vec2)> = weapon_drops
+        .iter()
+        .map(|(entity, transform, _)| (entity, transform.translation.truncate()))
+        .chain(
+            items
+                .iter()
+                .map(|(entity, transform, _)| (entity, transform.translation.truncate())),
+        )
+        .collect();
+    let shown = nearest_pickups(player, &pickups, LABEL_RADIUS, MAX_LABELS);
+
+    // Fade labels that stay shown, drop the rest
+    let mut labelled = Vec::new();
+    for (entity, label, mut text) in labels.iter_mut() {
+        match shown.iter().find(|(pickup, _)| *pickup == label.pickup) {
+            Some(&(_, distance)) => {
+                let alpha = label_alpha(distance);
+                for section in &mut text.sections {
+                    section.style.color.set_alpha(alpha);
+                }
+                labelled.push(label.pickup);
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    for &(pickup, distance) in &shown {
+        if labelled.contains(&pickup) {
+            continue;
+        }
+        let (text, color) = if let Ok((_, _, drop)) = weapon_drops.get(pickup) {
+            match weapon_registry.get_key(&drop.weapon) {
+                Some(data) => (
+                    format!("{}\n{}", data.name, weapon_stat_hint(data)),
+                    Color::srgb(1.0, 0.8, 0.5),
+                ),
+                None => continue,
+            }
+        } else if let Ok((_, _, item)) = items.get(pickup) {
+            (item.item_type.name().to_string(), item.item_type.color())
+        } else {
+            continue;
+        };
+
+        let label = commands
+            .spawn((
+                PickupLabel { pickup },
+                Text2dBundle {
+                    text: Text::from_section(
+                        text,
+                        super::text_style(14.0, color.with_alpha(label_alpha(distance))),
+                    )
+                    .with_justify(JustifyText::Center),
+                    // Parent pickups sit on the pickup layer
+                    transform: Transform::from_translation(Vec3::new(
+                        0.0,
+                        28.0,
+                        child_offset(WORLD_TEXT, PICKUPS),
+                    )),
+                    ..default()
+                },
+            ))
+            .id();
+        commands.entity(pickup).add_child(label);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bonuses::{Bonus, BonusType};
+    use crate::items::ItemType;
+    use crate::states::GameState;
+    use crate::weapons::WeaponId;
+
+    fn entities(count: u32) -> Vec<Entity> {
+        (0..count).map(Entity::from_raw).collect()
+    }
+
+    #[test]
+    fn nearest_pickups_are_capped_and_sorted() {
+        let e = entities(5);
+        let pickups = [
+            (e[0], Vec2::new(70.0, 0.0)),
+            (e[1], Vec2::new(0.0, 10.0)),
+            (e[2], Vec2::new(-50.0, 0.0)),
+            (e[3], Vec2::new(0.0, -30.0)),
+            (e[4], Vec2::new(200.0, 0.0)),
+        ];
+        let shown = nearest_pickups(Vec2::ZERO, &pickups, LABEL_RADIUS, MAX_LABELS);
+        let order: Vec<Entity> = shown.iter().map(|(entity, _)| *entity).collect();
+        assert_eq!(order, vec![e[1], e[3], e[2]]);
+    }
+
+    #[test]
+    fn nearest_pickups_ignores_out_of_range() {
+        let e = entities(2);
+        let pickups = [(e[0], Vec2::new(81.0, 0.0)), (e[1], Vec2::new(0.0, 80.0))];
+        let shown = nearest_pickups(Vec2::ZERO, &pickups, LABEL_RADIUS, MAX_LABELS);
+        assert_eq!(shown, vec![(e[1], 80.0)]);
+    }
+
+    #[test]
+    fn labels_fade_with_distance() {
+        assert_eq!(label_alpha(0.0), 1.0);
+        assert_eq!(label_alpha(LABEL_FADE_START), 1.0);
+        assert!((label_alpha(60.0) - 0.5).abs() < 1e-5);
+        assert_eq!(label_alpha(LABEL_RADIUS), 0.0);
+        assert_eq!(label_alpha(500.0), 0.0);
+        assert!(label_alpha(50.0) > label_alpha(70.0));
+    }
+
+    #[test]
+    fn weapon_hint_lists_damage_and_traits() {
+        let registry = WeaponRegistry::new();
+        let rocket = registry.get(WeaponId::PocketRocket).unwrap();
+        assert_eq!(weapon_stat_hint(rocket), "DMG 50 | SLOW | AOE");
+        let pistol = registry.get(WeaponId::Pistol).unwrap();
+        assert_eq!(weapon_stat_hint(pistol), "DMG 15");
+    }
+
+    #[test]
+    fn four_drops_get_three_labels_and_farthest_gets_none() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(GameState::Playing)
+            .add_sub_state::<PlayingState>()
+            .init_resource::<WeaponRegistry>()
+            .add_systems(Update, update_pickup_labels);
+
+        app.world_mut().spawn((Player::default(), Transform::default()));
+        let mut drops = Vec::new();
+        for (i, position) in [
+            Vec2::new(20.0, 0.0),
+            Vec2::new(0.0, -35.0),
+            Vec2::new(-50.0, 0.0),
+            Vec2::new(0.0, 75.0),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut drop = app.world_mut().spawn(Transform::from_translation(position.extend(0.0)));
+            if i % 2 == 0 {
+                drop.insert((
+                    Bonus {
+                        bonus_type: BonusType::WeaponPickup,
+                    },
+                    WeaponDrop {
+                        weapon: WeaponId::Shotgun.into(),
+                    },
+                ));
+            } else {
+                drop.insert(ItemPickup {
+                    item_type: ItemType::Nuke,
+                });
+            }
+            drops.push(drop.id());
+        }
+        app.update();
+
+        let labelled: Vec<Entity> = app
+            .world_mut()
+            .query::<&PickupLabel>()
+            .iter(app.world())
+            .map(|label| label.pickup)
+            .collect();
+        assert_eq!(labelled.len(), 3);
+        assert!(drops[..3].iter().all(|drop| labelled.contains(drop)));
+        assert!(!labelled.contains(&drops[3]));
+
+        // Labels follow their pickup and go away with it
+        let children = app.world().get::<Children>(drops[0]).unwrap();
+        assert!(app.world().get::<PickupLabel>(children[0]).is_some());
+        app.world_mut().entity_mut(drops[0]).despawn_recursive();
+        app.update();
+        let labelled: Vec<Entity> = app
+            .world_mut()
+            .query::<&PickupLabel>()
+            .iter(app.world())
+            .map(|label| label.pickup)
+            .collect();
+        assert_eq!(labelled.len(), 3);
+        assert!(labelled.contains(&drops[3]));
+    }
+
+    #[test]
+    fn labels_hide_during_perk_select() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .insert_state(GameState::Playing)
+            .add_sub_state::<PlayingState>()
+            .init_resource::<WeaponRegistry>()
+            .add_systems(Update, update_pickup_labels);
+        app.world_mut().spawn((Player::default(), Transform::default()));
+        app.world_mut().spawn((
+            Transform::from_xyz(10.0, 0.0, 0.0),
+            ItemPickup {
+                item_type: ItemType::Freeze,
+            },
+        ));
+        app.update();
+        assert_eq!(app.world_mut().query::<&PickupLabel>().iter(app.world()).count(), 1);
+
+        app.world_mut()
+            .resource_mut::<NextState<PlayingState>>()
+            .set(PlayingState::PerkSelect);
+        app.update();
+        app.update();
+        assert_eq!(app.world_mut().query::<&PickupLabel>().iter(app.world()).count(), 0);
+    }
+}
@@ -0,0 +1,298 @@
+//! Quest selection screen, grouped by chapter
+
+use bevy::prelude::*;
+
+use super::text_style;
+use crate::audio::{PlaySoundEvent, SoundEffect};
+use crate::quests::{
+    build_chapter_select, ActiveQuest, ChapterSelectView, QuestCompletionLog, QuestDatabase,
+};
+use crate::states::{GameMode, GameState};
+
+/// Marker for quest select UI root
+#[derive(Component)]
+pub struct QuestSelectUi;
+
+/// Marker for the container holding the chapter list (rebuilt on navigation)
+#[derive(Component)]
+pub struct QuestSelectList;
+
+/// Current quest select view and cursor
+#[derive(Resource, Default)]
+pub struct QuestSelectState {
+    pub view: ChapterSelectView,
+    pub chapter: usize,
+    pub quest: usize,
+}
+
+impl QuestSelectState {
+    fn move_chapter(&mut self, delta: isize) {
+        let count = self.view.chapters.len() as isize;
+        if count == 0 {
+            return;
+        }
+        self.chapter = (self.chapter as isize + delta).rem_euclid(count) as usize;
+        self.quest = 0;
+    }
+
+    fn move_quest(&mut self, delta: isize) {
+        let Some(chapter) = self.view.chapters.get(self.chapter) else {
+            return;
+        };
+        let count = chapter.quests.len() as isize;
+        if count == 0 || !chapter.unlocked {
+            return;
+        }
+        self.quest = (self.quest as isize + delta).rem_euclid(count) as usize;
+    }
+}
+
+/// Sets up the quest select screen
+pub fn setup_quest_select(
+    mut commands: Commands,
+    quest_db: Res<QuestDatabase>,
+    completion_log: Res<QuestCompletionLog>,
+) {
+    let view = build_chapter_select(&quest_db, &completion_log);
+    let (chapter, quest) = view.initial_selection;
+    let state = QuestSelectState {
+        view,
+        chapter,
+        quest,
+    };
+
+    commands
+        .spawn((
+            QuestSelectUi,
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::srgb(0.1, 0.05, 0.05)),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "SELECT QUEST",
+                TextStyle {
+                    font_size: 48.0,
+                    color: Color::srgb(0.8, 0.1, 0.1),
+                    ..default()
+                },
+            ));
+
+            parent
+                .spawn((
+                    QuestSelectList,
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::FlexStart,
+                            margin: UiRect::vertical(Val::Px(20.0)),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                ))
+                .with_children(|list| spawn_chapter_list(list, &state));
+
+            parent.spawn(TextBundle::from_section(
+                "[LEFT/RIGHT] Chapter   [UP/DOWN] Quest   [ENTER] Start   [ESC] Back",
+                text_style(16.0, Color::srgb(0.5, 0.5, 0.5)),
+            ));
+        });
+
+    commands.insert_resource(state);
+}
+
+fn spawn_chapter_list(parent: &mut ChildBuilder, state: &QuestSelectState) {
+    for (c, chapter) in state.view.chapters.iter().enumerate() {
+        let selected = c == state.chapter;
+        let header = if chapter.unlocked {
+            format!(
+                "{} Chapter {}: {}   {}/{}",
+                if selected { "v" } else { ">" },
+                chapter.number,
+                chapter.name,
+                chapter.completed,
+                chapter.total
+            )
+        } else {
+            format!("{} Chapter {}: ???", if selected { "v" } else { ">" }, chapter.number)
+        };
+        let header_color = match (selected, chapter.unlocked) {
+            (true, _) => Color::srgb(1.0, 0.8, 0.3),
+            (false, true) => Color::WHITE,
+            (false, false) => Color::srgb(0.4, 0.4, 0.4),
+        };
+        parent.spawn(TextBundle::from_section(header, text_style(24.0, header_color)));
+
+        if !selected {
+            continue;
+        }
+
+        if !chapter.unlocked {
+            if let Some(hint) = &chapter.unlock_hint {
+                parent.spawn(
+                    TextBundle::from_section(hint, text_style(18.0, Color::srgb(0.6, 0.6, 0.6)))
+                        .with_style(Style {
+                            margin: UiRect::left(Val::Px(30.0)),
+                            ..default()
+                        }),
+                );
+            }
+            continue;
+        }
+
+        for (q, quest) in chapter.quests.iter().enumerate() {
+            let highlighted = q == state.quest;
+            let label = match (quest.unlocked, quest.completed) {
+                (false, _) => "??? (locked)".to_string(),
+                (true, true) => format!("{} [done]", quest.name),
+                (true, false) => quest.name.clone(),
+            };
+            let color = if highlighted {
+                Color::srgb(1.0, 1.0, 0.5)
+            } else if quest.unlocked {
+                Color::srgb(0.8, 0.8, 0.8)
+            } else {
+                Color::srgb(0.4, 0.4, 0.4)
+            };
+            parent.spawn(
+                TextBundle::from_section(
+                    format!("{} {}", if highlighted { ">" } else { " " }, label),
+                    text_style(20.0, color),
+                )
+                .with_style(Style {
+                    margin: UiRect::left(Val::Px(30.0)),
+                    ..default()
+                }),
+            );
+        }
+    }
+}
+
+/// Handles quest select navigation and starting a quest
+#[allow(clippy::too_many_arguments)]
+pub fn handle_quest_select_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<QuestSelectState>,
+    mut active_quest: ResMut<ActiveQuest>,
+    mut game_mode: ResMut<GameMode>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    list_query: Query<Entity, With<QuestSelectList>>,
+) {
+    if keyboard.just_pressed(KeyCode::Escape) {
+        sound_events.send(PlaySoundEvent {
+            sound: SoundEffect::MenuBack,
+            position: None,
+        });
+        next_state.set(GameState::MainMenu);
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Enter) {
+        let chapter_unlocked = state
+            .view
+            .chapters
+            .get(state.chapter)
+            .is_some_and(|c| c.unlocked);
+        if let Some(quest) = state.view.quest_at(state.chapter, state.quest) {
+            if chapter_unlocked && quest.unlocked {
+                sound_events.send(PlaySoundEvent {
+                    sound: SoundEffect::MenuSelect,
+                    position: None,
+                });
+                *active_quest = ActiveQuest::new(quest.id);
+                *game_mode = GameMode::Quest;
+                next_state.set(GameState::Playing);
+                return;
+            }
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        state.move_chapter(-1);
+    }
+    if keyboard.just_pressed(KeyCode::ArrowRight) {
+        state.move_chapter(1);
+    }
+    if keyboard.just_pressed(KeyCode::ArrowUp) {
+        state.move_quest(-1);
+    }
+    if keyboard.just_pressed(KeyCode::ArrowDown) {
+        state.move_quest(1);
+    }
+
+    if state.is_changed() && !state.is_added() {
+        for list in list_query.iter() {
+            commands.entity(list).despawn_descendants();
+            commands
+                .entity(list)
+                .with_children(|parent| spawn_chapter_list(parent, &state));
+        }
+    }
+}
+
+/// Cleans up the quest select screen
+pub fn cleanup_quest_select(mut commands: Commands, query: Query<Entity, With<QuestSelectUi>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<QuestSelectState>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quests::QuestId;
+
+    fn state_with(log: QuestCompletionLog) -> QuestSelectState {
+        let view = build_chapter_select(&QuestDatabase::new(), &log);
+        let (chapter, quest) = view.initial_selection;
+        QuestSelectState {
+            view,
+            chapter,
+            quest,
+        }
+    }
+
+    #[test]
+    fn chapter_navigation_wraps() {
+        let mut state = state_with(QuestCompletionLog::default());
+        state.move_chapter(-1);
+        assert_eq!(state.chapter, state.view.chapters.len() - 1);
+        state.move_chapter(1);
+        assert_eq!(state.chapter, 0);
+    }
+
+    #[test]
+    fn quest_navigation_stays_in_chapter() {
+        let mut state = state_with(QuestCompletionLog {
+            completed: vec![QuestId::Q01LandHostile],
+            last_played: None,
+        });
+        let count = state.view.chapters[0].quests.len();
+        for _ in 0..count {
+            state.move_quest(1);
+        }
+        assert_eq!(state.chapter, 0);
+        assert_eq!(state.quest, state.view.initial_selection.1);
+    }
+
+    #[test]
+    fn locked_chapter_ignores_quest_navigation() {
+        let mut state = state_with(QuestCompletionLog::default());
+        state.move_chapter(1);
+        state.move_quest(1);
+        assert_eq!(state.quest, 0);
+    }
+}
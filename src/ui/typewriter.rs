@@ -0,0 +1,62 @@
+//! Typewriter text reveal
+//!
+//! Attach [`Typewriter`] to a text entity and its first section fills in
+//! character by character over the given duration.
+
+use bevy::prelude::*;
+
+/// Reveals `full_text` into the entity's first text section over `duration` seconds
+#[derive(Component, Debug)]
+pub struct Typewriter {
+    pub full_text: String,
+    pub duration: f32,
+    pub elapsed: f32,
+}
+
+impl Typewriter {
+    pub fn new(full_text: impl Into<String>, duration: f32) -> Self {
+        Self {
+            full_text: full_text.into(),
+            duration,
+            elapsed: 0.0,
+        }
+    }
+}
+
+/// Number of characters of a `total`-character text shown after `elapsed` seconds
+pub fn revealed_chars(total: usize, elapsed: f32, duration: f32) -> usize {
+    if duration <= 0.0 {
+        return total;
+    }
+    ((elapsed / duration).clamp(0.0, 1.0) * total as f32).floor() as usize
+}
+
+/// Advances every typewriter and writes the revealed prefix into its text
+pub fn update_typewriters(time: Res<Time>, mut query: Query<(&mut Typewriter, &mut Text)>) {
+    for (mut typewriter, mut text) in query.iter_mut() {
+        if typewriter.elapsed >= typewriter.duration && !text.sections[0].value.is_empty() {
+            continue;
+        }
+        typewriter.elapsed += time.delta_seconds();
+        let shown = revealed_chars(
+            typewriter.full_text.chars().count(),
+            typewriter.elapsed,
+            typewriter.duration,
+        );
+        text.sections[0].value = typewriter.full_text.chars().take(shown).collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveal_runs_from_nothing_to_everything() {
+        assert_eq!(revealed_chars(30, 0.0, 1.5), 0);
+        assert_eq!(revealed_chars(30, 0.75, 1.5), 15);
+        assert_eq!(revealed_chars(30, 1.5, 1.5), 30);
+        assert_eq!(revealed_chars(30, 9.0, 1.5), 30);
+        assert_eq!(revealed_chars(30, 0.0, 0.0), 30);
+    }
+}
@@ -0,0 +1,102 @@
+//! Wave transition overlay
+//!
+//! "WAVE N" header shown between quest waves, with the wave's intro text
+//! typed out underneath when it has one.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::{text_style, Typewriter};
+use crate::states::{WaveTransitionState, INTRO_REVEAL_DURATION};
+
+/// Window width assumed when no window is available
+const FALLBACK_WINDOW_WIDTH: f32 = 1280.0;
+/// Narrowest the narrative column gets
+const MIN_WRAP_WIDTH: f32 = 280.0;
+/// Widest the narrative column gets, so lines stay readable on wide screens
+const MAX_WRAP_WIDTH: f32 = 640.0;
+
+/// Marker for the wave transition overlay
+#[derive(Component)]
+pub struct WaveTransitionUi;
+
+/// Width narrative text wraps at for a window `window_width` pixels wide
+pub fn narrative_wrap_width(window_width: f32) -> f32 {
+    (window_width * 0.6).clamp(MIN_WRAP_WIDTH, MAX_WRAP_WIDTH)
+}
+
+/// Current window width, or a sensible default without one
+pub fn window_width(windows: &Query<&Window, With<PrimaryWindow>>) -> f32 {
+    windows
+        .get_single()
+        .map(|window| window.width())
+        .unwrap_or(FALLBACK_WINDOW_WIDTH)
+}
+
+pub fn setup_wave_transition(
+    mut commands: Commands,
+    transition: Option<Res<WaveTransitionState>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Some(transition) = transition else {
+        return;
+    };
+    let wrap_width = narrative_wrap_width(window_width(&windows));
+
+    // Upper third of the screen, clear of the player in the middle
+    commands
+        .spawn((
+            WaveTransitionUi,
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    top: Val::Percent(12.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                format!("WAVE {}", transition.next_wave),
+                text_style(48.0, Color::srgb(1.0, 0.85, 0.3)),
+            ));
+            if let Some(intro) = &transition.intro_text {
+                parent.spawn((
+                    TextBundle::from_section(String::new(), text_style(20.0, Color::srgb(0.85, 0.85, 0.85)))
+                        .with_text_justify(JustifyText::Center)
+                        .with_style(Style {
+                            max_width: Val::Px(wrap_width),
+                            ..default()
+                        }),
+                    Typewriter::new(intro.clone(), INTRO_REVEAL_DURATION),
+                ));
+                parent.spawn(TextBundle::from_section(
+                    "[ENTER] Skip",
+                    text_style(14.0, Color::srgba(1.0, 1.0, 1.0, 0.5)),
+                ));
+            }
+        });
+}
+
+pub fn cleanup_wave_transition(mut commands: Commands, query: Query<Entity, With<WaveTransitionUi>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_width_scales_with_the_window_within_limits() {
+        assert!((narrative_wrap_width(800.0) - 480.0).abs() < 1e-3);
+        assert_eq!(narrative_wrap_width(320.0), MIN_WRAP_WIDTH);
+        assert_eq!(narrative_wrap_width(3840.0), MAX_WRAP_WIDTH);
+    }
+}
@@ -0,0 +1,225 @@
+//! Viewport tracking
+//!
+//! Keeps a single cached view of the window size and the arena so spawn logic
+//! and camera clamping stay consistent across window resizes and fullscreen toggles.
+
+use bevy::prelude::*;
+use bevy::window::WindowResized;
+
+/// Extra distance beyond the view's edge where spawning starts
+pub const SPAWN_MARGIN: f32 = 50.0;
+/// Depth of the band beyond the view creatures spawn in
+pub const SPAWN_BAND_WIDTH: f32 = 200.0;
+
+/// Plugin for viewport tracking
+pub struct ViewportPlugin;
+
+impl Plugin for ViewportPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ViewportInfo>()
+            .init_resource::<ArenaBounds>()
+            .add_event::<WindowResized>()
+            .add_systems(PreUpdate, track_window_resize)
+            .add_systems(PostUpdate, apply_viewport_resize);
+    }
+}
+
+/// Playable area, centered on the origin
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ArenaBounds {
+    pub half_extents: Vec2,
+}
+
+impl Default for ArenaBounds {
+    fn default() -> Self {
+        Self {
+            half_extents: Vec2::new(800.0, 600.0),
+        }
+    }
+}
+
+impl ArenaBounds {
+    pub fn clamp(&self, position: Vec2) -> Vec2 {
+        position.clamp(-self.half_extents, self.half_extents)
+    }
+}
+
+/// Cached window size and derived world-space view extents
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ViewportInfo {
+    /// Window size in logical pixels
+    pub window_size: Vec2,
+    /// New size seen this frame; applied at the end of the frame
+    pub pending_size: Option<Vec2>,
+}
+
+impl Default for ViewportInfo {
+    fn default() -> Self {
+        Self::new(Vec2::new(1280.0, 720.0))
+    }
+}
+
+impl ViewportInfo {
+    pub fn new(window_size: Vec2) -> Self {
+        Self {
+            window_size,
+            pending_size: None,
+        }
+    }
+
+    /// False on the frame a resize was observed, while sizes are inconsistent
+    pub fn is_settled(&self) -> bool {
+        self.pending_size.is_none()
+    }
+
+    /// Half of the visible world area (the 2D camera maps one pixel to one world unit)
+    pub fn half_extents(&self) -> Vec2 {
+        self.window_size * 0.5
+    }
+
+    /// Half extents of the rectangle around the view center that spawns start beyond,
+    /// just outside the visible area
+    pub fn spawn_edge(&self) -> Vec2 {
+        self.half_extents() + Vec2::splat(SPAWN_MARGIN)
+    }
+
+    /// Moves a desired camera center so the view does not reveal area outside the arena.
+    /// Axes where the view is larger than the arena are centered instead.
+    pub fn clamp_camera(&self, desired: Vec2, arena: &ArenaBounds) -> Vec2 {
+        let slack = arena.half_extents - self.half_extents();
+        let axis = |value: f32, slack: f32| {
+            if slack > 0.0 {
+                value.clamp(-slack, slack)
+            } else {
+                0.0
+            }
+        };
+        Vec2::new(axis(desired.x, slack.x), axis(desired.y, slack.y))
+    }
+}
+
+/// Records the latest window size so consumers can hold off for the transition frame
+pub fn track_window_resize(
+    mut events: EventReader<WindowResized>,
+    mut viewport: ResMut<ViewportInfo>,
+) {
+    if let Some(event) = events.read().last() {
+        let size = Vec2::new(event.width, event.height);
+        if size != viewport.window_size && size.x > 0.0 && size.y > 0.0 {
+            viewport.pending_size = Some(size);
+        }
+    }
+}
+
+/// Applies a pending resize once every consumer has skipped the transition frame
+pub fn apply_viewport_resize(mut viewport: ResMut<ViewportInfo>) {
+    if let Some(size) = viewport.pending_size.take() {
+        viewport.window_size = size;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::creatures::{
+        handle_creature_spawns, CreatureType, PendingCreatureSpawns, SpawnCreatureEvent, SpawnWarning,
+    };
+
+    #[test]
+    fn half_extents_follow_window_size() {
+        for (w, h) in [(1280.0, 720.0), (1920.0, 1080.0), (800.0, 1200.0)] {
+            let viewport = ViewportInfo::new(Vec2::new(w, h));
+            assert_eq!(viewport.half_extents(), Vec2::new(w / 2.0, h / 2.0));
+        }
+    }
+
+    #[test]
+    fn spawn_edge_is_outside_the_view() {
+        for size in [Vec2::new(1280.0, 720.0), Vec2::new(2560.0, 1080.0), Vec2::new(720.0, 1280.0)] {
+            let viewport = ViewportInfo::new(size);
+            assert!(viewport.spawn_edge().cmpgt(viewport.half_extents()).all());
+        }
+    }
+
+    #[test]
+    fn camera_clamps_inside_arena() {
+        let arena = ArenaBounds::default();
+        let viewport = ViewportInfo::new(Vec2::new(1280.0, 720.0));
+        let center = viewport.clamp_camera(Vec2::new(1000.0, -1000.0), &arena);
+        assert_eq!(center, Vec2::new(160.0, -240.0));
+        assert_eq!(viewport.clamp_camera(Vec2::new(50.0, 20.0), &arena), Vec2::new(50.0, 20.0));
+    }
+
+    #[test]
+    fn view_wider_than_arena_is_centered() {
+        let arena = ArenaBounds::default();
+        let viewport = ViewportInfo::new(Vec2::new(2560.0, 720.0));
+        let center = viewport.clamp_camera(Vec2::new(300.0, 100.0), &arena);
+        assert_eq!(center, Vec2::new(0.0, 100.0));
+    }
+
+    #[test]
+    fn resize_is_applied_after_one_frame() {
+        let viewport = ViewportInfo {
+            pending_size: Some(Vec2::new(1920.0, 1080.0)),
+            ..default()
+        };
+        assert!(!viewport.is_settled());
+
+        let mut app = App::new();
+        app.insert_resource(viewport)
+            .add_systems(Update, apply_viewport_resize);
+        app.update();
+
+        let viewport = app.world().resource::<ViewportInfo>();
+        assert!(viewport.is_settled());
+        assert_eq!(viewport.window_size, Vec2::new(1920.0, 1080.0));
+    }
+
+    fn spawn_offsets(app: &mut App) -> Vec<Vec2> {
+        app.world_mut()
+            .query_filtered::<&Transform, With<SpawnWarning>>()
+            .iter(app.world())
+            .map(|t| t.translation.truncate().abs())
+            .collect()
+    }
+
+    #[test]
+    fn resize_mid_run_defers_spawns_then_uses_new_band() {
+        let mut app = App::new();
+        app.add_plugins(ViewportPlugin)
+            .insert_resource(ArenaBounds {
+                half_extents: Vec2::splat(10_000.0),
+            })
+            .init_resource::<PendingCreatureSpawns>()
+            .add_event::<SpawnCreatureEvent>()
+            .add_systems(Update, handle_creature_spawns);
+        app.update();
+
+        let window = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(WindowResized {
+            window,
+            width: 2560.0,
+            height: 1440.0,
+        });
+        for _ in 0..20 {
+            app.world_mut().send_event(SpawnCreatureEvent {
+                creature_type: CreatureType::Zombie,
+                position: None,
+                summoner: None,
+            });
+        }
+
+        app.update();
+        assert!(spawn_offsets(&mut app).is_empty());
+
+        app.update();
+        let edge = ViewportInfo::new(Vec2::new(2560.0, 1440.0)).spawn_edge();
+        let offsets = spawn_offsets(&mut app);
+        assert_eq!(offsets.len(), 20);
+        for offset in offsets {
+            assert!(offset.x >= edge.x - 0.01 || offset.y >= edge.y - 0.01, "{offset} is on screen");
+            assert!(offset.cmple(edge + Vec2::splat(SPAWN_BAND_WIDTH + 0.01)).all());
+        }
+    }
+}
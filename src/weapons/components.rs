@@ -3,6 +3,8 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::render_layers::{on_layer, PROJECTILES};
+
 /// Weapon types available in the game
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum WeaponId {
@@ -50,44 +52,147 @@ pub enum WeaponId {
     InfernoCannon,
 }
 
+/// Weapon family, used by perks that only boost one kind of weapon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WeaponClass {
+    Bullet,
+    Shotgun,
+    /// Boosted by Pyromaniac
+    Fire,
+    Energy,
+    /// Boosted by Ion Gun Master
+    Ion,
+    Explosive,
+    Exotic,
+}
+
+/// Registry key for a weapon: a built-in id or a mod weapon namespaced as "modname:entry"
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WeaponKey {
+    Builtin(WeaponId),
+    Custom(String),
+}
+
+impl Default for WeaponKey {
+    fn default() -> Self {
+        WeaponKey::Builtin(WeaponId::Pistol)
+    }
+}
+
+impl From<WeaponId> for WeaponKey {
+    fn from(id: WeaponId) -> Self {
+        WeaponKey::Builtin(id)
+    }
+}
+
+/// Fire rate of a spin-up weapon from a standstill, as a fraction of its full rate
+pub const SPIN_UP_MIN_RATE: f32 = 0.3;
+/// Seconds of held fire for a spin-up weapon to reach its full rate
+pub const SPIN_UP_TIME: f32 = 1.5;
+/// Seconds for a fully spun weapon to wind down once the trigger is released
+pub const SPIN_DOWN_TIME: f32 = 0.75;
+
+/// Base spread the moving penalty scales at least, so accurate weapons still bloom
+pub const MOVING_SPREAD_FLOOR: f32 = 0.03;
+/// Seconds of running for spread to bloom fully
+pub const SPREAD_BLOOM_TIME: f32 = 0.25;
+/// Seconds of standing still for spread to settle back to its base
+pub const SPREAD_RECOVERY_TIME: f32 = 0.5;
+
+/// How far a player's spread has bloomed from moving, 0 standing still to 1 at a full run
+#[derive(Component, Debug, Clone, Default)]
+pub struct SpreadBloom {
+    pub amount: f32,
+    last_position: Option<Vec2>,
+}
+
+impl SpreadBloom {
+    /// Distance moved since the previous call, nothing on the first
+    pub fn moved_to(&mut self, position: Vec2) -> f32 {
+        self.last_position
+            .replace(position)
+            .map_or(0.0, |last| last.distance(position))
+    }
+
+    /// Moves toward `movement` (current speed over move speed, 0-1): up quickly, back down over SPREAD_RECOVERY_TIME
+    pub fn update(&mut self, movement: f32, delta: f32) {
+        let target = movement.clamp(0.0, 1.0);
+        self.amount = if target > self.amount {
+            (self.amount + delta / SPREAD_BLOOM_TIME).min(target)
+        } else {
+            (self.amount - delta / SPREAD_RECOVERY_TIME).max(target)
+        };
+    }
+
+    /// Spread of a weapon with this bloom, before accuracy perks
+    pub fn spread(&self, base_spread: f32, moving_spread_penalty: f32) -> f32 {
+        base_spread + self.amount * moving_spread_penalty * base_spread.max(MOVING_SPREAD_FLOOR)
+    }
+}
+
+/// Heat an overheated weapon has to cool below before it fires again
+pub const OVERHEAT_RESUME_HEAT: f32 = 0.4;
 
 /// Component for the player's currently equipped weapon
 #[derive(Component, Debug, Clone)]
 pub struct EquippedWeapon {
-    pub weapon_id: WeaponId,
+    pub weapon: WeaponKey,
     pub ammo: Option<u32>,
     pub fire_cooldown: f32,
     /// Timer for reload (0.0 means not reloading)
     pub reload_timer: f32,
+    /// Length of the reload in progress, for progress display
+    pub reload_duration: f32,
     /// Max ammo capacity for current weapon
     pub max_ammo: Option<u32>,
+    /// Max ammo capacity before clip size perks
+    pub base_clip: Option<u32>,
+    /// How far a spin-up weapon has wound up, 0-1
+    pub spin: f32,
+    /// Heat built up by an energy weapon, 0-1; it overheats at 1
+    pub heat: f32,
+    /// Locked out after overheating until heat falls to OVERHEAT_RESUME_HEAT
+    pub overheated: bool,
 }
 
 impl Default for EquippedWeapon {
     fn default() -> Self {
         Self {
-            weapon_id: WeaponId::Pistol,
+            weapon: WeaponKey::default(),
             ammo: None, // Infinite ammo for pistol
             fire_cooldown: 0.0,
             reload_timer: 0.0,
+            reload_duration: 0.0,
             max_ammo: None,
+            base_clip: None,
+            spin: 0.0,
+            heat: 0.0,
+            overheated: false,
         }
     }
 }
 
 impl EquippedWeapon {
-    pub fn new(weapon_id: WeaponId, ammo: Option<u32>) -> Self {
+    pub fn new(weapon: impl Into<WeaponKey>, ammo: Option<u32>) -> Self {
         Self {
-            weapon_id,
+            weapon: weapon.into(),
             ammo,
             fire_cooldown: 0.0,
             reload_timer: 0.0,
+            reload_duration: 0.0,
             max_ammo: ammo,
+            base_clip: ammo,
+            spin: 0.0,
+            heat: 0.0,
+            overheated: false,
         }
     }
 
     pub fn can_fire(&self) -> bool {
-        self.fire_cooldown <= 0.0 && self.reload_timer <= 0.0 && self.ammo.map(|a| a > 0).unwrap_or(true)
+        self.fire_cooldown <= 0.0
+            && self.reload_timer <= 0.0
+            && !self.overheated
+            && self.ammo.map(|a| a > 0).unwrap_or(true)
     }
 
     pub fn consume_ammo(&mut self) {
@@ -104,17 +209,97 @@ impl EquippedWeapon {
         self.reload_timer > 0.0
     }
 
+    /// Starts refilling the clip. Ignored for infinite ammo, a full clip or a reload in progress.
     pub fn start_reload(&mut self, reload_time: f32) {
-        if self.ammo.is_some() && !self.is_reloading() {
+        if self.ammo.is_some() && self.ammo != self.max_ammo && !self.is_reloading() {
             self.reload_timer = reload_time;
+            self.reload_duration = reload_time;
+        }
+    }
+
+    /// Fraction of the current reload done, None when not reloading
+    pub fn reload_progress(&self) -> Option<f32> {
+        if !self.is_reloading() || self.reload_duration <= 0.0 {
+            return None;
+        }
+        Some(1.0 - (self.reload_timer / self.reload_duration).clamp(0.0, 1.0))
+    }
+
+    /// Winds a spin-up weapon up while the trigger is held and down otherwise
+    pub fn update_spin(&mut self, held: bool, delta: f32) {
+        self.spin = if held {
+            (self.spin + delta / SPIN_UP_TIME).min(1.0)
+        } else {
+            (self.spin - delta / SPIN_DOWN_TIME).max(0.0)
+        };
+    }
+
+    /// Fire rate multiplier from the current spin: SPIN_UP_MIN_RATE at rest, 1 when fully spun
+    pub fn spin_rate(&self) -> f32 {
+        SPIN_UP_MIN_RATE + (1.0 - SPIN_UP_MIN_RATE) * self.spin
+    }
+
+    /// Adds the heat of a shot; reaching full heat overheats the weapon
+    pub fn add_heat(&mut self, amount: f32) {
+        self.heat = (self.heat + amount).min(1.0);
+        if self.heat >= 1.0 {
+            self.overheated = true;
         }
     }
 
+    /// Sheds heat, lifting an overheat lockout once cool enough
+    pub fn cool(&mut self, amount: f32) {
+        self.heat = (self.heat - amount).max(0.0);
+        if self.overheated && self.heat <= OVERHEAT_RESUME_HEAT {
+            self.overheated = false;
+        }
+    }
+
+    /// Changes the clip size, keeping the clip as full as it was. Ignored for infinite ammo
+    pub fn set_clip_size(&mut self, clip_size: u32) {
+        let (Some(ammo), Some(max)) = (self.ammo, self.max_ammo) else {
+            return;
+        };
+        let fill = if max == 0 { 1.0 } else { ammo as f32 / max as f32 };
+        self.ammo = Some((fill * clip_size as f32).round() as u32);
+        self.max_ammo = Some(clip_size);
+    }
+
     pub fn finish_reload(&mut self) {
         if let Some(max) = self.max_ammo {
             self.ammo = Some(max);
         }
         self.reload_timer = 0.0;
+        self.reload_duration = 0.0;
+    }
+}
+
+/// Move speed multiplier while carrying a second weapon
+pub const ALTERNATE_WEAPON_SPEED_MULTIPLIER: f32 = 0.85;
+
+/// Second weapon slot from the AlternateWeapon perk. The stowed weapon keeps its
+/// ammo, cooldown and reload progress until it is swapped back in.
+#[derive(Component, Debug, Clone, Default)]
+pub struct AlternateWeaponSlot {
+    pub stowed: Option<EquippedWeapon>,
+}
+
+impl AlternateWeaponSlot {
+    /// Swaps the equipped and stowed weapons; nothing happens while the slot is empty
+    pub fn swap(&mut self, equipped: &mut EquippedWeapon) {
+        if let Some(stowed) = &mut self.stowed {
+            std::mem::swap(stowed, equipped);
+            // A stowed spin-up weapon winds down
+            stowed.spin = 0.0;
+        }
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.stowed.is_some() {
+            ALTERNATE_WEAPON_SPEED_MULTIPLIER
+        } else {
+            1.0
+        }
     }
 }
 
@@ -125,6 +310,8 @@ pub struct Projectile {
     pub damage: f32,
     pub owner: Entity,
     pub pierce_count: u32,
+    /// Creatures this projectile already damaged; a piercing shot hits each once
+    pub already_hit: Vec<Entity>,
 }
 
 /// Velocity component for moving projectiles
@@ -163,6 +350,8 @@ pub struct Homing {
 pub struct Explosive {
     pub radius: f32,
     pub damage: f32,
+    /// The blast also hurts players
+    pub self_damage: bool,
 }
 
 /// Component for chain lightning projectiles (ChainReactor)
@@ -190,6 +379,19 @@ impl ChainLightning {
     }
 }
 
+/// Base speed and lifetime of a chain lightning jump, before perks
+pub const CHAIN_JUMP_SPEED: f32 = 800.0;
+pub const CHAIN_JUMP_LIFETIME: f32 = 0.5;
+/// Base speed and lifetime of a Splitter Gun fragment, before perks
+pub const SPLIT_FRAGMENT_SPEED: f32 = 500.0;
+pub const SPLIT_FRAGMENT_LIFETIME: f32 = 1.5;
+
+/// Color of chain lightning jumps and the arcs drawn along them
+pub const CHAIN_LIGHTNING_COLOR: Color = Color::srgb(0.5, 0.7, 1.0);
+
+/// Color of hitscan beams
+pub const BEAM_COLOR: Color = Color::srgb(0.6, 1.0, 1.0);
+
 /// Component for splitter projectiles (SplitterGun)
 /// Projectile splits into multiple smaller projectiles on hit
 #[derive(Component, Debug, Clone)]
@@ -212,6 +414,23 @@ impl Splitter {
     }
 }
 
+/// Component for projectiles that bounce off the arena edge (Blade Cannon)
+#[derive(Component, Debug, Clone)]
+pub struct Ricochet {
+    pub bounces_remaining: u8,
+    /// Damage multiplier applied on each bounce
+    pub damage_retained: f32,
+}
+
+impl Ricochet {
+    pub fn new(bounces: u8, damage_retained: f32) -> Self {
+        Self {
+            bounces_remaining: bounces,
+            damage_retained,
+        }
+    }
+}
+
 /// Component for freezing projectiles (FreezeRay)
 /// Slows down enemies on hit
 #[derive(Component, Debug, Clone)]
@@ -222,6 +441,121 @@ pub struct Freezing {
     pub duration: f32,
 }
 
+/// Component for shrinking projectiles (ShrinkRay)
+#[derive(Component, Debug, Clone)]
+pub struct Shrinking {
+    /// Size multiplier per hit (0.75 = a quarter smaller each hit)
+    pub per_hit: f32,
+    /// Seconds until the creature grows back, refreshed on every hit
+    pub duration: f32,
+}
+
+/// Shoves creatures a projectile hits along its flight, in pixels per second
+/// for a creature of mass 1
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HitKnockback(pub f32);
+
+/// Component for fire projectiles (Flamethrower, Blowtorch, Inferno Cannon)
+/// Sets creatures burning on hit
+#[derive(Component, Debug, Clone)]
+pub struct Igniting {
+    /// Burn damage per second, already scaled by the shooter's fire damage bonus
+    pub dps: f32,
+    /// Duration of the burn
+    pub duration: f32,
+}
+
+impl Igniting {
+    /// Burn a weapon's projectiles carry, if it is a fire weapon
+    pub fn for_weapon(weapon: WeaponId, fire_damage_multiplier: f32) -> Option<Self> {
+        let (dps, duration) = match weapon {
+            WeaponId::Blowtorch => (8.0, 1.5),
+            WeaponId::Flamethrower => (12.0, 2.0),
+            WeaponId::InfernoCannon => (20.0, 3.0),
+            _ => return None,
+        };
+        Some(Self {
+            dps: dps * fire_damage_multiplier,
+            duration,
+        })
+    }
+}
+
+/// Seconds a fire patch burns
+pub const FIRE_PATCH_DURATION: f32 = 4.0;
+/// How long a creature keeps burning after leaving a fire patch
+pub const FIRE_PATCH_BURN_DURATION: f32 = 1.0;
+
+/// Explosive projectile that leaves a [`FirePatch`] where it detonates (Inferno Cannon)
+#[derive(Component, Debug, Clone)]
+pub struct Incendiary {
+    /// Damage per second of the patch, already scaled by the shooter's fire damage bonus
+    pub dps: f32,
+}
+
+impl Incendiary {
+    /// Patch a weapon's projectiles leave, if it has one
+    pub fn for_weapon(weapon: WeaponId, fire_damage_multiplier: f32) -> Option<Self> {
+        let dps = match weapon {
+            WeaponId::InfernoCannon => 10.0,
+            _ => return None,
+        };
+        Some(Self {
+            dps: dps * fire_damage_multiplier,
+        })
+    }
+}
+
+/// Burning ground. Creatures inside take its dps and catch fire; where patches
+/// overlap only the hottest one counts
+#[derive(Component, Debug, Clone)]
+pub struct FirePatch {
+    pub radius: f32,
+    pub dps: f32,
+    pub remaining: f32,
+    /// Whoever fired the Incendiary projectile
+    pub owner: Entity,
+}
+
+/// Lobbed projectile (Grenade Launcher): flies over everything to `target`
+/// and only interacts with the world when it lands
+#[derive(Component, Debug, Clone)]
+pub struct GrenadeArc {
+    pub target: Vec2,
+    /// Seconds from launch to landing
+    pub flight_time: f32,
+    pub elapsed: f32,
+}
+
+impl GrenadeArc {
+    pub fn new(target: Vec2, flight_time: f32) -> Self {
+        Self {
+            target,
+            flight_time,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Flight progress, 0 at launch and 1 on landing
+    pub fn progress(&self) -> f32 {
+        if self.flight_time <= 0.0 {
+            return 1.0;
+        }
+        (self.elapsed / self.flight_time).clamp(0.0, 1.0)
+    }
+
+    /// Fake height as a sprite scale: grows towards the top of the arc and back
+    pub fn scale(&self) -> f32 {
+        let t = self.progress();
+        1.0 + GRENADE_ARC_SCALE * 4.0 * t * (1.0 - t)
+    }
+}
+
+/// Extra sprite scale at the top of a grenade's arc
+pub const GRENADE_ARC_SCALE: f32 = 0.8;
+/// Shortest flight, so point-blank lobs still read as a throw
+pub const MIN_GRENADE_FLIGHT_TIME: f32 = 0.25;
+
 /// Marker for projectiles to be cleaned up
 #[derive(Component)]
 pub struct ProjectileDespawn;
@@ -254,6 +588,7 @@ impl ProjectileBundle {
                 damage,
                 owner,
                 pierce_count: 0,
+                already_hit: Vec::new(),
             },
             velocity: Velocity(direction.normalize_or_zero() * speed),
             lifetime: Lifetime::new(lifetime),
@@ -263,22 +598,52 @@ impl ProjectileBundle {
                     custom_size: Some(Vec2::new(size, size * 0.5)),
                     ..default()
                 },
-                transform: Transform::from_translation(position)
+                transform: Transform::from_translation(on_layer(position, PROJECTILES))
                     .with_rotation(Quat::from_rotation_z(direction.y.atan2(direction.x))),
                 ..default()
             },
         }
     }
+
+    /// Lets the projectile pass through `pierce_count` creatures
+    pub fn with_pierce(mut self, pierce_count: u32) -> Self {
+        self.projectile.pierce_count = pierce_count;
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn swapping_keeps_each_weapons_state() {
+        let mut equipped = EquippedWeapon::new(WeaponId::Shotgun, Some(12));
+        equipped.ammo = Some(5);
+        equipped.fire_cooldown = 0.4;
+        let mut slot = AlternateWeaponSlot::default();
+
+        // Empty slot: nothing to swap to, no penalty
+        slot.swap(&mut equipped);
+        assert_eq!(equipped.weapon, WeaponId::Shotgun.into());
+        assert_eq!(slot.speed_multiplier(), 1.0);
+
+        slot.stowed = Some(EquippedWeapon::new(WeaponId::Uzi, Some(40)));
+        slot.swap(&mut equipped);
+        assert_eq!(equipped.weapon, WeaponId::Uzi.into());
+        assert_eq!(slot.speed_multiplier(), ALTERNATE_WEAPON_SPEED_MULTIPLIER);
+
+        slot.swap(&mut equipped);
+        assert_eq!(equipped.weapon, WeaponId::Shotgun.into());
+        assert_eq!(equipped.ammo, Some(5));
+        assert_eq!(equipped.fire_cooldown, 0.4);
+        assert_eq!(slot.stowed.as_ref().unwrap().ammo, Some(40));
+    }
+
     #[test]
     fn equipped_weapon_default_is_pistol() {
         let weapon = EquippedWeapon::default();
-        assert_eq!(weapon.weapon_id, WeaponId::Pistol);
+        assert_eq!(weapon.weapon, WeaponKey::Builtin(WeaponId::Pistol));
         assert!(weapon.ammo.is_none()); // Infinite ammo
     }
 
@@ -326,6 +691,80 @@ mod tests {
         assert_eq!(weapon.ammo, Some(0));
     }
 
+    #[test]
+    fn spin_ramps_fire_rate_up_and_back_down() {
+        let mut weapon = EquippedWeapon::new(WeaponId::Minigun, Some(150));
+        assert_eq!(weapon.spin_rate(), SPIN_UP_MIN_RATE);
+
+        weapon.update_spin(true, SPIN_UP_TIME / 2.0);
+        assert!((weapon.spin_rate() - (SPIN_UP_MIN_RATE + 1.0) / 2.0).abs() < 1e-6);
+        weapon.update_spin(true, SPIN_UP_TIME);
+        assert_eq!(weapon.spin_rate(), 1.0);
+
+        weapon.update_spin(false, SPIN_DOWN_TIME / 2.0);
+        assert_eq!(weapon.spin, 0.5);
+        weapon.update_spin(false, SPIN_DOWN_TIME);
+        assert_eq!(weapon.spin, 0.0);
+    }
+
+    #[test]
+    fn spread_blooms_while_running_and_settles_when_still() {
+        let mut bloom = SpreadBloom::default();
+        assert_eq!(bloom.spread(0.1, 0.5), 0.1);
+
+        bloom.update(1.0, SPREAD_BLOOM_TIME);
+        assert_eq!(bloom.amount, 1.0);
+        assert!((bloom.spread(0.1, 0.5) - 0.15).abs() < 1e-6, "+50% at a full run");
+        assert!((bloom.spread(0.0, 1.5) - MOVING_SPREAD_FLOOR * 1.5).abs() < 1e-6);
+
+        bloom.update(0.0, SPREAD_RECOVERY_TIME / 2.0);
+        assert!((bloom.amount - 0.5).abs() < 1e-6);
+        bloom.update(0.0, SPREAD_RECOVERY_TIME / 2.0);
+        assert_eq!(bloom.amount, 0.0);
+    }
+
+    #[test]
+    fn overheating_locks_the_weapon_until_it_cools() {
+        let mut weapon = EquippedWeapon::new(WeaponId::PlasmaRifle, Some(150));
+        weapon.add_heat(0.6);
+        assert!(weapon.can_fire());
+        weapon.add_heat(0.6);
+        assert_eq!(weapon.heat, 1.0);
+        assert!(!weapon.can_fire());
+
+        weapon.cool(1.0 - OVERHEAT_RESUME_HEAT - 0.1);
+        assert!(!weapon.can_fire(), "still too hot");
+        weapon.cool(0.1);
+        assert!(weapon.can_fire());
+        weapon.cool(1.0);
+        assert_eq!(weapon.heat, 0.0);
+    }
+
+    #[test]
+    fn reload_refills_clip_and_reports_progress() {
+        let mut weapon = EquippedWeapon::new(WeaponId::Shotgun, Some(12));
+        weapon.start_reload(2.0);
+        assert!(!weapon.is_reloading(), "full clip should not reload");
+
+        weapon.ammo = Some(3);
+        weapon.start_reload(2.0);
+        assert_eq!(weapon.reload_progress(), Some(0.0));
+        weapon.reload_timer = 0.5;
+        assert_eq!(weapon.reload_progress(), Some(0.75));
+        assert!(!weapon.can_fire());
+
+        weapon.finish_reload();
+        assert_eq!(weapon.ammo, Some(12));
+        assert_eq!(weapon.reload_progress(), None);
+    }
+
+    #[test]
+    fn infinite_ammo_never_reloads() {
+        let mut weapon = EquippedWeapon::default();
+        weapon.start_reload(2.0);
+        assert!(!weapon.is_reloading());
+    }
+
     #[test]
     fn lifetime_expires_correctly() {
         let mut lifetime = Lifetime::new(1.0);
@@ -3,15 +3,19 @@
 //! Handles weapons, projectiles, and firing mechanics.
 
 pub mod components;
+pub mod pool;
 pub mod registry;
 pub mod systems;
 
 pub use components::*;
+pub use pool::*;
 pub use registry::*;
 pub use systems::*;
 
 use bevy::prelude::*;
 
+use crate::creatures::rebuild_creature_grid;
+use crate::schedule::GameSet;
 use crate::states::GameState;
 
 /// Plugin for weapon-related functionality
@@ -20,22 +24,49 @@ pub struct WeaponsPlugin;
 impl Plugin for WeaponsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<WeaponRegistry>()
+            .init_resource::<ProjectilePool>()
             .add_event::<FireWeaponEvent>()
+            .add_event::<WeaponGrantedEvent>()
+            .add_event::<OutOfAmmoEvent>()
             .add_event::<ProjectileHitEvent>()
-            .add_systems(OnExit(GameState::Playing), despawn_all_projectiles)
+            .add_event::<ExplosionEvent>()
+            .add_systems(Startup, load_weapon_table)
+            .add_systems(
+                OnExit(GameState::Playing),
+                (despawn_all_projectiles, despawn_all_fire_patches),
+            )
             .add_systems(
                 Update,
                 (
-                    weapon_reload_system,
-                    fire_weapon_system,
-                    homing_projectile_update,
-                    projectile_movement,
-                    projectile_collision,
-                    update_frozen_creatures,
-                    projectile_lifetime,
-                    cleanup_projectiles,
+                    (
+                        apply_clip_size_bonuses.after(crate::perks::apply_perk_effects),
+                        weapon_reload_system.after(crate::player::systems::update_stationary_timers),
+                        fall_back_to_pistol,
+                        update_spread_bloom,
+                        fire_weapon_system,
+                        spawn_projectiles_from_fire_events,
+                        homing_projectile_update,
+                        projectile_movement,
+                        update_grenade_arcs,
+                    )
+                        .chain()
+                        .after(rebuild_creature_grid)
+                        .in_set(GameSet::Simulation),
+                    (
+                        fire_hitscan_weapons,
+                        projectile_collision,
+                        apply_shrink_hits,
+                        update_frozen_creatures,
+                        update_shrunk_creatures,
+                        projectile_lifetime,
+                        detonate_explosives,
+                        apply_explosion_damage,
+                        update_fire_patches,
+                        cleanup_projectiles,
+                    )
+                        .chain()
+                        .in_set(GameSet::DamageResolution),
                 )
-                    .chain()
                     .run_if(in_state(GameState::Playing)),
             );
     }
@@ -0,0 +1,136 @@
+//! Projectile entity pool
+//!
+//! Fast weapons fire dozens of projectiles a second, and ranged creatures and
+//! boss barrages add their own. Finished projectiles of either kind are
+//! stripped down to a hidden sprite and kept for the next shot instead of
+//! being despawned, so the Minigun doesn't churn entities and components.
+
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+/// Most finished projectiles kept for reuse; any beyond this are despawned
+pub const PROJECTILE_POOL_CAPACITY: usize = 256;
+
+/// Marks a finished projectile waiting in the pool. It has no `Projectile`
+/// component, so projectile queries don't see it
+#[derive(Component, Debug, Default)]
+pub struct Pooled;
+
+/// Finished projectile entities ready to be reused
+#[derive(Resource, Debug, Default)]
+pub struct ProjectilePool {
+    free: Vec<Entity>,
+}
+
+impl ProjectilePool {
+    /// Spawns `bundle` on a pooled entity, or a new one when the pool is empty.
+    /// The bundle replaces everything the entity had as a projectile before.
+    pub fn spawn<'a>(&mut self, commands: &'a mut Commands, bundle: impl Bundle) -> EntityCommands<'a> {
+        while let Some(entity) = self.free.pop() {
+            // Skip entities despawned while pooled
+            if commands.get_entity(entity).is_some() {
+                let mut entity_commands = commands.entity(entity);
+                entity_commands.remove::<Pooled>().insert(bundle);
+                return entity_commands;
+            }
+        }
+        commands.spawn(bundle)
+    }
+
+    /// Returns a finished projectile to the pool: everything but its sprite is
+    /// removed and it is hidden. Despawns it instead once the pool is full.
+    pub fn release(&mut self, commands: &mut Commands, entity: Entity) {
+        if self.free.len() >= PROJECTILE_POOL_CAPACITY {
+            commands.entity(entity).despawn_recursive();
+            return;
+        }
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .retain::<SpriteBundle>()
+            .insert((Pooled, Visibility::Hidden));
+        self.free.push(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use crate::weapons::{
+        cleanup_projectiles, Projectile, ProjectileBundle, ProjectileDespawn, Velocity, WeaponId,
+    };
+
+    fn fire(app: &mut App, count: usize) {
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands, mut pool: ResMut<ProjectilePool>| {
+                for i in 0..count {
+                    pool.spawn(
+                        &mut commands,
+                        ProjectileBundle::new(
+                            WeaponId::Minigun,
+                            10.0,
+                            Entity::PLACEHOLDER,
+                            Vec3::new(i as f32, 0.0, 0.0),
+                            Vec2::X,
+                            500.0,
+                            1.0,
+                            Color::WHITE,
+                            4.0,
+                        ),
+                    );
+                }
+            });
+    }
+
+    fn finish_all(app: &mut App) {
+        let projectiles: Vec<Entity> = app
+            .world_mut()
+            .query_filtered::<Entity, With<Projectile>>()
+            .iter(app.world())
+            .collect();
+        for entity in projectiles {
+            app.world_mut().entity_mut(entity).insert(ProjectileDespawn);
+        }
+        app.update();
+    }
+
+    #[test]
+    fn pool_reuses_finished_projectiles_and_caps_live_entities() {
+        let mut app = App::new();
+        app.init_resource::<ProjectilePool>()
+            .add_systems(Update, cleanup_projectiles);
+        assert!(app.world().resource::<ProjectilePool>().free.is_empty());
+
+        for _ in 0..3 {
+            fire(&mut app, 5_000);
+            assert_eq!(app.world().entities().len() as usize, 5_000);
+            finish_all(&mut app);
+            assert_eq!(app.world().resource::<ProjectilePool>().free.len(), PROJECTILE_POOL_CAPACITY);
+            assert_eq!(app.world().entities().len() as usize, PROJECTILE_POOL_CAPACITY);
+        }
+
+        // Pooled entities are hidden, stripped and invisible to projectile queries
+        let pooled: Vec<Entity> = app
+            .world_mut()
+            .query_filtered::<Entity, With<Pooled>>()
+            .iter(app.world())
+            .collect();
+        assert_eq!(pooled.len(), PROJECTILE_POOL_CAPACITY);
+        let entity = app.world().entity(pooled[0]);
+        assert_eq!(entity.get::<Visibility>(), Some(&Visibility::Hidden));
+        assert!(entity.get::<Velocity>().is_none());
+        assert!(entity.get::<ProjectileDespawn>().is_none());
+
+        // Reuse gives a fresh projectile
+        fire(&mut app, 1);
+        let (projectile, velocity, visibility) = app
+            .world_mut()
+            .query_filtered::<(&Projectile, &Velocity, &Visibility), Without<Pooled>>()
+            .single(app.world());
+        assert!(projectile.already_hit.is_empty());
+        assert_eq!(velocity.0, Vec2::X * 500.0);
+        assert_eq!(*visibility, Visibility::Inherited);
+        assert_eq!(app.world().resource::<ProjectilePool>().free.len(), PROJECTILE_POOL_CAPACITY - 1);
+    }
+}
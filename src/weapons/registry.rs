@@ -1,14 +1,57 @@
 //! Weapon registry and data
 
 use bevy::prelude::*;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use super::components::WeaponId;
+use super::components::{WeaponClass, WeaponId, WeaponKey};
+
+/// Built-in weapons that can come out of a WeaponPickup bonus, with their drop tier
+pub const BUILTIN_DROP_POOL: [(WeaponId, u8); 7] = [
+    (WeaponId::Shotgun, 1),
+    (WeaponId::Uzi, 1),
+    (WeaponId::AssaultRifle, 2),
+    (WeaponId::PlasmaRifle, 3),
+    (WeaponId::RocketLauncher, 3),
+    (WeaponId::Flamethrower, 2),
+    (WeaponId::Minigun, 3),
+];
+
+/// Turn rate of the Homing Missile, in radians per second
+pub const DEFAULT_HOMING_TURN_RATE: f32 = 3.0;
+
+/// Moving spread penalty of most built-in weapons, and of table entries that leave it out
+pub const DEFAULT_MOVING_SPREAD_PENALTY: f32 = 0.5;
+
+fn default_moving_spread_penalty() -> f32 {
+    DEFAULT_MOVING_SPREAD_PENALTY
+}
+
+/// Weapon balance table read at startup; entries override the built-in stats
+#[cfg(not(target_arch = "wasm32"))]
+pub const WEAPONS_FILE: &str = "assets/data/weapons.ron";
+
+/// Seconds of game time for each tier above 1 to reach its full drop weight
+const TIER_RAMP_SECONDS: f32 = 120.0;
+/// Fraction of full drop weight a tier starts at before its ramp
+const TIER_MIN_SHARE: f32 = 0.1;
+
+/// Drop weight of a weapon tier at `game_time`: tier 1 always has full weight,
+/// tier N ramps up over (N - 1) * TIER_RAMP_SECONDS
+pub fn drop_weight(tier: u8, game_time: f32) -> f32 {
+    if tier <= 1 {
+        return 1.0;
+    }
+    let ramp = TIER_RAMP_SECONDS * (tier - 1) as f32;
+    (game_time / ramp).clamp(TIER_MIN_SHARE, 1.0)
+}
 
 /// Registry containing all weapon definitions
 #[derive(Resource)]
 pub struct WeaponRegistry {
     pub weapons: Vec<WeaponData>,
+    /// Weapons added by mods
+    pub custom: Vec<CustomWeapon>,
 }
 
 impl Default for WeaponRegistry {
@@ -21,6 +64,7 @@ impl WeaponRegistry {
     pub fn new() -> Self {
         let mut registry = Self {
             weapons: Vec::new(),
+            custom: Vec::new(),
         };
         registry.register_all_weapons();
         registry
@@ -30,12 +74,76 @@ impl WeaponRegistry {
         self.weapons.iter().find(|w| w.id == id)
     }
 
+    pub fn get_custom(&self, id: &str) -> Option<&CustomWeapon> {
+        self.custom.iter().find(|w| w.id == id)
+    }
+
+    /// Looks up a built-in or custom weapon. None if a custom weapon's mod is not loaded.
+    pub fn get_key(&self, key: &WeaponKey) -> Option<&WeaponData> {
+        match key {
+            WeaponKey::Builtin(id) => self.get(*id),
+            WeaponKey::Custom(id) => self.get_custom(id).map(|w| &w.data),
+        }
+    }
+
+    /// Weapons a WeaponPickup bonus can roll, with their tier: the built-in pool plus custom weapons with tier 1+
+    pub fn drop_table(&self) -> Vec<(WeaponKey, u8)> {
+        BUILTIN_DROP_POOL
+            .iter()
+            .map(|&(id, tier)| (WeaponKey::Builtin(id), tier))
+            .chain(
+                self.custom
+                    .iter()
+                    .filter(|w| w.tier > 0)
+                    .map(|w| (WeaponKey::Custom(w.id.clone()), w.tier)),
+            )
+            .collect()
+    }
+
+    /// Rolls a weapon for a WeaponPickup, favouring higher tiers as the run goes on
+    pub fn roll_drop(&self, game_time: f32, rng: &mut impl Rng) -> Option<WeaponKey> {
+        let table = self.drop_table();
+        let total: f32 = table.iter().map(|(_, tier)| drop_weight(*tier, game_time)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = rng.gen_range(0.0..total);
+        for (key, tier) in &table {
+            roll -= drop_weight(*tier, game_time);
+            if roll < 0.0 {
+                return Some(key.clone());
+            }
+        }
+        table.last().map(|(key, _)| key.clone())
+    }
+
+    /// Applies a RON weapon table over the current stats. Entries replace the
+    /// weapon with the same id; invalid entries are logged and keep the old stats.
+    /// Returns the number of entries applied, or the parse error with nothing changed.
+    pub fn apply_table(&mut self, text: &str) -> Result<usize, ron::error::SpannedError> {
+        let entries: Vec<WeaponData> = ron::from_str(text)?;
+        let mut applied = 0;
+        for entry in entries {
+            if let Err(reason) = entry.validate() {
+                warn!("Rejected weapon entry {:?}: {}", entry.id, reason);
+                continue;
+            }
+            match self.weapons.iter_mut().find(|w| w.id == entry.id) {
+                Some(existing) => *existing = entry,
+                None => self.weapons.push(entry),
+            }
+            applied += 1;
+        }
+        Ok(applied)
+    }
+
     fn register_all_weapons(&mut self) {
         self.weapons = vec![
             // Pistols
             WeaponData {
                 id: WeaponId::Pistol,
                 name: "Pistol".into(),
+                class: WeaponClass::Bullet,
                 damage: 15.0,
                 fire_rate: 5.0,
                 projectile_speed: 800.0,
@@ -46,11 +154,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.0,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 60.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::PocketRocket,
                 name: "Pocket Rocket".into(),
+                class: WeaponClass::Explosive,
                 damage: 50.0,
                 fire_rate: 2.0,
                 projectile_speed: 500.0,
@@ -61,11 +179,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 3.0,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 50.0,
+                recoil: 0.0,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: true,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::Magnum,
                 name: "Magnum".into(),
+                class: WeaponClass::Bullet,
                 damage: 60.0,
                 fire_rate: 2.0,
                 projectile_speed: 1000.0,
@@ -76,12 +204,22 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.5,
                 pierce_count: 1,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 160.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 1.5,
             },
             // Submachine Guns
             WeaponData {
                 id: WeaponId::Uzi,
                 name: "Uzi".into(),
+                class: WeaponClass::Bullet,
                 damage: 10.0,
                 fire_rate: 15.0,
                 projectile_speed: 700.0,
@@ -92,11 +230,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 1.5,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 30.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.25,
             },
             WeaponData {
                 id: WeaponId::Smg,
                 name: "SMG".into(),
+                class: WeaponClass::Bullet,
                 damage: 12.0,
                 fire_rate: 12.0,
                 projectile_speed: 750.0,
@@ -107,11 +255,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 1.5,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 30.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.25,
             },
             WeaponData {
                 id: WeaponId::DualSmg,
                 name: "Dual SMG".into(),
+                class: WeaponClass::Bullet,
                 damage: 10.0,
                 fire_rate: 20.0,
                 projectile_speed: 750.0,
@@ -122,12 +280,22 @@ impl WeaponRegistry {
                 projectile_lifetime: 1.5,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 30.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.25,
             },
             // Rifles
             WeaponData {
                 id: WeaponId::AssaultRifle,
                 name: "Assault Rifle".into(),
+                class: WeaponClass::Bullet,
                 damage: 18.0,
                 fire_rate: 10.0,
                 projectile_speed: 900.0,
@@ -138,11 +306,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.0,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 60.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::MachineGun,
                 name: "Machine Gun".into(),
+                class: WeaponClass::Bullet,
                 damage: 15.0,
                 fire_rate: 14.0,
                 projectile_speed: 850.0,
@@ -153,11 +331,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.0,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 60.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::Minigun,
                 name: "Minigun".into(),
+                class: WeaponClass::Bullet,
                 damage: 12.0,
                 fire_rate: 30.0,
                 projectile_speed: 800.0,
@@ -168,12 +356,22 @@ impl WeaponRegistry {
                 projectile_lifetime: 1.5,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 35.0,
+                spin_up: true,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             // Shotguns
             WeaponData {
                 id: WeaponId::Shotgun,
                 name: "Shotgun".into(),
+                class: WeaponClass::Shotgun,
                 damage: 8.0,
                 fire_rate: 2.0,
                 projectile_speed: 600.0,
@@ -184,11 +382,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 0.8,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 90.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::DoubleBarrel,
                 name: "Double Barrel".into(),
+                class: WeaponClass::Shotgun,
                 damage: 10.0,
                 fire_rate: 1.5,
                 projectile_speed: 600.0,
@@ -199,11 +407,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 0.7,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 110.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::Jackhammer,
                 name: "Jackhammer".into(),
+                class: WeaponClass::Shotgun,
                 damage: 7.0,
                 fire_rate: 4.0,
                 projectile_speed: 650.0,
@@ -214,11 +432,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 0.9,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 80.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::Blowtorch,
                 name: "Blowtorch".into(),
+                class: WeaponClass::Fire,
                 damage: 5.0,
                 fire_rate: 20.0,
                 projectile_speed: 400.0,
@@ -229,12 +457,22 @@ impl WeaponRegistry {
                 projectile_lifetime: 0.3,
                 pierce_count: 2,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             // Special Weapons
             WeaponData {
                 id: WeaponId::Flamethrower,
                 name: "Flamethrower".into(),
+                class: WeaponClass::Fire,
                 damage: 8.0,
                 fire_rate: 25.0,
                 projectile_speed: 300.0,
@@ -245,11 +483,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 0.5,
                 pierce_count: 3,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::PlasmaRifle,
                 name: "Plasma Rifle".into(),
+                class: WeaponClass::Energy,
                 damage: 25.0,
                 fire_rate: 8.0,
                 projectile_speed: 600.0,
@@ -260,11 +508,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.0,
                 pierce_count: 2,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 80.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.06,
+                cooling_rate: 0.25,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::PulseGun,
                 name: "Pulse Gun".into(),
+                class: WeaponClass::Energy,
                 damage: 30.0,
                 fire_rate: 6.0,
                 projectile_speed: 550.0,
@@ -275,11 +533,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.5,
                 pierce_count: 3,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 120.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.08,
+                cooling_rate: 0.25,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::IonRifle,
                 name: "Ion Rifle".into(),
+                class: WeaponClass::Ion,
                 damage: 40.0,
                 fire_rate: 3.0,
                 projectile_speed: 1200.0,
@@ -290,11 +558,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.0,
                 pierce_count: 5,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 100.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.15,
+                cooling_rate: 0.2,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::GaussGun,
                 name: "Gauss Gun".into(),
+                class: WeaponClass::Energy,
                 damage: 80.0,
                 fire_rate: 1.5,
                 projectile_speed: 1500.0,
@@ -305,11 +583,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 3.0,
                 pierce_count: 10,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.35,
+                knockback: 400.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: true,
+                moving_spread_penalty: 1.5,
             },
             WeaponData {
                 id: WeaponId::GaussShotgun,
                 name: "Gauss Shotgun".into(),
+                class: WeaponClass::Energy,
                 damage: 30.0,
                 fire_rate: 1.0,
                 projectile_speed: 1200.0,
@@ -320,11 +608,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.0,
                 pierce_count: 3,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 150.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::ShrinkRay,
                 name: "Shrink Ray".into(),
+                class: WeaponClass::Exotic,
                 damage: 5.0,
                 fire_rate: 10.0,
                 projectile_speed: 500.0,
@@ -335,11 +633,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 1.5,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::FreezeRay,
                 name: "Freeze Ray".into(),
+                class: WeaponClass::Exotic,
                 damage: 3.0,
                 fire_rate: 15.0,
                 projectile_speed: 400.0,
@@ -350,12 +658,22 @@ impl WeaponRegistry {
                 projectile_lifetime: 1.0,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             // Heavy Weapons
             WeaponData {
                 id: WeaponId::RocketLauncher,
                 name: "Rocket Launcher".into(),
+                class: WeaponClass::Explosive,
                 damage: 100.0,
                 fire_rate: 1.0,
                 projectile_speed: 400.0,
@@ -366,11 +684,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 4.0,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 80.0,
+                recoil: 0.4,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: true,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::HomingMissile,
                 name: "Homing Missile".into(),
+                class: WeaponClass::Explosive,
                 damage: 80.0,
                 fire_rate: 2.0,
                 projectile_speed: 350.0,
@@ -381,11 +709,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 5.0,
                 pierce_count: 0,
                 homing: true,
+                homing_turn_rate: DEFAULT_HOMING_TURN_RATE,
                 explosive_radius: 60.0,
+                recoil: 0.0,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: true,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::GrenadeLauncher,
                 name: "Grenade Launcher".into(),
+                class: WeaponClass::Explosive,
                 damage: 70.0,
                 fire_rate: 2.0,
                 projectile_speed: 350.0,
@@ -396,12 +734,22 @@ impl WeaponRegistry {
                 projectile_lifetime: 3.0,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 100.0,
+                recoil: 0.0,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: true,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             // Exotic Weapons
             WeaponData {
                 id: WeaponId::BladeCannon,
                 name: "Blade Cannon".into(),
+                class: WeaponClass::Exotic,
                 damage: 35.0,
                 fire_rate: 5.0,
                 projectile_speed: 700.0,
@@ -412,11 +760,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.0,
                 pierce_count: 5,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 100.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::ChainReactor,
                 name: "Chain Reactor".into(),
+                class: WeaponClass::Exotic,
                 damage: 20.0,
                 fire_rate: 4.0,
                 projectile_speed: 500.0,
@@ -427,11 +785,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.5,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 40.0,
+                recoil: 0.0,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::SplitterGun,
                 name: "Splitter Gun".into(),
+                class: WeaponClass::Exotic,
                 damage: 15.0,
                 fire_rate: 3.0,
                 projectile_speed: 600.0,
@@ -442,11 +810,21 @@ impl WeaponRegistry {
                 projectile_lifetime: 2.0,
                 pierce_count: 0,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 0.0,
+                recoil: 0.0,
+                knockback: 50.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
             WeaponData {
                 id: WeaponId::InfernoCannon,
                 name: "Inferno Cannon".into(),
+                class: WeaponClass::Fire,
                 damage: 50.0,
                 fire_rate: 2.0,
                 projectile_speed: 450.0,
@@ -457,17 +835,63 @@ impl WeaponRegistry {
                 projectile_lifetime: 3.0,
                 pierce_count: 2,
                 homing: false,
+                homing_turn_rate: 0.0,
                 explosive_radius: 70.0,
+                recoil: 0.0,
+                knockback: 0.0,
+                spin_up: false,
+                self_damage: false,
+                heat_per_shot: 0.0,
+                cooling_rate: 0.0,
+                hitscan: false,
+                moving_spread_penalty: 0.5,
             },
         ];
     }
 }
 
+#[cfg(test)]
+impl WeaponRegistry {
+    /// Built-in weapon stats keyed by weapon id, for the balance snapshot
+    pub fn snapshot_repr(&self) -> crate::snapshot::SnapshotTable {
+        let mut table = crate::snapshot::SnapshotTable::new();
+        for weapon in &self.weapons {
+            let mut entry = crate::snapshot::SnapshotTable::new();
+            entry
+                .ident("class", weapon.class)
+                .float("damage", weapon.damage)
+                .float("fire_rate", weapon.fire_rate)
+                .float("projectile_speed", weapon.projectile_speed)
+                .float("spread", weapon.spread)
+                .int("projectiles_per_shot", weapon.projectiles_per_shot)
+                .ident("ammo_capacity", weapon.ammo_capacity)
+                .float("reload_time", weapon.reload_time)
+                .float("projectile_lifetime", weapon.projectile_lifetime)
+                .int("pierce_count", weapon.pierce_count)
+                .flag("homing", weapon.homing)
+                .float("homing_turn_rate", weapon.homing_turn_rate)
+                .float("explosive_radius", weapon.explosive_radius)
+                .float("recoil", weapon.recoil)
+                .float("knockback", weapon.knockback)
+                .flag("spin_up", weapon.spin_up)
+                .flag("self_damage", weapon.self_damage)
+                .float("heat_per_shot", weapon.heat_per_shot)
+                .float("cooling_rate", weapon.cooling_rate)
+                .flag("hitscan", weapon.hitscan)
+                .float("moving_spread_penalty", weapon.moving_spread_penalty);
+            table.table(&format!("{:?}", weapon.id), entry);
+        }
+        table
+    }
+}
+
 /// Data for a weapon type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeaponData {
     pub id: WeaponId,
     pub name: String,
+    /// Family the weapon belongs to, for class-specific perks
+    pub class: WeaponClass,
     pub damage: f32,
     /// Shots per second
     pub fire_rate: f32,
@@ -482,8 +906,33 @@ pub struct WeaponData {
     /// Number of enemies a projectile can pass through
     pub pierce_count: u32,
     pub homing: bool,
+    /// Radians per second a homing projectile can turn
+    pub homing_turn_rate: f32,
     /// 0 means no explosion
     pub explosive_radius: f32,
+    /// Kick of a single shot, 0-1; drives the controller rumble pulse
+    pub recoil: f32,
+    /// Shove speed a hit gives a creature of mass 1, in pixels per second
+    #[serde(default)]
+    pub knockback: f32,
+    /// Fire rate ramps up from SPIN_UP_MIN_RATE while the trigger is held (Minigun)
+    #[serde(default)]
+    pub spin_up: bool,
+    /// Its explosions also hurt players caught in the blast
+    #[serde(default)]
+    pub self_damage: bool,
+    /// Heat a shot adds, as a fraction of the overheat point; 0 never overheats
+    #[serde(default)]
+    pub heat_per_shot: f32,
+    /// Heat shed per second
+    #[serde(default)]
+    pub cooling_rate: f32,
+    /// Fires an instant beam instead of projectiles; range is projectile_speed * projectile_lifetime
+    #[serde(default)]
+    pub hitscan: bool,
+    /// Extra spread at a full run, as a fraction of the base spread (at least MOVING_SPREAD_FLOOR)
+    #[serde(default = "default_moving_spread_penalty")]
+    pub moving_spread_penalty: f32,
 }
 
 impl WeaponData {
@@ -498,6 +947,57 @@ impl WeaponData {
     pub fn is_explosive(&self) -> bool {
         self.explosive_radius > 0.0
     }
+
+    pub fn builds_heat(&self) -> bool {
+        self.heat_per_shot > 0.0
+    }
+
+    /// Why the stats are unusable, or Ok if they can be fired
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if !self.fire_rate.is_finite() || self.fire_rate <= 0.0 {
+            return Err("fire_rate must be positive");
+        }
+        if self.projectiles_per_shot == 0 {
+            return Err("projectiles_per_shot must be at least 1");
+        }
+        if self.heat_per_shot > 0.0 && self.cooling_rate <= 0.0 {
+            return Err("cooling_rate must be positive for a weapon that heats up");
+        }
+        Ok(())
+    }
+}
+
+/// Loads [`WEAPONS_FILE`] over the built-in weapons. A missing file keeps the
+/// defaults; an unreadable or malformed one is logged and ignored.
+pub fn load_weapon_table(mut registry: ResMut<WeaponRegistry>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let text = match std::fs::read_to_string(WEAPONS_FILE) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return,
+            Err(err) => {
+                warn!("Cannot read {}, using built-in weapons: {}", WEAPONS_FILE, err);
+                return;
+            }
+        };
+        match registry.apply_table(&text) {
+            Ok(applied) => info!("Loaded {} weapons from {}", applied, WEAPONS_FILE),
+            Err(err) => warn!("Ignoring invalid {}, using built-in weapons: {}", WEAPONS_FILE, err),
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = &mut registry;
+}
+
+/// A weapon added by a mod
+#[derive(Debug, Clone)]
+pub struct CustomWeapon {
+    /// Namespaced id ("modname:entry")
+    pub id: String,
+    /// Drop tier; 0 never drops
+    pub tier: u8,
+    /// Stats. `data.id` is the built-in weapon whose visuals, sounds and special behavior are reused.
+    pub data: WeaponData,
 }
 
 #[cfg(test)]
@@ -520,6 +1020,7 @@ mod tests {
         let weapon = WeaponData {
             id: WeaponId::Pistol,
             name: "Test".into(),
+            class: WeaponClass::Bullet,
             damage: 10.0,
             fire_rate: 5.0, // 5 shots per second
             projectile_speed: 500.0,
@@ -530,7 +1031,16 @@ mod tests {
             projectile_lifetime: 2.0,
             pierce_count: 0,
             homing: false,
+            homing_turn_rate: 0.0,
             explosive_radius: 0.0,
+            recoil: 0.0,
+            knockback: 0.0,
+            spin_up: false,
+            self_damage: false,
+            heat_per_shot: 0.0,
+            cooling_rate: 0.0,
+            hitscan: false,
+            moving_spread_penalty: 0.5,
         };
 
         assert!((weapon.fire_cooldown() - 0.2).abs() < 0.001);
@@ -556,4 +1066,99 @@ mod tests {
         let homing = registry.get(WeaponId::HomingMissile).unwrap();
         assert!(homing.homing);
     }
+
+    #[test]
+    fn higher_tiers_ramp_in_with_game_time() {
+        assert_eq!(drop_weight(1, 0.0), 1.0);
+        assert_eq!(drop_weight(2, 0.0), TIER_MIN_SHARE);
+        assert_eq!(drop_weight(2, 60.0), 0.5);
+        assert_eq!(drop_weight(2, 120.0), 1.0);
+        assert_eq!(drop_weight(3, 120.0), 0.5);
+        assert_eq!(drop_weight(3, 1000.0), 1.0);
+    }
+
+    #[test]
+    fn early_rolls_favour_tier_one() {
+        use rand::SeedableRng;
+        let registry = WeaponRegistry::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let tier_one = |key: &WeaponKey| {
+            BUILTIN_DROP_POOL
+                .iter()
+                .any(|&(id, tier)| tier == 1 && *key == WeaponKey::Builtin(id))
+        };
+        let count_tier_one = |game_time: f32, rng: &mut rand::rngs::StdRng| {
+            (0..1000)
+                .filter(|_| tier_one(&registry.roll_drop(game_time, rng).unwrap()))
+                .count()
+        };
+        let early = count_tier_one(0.0, &mut rng);
+        let late = count_tier_one(600.0, &mut rng);
+        assert!(early > 700, "{} tier-1 drops at the start", early);
+        assert!(late < 400, "{} tier-1 drops late", late);
+    }
+
+    #[test]
+    fn default_table_round_trips_through_ron() {
+        let defaults = WeaponRegistry::new();
+        let text = ron::ser::to_string_pretty(&defaults.weapons, Default::default()).unwrap();
+
+        let mut loaded = WeaponRegistry::new();
+        loaded.weapons.clear();
+        assert_eq!(loaded.apply_table(&text).unwrap(), defaults.weapons.len());
+        assert_eq!(loaded.weapons, defaults.weapons);
+    }
+
+    #[test]
+    fn invalid_entries_keep_the_built_in_stats() {
+        let mut registry = WeaponRegistry::new();
+        let mut shotgun = registry.get(WeaponId::Shotgun).unwrap().clone();
+        let mut pistol = registry.get(WeaponId::Pistol).unwrap().clone();
+        shotgun.projectiles_per_shot = 0;
+        pistol.damage = 99.0;
+        let text = ron::to_string(&vec![shotgun, pistol]).unwrap();
+
+        assert_eq!(registry.apply_table(&text).unwrap(), 1);
+        assert_eq!(registry.get(WeaponId::Shotgun), WeaponRegistry::new().get(WeaponId::Shotgun));
+        assert_eq!(registry.get(WeaponId::Pistol).unwrap().damage, 99.0);
+    }
+
+    #[test]
+    fn malformed_table_changes_nothing() {
+        let mut registry = WeaponRegistry::new();
+        assert!(registry.apply_table("[(id: Pistol, damage: ").is_err());
+        assert_eq!(registry.weapons, WeaponRegistry::new().weapons);
+    }
+
+    #[test]
+    fn omitted_optional_fields_take_the_documented_defaults() {
+        let mut registry = WeaponRegistry::new();
+        let text = "[(id: Shotgun, name: \"Shotgun\", class: Shotgun, damage: 12.0, fire_rate: 1.5, \
+                    projectile_speed: 600.0, spread: 0.3, projectiles_per_shot: 8, ammo_capacity: Some(12), \
+                    reload_time: 2.0, projectile_lifetime: 0.5, pierce_count: 0, homing: false, \
+                    homing_turn_rate: 0.0, explosive_radius: 0.0, recoil: 0.5)]";
+        assert_eq!(registry.apply_table(text).unwrap(), 1);
+
+        // An entry replaces the whole weapon, so the built-in Shotgun's knockback doesn't carry over
+        let shotgun = registry.get(WeaponId::Shotgun).unwrap();
+        assert_eq!(shotgun.knockback, 0.0);
+        assert!(!shotgun.spin_up && !shotgun.self_damage && !shotgun.hitscan);
+        assert_eq!((shotgun.heat_per_shot, shotgun.cooling_rate), (0.0, 0.0));
+        assert_eq!(shotgun.moving_spread_penalty, DEFAULT_MOVING_SPREAD_PENALTY);
+    }
+
+    #[test]
+    fn shipped_table_matches_the_built_in_weapons() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(WEAPONS_FILE);
+        let mut registry = WeaponRegistry::new();
+        let defaults = registry.weapons.clone();
+        if std::env::var_os(crate::snapshot::BLESS_ENV).is_some() {
+            let text = ron::ser::to_string_pretty(&defaults, Default::default()).unwrap();
+            std::fs::write(&path, text + "\n").unwrap();
+        }
+
+        let text = std::fs::read_to_string(&path).expect("shipped weapon table");
+        assert_eq!(registry.apply_table(&text).unwrap(), defaults.len());
+        assert_eq!(registry.weapons, defaults);
+    }
 }
@@ -1,117 +1,315 @@
 //! Weapon systems
 
 use bevy::prelude::*;
+use bevy::utils::HashMap;
 use rand::Rng;
 
 use super::components::*;
-use super::registry::WeaponRegistry;
+use super::pool::ProjectilePool;
+use super::registry::{WeaponData, WeaponRegistry};
+use crate::assists::{aim_ray_hits, assisted_direction, AimAssistSettings, AssistedAim, RunAssists};
 use crate::bonuses::components::ActiveBonusEffects;
-use crate::creatures::{Creature, CreatureHealth, CreatureSpeed, FrozenStatus, MarkedForDespawn};
-use crate::perks::components::PerkBonuses;
-use crate::player::components::{AimDirection, Firing, Player};
+use crate::creatures::{
+    resize_creature, ContactDamage, Creature, CreatureGrid, CreatureHealth, CreatureSpeed, CreatureType,
+    FrozenStatus, GhostPhase, Knockback, MarkedForDespawn, Shrunk, StatusEffects, StatusKind, FROZEN_TINT,
+};
+use crate::effects::{EffectType, SpawnEffectEvent};
+use crate::perks::components::{JinxState, PerkBonuses, ANXIOUS_LOADER_RELOAD_CUT};
+use crate::player::components::{AimDirection, Firing, MoveSpeed, Player, StationaryTimer};
+use crate::player::systems::PlayerDamageEvent;
+use crate::viewport::ArenaBounds;
 
 /// Event to fire a weapon
 #[derive(Event)]
 pub struct FireWeaponEvent {
     pub shooter: Entity,
     pub position: Vec3,
+    /// Shot direction, after aim assist
     pub direction: Vec2,
+    /// Weapon whose visuals and sounds the shot uses (a custom weapon's base)
     pub weapon_id: WeaponId,
+    /// Weapon whose stats the projectiles get
+    pub weapon: WeaponKey,
+    /// Aim point; grenades land here when it is in range
+    pub target: Option<Vec2>,
+}
+
+/// Event when a player is handed a new weapon (weapon pickups), for the pickup cues
+#[derive(Event)]
+pub struct WeaponGrantedEvent {
+    pub position: Vec3,
+}
+
+/// Event when an empty weapon that cannot reload is swapped out for the Pistol
+#[derive(Event)]
+pub struct OutOfAmmoEvent {
+    pub player_entity: Entity,
+    pub position: Vec3,
 }
 
 /// Event when a projectile hits something
 #[derive(Event)]
 pub struct ProjectileHitEvent {
+    /// The projectile, or the shooter for a hitscan beam
     pub projectile: Entity,
     pub target: Entity,
+    /// Damage dealt, after the critical hit roll
     pub damage: f32,
     pub position: Vec3,
+    pub was_crit: bool,
+    /// Highlander's instant kill went off
+    pub instant_kill: bool,
+}
+
+/// Event when an explosive projectile detonates, whether on a hit or at the end of its flight
+#[derive(Event, Debug, Clone)]
+pub struct ExplosionEvent {
+    pub position: Vec3,
+    pub radius: f32,
+    pub damage: f32,
+    /// Creatures the projectile already hit directly, which the blast leaves alone
+    pub spared: Vec<Entity>,
+    /// Whoever set it off: the shooter, or the creature that blew up
+    pub owner: Entity,
+    /// The blast also hurts players
+    pub self_damage: bool,
+}
+
+/// Highlander procs on bosses at this fraction of the shooter's chance
+pub const HIGHLANDER_BOSS_CHANCE_SCALE: f32 = 0.1;
+
+/// Whether a hit kills outright (Highlander). Bosses shrug off most of the chance
+pub fn roll_instant_kill(bonuses: &PerkBonuses, creature_type: CreatureType, rng: &mut impl Rng) -> bool {
+    let chance = if creature_type.is_boss() {
+        bonuses.instant_kill_chance * HIGHLANDER_BOSS_CHANCE_SCALE
+    } else {
+        bonuses.instant_kill_chance
+    };
+    chance > 0.0 && rng.gen::<f32>() < chance
+}
+
+/// Damage after a critical hit roll against the shooter's crit chance, and whether it crit
+pub fn roll_crit(damage: f32, bonuses: &PerkBonuses, rng: &mut impl Rng) -> (f32, bool) {
+    if bonuses.crit_chance > 0.0 && rng.gen::<f32>() < bonuses.crit_chance {
+        (damage * bonuses.crit_multiplier, true)
+    } else {
+        (damage, false)
+    }
 }
 
-/// System that handles weapon firing from player input
-/// Integrates perk bonuses: fire_rate_multiplier, damage_multiplier, crit_chance, accuracy_bonus, range_multiplier
+/// Turns held fire into [`FireWeaponEvent`]s: cooldown, ammo, heat and fire rate
+/// (fire_rate_multiplier, the fire rate pickup, a Jinxed burst and spin-up). Projectiles are spawned from
+/// the events by [`spawn_projectiles_from_fire_events`].
+/// Aim assist bends the shot direction only; the visible aim is left untouched
 #[allow(clippy::type_complexity)]
 pub fn fire_weapon_system(
-    mut commands: Commands,
     weapon_registry: Res<WeaponRegistry>,
     time: Res<Time>,
+    assist_settings: Res<AimAssistSettings>,
+    run_assists: Res<RunAssists>,
     mut query: Query<
         (
             Entity,
             &Transform,
             &AimDirection,
+            Option<&AssistedAim>,
             &Firing,
             &mut EquippedWeapon,
             &PerkBonuses,
             &ActiveBonusEffects,
+            Option<&JinxState>,
         ),
         With<Player>,
     >,
+    creature_query: Query<&Transform, (With<Creature>, Without<MarkedForDespawn>, Without<Player>)>,
     mut fire_events: EventWriter<FireWeaponEvent>,
 ) {
-    for (entity, transform, aim, firing, mut weapon, perk_bonuses, bonus_effects) in query.iter_mut()
+    let assist_strength = run_assists.effective_strength(&assist_settings);
+
+    for (entity, transform, aim, assisted_aim, firing, mut weapon, perk_bonuses, bonus_effects, jinx) in
+        query.iter_mut()
     {
         // Update cooldown
         weapon.fire_cooldown = (weapon.fire_cooldown - time.delta_seconds()).max(0.0);
 
-        if !firing.is_firing || !weapon.can_fire() {
+        let Some(weapon_data) = weapon_registry.get_key(&weapon.weapon) else {
+            // Custom weapon whose mod is no longer loaded
+            *weapon = EquippedWeapon::default();
             continue;
+        };
+        if weapon_data.spin_up {
+            weapon.update_spin(firing.is_firing, time.delta_seconds());
         }
+        weapon.cool(weapon_data.cooling_rate * time.delta_seconds());
 
-        let Some(weapon_data) = weapon_registry.get(weapon.weapon_id) else {
+        if !firing.is_firing || !weapon.can_fire() {
             continue;
-        };
+        }
 
-        // Fire projectiles
-        let mut rng = rand::thread_rng();
-        // Use aim.direction for muzzle flash offset (slightly in front of player)
+        // Muzzle slightly in front of the player
         let muzzle_offset = aim.direction * 20.0;
         let position = transform.translation + Vec3::new(muzzle_offset.x, muzzle_offset.y, 0.0);
 
-        for _ in 0..weapon_data.projectiles_per_shot {
-            // Apply spread with accuracy bonus (accuracy reduces spread)
-            let spread_reduction = 1.0 - perk_bonuses.accuracy_bonus.min(0.9); // Cap at 90% reduction
-            let effective_spread = weapon_data.spread * spread_reduction;
-            let spread_angle = rng.gen_range(-effective_spread..effective_spread);
-            let base_angle = aim.angle;
-            let final_angle = base_angle + spread_angle;
-            let direction = Vec2::new(final_angle.cos(), final_angle.sin());
+        // Sticky targeting lags the raw aim; magnetism then bends toward a nearby creature
+        let raw_direction = assisted_aim.map_or(aim.direction, AssistedAim::direction);
+        let fire_direction = assisted_direction(
+            transform.translation.truncate(),
+            raw_direction,
+            creature_query.iter().map(|t| t.translation.truncate()),
+            assist_strength,
+        );
 
-            // Calculate damage with perk and bonus effects
-            let mut damage = weapon_data.damage * perk_bonuses.damage_multiplier;
-            if bonus_effects.has_damage_boost() {
-                damage *= 1.5; // 50% damage boost from pickup
-            }
+        fire_events.send(FireWeaponEvent {
+            shooter: entity,
+            position,
+            direction: fire_direction,
+            weapon_id: weapon_data.id,
+            weapon: weapon.weapon.clone(),
+            target: aim.target,
+        });
 
-            // Check for critical hit
-            if perk_bonuses.crit_chance > 0.0 && rng.gen::<f32>() < perk_bonuses.crit_chance {
-                damage *= perk_bonuses.crit_multiplier;
-            }
+        // Consume ammo and set cooldown (fire rate multiplier reduces cooldown).
+        // Heat is per shot, so faster firing overheats sooner
+        weapon.consume_ammo();
+        weapon.add_heat(weapon_data.heat_per_shot);
+        let mut fire_rate_mult = perk_bonuses.fire_rate_multiplier;
+        if bonus_effects.has_fire_rate_boost() {
+            fire_rate_mult *= 1.5; // 50% faster fire rate from pickup
+        }
+        if let Some(jinx) = jinx {
+            fire_rate_mult *= jinx.fire_rate_multiplier();
+        }
+        if weapon_data.spin_up {
+            fire_rate_mult *= weapon.spin_rate();
+        }
+        weapon.fire_cooldown = weapon_data.fire_cooldown() / fire_rate_mult;
+    }
+}
+
+/// Damage of one projectile or beam with the shooter's perks and the damage pickup.
+/// `still_seconds` is how long the shooter has stood still (LivingFortress)
+fn shot_damage(weapon_data: &WeaponData, perk_bonuses: &PerkBonuses, damage_boost: bool, still_seconds: f32) -> f32 {
+    let mut damage = weapon_data.damage
+        * perk_bonuses.damage_multiplier
+        * perk_bonuses.class_damage_multiplier(weapon_data.class)
+        * perk_bonuses.living_fortress_multiplier(still_seconds);
+    if damage_boost {
+        damage *= 1.5; // 50% damage boost from pickup
+    }
+    damage
+}
+
+/// Shot direction with the weapon's spread, widened by the shooter's movement bloom
+/// and narrowed by accuracy_bonus (capped at 90%)
+fn spread_direction(
+    base_angle: f32,
+    weapon_data: &WeaponData,
+    perk_bonuses: &PerkBonuses,
+    bloom: Option<&SpreadBloom>,
+    rng: &mut impl Rng,
+) -> Vec2 {
+    let spread = bloom.map_or(weapon_data.spread, |bloom| {
+        bloom.spread(weapon_data.spread, weapon_data.moving_spread_penalty)
+    });
+    let spread_reduction = 1.0 - perk_bonuses.accuracy_bonus.min(0.9);
+    let effective_spread = spread * spread_reduction;
+    let spread_angle = if effective_spread > 0.0 {
+        rng.gen_range(-effective_spread..effective_spread)
+    } else {
+        0.0
+    };
+    Vec2::from_angle(base_angle + spread_angle)
+}
+
+/// Spawns the projectiles of every fired shot, whoever sent the event.
+/// Integrates the shooter's perk bonuses: damage_multiplier, accuracy_bonus,
+/// projectile_speed_multiplier, range_multiplier; shooters without perks fire base stats
+#[allow(clippy::type_complexity)]
+pub fn spawn_projectiles_from_fire_events(
+    mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
+    weapon_registry: Res<WeaponRegistry>,
+    mut fire_events: EventReader<FireWeaponEvent>,
+    shooter_query: Query<(
+        Option<&PerkBonuses>,
+        Option<&ActiveBonusEffects>,
+        Option<&SpreadBloom>,
+        Option<&StationaryTimer>,
+    )>,
+) {
+    let no_perks = PerkBonuses::default();
+    let mut rng = rand::thread_rng();
+
+    for event in fire_events.read() {
+        let Some(weapon_data) = weapon_registry.get_key(&event.weapon) else {
+            continue;
+        };
+        // Beams are traced by fire_hitscan_weapons
+        if weapon_data.hitscan {
+            continue;
+        }
+        let (perk_bonuses, bonus_effects, bloom, stationary) =
+            shooter_query.get(event.shooter).unwrap_or((None, None, None, None));
+        let still_seconds = stationary.map_or(0.0, |timer| timer.seconds);
+        let perk_bonuses = perk_bonuses.unwrap_or(&no_perks);
+        let damage_boost = bonus_effects.is_some_and(ActiveBonusEffects::has_damage_boost);
+        let entity = event.shooter;
+        let position = event.position;
+        let base_angle = event.direction.y.atan2(event.direction.x);
 
-            // Apply range multiplier to projectile lifetime
+        for _ in 0..weapon_data.projectiles_per_shot {
+            let direction = spread_direction(base_angle, weapon_data, perk_bonuses, bloom, &mut rng);
+
+            let damage = shot_damage(weapon_data, perk_bonuses, damage_boost, still_seconds);
+
+            // Apply speed and range multipliers
+            let projectile_speed = weapon_data.projectile_speed * perk_bonuses.projectile_speed_multiplier;
             let projectile_lifetime = weapon_data.projectile_lifetime * perk_bonuses.range_multiplier;
 
             // Determine projectile color based on weapon type
-            let color = get_projectile_color(weapon.weapon_id);
-            let size = get_projectile_size(weapon.weapon_id);
+            let color = get_projectile_color(weapon_data.id);
+            let size = get_projectile_size(weapon_data.id);
+
+            // Grenades are lobbed at the cursor (or as far as they carry); spread scatters the landing spot
+            let grenade_arc = (weapon_data.id == WeaponId::GrenadeLauncher).then(|| {
+                let origin = position.truncate();
+                let max_range = projectile_speed * projectile_lifetime;
+                let distance = event
+                    .target
+                    .map_or(max_range, |target| origin.distance(target).min(max_range));
+                let flight_time = (distance / projectile_speed).max(MIN_GRENADE_FLIGHT_TIME);
+                (GrenadeArc::new(origin + direction * distance, flight_time), distance)
+            });
 
             // Spawn projectile
-            let mut projectile_commands = commands.spawn(ProjectileBundle::new(
-                weapon.weapon_id,
-                damage,
-                entity,
-                position,
-                direction,
-                weapon_data.projectile_speed,
-                projectile_lifetime,
-                color,
-                size,
-            ));
+            let mut projectile_commands = pool.spawn(
+                &mut commands,
+                ProjectileBundle::new(
+                    weapon_data.id,
+                    damage,
+                    entity,
+                    position,
+                    direction,
+                    projectile_speed,
+                    projectile_lifetime,
+                    color,
+                    size,
+                )
+                .with_pierce(weapon_data.pierce_count),
+            );
+
+            if let Some((arc, distance)) = grenade_arc {
+                projectile_commands.insert((
+                    Velocity(direction * distance / arc.flight_time),
+                    Lifetime::new(arc.flight_time),
+                    arc,
+                ));
+            }
 
             // Add homing component if needed
             if weapon_data.homing {
                 projectile_commands.insert(Homing {
-                    turn_rate: 3.0,
+                    turn_rate: weapon_data.homing_turn_rate,
                     target: None,
                 });
             }
@@ -119,13 +317,14 @@ pub fn fire_weapon_system(
             // Add explosive component if needed
             if weapon_data.is_explosive() {
                 projectile_commands.insert(Explosive {
-                    radius: weapon_data.explosive_radius,
+                    radius: weapon_data.explosive_radius * perk_bonuses.class_aoe_multiplier(weapon_data.class),
                     damage,
+                    self_damage: weapon_data.self_damage,
                 });
             }
 
             // Add special weapon components
-            match weapon.weapon_id {
+            match weapon_data.id {
                 WeaponId::ChainReactor => {
                     projectile_commands.insert(ChainLightning::new(5, 150.0, 0.8));
                 }
@@ -138,40 +337,108 @@ pub fn fire_weapon_system(
                         duration: 3.0,
                     });
                 }
+                WeaponId::BladeCannon => {
+                    projectile_commands.insert(Ricochet::new(3, 0.85));
+                }
+                WeaponId::ShrinkRay => {
+                    projectile_commands.insert(Shrinking {
+                        per_hit: 0.75,
+                        duration: 5.0,
+                    });
+                }
                 _ => {}
             }
+            if weapon_data.knockback > 0.0 {
+                projectile_commands.insert(HitKnockback(weapon_data.knockback));
+            }
+            if let Some(igniting) = Igniting::for_weapon(weapon_data.id, perk_bonuses.fire_damage_multiplier) {
+                projectile_commands.insert(igniting);
+            }
+            if weapon_data.is_explosive() {
+                if let Some(incendiary) = Incendiary::for_weapon(weapon_data.id, perk_bonuses.fire_damage_multiplier) {
+                    projectile_commands.insert(incendiary);
+                }
+            }
         }
 
-        // Consume ammo and set cooldown (fire rate multiplier reduces cooldown)
-        weapon.consume_ammo();
-        let mut fire_rate_mult = perk_bonuses.fire_rate_multiplier;
-        if bonus_effects.has_fire_rate_boost() {
-            fire_rate_mult *= 1.5; // 50% faster fire rate from pickup
-        }
-        weapon.fire_cooldown = weapon_data.fire_cooldown() / fire_rate_mult;
+    }
+}
 
-        // Send fire event for audio and visual effects
-        fire_events.send(FireWeaponEvent {
-            shooter: entity,
-            position,
-            direction: Vec2::new(aim.angle.cos(), aim.angle.sin()),
-            weapon_id: weapon.weapon_id,
-        });
+/// Blooms each player's spread from how fast they moved since the last frame
+pub fn update_spread_bloom(
+    time: Res<Time>,
+    mut query: Query<(&Transform, &MoveSpeed, &mut SpreadBloom), With<Player>>,
+) {
+    let delta = time.delta_seconds();
+    for (transform, move_speed, mut bloom) in query.iter_mut() {
+        let moved = bloom.moved_to(transform.translation.truncate());
+        let movement = if delta > 0.0 && move_speed.0 > 0.0 {
+            moved / delta / move_speed.0
+        } else {
+            0.0
+        };
+        bloom.update(movement, delta);
+    }
+}
+
+/// Keeps players' clip sizes in line with their clip size perks (AmmoManiac,
+/// MyFavouriteWeapon), so a newly equipped weapon or a newly picked perk resizes
+/// the clip, keeping it as full as it was. The stowed weapon follows along
+pub fn apply_clip_size_bonuses(
+    mut query: Query<(&PerkBonuses, &mut EquippedWeapon, Option<&mut AlternateWeaponSlot>), With<Player>>,
+) {
+    let clip_size = |bonuses: &PerkBonuses, weapon: &EquippedWeapon| {
+        let clip_size = bonuses.clip_size(weapon.base_clip?);
+        weapon.max_ammo.is_some_and(|max| max != clip_size).then_some(clip_size)
+    };
+    for (bonuses, mut weapon, slot) in query.iter_mut() {
+        if let Some(size) = clip_size(bonuses, &weapon) {
+            weapon.set_clip_size(size);
+        }
+        let Some(mut slot) = slot else {
+            continue;
+        };
+        if let Some(size) = slot.stowed.as_ref().and_then(|stowed| clip_size(bonuses, stowed)) {
+            if let Some(stowed) = slot.stowed.as_mut() {
+                stowed.set_clip_size(size);
+            }
+        }
     }
 }
 
-/// System that handles weapon reloading
-/// Uses reload_speed_multiplier from perks to speed up reloads
+/// System that handles weapon reloading.
+/// Reloads progress at reload_speed_multiplier (Fastloader) times
+/// stationary_reload_multiplier (StationaryReloader) while the player stands still;
+/// with AnxiousLoader each fresh pull of the trigger then takes a flat
+/// ANXIOUS_LOADER_RELOAD_CUT off what remains
+#[allow(clippy::type_complexity)]
 pub fn weapon_reload_system(
     time: Res<Time>,
     weapon_registry: Res<WeaponRegistry>,
-    mut query: Query<(&mut EquippedWeapon, &PerkBonuses), With<Player>>,
+    mut query: Query<
+        (&mut EquippedWeapon, &PerkBonuses, Option<&StationaryTimer>, Option<&mut Firing>),
+        With<Player>,
+    >,
 ) {
-    for (mut weapon, perk_bonuses) in query.iter_mut() {
+    for (mut weapon, perk_bonuses, stationary, firing) in query.iter_mut() {
+        let stationary = stationary.is_some_and(|timer| timer.seconds > 0.0);
+        let trigger_pulled = firing.is_some_and(|mut firing| {
+            let is_firing = firing.is_firing;
+            let was_firing = std::mem::replace(&mut firing.was_firing, is_firing);
+            is_firing && !was_firing
+        });
+
         // If currently reloading, update the timer
         if weapon.is_reloading() {
-            // Apply reload speed multiplier from perks
-            let reload_speed = time.delta_seconds() * perk_bonuses.reload_speed_multiplier;
+            // Apply reload speed multipliers from perks
+            let mut multiplier = perk_bonuses.reload_speed_multiplier;
+            if stationary {
+                multiplier *= perk_bonuses.stationary_reload_multiplier;
+            }
+            let mut reload_speed = time.delta_seconds() * multiplier;
+            if perk_bonuses.anxious_loader && trigger_pulled {
+                reload_speed += ANXIOUS_LOADER_RELOAD_CUT;
+            }
             weapon.reload_timer = (weapon.reload_timer - reload_speed).max(0.0);
 
             // Reload complete
@@ -180,7 +447,7 @@ pub fn weapon_reload_system(
             }
         } else if !weapon.has_ammo() {
             // Start reload if out of ammo
-            if let Some(weapon_data) = weapon_registry.get(weapon.weapon_id) {
+            if let Some(weapon_data) = weapon_registry.get_key(&weapon.weapon) {
                 let base_reload_time = weapon_data.reload_time;
                 if base_reload_time > 0.0 {
                     weapon.start_reload(base_reload_time);
@@ -190,6 +457,37 @@ pub fn weapon_reload_system(
     }
 }
 
+/// Swaps an empty weapon that cannot reload for the infinite-ammo Pistol.
+/// With a second slot the empty weapon is stowed (trading places with any stowed
+/// weapon); otherwise it is dropped. A weapon pickup hands out a full clip again.
+pub fn fall_back_to_pistol(
+    weapon_registry: Res<WeaponRegistry>,
+    mut query: Query<(Entity, &Transform, &mut EquippedWeapon, Option<&mut AlternateWeaponSlot>), With<Player>>,
+    mut out_of_ammo_events: EventWriter<OutOfAmmoEvent>,
+) {
+    for (entity, transform, mut weapon, alternate_slot) in query.iter_mut() {
+        if weapon.has_ammo() || weapon.is_reloading() {
+            continue;
+        }
+        let reloads = weapon_registry
+            .get_key(&weapon.weapon)
+            .is_some_and(|data| data.reload_time > 0.0);
+        if reloads {
+            continue;
+        }
+
+        out_of_ammo_events.send(OutOfAmmoEvent {
+            player_entity: entity,
+            position: transform.translation,
+        });
+        match alternate_slot {
+            Some(mut slot) if slot.stowed.is_some() => slot.swap(&mut weapon),
+            Some(mut slot) => slot.stowed = Some(std::mem::take(&mut *weapon)),
+            None => *weapon = EquippedWeapon::default(),
+        }
+    }
+}
+
 fn get_projectile_color(weapon_id: WeaponId) -> Color {
     match weapon_id {
         WeaponId::Pistol | WeaponId::Magnum => Color::srgb(1.0, 0.9, 0.3),
@@ -226,14 +524,50 @@ fn get_projectile_size(weapon_id: WeaponId) -> f32 {
 }
 
 /// Moves projectiles based on their velocity
+/// Ricocheting projectiles bounce off the arena edge
+#[allow(clippy::type_complexity)]
 pub fn projectile_movement(
     time: Res<Time>,
-    mut query: Query<(&mut Transform, &Velocity), With<Projectile>>,
+    arena: Res<ArenaBounds>,
+    mut query: Query<
+        (&mut Transform, &mut Velocity, Option<(&mut Ricochet, &mut Projectile)>),
+        With<Projectile>,
+    >,
 ) {
-    for (mut transform, velocity) in query.iter_mut() {
+    for (mut transform, mut velocity, ricochet) in query.iter_mut() {
         transform.translation.x += velocity.0.x * time.delta_seconds();
         transform.translation.y += velocity.0.y * time.delta_seconds();
+
+        let Some((mut ricochet, mut projectile)) = ricochet else {
+            continue;
+        };
+        if ricochet.bounces_remaining == 0 {
+            continue;
+        }
+        let mut position = transform.translation.truncate();
+        if reflect_off_bounds(&mut position, &mut velocity.0, arena.half_extents) {
+            transform.translation = position.extend(transform.translation.z);
+            ricochet.bounces_remaining -= 1;
+            projectile.damage *= ricochet.damage_retained;
+            // A blade coming back can cut through the same creatures again
+            projectile.already_hit.clear();
+        }
+    }
+}
+
+/// Mirrors a point that left the `half_extents` box back inside it, flipping the
+/// velocity on each crossed axis. Returns true if it bounced.
+pub fn reflect_off_bounds(position: &mut Vec2, velocity: &mut Vec2, half_extents: Vec2) -> bool {
+    let mut bounced = false;
+    for axis in 0..2 {
+        let limit = half_extents[axis];
+        if position[axis].abs() > limit && position[axis] * velocity[axis] > 0.0 {
+            position[axis] = position[axis].signum() * 2.0 * limit - position[axis];
+            velocity[axis] = -velocity[axis];
+            bounced = true;
+        }
     }
+    bounced
 }
 
 /// Updates homing projectiles to track targets
@@ -304,52 +638,83 @@ pub fn homing_projectile_update(
     }
 }
 
+/// Projectiles hit creatures within this distance
+pub const COLLISION_RADIUS: f32 = 20.0;
+/// Damage each further creature on a hitscan beam takes, relative to the one before it
+pub const HITSCAN_PIERCE_FALLOFF: f32 = 0.8;
+/// Fraction of a player-made blast's damage that players caught in it take
+pub const PLAYER_BLAST_DAMAGE_SCALE: f32 = 0.5;
+/// Fastest a creature can be sent flying by hits, however many land at once
+pub const MAX_HIT_KNOCKBACK_SPEED: f32 = 500.0;
+
 /// Handles projectile collision with creatures
-/// Also handles special weapon effects: chain lightning, splitter, freezing
+/// Also handles special weapon effects: chain lightning, splitter, freezing, knockback
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn projectile_collision(
     mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
     mut projectile_query: Query<
         (
             Entity,
             &Transform,
             &mut Projectile,
-            Option<&Explosive>,
             Option<&mut ChainLightning>,
             Option<&Splitter>,
             Option<&Freezing>,
+            Option<&Igniting>,
+            Option<(&HitKnockback, &Velocity)>,
         ),
-        Without<ProjectileDespawn>,
+        // Grenades in flight pass over everything
+        (Without<ProjectileDespawn>, Without<GrenadeArc>),
     >,
     mut creature_query: Query<
-        (Entity, &Transform, &mut CreatureHealth, &mut CreatureSpeed),
-        (With<Creature>, Without<MarkedForDespawn>),
+        (Entity, &Transform, &mut CreatureHealth, Option<&mut StatusEffects>, &Creature, Option<&GhostPhase>),
+        Without<MarkedForDespawn>,
     >,
+    mut freezable_query: Query<(&CreatureSpeed, &mut Sprite, Option<&mut FrozenStatus>), With<Creature>>,
+    mut knockback_query: Query<(&Creature, Option<&mut Knockback>)>,
+    owner_query: Query<&PerkBonuses>,
+    creature_grid: Res<CreatureGrid>,
     mut hit_events: EventWriter<ProjectileHitEvent>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
 ) {
-    const COLLISION_RADIUS: f32 = 20.0;
 
     // Collect data for effects to apply after the main loop
-    let mut explosions: Vec<(Vec2, f32, f32, Entity)> = Vec::new();
     let mut chain_spawns: Vec<(Vec2, f32, u32, f32, f32, Vec<Entity>, Entity)> = Vec::new();
     let mut split_spawns: Vec<(Vec2, Vec2, f32, u32, u32, f32, Entity)> = Vec::new();
-    let mut freeze_targets: Vec<(Entity, f32, f32, f32)> = Vec::new(); // (entity, duration, original_speed, slow_amount)
+    let mut freeze_targets: Vec<(Entity, f32, f32)> = Vec::new(); // (entity, duration, slow_amount)
+    let mut shoves: HashMap<Entity, Vec2> = HashMap::new();
+    let mut rng = rand::thread_rng();
+    let default_bonuses = PerkBonuses::default();
+    let bonuses_of = |owner: Entity| owner_query.get(owner).unwrap_or(&default_bonuses);
 
     for (
         projectile_entity,
         projectile_transform,
         mut projectile,
-        explosive,
         mut chain_lightning,
         splitter,
         freezing,
+        igniting,
+        hit_knockback,
     ) in projectile_query.iter_mut()
     {
         let projectile_pos = projectile_transform.translation.truncate();
 
-        for (creature_entity, creature_transform, mut creature_health, creature_speed) in
-            creature_query.iter_mut()
-        {
+        for (creature_entity, _) in creature_grid.candidates(projectile_pos, COLLISION_RADIUS) {
+            let Ok((_, creature_transform, mut creature_health, status_effects, creature, ghost)) =
+                creature_query.get_mut(creature_entity)
+            else {
+                continue;
+            };
+            // Phased Ghosts let shots pass straight through
+            if ghost.is_some_and(GhostPhase::is_phased) {
+                continue;
+            }
+            // A piercing projectile damages each creature once
+            if projectile.already_hit.contains(&creature_entity) {
+                continue;
+            }
             // Skip if chain lightning already hit this target
             if let Some(ref chain) = chain_lightning {
                 if chain.already_hit.contains(&creature_entity) {
@@ -361,36 +726,43 @@ pub fn projectile_collision(
             let distance = projectile_pos.distance(creature_pos);
 
             if distance < COLLISION_RADIUS {
-                // Apply damage
-                creature_health.damage(projectile.damage);
+                // Each hit rolls its own crit, so a piercing shot can crit some targets and not others
+                let (mut damage, was_crit) = roll_crit(projectile.damage, bonuses_of(projectile.owner), &mut rng);
+                let instant_kill = roll_instant_kill(bonuses_of(projectile.owner), creature.creature_type, &mut rng);
+                if instant_kill {
+                    damage = damage.max(creature_health.current);
+                }
+                creature_health.damage(damage);
+                projectile.already_hit.push(creature_entity);
 
                 // Use projectile.weapon_id for weapon-specific hit effects
                 let _weapon_type = projectile.weapon_id;
                 hit_events.send(ProjectileHitEvent {
                     projectile: projectile_entity,
                     target: creature_entity,
-                    damage: projectile.damage,
+                    damage,
                     position: projectile_transform.translation,
+                    was_crit,
+                    instant_kill,
                 });
 
-                // Queue freezing effect
-                if let Some(freeze) = &freezing {
-                    freeze_targets.push((
-                        creature_entity,
-                        freeze.duration,
-                        creature_speed.0,
-                        freeze.slow_amount,
-                    ));
+                if let (Some(ignite), Some(mut status_effects)) = (igniting, status_effects) {
+                    status_effects.apply(
+                        StatusKind::Burning,
+                        ignite.dps,
+                        ignite.duration,
+                        Some(projectile.owner),
+                    );
+                }
+
+                // Queue a shove along the projectile's flight
+                if let Some((knockback, velocity)) = hit_knockback {
+                    *shoves.entry(creature_entity).or_default() += velocity.0.normalize_or_zero() * knockback.0;
                 }
 
-                // Queue explosive damage for later
-                if let Some(explosive) = explosive {
-                    explosions.push((
-                        projectile_pos,
-                        explosive.radius,
-                        explosive.damage,
-                        creature_entity,
-                    ));
+                // Queue freezing effect
+                if let Some(freeze) = &freezing {
+                    freeze_targets.push((creature_entity, freeze.duration, freeze.slow_amount));
                 }
 
                 // Queue chain lightning spawn
@@ -438,23 +810,8 @@ pub fn projectile_collision(
         }
     }
 
-    // Apply explosion damage
-    for (center, radius, damage, already_hit) in explosions {
-        for (entity, transform, mut health, _) in creature_query.iter_mut() {
-            if entity == already_hit {
-                continue;
-            }
-
-            let pos = transform.translation.truncate();
-            let distance = center.distance(pos);
-
-            if distance < radius {
-                let falloff = 1.0 - (distance / radius);
-                let explosion_damage = damage * falloff;
-                health.damage(explosion_damage);
-            }
-        }
-    }
+    // Chain jumps and fragments carry the shooter's speed and range bonuses;
+    // damage_multiplier is already in the parent's damage and crits roll on their own hits
 
     // Spawn chain lightning projectiles
     for (pos, damage, jumps, range, falloff, already_hit, owner) in chain_spawns {
@@ -462,11 +819,10 @@ pub fn projectile_collision(
         let mut nearest: Option<(Entity, Vec2)> = None;
         let mut nearest_dist = f32::MAX;
 
-        for (entity, transform, _, _) in creature_query.iter() {
+        for (entity, creature_pos) in creature_grid.candidates(pos, range) {
             if already_hit.contains(&entity) {
                 continue;
             }
-            let creature_pos = transform.translation.truncate();
             let dist = pos.distance(creature_pos);
             if dist < range && dist < nearest_dist {
                 nearest = Some((entity, creature_pos));
@@ -475,29 +831,42 @@ pub fn projectile_collision(
         }
 
         if let Some((_, target_pos)) = nearest {
+            effect_events.send(SpawnEffectEvent {
+                effect_type: EffectType::LightningArc {
+                    from: pos.extend(0.0),
+                    to: target_pos.extend(0.0),
+                },
+                position: pos.midpoint(target_pos).extend(0.0),
+                count: 1,
+            });
             let direction = (target_pos - pos).normalize_or_zero();
             let mut new_chain = ChainLightning::new(jumps, range, falloff);
             new_chain.already_hit = already_hit;
-
-            commands.spawn((
-                ProjectileBundle::new(
-                    WeaponId::ChainReactor,
-                    damage,
-                    owner,
-                    Vec3::new(pos.x, pos.y, 0.0),
-                    direction,
-                    800.0, // Fast chain lightning
-                    0.5,   // Short lifetime
-                    Color::srgb(0.5, 0.7, 1.0), // Blue lightning color
-                    4.0,
+            let bonuses = bonuses_of(owner);
+
+            pool.spawn(
+                &mut commands,
+                (
+                    ProjectileBundle::new(
+                        WeaponId::ChainReactor,
+                        damage,
+                        owner,
+                        Vec3::new(pos.x, pos.y, 0.0),
+                        direction,
+                        CHAIN_JUMP_SPEED * bonuses.projectile_speed_multiplier,
+                        CHAIN_JUMP_LIFETIME * bonuses.range_multiplier,
+                        CHAIN_LIGHTNING_COLOR,
+                        4.0,
+                    ),
+                    new_chain,
                 ),
-                new_chain,
-            ));
+            );
         }
     }
 
     // Spawn splitter projectiles
     for (pos, base_dir, damage, splits, count, mult, owner) in split_spawns {
+        let bonuses = bonuses_of(owner);
         let angle_spread = std::f32::consts::PI / 3.0; // 60 degree spread
         let angle_step = angle_spread / (count as f32 - 1.0).max(1.0);
         let start_angle = base_dir.y.atan2(base_dir.x) - angle_spread / 2.0;
@@ -506,17 +875,20 @@ pub fn projectile_collision(
             let angle = start_angle + angle_step * i as f32;
             let direction = Vec2::new(angle.cos(), angle.sin());
 
-            let mut projectile_commands = commands.spawn(ProjectileBundle::new(
-                WeaponId::SplitterGun,
-                damage,
-                owner,
-                Vec3::new(pos.x, pos.y, 0.0),
-                direction,
-                500.0,
-                1.5,
-                Color::srgb(0.8, 0.4, 1.0), // Purple splitter color
-                4.0,
-            ));
+            let mut projectile_commands = pool.spawn(
+                &mut commands,
+                ProjectileBundle::new(
+                    WeaponId::SplitterGun,
+                    damage,
+                    owner,
+                    Vec3::new(pos.x, pos.y, 0.0),
+                    direction,
+                    SPLIT_FRAGMENT_SPEED * bonuses.projectile_speed_multiplier,
+                    SPLIT_FRAGMENT_LIFETIME * bonuses.range_multiplier,
+                    Color::srgb(0.8, 0.4, 1.0), // Purple splitter color
+                    4.0,
+                ),
+            );
 
             if splits > 0 {
                 projectile_commands.insert(Splitter::new(splits, count, mult));
@@ -524,73 +896,431 @@ pub fn projectile_collision(
         }
     }
 
-    // Apply freeze effects
-    for (entity, duration, original_speed, slow_amount) in freeze_targets {
-        // Apply the slow by setting speed to slowed value and adding FrozenStatus
-        if let Ok((_, _, _, mut speed)) = creature_query.get_mut(entity) {
-            speed.0 = original_speed * slow_amount;
-            commands
-                .entity(entity)
-                .insert(FrozenStatus::new(duration, original_speed, slow_amount));
+    // Apply freeze effects (update_frozen_creatures applies the slow itself)
+    for (entity, duration, slow_amount) in freeze_targets {
+        let Ok((speed, mut sprite, frozen)) = freezable_query.get_mut(entity) else {
+            continue;
+        };
+        match frozen {
+            Some(mut frozen) => frozen.refresh(duration, slow_amount),
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(FrozenStatus::new(duration, speed.0, slow_amount, sprite.color));
+                sprite.color = FROZEN_TINT.with_alpha(sprite.color.alpha());
+            }
         }
     }
+
+    apply_shoves(&mut commands, &mut knockback_query, shoves);
 }
 
-/// Updates frozen creatures and restores speed when effect expires
-pub fn update_frozen_creatures(
+/// Applies queued shoves, resisted by the creature's mass (update_knockback_and_stagger moves them)
+fn apply_shoves(
+    commands: &mut Commands,
+    knockback_query: &mut Query<(&Creature, Option<&mut Knockback>)>,
+    shoves: HashMap<Entity, Vec2>,
+) {
+    for (entity, shove) in shoves {
+        let Ok((creature, knockback)) = knockback_query.get_mut(entity) else {
+            continue;
+        };
+        let velocity = shove / creature.creature_type.mass();
+        if velocity == Vec2::ZERO {
+            continue;
+        }
+        match knockback {
+            Some(mut knockback) => {
+                knockback.velocity = (knockback.velocity + velocity).clamp_length_max(MAX_HIT_KNOCKBACK_SPEED);
+            }
+            None => {
+                commands.entity(entity).insert(Knockback {
+                    velocity: velocity.clamp_length_max(MAX_HIT_KNOCKBACK_SPEED),
+                });
+            }
+        }
+    }
+}
+
+/// How far a shot from `weapon_data` travels with `perk_bonuses` applied
+pub fn weapon_range(weapon_data: &WeaponData, perk_bonuses: &PerkBonuses) -> f32 {
+    weapon_data.projectile_speed
+        * perk_bonuses.projectile_speed_multiplier
+        * weapon_data.projectile_lifetime
+        * perk_bonuses.range_multiplier
+}
+
+/// Creatures a ray from `origin` along `direction` passes through within `range`,
+/// nearest first, with their position and distance along the ray
+pub fn creatures_along_ray(
+    creature_grid: &CreatureGrid,
+    origin: Vec2,
+    direction: Vec2,
+    range: f32,
+) -> Vec<(Entity, Vec2, f32)> {
+    let midpoint = origin + direction * range / 2.0;
+    let mut in_line: Vec<(Entity, Vec2, f32)> = creature_grid
+        .candidates(midpoint, range / 2.0 + COLLISION_RADIUS)
+        .into_iter()
+        .filter(|&(_, position)| aim_ray_hits(origin, direction, position, COLLISION_RADIUS, range))
+        .map(|(entity, position)| (entity, position, (position - origin).dot(direction)))
+        .collect();
+    in_line.sort_by(|a, b| a.2.total_cmp(&b.2));
+    in_line
+}
+
+/// Traces the beams of hitscan weapons (Gauss Gun) the moment they fire. A beam
+/// hits the first `pierce_count + 1` creatures along it, each taking
+/// HITSCAN_PIERCE_FALLOFF of the damage the one before it took, and stops at the last.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn fire_hitscan_weapons(
     mut commands: Commands,
-    time: Res<Time>,
-    mut query: Query<(Entity, &mut FrozenStatus, &mut CreatureSpeed)>,
+    weapon_registry: Res<WeaponRegistry>,
+    mut fire_events: EventReader<FireWeaponEvent>,
+    shooter_query: Query<(
+        Option<&PerkBonuses>,
+        Option<&ActiveBonusEffects>,
+        Option<&SpreadBloom>,
+        Option<&StationaryTimer>,
+    )>,
+    mut creature_query: Query<(&mut CreatureHealth, &Creature, Option<&GhostPhase>), Without<MarkedForDespawn>>,
+    mut knockback_query: Query<(&Creature, Option<&mut Knockback>)>,
+    creature_grid: Res<CreatureGrid>,
+    mut hit_events: EventWriter<ProjectileHitEvent>,
+    mut effect_events: EventWriter<SpawnEffectEvent>,
 ) {
-    for (entity, mut frozen, mut speed) in query.iter_mut() {
-        frozen.tick(time.delta_seconds());
+    let no_perks = PerkBonuses::default();
+    let mut rng = rand::thread_rng();
+    let mut shoves: HashMap<Entity, Vec2> = HashMap::new();
 
-        // Keep speed slowed based on slow_multiplier while frozen
-        speed.0 = frozen.original_speed * frozen.slow_multiplier;
+    for event in fire_events.read() {
+        let Some(weapon_data) = weapon_registry.get_key(&event.weapon).filter(|data| data.hitscan) else {
+            continue;
+        };
+        let (perk_bonuses, bonus_effects, bloom, stationary) =
+            shooter_query.get(event.shooter).unwrap_or((None, None, None, None));
+        let still_seconds = stationary.map_or(0.0, |timer| timer.seconds);
+        let perk_bonuses = perk_bonuses.unwrap_or(&no_perks);
+        let damage_boost = bonus_effects.is_some_and(ActiveBonusEffects::has_damage_boost);
+        let origin = event.position.truncate();
+        let range = weapon_range(weapon_data, perk_bonuses);
+        let base_angle = event.direction.to_angle();
 
-        if frozen.is_expired() {
-            // Restore original speed
-            speed.0 = frozen.original_speed;
-            commands.entity(entity).remove::<FrozenStatus>();
+        for _ in 0..weapon_data.projectiles_per_shot {
+            let direction = spread_direction(base_angle, weapon_data, perk_bonuses, bloom, &mut rng);
+            let in_line = creatures_along_ray(&creature_grid, origin, direction, range);
+
+            let mut damage = shot_damage(weapon_data, perk_bonuses, damage_boost, still_seconds);
+            let mut end = origin + direction * range;
+            let mut hits_left = weapon_data.pierce_count + 1;
+            for (creature_entity, position, along) in in_line {
+                let Ok((mut creature_health, creature, ghost)) = creature_query.get_mut(creature_entity) else {
+                    continue;
+                };
+                // The beam passes through phased Ghosts like projectiles do
+                if ghost.is_some_and(GhostPhase::is_phased) {
+                    continue;
+                }
+                let (mut dealt, was_crit) = roll_crit(damage, perk_bonuses, &mut rng);
+                let instant_kill = roll_instant_kill(perk_bonuses, creature.creature_type, &mut rng);
+                if instant_kill {
+                    dealt = dealt.max(creature_health.current);
+                }
+                creature_health.damage(dealt);
+                hit_events.send(ProjectileHitEvent {
+                    projectile: event.shooter,
+                    target: creature_entity,
+                    damage: dealt,
+                    position: position.extend(0.0),
+                    was_crit,
+                    instant_kill,
+                });
+                if weapon_data.knockback > 0.0 {
+                    *shoves.entry(creature_entity).or_default() += direction * weapon_data.knockback;
+                }
+                damage *= HITSCAN_PIERCE_FALLOFF;
+                hits_left -= 1;
+                if hits_left == 0 {
+                    end = origin + direction * along;
+                    break;
+                }
+            }
+
+            effect_events.send(SpawnEffectEvent {
+                effect_type: EffectType::Beam {
+                    from: origin.extend(0.0),
+                    to: end.extend(0.0),
+                },
+                position: origin.midpoint(end).extend(0.0),
+                count: 1,
+            });
         }
     }
+
+    apply_shoves(&mut commands, &mut knockback_query, shoves);
 }
 
-/// Updates projectile lifetimes and marks expired ones for despawn
-#[allow(clippy::type_complexity)]
-pub fn projectile_lifetime(
+/// Fakes a lobbed grenade's height with its sprite scale and brings it down on
+/// its target at the end of the flight
+pub fn update_grenade_arcs(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut Lifetime), (With<Projectile>, Without<ProjectileDespawn>)>,
+    mut query: Query<(Entity, &mut Transform, &mut GrenadeArc), Without<ProjectileDespawn>>,
 ) {
-    for (entity, mut lifetime) in query.iter_mut() {
-        lifetime.tick(time.delta_seconds());
-        if lifetime.is_expired() {
+    for (entity, mut transform, mut arc) in query.iter_mut() {
+        arc.elapsed += time.delta_seconds();
+        transform.scale = Vec3::splat(arc.scale());
+        if arc.progress() >= 1.0 {
+            transform.translation = arc.target.extend(transform.translation.z);
             commands.entity(entity).insert(ProjectileDespawn);
         }
     }
 }
 
-/// Removes projectiles marked for despawn
-pub fn cleanup_projectiles(
+/// Detonates explosive projectiles as they are marked for despawn, so a rocket
+/// that times out without touching anything still goes off where it ends up.
+/// Incendiary ones leave a fire patch behind
+#[allow(clippy::type_complexity)]
+pub fn detonate_explosives(
     mut commands: Commands,
-    query: Query<Entity, With<ProjectileDespawn>>,
+    query: Query<(&Transform, &Projectile, &Explosive, Option<&Incendiary>), Added<ProjectileDespawn>>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
 ) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn_recursive();
+    for (transform, projectile, explosive, incendiary) in query.iter() {
+        if let Some(incendiary) = incendiary {
+            commands.spawn((
+                FirePatch {
+                    radius: explosive.radius,
+                    dps: incendiary.dps,
+                    remaining: FIRE_PATCH_DURATION,
+                    owner: projectile.owner,
+                },
+                SpatialBundle::from_transform(Transform::from_translation(transform.translation)),
+            ));
+        }
+        explosion_events.send(ExplosionEvent {
+            position: transform.translation,
+            radius: explosive.radius,
+            damage: explosive.damage,
+            spared: projectile.already_hit.clone(),
+            owner: projectile.owner,
+            self_damage: explosive.self_damage,
+        });
     }
 }
 
-/// Despawns all projectiles when leaving Playing state
-pub fn despawn_all_projectiles(mut commands: Commands, query: Query<Entity, With<Projectile>>) {
-    for entity in query.iter() {
-        commands.entity(entity).despawn_recursive();
+/// Deals blast damage with linear falloff to creatures in each explosion's radius.
+/// Self-damaging blasts also hurt players in range, scaled by PLAYER_BLAST_DAMAGE_SCALE
+/// when a player set them off; apply_player_damage handles invincibility and dodge.
+#[allow(clippy::type_complexity)]
+pub fn apply_explosion_damage(
+    mut explosion_events: EventReader<ExplosionEvent>,
+    mut creature_query: Query<(&Transform, &mut CreatureHealth), (With<Creature>, Without<MarkedForDespawn>)>,
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    creature_grid: Res<CreatureGrid>,
+    mut player_damage_events: EventWriter<PlayerDamageEvent>,
+) {
+    for explosion in explosion_events.read() {
+        let center = explosion.position.truncate();
+        for (entity, _) in creature_grid.candidates(center, explosion.radius) {
+            if explosion.spared.contains(&entity) {
+                continue;
+            }
+            let Ok((transform, mut health)) = creature_query.get_mut(entity) else {
+                continue;
+            };
+
+            let distance = center.distance(transform.translation.truncate());
+            if distance < explosion.radius {
+                let falloff = 1.0 - (distance / explosion.radius);
+                health.damage(explosion.damage * falloff);
+            }
+        }
+
+        if !explosion.self_damage {
+            continue;
+        }
+        let player_scale = if player_query.contains(explosion.owner) {
+            PLAYER_BLAST_DAMAGE_SCALE
+        } else {
+            1.0
+        };
+        for (player_entity, transform) in player_query.iter() {
+            let distance = center.distance(transform.translation.truncate());
+            if distance < explosion.radius {
+                let falloff = 1.0 - (distance / explosion.radius);
+                player_damage_events.send(PlayerDamageEvent {
+                    player_entity,
+                    damage: explosion.damage * falloff * player_scale,
+                    source: Some(explosion.owner),
+                    unavoidable: false,
+                });
+            }
+        }
     }
 }
 
-#[cfg(test)]
+/// Shrinks creatures hit by ShrinkRay projectiles
+#[allow(clippy::type_complexity)]
+pub fn apply_shrink_hits(
+    mut commands: Commands,
+    mut hit_events: EventReader<ProjectileHitEvent>,
+    projectile_query: Query<&Shrinking>,
+    mut creature_query: Query<
+        (&mut Transform, &mut ContactDamage, &mut CreatureHealth, Option<&mut Shrunk>),
+        (With<Creature>, Without<MarkedForDespawn>),
+    >,
+) {
+    for hit in hit_events.read() {
+        let Ok(shrinking) = projectile_query.get(hit.projectile) else {
+            continue;
+        };
+        let Ok((mut transform, mut contact_damage, mut health, shrunk)) = creature_query.get_mut(hit.target)
+        else {
+            continue;
+        };
+
+        let factor = shrunk.as_ref().map_or(1.0, |s| s.factor);
+        let new_factor = Shrunk::shrink(factor, shrinking.per_hit);
+        resize_creature(&mut transform, &mut contact_damage, &mut health, new_factor / factor);
+        match shrunk {
+            Some(mut shrunk) => {
+                shrunk.factor = new_factor;
+                shrunk.remaining = shrinking.duration;
+            }
+            None => {
+                commands.entity(hit.target).insert(Shrunk {
+                    factor: new_factor,
+                    remaining: shrinking.duration,
+                });
+            }
+        }
+    }
+}
+
+/// Grows shrunk creatures back to full size when the effect runs out
+pub fn update_shrunk_creatures(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Shrunk, &mut Transform, &mut ContactDamage, &mut CreatureHealth)>,
+) {
+    for (entity, mut shrunk, mut transform, mut contact_damage, mut health) in query.iter_mut() {
+        shrunk.remaining -= time.delta_seconds();
+        if shrunk.remaining <= 0.0 {
+            let ratio = 1.0 / shrunk.factor;
+            resize_creature(&mut transform, &mut contact_damage, &mut health, ratio);
+            commands.entity(entity).remove::<Shrunk>();
+        }
+    }
+}
+
+/// Updates frozen creatures and restores speed and color when effect expires
+pub fn update_frozen_creatures(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut FrozenStatus, &mut CreatureSpeed, &mut Sprite)>,
+) {
+    for (entity, mut frozen, mut speed, mut sprite) in query.iter_mut() {
+        frozen.tick(time.delta_seconds());
+
+        // Keep speed slowed based on slow_multiplier while frozen
+        speed.0 = frozen.original_speed * frozen.slow_multiplier;
+
+        if frozen.is_expired() {
+            // Restore original speed and color
+            speed.0 = frozen.original_speed;
+            sprite.color = frozen.original_color;
+            commands.entity(entity).remove::<FrozenStatus>();
+        }
+    }
+}
+
+/// Updates projectile lifetimes and marks expired ones for despawn
+#[allow(clippy::type_complexity)]
+pub fn projectile_lifetime(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Lifetime), (With<Projectile>, Without<ProjectileDespawn>)>,
+) {
+    for (entity, mut lifetime) in query.iter_mut() {
+        lifetime.tick(time.delta_seconds());
+        if lifetime.is_expired() {
+            commands.entity(entity).insert(ProjectileDespawn);
+        }
+    }
+}
+
+/// Removes projectiles marked for despawn
+pub fn cleanup_projectiles(
+    mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
+    query: Query<Entity, With<ProjectileDespawn>>,
+) {
+    for entity in query.iter() {
+        pool.release(&mut commands, entity);
+    }
+}
+
+/// Sets creatures standing in fire patches burning and puts out expired patches.
+/// The damage itself comes from the Burning status; a creature in several patches
+/// burns at the hottest one's dps, not the sum
+#[allow(clippy::type_complexity)]
+pub fn update_fire_patches(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut patch_query: Query<(Entity, &Transform, &mut FirePatch)>,
+    mut creature_query: Query<(&Transform, &mut StatusEffects), (With<Creature>, Without<MarkedForDespawn>)>,
+    creature_grid: Res<CreatureGrid>,
+) {
+    let delta = time.delta_seconds();
+    let mut hottest: HashMap<Entity, (f32, Entity)> = HashMap::new();
+    for (patch_entity, transform, mut patch) in patch_query.iter_mut() {
+        patch.remaining -= delta;
+        if patch.remaining <= 0.0 {
+            commands.entity(patch_entity).despawn_recursive();
+            continue;
+        }
+        let center = transform.translation.truncate();
+        for (entity, _) in creature_grid.candidates(center, patch.radius) {
+            let Ok((creature_transform, _)) = creature_query.get(entity) else {
+                continue;
+            };
+            if center.distance(creature_transform.translation.truncate()) >= patch.radius {
+                continue;
+            }
+            let entry = hottest.entry(entity).or_insert((0.0, patch.owner));
+            if patch.dps > entry.0 {
+                *entry = (patch.dps, patch.owner);
+            }
+        }
+    }
+
+    for (entity, (dps, owner)) in hottest {
+        if let Ok((_, mut status_effects)) = creature_query.get_mut(entity) {
+            status_effects.apply(StatusKind::Burning, dps, FIRE_PATCH_BURN_DURATION, Some(owner));
+        }
+    }
+}
+
+/// Despawns all projectiles when leaving Playing state
+pub fn despawn_all_projectiles(mut commands: Commands, query: Query<Entity, With<Projectile>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Despawns all fire patches when leaving Playing state
+pub fn despawn_all_fire_patches(mut commands: Commands, query: Query<Entity, With<FirePatch>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
+    use crate::creatures::{rebuild_creature_grid, tick_status_effects};
+    use crate::weapons::Pooled;
 
     #[test]
     fn fire_weapon_event_can_be_created() {
@@ -599,10 +1329,33 @@ mod tests {
             position: Vec3::ZERO,
             direction: Vec2::X,
             weapon_id: WeaponId::Pistol,
+            weapon: WeaponKey::Builtin(WeaponId::Pistol),
+            target: None,
         };
         assert_eq!(event.weapon_id, WeaponId::Pistol);
     }
 
+    #[test]
+    fn highlander_rolls_a_tenth_as_often_against_bosses() {
+        use rand::SeedableRng;
+
+        let bonuses = PerkBonuses {
+            instant_kill_chance: 0.1,
+            ..default()
+        };
+        let procs = |creature_type: CreatureType| {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(799);
+            (0..10_000)
+                .filter(|_| roll_instant_kill(&bonuses, creature_type, &mut rng))
+                .count()
+        };
+        assert!((800..1200).contains(&procs(CreatureType::Zombie)));
+        assert!((50..150).contains(&procs(CreatureType::BossSpider)));
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(799);
+        assert!(!roll_instant_kill(&PerkBonuses::default(), CreatureType::Zombie, &mut rng));
+    }
+
     #[test]
     fn projectile_hit_event_can_be_created() {
         let event = ProjectileHitEvent {
@@ -610,6 +1363,8 @@ mod tests {
             target: Entity::PLACEHOLDER,
             damage: 25.0,
             position: Vec3::new(10.0, 20.0, 0.0),
+            was_crit: false,
+            instant_kill: false,
         };
         assert_eq!(event.damage, 25.0);
     }
@@ -624,4 +1379,1494 @@ mod tests {
         assert_ne!(pistol_color, plasma_color);
         assert_ne!(plasma_color, freeze_color);
     }
+
+    #[test]
+    fn homing_missile_turns_toward_a_creature() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .add_systems(Update, (homing_projectile_update, projectile_movement).chain());
+        let turn_rate = WeaponRegistry::new()
+            .get(WeaponId::HomingMissile)
+            .unwrap()
+            .homing_turn_rate;
+        let missile = app
+            .world_mut()
+            .spawn((
+                ProjectileBundle::new(
+                    WeaponId::HomingMissile,
+                    80.0,
+                    Entity::PLACEHOLDER,
+                    Vec3::ZERO,
+                    Vec2::X,
+                    350.0,
+                    5.0,
+                    Color::WHITE,
+                    12.0,
+                ),
+                Homing {
+                    turn_rate,
+                    target: None,
+                },
+            ))
+            .id();
+        app.world_mut()
+            .spawn(crate::creatures::CreatureBundle::new(
+                crate::creatures::CreatureType::Zombie,
+                Vec3::new(0.0, 300.0, 0.0),
+            ));
+
+        let heading = |app: &App| app.world().get::<Velocity>(missile).unwrap().0.to_angle();
+        let start = heading(&app);
+        for _ in 0..5 {
+            advance(&mut app, 0.1);
+        }
+        let turned = heading(&app);
+        // Turning left toward the creature above, at no more than the turn rate
+        assert!(turned > start + 0.5, "heading {} -> {}", start, turned);
+        assert!(turned <= start + turn_rate * 0.5 + 1e-3);
+    }
+
+    #[test]
+    fn piercing_projectile_damages_a_creature_once() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(
+                Update,
+                (projectile_movement, rebuild_creature_grid, projectile_collision).chain(),
+            );
+        let creature = app
+            .world_mut()
+            .spawn(crate::creatures::CreatureBundle::new(
+                crate::creatures::CreatureType::Zombie,
+                Vec3::new(30.0, 0.0, 0.0),
+            ))
+            .id();
+        let start_health = app.world().get::<CreatureHealth>(creature).unwrap().current;
+        // Slow enough to spend many frames inside the creature's hit radius
+        let projectile = app
+            .world_mut()
+            .spawn(
+                ProjectileBundle::new(
+                    WeaponId::GaussGun,
+                    5.0,
+                    Entity::PLACEHOLDER,
+                    Vec3::ZERO,
+                    Vec2::X,
+                    60.0,
+                    5.0,
+                    Color::WHITE,
+                    4.0,
+                )
+                .with_pierce(10),
+            )
+            .id();
+
+        for _ in 0..60 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+
+        let health = app.world().get::<CreatureHealth>(creature).unwrap().current;
+        assert_eq!(start_health - health, 5.0);
+        let projectile = app.world().get::<Projectile>(projectile).unwrap();
+        assert_eq!(projectile.pierce_count, 9);
+        assert_eq!(projectile.already_hit, vec![creature]);
+    }
+
+    #[test]
+    fn projectiles_pass_through_phased_ghosts() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(
+                Update,
+                (projectile_movement, rebuild_creature_grid, projectile_collision).chain(),
+            );
+        let ghost_at = |app: &mut App, x: f32, phased: bool| {
+            app.world_mut()
+                .spawn((
+                    crate::creatures::CreatureBundle::new(CreatureType::Ghost, Vec3::new(x, 0.0, 0.0)),
+                    GhostPhase {
+                        phased,
+                        ..default()
+                    },
+                ))
+                .id()
+        };
+        let phased = ghost_at(&mut app, 30.0, true);
+        let solid = ghost_at(&mut app, 90.0, false);
+        app.world_mut().spawn(ProjectileBundle::new(
+            WeaponId::Pistol,
+            5.0,
+            Entity::PLACEHOLDER,
+            Vec3::ZERO,
+            Vec2::X,
+            600.0,
+            5.0,
+            Color::WHITE,
+            4.0,
+        ));
+
+        for _ in 0..15 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+
+        let health = |app: &App, ghost: Entity| app.world().get::<CreatureHealth>(ghost).unwrap().current;
+        let full = CreatureType::Ghost.base_health();
+        assert_eq!(health(&app, phased), full);
+        assert_eq!(health(&app, solid), full - 5.0);
+    }
+
+    #[test]
+    fn repeated_freeze_hits_refresh_the_slow_instead_of_stacking_it() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(
+                Update,
+                (rebuild_creature_grid, projectile_collision, update_frozen_creatures).chain(),
+            );
+        let zombie = crate::creatures::CreatureType::Zombie;
+        let creature = app
+            .world_mut()
+            .spawn(crate::creatures::CreatureBundle::new(zombie, Vec3::ZERO))
+            .id();
+        let base_color = app.world().get::<Sprite>(creature).unwrap().color;
+        let fire_freeze_shot = |app: &mut App| {
+            app.world_mut().spawn((
+                ProjectileBundle::new(
+                    WeaponId::FreezeRay,
+                    1.0,
+                    Entity::PLACEHOLDER,
+                    Vec3::ZERO,
+                    Vec2::X,
+                    0.0,
+                    5.0,
+                    Color::WHITE,
+                    4.0,
+                ),
+                Freezing {
+                    slow_amount: 0.3,
+                    duration: 1.0,
+                },
+            ));
+        };
+
+        for _ in 0..3 {
+            fire_freeze_shot(&mut app);
+            advance(&mut app, 0.5);
+        }
+        let speed = app.world().get::<CreatureSpeed>(creature).unwrap().0;
+        assert!((speed - zombie.base_speed() * 0.3).abs() < 1e-3, "speed {}", speed);
+        assert_ne!(app.world().get::<Sprite>(creature).unwrap().color, base_color);
+
+        // The last hit's full duration runs out, then everything is restored
+        advance(&mut app, 0.6);
+        advance(&mut app, 0.6);
+        assert!(app.world().get::<FrozenStatus>(creature).is_none());
+        assert_eq!(app.world().get::<CreatureSpeed>(creature).unwrap().0, zombie.base_speed());
+        assert_eq!(app.world().get::<Sprite>(creature).unwrap().color, base_color);
+    }
+
+    #[test]
+    fn fire_projectiles_leave_creatures_burning() {
+        let pyromaniac = Igniting::for_weapon(WeaponId::Flamethrower, 1.5).unwrap();
+        let plain = Igniting::for_weapon(WeaponId::Flamethrower, 1.0).unwrap();
+        assert!((pyromaniac.dps - plain.dps * 1.5).abs() < 1e-3);
+        assert!(Igniting::for_weapon(WeaponId::Pistol, 1.5).is_none());
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<crate::creatures::StatusDamageEvent>()
+            .add_systems(
+                Update,
+                (rebuild_creature_grid, projectile_collision, tick_status_effects).chain(),
+            );
+        let creature = app
+            .world_mut()
+            .spawn(crate::creatures::CreatureBundle::new(
+                crate::creatures::CreatureType::Giant,
+                Vec3::ZERO,
+            ))
+            .id();
+        app.world_mut().spawn((
+            ProjectileBundle::new(
+                WeaponId::Flamethrower,
+                1.0,
+                Entity::PLACEHOLDER,
+                Vec3::ZERO,
+                Vec2::X,
+                0.0,
+                5.0,
+                Color::WHITE,
+                4.0,
+            ),
+            plain.clone(),
+        ));
+        advance(&mut app, 0.0);
+        let after_hit = app.world().get::<CreatureHealth>(creature).unwrap().current;
+
+        for _ in 0..10 {
+            advance(&mut app, 0.1);
+        }
+        let burned = after_hit - app.world().get::<CreatureHealth>(creature).unwrap().current;
+        assert!((burned - plain.dps).abs() < 0.01, "burned {}", burned);
+
+        // It burns out after its duration
+        for _ in 0..20 {
+            advance(&mut app, 0.1);
+        }
+        let status = app.world().get::<StatusEffects>(creature).unwrap();
+        assert!(status.entries.is_empty());
+    }
+
+    #[test]
+    fn shrink_ray_hits_shrink_to_the_floor_then_wear_off() {
+        use crate::creatures::{ContactDamage, CreatureType, SHRINK_FLOOR};
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(
+                Update,
+                (
+                    rebuild_creature_grid,
+                    projectile_collision,
+                    apply_shrink_hits,
+                    update_shrunk_creatures,
+                )
+                    .chain(),
+            );
+        let giant = CreatureType::Giant;
+        let creature = app
+            .world_mut()
+            .spawn(crate::creatures::CreatureBundle::new(giant, Vec3::ZERO))
+            .id();
+        for _ in 0..6 {
+            app.world_mut().spawn((
+                ProjectileBundle::new(
+                    WeaponId::ShrinkRay,
+                    0.0,
+                    Entity::PLACEHOLDER,
+                    Vec3::ZERO,
+                    Vec2::X,
+                    0.0,
+                    5.0,
+                    Color::WHITE,
+                    4.0,
+                ),
+                Shrinking {
+                    per_hit: 0.75,
+                    duration: 1.0,
+                },
+            ));
+            advance(&mut app, 0.1);
+        }
+
+        let world = app.world();
+        assert_eq!(world.get::<Shrunk>(creature).unwrap().factor, SHRINK_FLOOR);
+        assert!((world.get::<Transform>(creature).unwrap().scale.x - SHRINK_FLOOR).abs() < 1e-4);
+        let damage = world.get::<ContactDamage>(creature).unwrap().0;
+        assert!((damage - giant.base_damage() * SHRINK_FLOOR).abs() < 1e-3);
+        let health = world.get::<CreatureHealth>(creature).unwrap();
+        assert!((health.max - giant.base_health() * SHRINK_FLOOR).abs() < 1e-3);
+
+        for _ in 0..11 {
+            advance(&mut app, 0.1);
+        }
+        let world = app.world();
+        assert!(world.get::<Shrunk>(creature).is_none());
+        assert!((world.get::<Transform>(creature).unwrap().scale.x - 1.0).abs() < 1e-4);
+        assert!((world.get::<ContactDamage>(creature).unwrap().0 - giant.base_damage()).abs() < 1e-3);
+        assert!((world.get::<CreatureHealth>(creature).unwrap().max - giant.base_health()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn chain_jump_draws_a_fading_arc_between_the_creatures() {
+        use crate::effects::{cleanup_expired_effects, handle_effect_spawns, update_particles, Effect};
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(
+                Update,
+                (
+                    rebuild_creature_grid,
+                    projectile_collision,
+                    handle_effect_spawns,
+                    update_particles,
+                    cleanup_expired_effects,
+                )
+                    .chain(),
+            );
+        for x in [0.0, 100.0] {
+            app.world_mut().spawn(crate::creatures::CreatureBundle::new(
+                crate::creatures::CreatureType::Giant,
+                Vec3::new(x, 0.0, 0.0),
+            ));
+        }
+        app.world_mut().spawn((
+            ProjectileBundle::new(
+                WeaponId::ChainReactor,
+                1.0,
+                Entity::PLACEHOLDER,
+                Vec3::ZERO,
+                Vec2::X,
+                0.0,
+                5.0,
+                Color::WHITE,
+                4.0,
+            ),
+            ChainLightning::new(1, 150.0, 0.8),
+        ));
+        advance(&mut app, 0.0);
+
+        let arcs: Vec<(Vec2, f32)> = app
+            .world_mut()
+            .query::<(&Effect, &Sprite)>()
+            .iter(app.world())
+            .filter_map(|(effect, sprite)| match effect.effect_type {
+                EffectType::LightningArc { from, to } => {
+                    Some(((to - from).truncate(), sprite.custom_size.unwrap().x))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(arcs, vec![(Vec2::new(100.0, 0.0), 100.0)]);
+
+        advance(&mut app, crate::effects::LIGHTNING_ARC_LIFETIME + 0.01);
+        let effects = app.world_mut().query::<&Effect>().iter(app.world()).count();
+        assert_eq!(effects, 0);
+    }
+
+    /// Fires one Splitter Gun shot into a creature and returns the first fragments'
+    /// (damage, speed, lifetime)
+    fn first_fragments(bonuses: PerkBonuses) -> Vec<(f32, f32, f32)> {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<WeaponRegistry>()
+            .init_resource::<AimAssistSettings>()
+            .init_resource::<RunAssists>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<FireWeaponEvent>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(
+                Update,
+                (
+                    fire_weapon_system,
+                    spawn_projectiles_from_fire_events,
+                    projectile_movement,
+                    rebuild_creature_grid,
+                    projectile_collision,
+                )
+                    .chain(),
+            );
+        app.world_mut().spawn((
+            Player { index: 0 },
+            Transform::default(),
+            AimDirection::from_angle(0.0),
+            Firing {
+                is_firing: true,
+                ..default()
+            },
+            EquippedWeapon::new(WeaponId::SplitterGun, Some(10)),
+            bonuses,
+            ActiveBonusEffects::default(),
+        ));
+        app.world_mut().spawn(crate::creatures::CreatureBundle::new(
+            crate::creatures::CreatureType::Giant,
+            Vec3::new(120.0, 0.0, 0.0),
+        ));
+
+        for _ in 0..120 {
+            advance(&mut app, 1.0 / 60.0);
+            let fragments: Vec<_> = app
+                .world_mut()
+                .query::<(&Projectile, &Velocity, &Lifetime, &Splitter)>()
+                .iter(app.world())
+                .filter(|(_, _, _, splitter)| splitter.splits_remaining == 1)
+                .map(|(projectile, velocity, lifetime, _)| {
+                    (projectile.damage, velocity.0.length(), lifetime.remaining)
+                })
+                .collect();
+            if !fragments.is_empty() {
+                return fragments;
+            }
+        }
+        panic!("the shot never split");
+    }
+
+    #[test]
+    fn split_fragments_carry_the_shooters_bonuses() {
+        let plain = first_fragments(PerkBonuses::default());
+        let boosted = first_fragments(PerkBonuses {
+            damage_multiplier: 2.0,
+            projectile_speed_multiplier: 1.5,
+            range_multiplier: 1.2,
+            ..PerkBonuses::default()
+        });
+        assert_eq!(plain.len(), 3);
+        assert_eq!(boosted.len(), 3);
+        for (&(damage, speed, lifetime), &(boosted_damage, boosted_speed, boosted_lifetime)) in
+            plain.iter().zip(&boosted)
+        {
+            assert!((boosted_damage - damage * 2.0).abs() < 1e-3);
+            assert!((speed - SPLIT_FRAGMENT_SPEED).abs() < 1e-2);
+            assert!((boosted_speed - SPLIT_FRAGMENT_SPEED * 1.5).abs() < 1e-2);
+            assert!((lifetime - SPLIT_FRAGMENT_LIFETIME).abs() < 1e-3);
+            assert!((boosted_lifetime - SPLIT_FRAGMENT_LIFETIME * 1.2).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn reflection_mirrors_only_the_crossed_axis() {
+        let half = Vec2::new(100.0, 50.0);
+        let mut position = Vec2::new(110.0, 20.0);
+        let mut velocity = Vec2::new(300.0, 40.0);
+        assert!(reflect_off_bounds(&mut position, &mut velocity, half));
+        assert_eq!(position, Vec2::new(90.0, 20.0));
+        assert_eq!(velocity, Vec2::new(-300.0, 40.0));
+
+        // Already heading back in: no second flip
+        let mut position = Vec2::new(105.0, 0.0);
+        let mut velocity = Vec2::new(-300.0, 0.0);
+        assert!(!reflect_off_bounds(&mut position, &mut velocity, half));
+
+        // A corner flips both
+        let mut position = Vec2::new(-101.0, -52.0);
+        let mut velocity = Vec2::new(-10.0, -10.0);
+        assert!(reflect_off_bounds(&mut position, &mut velocity, half));
+        assert_eq!(velocity, Vec2::new(10.0, 10.0));
+    }
+
+    #[test]
+    fn blade_bounces_back_to_cut_the_same_creature_again() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .insert_resource(ArenaBounds {
+                half_extents: Vec2::splat(200.0),
+            })
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(
+                Update,
+                (projectile_movement, rebuild_creature_grid, projectile_collision).chain(),
+            );
+        let creature = app
+            .world_mut()
+            .spawn(crate::creatures::CreatureBundle::new(
+                crate::creatures::CreatureType::Giant,
+                Vec3::new(100.0, 0.0, 0.0),
+            ))
+            .id();
+        let start_health = app.world().get::<CreatureHealth>(creature).unwrap().current;
+        let blade = app
+            .world_mut()
+            .spawn((
+                ProjectileBundle::new(
+                    WeaponId::BladeCannon,
+                    10.0,
+                    Entity::PLACEHOLDER,
+                    Vec3::ZERO,
+                    Vec2::X,
+                    600.0,
+                    5.0,
+                    Color::WHITE,
+                    4.0,
+                )
+                .with_pierce(5),
+                Ricochet::new(3, 0.5),
+            ))
+            .id();
+
+        // Out through the creature, off the wall at x = 200 and back through it
+        for _ in 0..30 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+        let health = app.world().get::<CreatureHealth>(creature).unwrap().current;
+        assert!((start_health - health - 15.0).abs() < 1e-3, "took {}", start_health - health);
+        assert_eq!(app.world().get::<Ricochet>(blade).unwrap().bounces_remaining, 2);
+        assert!(app.world().get::<Velocity>(blade).unwrap().0.x < 0.0);
+    }
+
+    #[test]
+    fn explosive_detonates_when_its_lifetime_runs_out() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<PlayerDamageEvent>()
+            .add_systems(
+                Update,
+                (
+                    projectile_movement,
+                    rebuild_creature_grid,
+                    projectile_collision,
+                    projectile_lifetime,
+                    detonate_explosives,
+                    apply_explosion_damage,
+                    cleanup_projectiles,
+                )
+                    .chain(),
+            );
+        // Off to the side of the flight path, never touched directly
+        let creature = app
+            .world_mut()
+            .spawn(crate::creatures::CreatureBundle::new(
+                crate::creatures::CreatureType::Zombie,
+                Vec3::new(50.0, 40.0, 0.0),
+            ))
+            .id();
+        let start_health = app.world().get::<CreatureHealth>(creature).unwrap().current;
+        let grenade = app
+            .world_mut()
+            .spawn((
+                ProjectileBundle::new(
+                    WeaponId::GrenadeLauncher,
+                    30.0,
+                    Entity::PLACEHOLDER,
+                    Vec3::ZERO,
+                    Vec2::X,
+                    100.0,
+                    0.5,
+                    Color::WHITE,
+                    6.0,
+                ),
+                Explosive {
+                    radius: 80.0,
+                    damage: 30.0,
+                    self_damage: false,
+                },
+            ))
+            .id();
+
+        for _ in 0..40 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+
+        // The spent grenade went back to the pool
+        assert!(app.world().get::<Projectile>(grenade).is_none());
+        assert!(app.world().get::<Pooled>(grenade).is_some());
+        // One blast with falloff, not one per frame
+        let taken = start_health - app.world().get::<CreatureHealth>(creature).unwrap().current;
+        assert!(taken > 10.0 && taken < 30.0, "took {}", taken);
+    }
+
+    #[test]
+    fn grenade_lands_on_the_cursor_and_passes_over_nearer_creatures() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<WeaponRegistry>()
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .insert_resource(AimAssistSettings {
+                strength_percent: 0,
+                sticky_target: false,
+            })
+            .init_resource::<RunAssists>()
+            .add_event::<FireWeaponEvent>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<PlayerDamageEvent>()
+            .add_systems(
+                Update,
+                (
+                    fire_weapon_system,
+                    spawn_projectiles_from_fire_events,
+                    projectile_movement,
+                    update_grenade_arcs,
+                    rebuild_creature_grid,
+                    projectile_collision,
+                    projectile_lifetime,
+                    detonate_explosives,
+                    apply_explosion_damage,
+                    cleanup_projectiles,
+                )
+                    .chain(),
+            );
+        let target = Vec2::new(300.0, 0.0);
+        let mut aim = AimDirection::from_direction(Vec2::X);
+        aim.target = Some(target);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                aim,
+                Firing {
+                    is_firing: true,
+                    ..default()
+                },
+                EquippedWeapon::new(WeaponId::GrenadeLauncher, Some(40)),
+                PerkBonuses::default(),
+                ActiveBonusEffects::default(),
+            ))
+            .id();
+        let spawn_zombie = |app: &mut App, x: f32| {
+            let creature = app
+                .world_mut()
+                .spawn(crate::creatures::CreatureBundle::new(
+                    crate::creatures::CreatureType::Zombie,
+                    Vec3::new(x, 0.0, 0.0),
+                ))
+                .id();
+            let health = app.world().get::<CreatureHealth>(creature).unwrap().current;
+            (creature, health)
+        };
+        let (in_path, in_path_health) = spawn_zombie(&mut app, 60.0);
+        let (under_marker, under_marker_health) = spawn_zombie(&mut app, 300.0);
+
+        advance(&mut app, 1.0 / 60.0);
+        app.world_mut().get_mut::<Firing>(player).unwrap().is_firing = false;
+        let mut grenades = app.world_mut().query::<&GrenadeArc>();
+        let arc = grenades.single(app.world()).clone();
+        assert!(arc.target.distance(target) < 20.0, "aimed at {}", arc.target);
+
+        // Mid-flight the grenade is drawn bigger, as if higher up
+        for _ in 0..30 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+        let mut sprites = app.world_mut().query_filtered::<&Transform, With<GrenadeArc>>();
+        assert!(sprites.single(app.world()).scale.x > 1.2);
+
+        for _ in 0..60 {
+            advance(&mut app, 1.0 / 60.0);
+        }
+        let health = |app: &App, creature: Entity| app.world().get::<CreatureHealth>(creature).unwrap().current;
+        assert_eq!(health(&app, in_path), in_path_health);
+        assert!(health(&app, under_marker) < under_marker_health);
+    }
+
+    fn reload_app(bonuses: PerkBonuses) -> (App, Entity) {
+        use crate::player::systems::update_stationary_timers;
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<ArenaBounds>()
+            .init_resource::<WeaponRegistry>()
+            .add_systems(Update, (update_stationary_timers, weapon_reload_system).chain());
+        let mut weapon = EquippedWeapon::new(WeaponId::Shotgun, Some(12));
+        weapon.ammo = Some(0);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                StationaryTimer::default(),
+                Firing::default(),
+                weapon,
+                bonuses,
+            ))
+            .id();
+        (app, player)
+    }
+
+    fn advance(app: &mut App, seconds: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(seconds));
+        app.update();
+    }
+
+    #[test]
+    fn empty_clip_reloads_automatically() {
+        let (mut app, player) = reload_app(PerkBonuses::default());
+        let reload_time = WeaponRegistry::new().get(WeaponId::Shotgun).unwrap().reload_time;
+
+        app.update();
+        assert!(app.world().get::<EquippedWeapon>(player).unwrap().is_reloading());
+
+        advance(&mut app, reload_time + 0.01);
+        let weapon = app.world().get::<EquippedWeapon>(player).unwrap();
+        assert!(!weapon.is_reloading());
+        assert_eq!(weapon.ammo, Some(12));
+    }
+
+    #[test]
+    fn standing_still_speeds_up_stationary_reloader() {
+        let bonuses = PerkBonuses {
+            stationary_reload_multiplier: 3.0,
+            ..default()
+        };
+        let (mut app, player) = reload_app(bonuses);
+        let reload_time = WeaponRegistry::new().get(WeaponId::Shotgun).unwrap().reload_time;
+
+        app.update();
+        advance(&mut app, reload_time / 3.0 + 0.01);
+        assert_eq!(app.world().get::<EquippedWeapon>(player).unwrap().ammo, Some(12));
+    }
+
+    #[test]
+    fn reload_perks_stack_speed_multipliers_then_cut_flat_time() {
+        let bonuses = PerkBonuses {
+            reload_speed_multiplier: 2.0,
+            stationary_reload_multiplier: 3.0,
+            anxious_loader: true,
+            ..default()
+        };
+        let (mut app, player) = reload_app(bonuses);
+        let remaining = |app: &App| app.world().get::<EquippedWeapon>(player).unwrap().reload_timer;
+        let step = |app: &mut App, walking: bool, firing: bool| {
+            if walking {
+                app.world_mut().get_mut::<Transform>(player).unwrap().translation.x += 5.0;
+            }
+            app.world_mut().get_mut::<Firing>(player).unwrap().is_firing = firing;
+            let before = remaining(app);
+            advance(app, 0.05);
+            before - remaining(app)
+        };
+        let assert_close = |progress: f32, expected: f32| {
+            assert!((progress - expected).abs() < 1e-4, "progress {progress}, expected {expected}");
+        };
+
+        app.update();
+        assert!(remaining(&app) > 1.0);
+        // Standing still: Fastloader and StationaryReloader multiply
+        assert_close(step(&mut app, false, false), 0.05 * 2.0 * 3.0);
+        // Moving drops StationaryReloader at once
+        assert_close(step(&mut app, true, false), 0.05 * 2.0);
+        // Each fresh trigger pull cuts a flat amount on top; holding it doesn't
+        assert_close(step(&mut app, true, true), 0.05 * 2.0 + ANXIOUS_LOADER_RELOAD_CUT);
+        assert_close(step(&mut app, true, true), 0.05 * 2.0);
+        assert_close(step(&mut app, false, false), 0.05 * 2.0 * 3.0);
+        assert_close(step(&mut app, false, true), 0.05 * 2.0 * 3.0 + ANXIOUS_LOADER_RELOAD_CUT);
+    }
+
+    #[test]
+    fn fire_events_spawn_projectiles_without_a_player() {
+        let mut app = App::new();
+        app.init_resource::<WeaponRegistry>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<FireWeaponEvent>()
+            .add_systems(Update, spawn_projectiles_from_fire_events);
+        // A scripted shooter with no perks or pickups
+        let turret = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(FireWeaponEvent {
+            shooter: turret,
+            position: Vec3::ZERO,
+            direction: Vec2::Y,
+            weapon_id: WeaponId::Shotgun,
+            weapon: WeaponKey::Builtin(WeaponId::Shotgun),
+            target: None,
+        });
+        app.update();
+
+        let shotgun = WeaponRegistry::new().get(WeaponId::Shotgun).unwrap().clone();
+        let pellets: Vec<(Entity, f32)> = app
+            .world_mut()
+            .query::<&Projectile>()
+            .iter(app.world())
+            .map(|p| (p.owner, p.damage))
+            .collect();
+        assert_eq!(pellets.len(), shotgun.projectiles_per_shot as usize);
+        assert!(pellets.iter().all(|&(owner, damage)| owner == turret && damage == shotgun.damage));
+    }
+
+    /// App running only the trigger side of firing, with one player holding fire
+    fn trigger_app(weapon: WeaponId, ammo: Option<u32>) -> (App, Entity) {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<WeaponRegistry>()
+            .init_resource::<AimAssistSettings>()
+            .init_resource::<RunAssists>()
+            .add_event::<FireWeaponEvent>()
+            .add_systems(Update, fire_weapon_system);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player { index: 0 },
+                Transform::default(),
+                AimDirection::from_angle(0.0),
+                Firing {
+                    is_firing: true,
+                    ..default()
+                },
+                EquippedWeapon::new(weapon, ammo),
+                PerkBonuses::default(),
+                ActiveBonusEffects::default(),
+            ))
+            .id();
+        (app, player)
+    }
+
+    #[test]
+    fn held_fire_sends_one_event_per_cooldown() {
+        let (mut app, player) = trigger_app(WeaponId::Pistol, None);
+        let cooldown = WeaponRegistry::new().get(WeaponId::Pistol).unwrap().fire_cooldown();
+
+        let mut shots = 0;
+        for _ in 0..4 {
+            advance(&mut app, cooldown / 2.0 + 0.001);
+            let events = app.world().resource::<Events<FireWeaponEvent>>();
+            shots += events.get_reader().read(events).filter(|e| e.shooter == player).count();
+            app.world_mut().resource_mut::<Events<FireWeaponEvent>>().clear();
+        }
+        // First shot immediately, then one every full cooldown
+        assert_eq!(shots, 2);
+        assert_eq!(app.world_mut().query::<&Projectile>().iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn class_perks_only_boost_their_own_weapons() {
+        let mut app = App::new();
+        app.init_resource::<WeaponRegistry>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<FireWeaponEvent>()
+            .add_systems(Update, spawn_projectiles_from_fire_events);
+        let bonuses = PerkBonuses {
+            fire_damage_multiplier: 1.5,
+            ..default()
+        };
+        let shooter = app.world_mut().spawn(bonuses).id();
+        for id in [WeaponId::Flamethrower, WeaponId::Pistol] {
+            app.world_mut().send_event(FireWeaponEvent {
+                shooter,
+                position: Vec3::ZERO,
+                direction: Vec2::X,
+                weapon_id: id,
+                weapon: WeaponKey::Builtin(id),
+                target: None,
+            });
+        }
+        app.update();
+
+        let registry = WeaponRegistry::new();
+        let damage_of = |app: &mut App, id: WeaponId| {
+            app.world_mut()
+                .query::<&Projectile>()
+                .iter(app.world())
+                .find(|p| p.weapon_id == id)
+                .unwrap()
+                .damage
+        };
+        let flame = registry.get(WeaponId::Flamethrower).unwrap().damage;
+        let pistol = registry.get(WeaponId::Pistol).unwrap().damage;
+        assert_eq!(damage_of(&mut app, WeaponId::Flamethrower), flame * 1.5);
+        assert_eq!(damage_of(&mut app, WeaponId::Pistol), pistol);
+    }
+
+    #[test]
+    fn minigun_cooldown_shortens_while_the_trigger_is_held() {
+        let (mut app, player) = trigger_app(WeaponId::Minigun, Some(10_000));
+        let full_cooldown = WeaponRegistry::new().get(WeaponId::Minigun).unwrap().fire_cooldown();
+
+        let mut cooldowns = Vec::new();
+        for _ in 0..120 {
+            advance(&mut app, 1.0 / 60.0);
+            let events = app.world().resource::<Events<FireWeaponEvent>>();
+            if events.get_reader().read(events).next().is_some() {
+                cooldowns.push(app.world().get::<EquippedWeapon>(player).unwrap().fire_cooldown);
+            }
+            app.world_mut().resource_mut::<Events<FireWeaponEvent>>().clear();
+        }
+        assert!(cooldowns.windows(2).all(|pair| pair[1] <= pair[0]), "{:?}", cooldowns);
+        assert!(cooldowns[0] > full_cooldown * 2.0);
+        assert!((cooldowns.last().unwrap() - full_cooldown).abs() < 1e-4);
+
+        // Releasing the trigger winds it back down
+        app.world_mut().get_mut::<Firing>(player).unwrap().is_firing = false;
+        advance(&mut app, SPIN_DOWN_TIME);
+        assert_eq!(app.world().get::<EquippedWeapon>(player).unwrap().spin, 0.0);
+    }
+
+    #[test]
+    fn sustained_fire_overheats_and_heat_decays_when_released() {
+        let seconds_to_overheat = |fire_rate_multiplier: f32| {
+            let (mut app, player) = trigger_app(WeaponId::PlasmaRifle, Some(10_000));
+            app.world_mut().get_mut::<PerkBonuses>(player).unwrap().fire_rate_multiplier =
+                fire_rate_multiplier;
+            let mut elapsed = 0.0;
+            while !app.world().get::<EquippedWeapon>(player).unwrap().overheated {
+                advance(&mut app, 1.0 / 60.0);
+                elapsed += 1.0 / 60.0;
+                assert!(elapsed < 30.0, "never overheated");
+            }
+            (app, player, elapsed)
+        };
+
+        let (mut app, player, normal) = seconds_to_overheat(1.0);
+        let (_, _, fastshot) = seconds_to_overheat(1.5);
+        assert!(fastshot < normal, "fastshot {} vs {}", fastshot, normal);
+
+        // Locked out: held fire sends nothing until it cools
+        app.world_mut().resource_mut::<Events<FireWeaponEvent>>().clear();
+        advance(&mut app, 0.5);
+        let events = app.world().resource::<Events<FireWeaponEvent>>();
+        assert_eq!(events.get_reader().read(events).count(), 0);
+
+        app.world_mut().get_mut::<Firing>(player).unwrap().is_firing = false;
+        let heat = app.world().get::<EquippedWeapon>(player).unwrap().heat;
+        advance(&mut app, 1.0);
+        let weapon = app.world().get::<EquippedWeapon>(player).unwrap();
+        let cooling_rate = WeaponRegistry::new().get(WeaponId::PlasmaRifle).unwrap().cooling_rate;
+        assert!((weapon.heat - (heat - cooling_rate)).abs() < 1e-4);
+        advance(&mut app, 10.0);
+        let weapon = app.world().get::<EquippedWeapon>(player).unwrap();
+        assert_eq!(weapon.heat, 0.0);
+        assert!(!weapon.overheated);
+    }
+
+    #[test]
+    fn gauss_beam_hits_creatures_in_line_instantly_with_falloff() {
+        use crate::creatures::{CreatureBundle, CreatureType};
+
+        let mut registry = WeaponRegistry::new();
+        let gauss = registry.weapons.iter_mut().find(|w| w.id == WeaponId::GaussGun).unwrap();
+        gauss.pierce_count = 1;
+        let damage = gauss.damage;
+        let mut app = App::new();
+        app.insert_resource(registry)
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<FireWeaponEvent>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(
+                Update,
+                (rebuild_creature_grid, spawn_projectiles_from_fire_events, fire_hitscan_weapons).chain(),
+            );
+        let spawn = |app: &mut App, x: f32, y: f32| {
+            app.world_mut()
+                .spawn(CreatureBundle::new(CreatureType::Giant, Vec3::new(x, y, 0.0)))
+                .id()
+        };
+        let far = spawn(&mut app, 900.0, 0.0);
+        let near = spawn(&mut app, 300.0, 5.0);
+        let beside = spawn(&mut app, 400.0, 80.0);
+        let behind = spawn(&mut app, 1500.0, 0.0);
+        app.world_mut().send_event(FireWeaponEvent {
+            shooter: Entity::PLACEHOLDER,
+            position: Vec3::ZERO,
+            direction: Vec2::X,
+            weapon_id: WeaponId::GaussGun,
+            weapon: WeaponId::GaussGun.into(),
+            target: None,
+        });
+        app.update();
+
+        let events = app.world().resource::<Events<ProjectileHitEvent>>();
+        let hits: Vec<(Entity, f32)> = events.get_reader().read(events).map(|e| (e.target, e.damage)).collect();
+        assert_eq!(hits, vec![(near, damage), (far, damage * HITSCAN_PIERCE_FALLOFF)]);
+        for untouched in [beside, behind] {
+            let health = app.world().get::<CreatureHealth>(untouched).unwrap();
+            assert_eq!(health.current, health.max);
+        }
+        assert_eq!(app.world_mut().query::<&Projectile>().iter(app.world()).count(), 0);
+
+        // The beam is drawn to the last creature it hit
+        let effects = app.world().resource::<Events<SpawnEffectEvent>>();
+        let beams: Vec<Vec3> = effects
+            .get_reader()
+            .read(effects)
+            .filter_map(|e| match e.effect_type {
+                EffectType::Beam { to, .. } => Some(to),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(beams, vec![Vec3::new(900.0, 0.0, 0.0)]);
+    }
+
+    #[test]
+    fn gauss_beam_passes_through_phased_ghosts() {
+        use crate::creatures::CreatureBundle;
+
+        let registry = WeaponRegistry::new();
+        let damage = registry.get(WeaponId::GaussGun).unwrap().damage;
+        let mut app = App::new();
+        app.insert_resource(registry)
+            .init_resource::<CreatureGrid>()
+            .add_event::<FireWeaponEvent>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(Update, (rebuild_creature_grid, fire_hitscan_weapons).chain());
+        let ghost_at = |app: &mut App, x: f32, phased: bool| {
+            app.world_mut()
+                .spawn((
+                    CreatureBundle::new(CreatureType::Ghost, Vec3::new(x, 0.0, 0.0)),
+                    GhostPhase {
+                        phased,
+                        ..default()
+                    },
+                ))
+                .id()
+        };
+        let phased = ghost_at(&mut app, 200.0, true);
+        let solid = ghost_at(&mut app, 400.0, false);
+        app.world_mut().send_event(FireWeaponEvent {
+            shooter: Entity::PLACEHOLDER,
+            position: Vec3::ZERO,
+            direction: Vec2::X,
+            weapon_id: WeaponId::GaussGun,
+            weapon: WeaponId::GaussGun.into(),
+            target: None,
+        });
+        app.update();
+
+        let events = app.world().resource::<Events<ProjectileHitEvent>>();
+        let hits: Vec<(Entity, f32)> = events.get_reader().read(events).map(|e| (e.target, e.damage)).collect();
+        assert_eq!(hits, vec![(solid, damage)]);
+        let health = app.world().get::<CreatureHealth>(phased).unwrap();
+        assert_eq!(health.current, health.max);
+    }
+
+    #[test]
+    fn incendiary_blasts_leave_a_fire_patch_that_burns_at_the_hottest_rate() {
+        use crate::creatures::{CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<CreatureGrid>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<crate::creatures::StatusDamageEvent>()
+            .add_systems(
+                Update,
+                (rebuild_creature_grid, detonate_explosives, update_fire_patches, tick_status_effects)
+                    .chain(),
+            );
+        let owner = app.world_mut().spawn_empty().id();
+        let creature = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Giant, Vec3::new(10.0, 0.0, 0.0)))
+            .id();
+        let start_health = app.world().get::<CreatureHealth>(creature).unwrap().current;
+        let hot = Incendiary::for_weapon(WeaponId::InfernoCannon, 2.0).unwrap();
+        let plain = Incendiary::for_weapon(WeaponId::InfernoCannon, 1.0).unwrap();
+        assert_eq!(hot.dps, plain.dps * 2.0);
+        assert!(Incendiary::for_weapon(WeaponId::RocketLauncher, 1.0).is_none());
+        // Two overlapping blasts
+        for incendiary in [plain.clone(), hot.clone()] {
+            app.world_mut().spawn((
+                ProjectileBundle::new(
+                    WeaponId::InfernoCannon,
+                    0.0,
+                    owner,
+                    Vec3::ZERO,
+                    Vec2::X,
+                    0.0,
+                    1.0,
+                    Color::WHITE,
+                    4.0,
+                ),
+                Explosive {
+                    radius: 70.0,
+                    damage: 0.0,
+                    self_damage: false,
+                },
+                incendiary,
+                ProjectileDespawn,
+            ));
+        }
+        app.update();
+        assert_eq!(app.world_mut().query::<&FirePatch>().iter(app.world()).count(), 2);
+
+        // Burning is the only damage: standing in the patch must not also hurt directly
+        advance(&mut app, 0.5);
+        let health = app.world().get::<CreatureHealth>(creature).unwrap().current;
+        assert!((start_health - health - hot.dps * 0.5).abs() < 1e-3, "took {}", start_health - health);
+        let burning = &app.world().get::<StatusEffects>(creature).unwrap().entries[0];
+        assert_eq!((burning.kind, burning.dps, burning.source), (StatusKind::Burning, hot.dps, Some(owner)));
+
+        advance(&mut app, FIRE_PATCH_DURATION);
+        app.update();
+        assert_eq!(app.world_mut().query::<&FirePatch>().iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn running_blooms_spread_until_the_player_stands_still() {
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .add_systems(Update, update_spread_bloom);
+        let player = app
+            .world_mut()
+            .spawn((Player { index: 0 }, Transform::default(), MoveSpeed(200.0), SpreadBloom::default()))
+            .id();
+        app.update();
+
+        // A full run for half a second
+        for _ in 0..30 {
+            app.world_mut().get_mut::<Transform>(player).unwrap().translation.x += 200.0 / 60.0;
+            advance(&mut app, 1.0 / 60.0);
+        }
+        assert!(app.world().get::<SpreadBloom>(player).unwrap().amount > 0.99);
+
+        advance(&mut app, SPREAD_RECOVERY_TIME / 2.0);
+        let half = app.world().get::<SpreadBloom>(player).unwrap().amount;
+        assert!(half > 0.0 && half < 1.0, "recovering, {}", half);
+        advance(&mut app, SPREAD_RECOVERY_TIME / 2.0);
+        assert_eq!(app.world().get::<SpreadBloom>(player).unwrap().amount, 0.0);
+    }
+
+    #[test]
+    fn highlander_hits_kill_outright() {
+        use crate::creatures::{CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(Update, (rebuild_creature_grid, projectile_collision).chain());
+        let shooter = app
+            .world_mut()
+            .spawn(PerkBonuses {
+                instant_kill_chance: 1.0,
+                ..default()
+            })
+            .id();
+        let creature = app
+            .world_mut()
+            .spawn(CreatureBundle::new(CreatureType::Giant, Vec3::ZERO))
+            .id();
+        app.world_mut().spawn(ProjectileBundle::new(
+            WeaponId::Pistol,
+            1.0,
+            shooter,
+            Vec3::ZERO,
+            Vec2::X,
+            100.0,
+            1.0,
+            Color::WHITE,
+            4.0,
+        ));
+        app.update();
+
+        assert!(app.world().get::<CreatureHealth>(creature).unwrap().is_dead());
+        let events = app.world().resource::<Events<ProjectileHitEvent>>();
+        assert_eq!(events.len(), 1);
+        assert!(events.get_reader().read(events).all(|hit| hit.instant_kill));
+    }
+
+    #[test]
+    fn hits_shove_creatures_along_the_shot_resisted_by_mass() {
+        use crate::creatures::{CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(Update, (rebuild_creature_grid, projectile_collision).chain());
+        let targets: Vec<(CreatureType, Entity)> = [CreatureType::Zombie, CreatureType::BossSpider, CreatureType::Turret]
+            .into_iter()
+            .enumerate()
+            .map(|(i, creature_type)| {
+                let position = Vec3::new(0.0, i as f32 * 500.0, 0.0);
+                let creature = app.world_mut().spawn(CreatureBundle::new(creature_type, position)).id();
+                app.world_mut().spawn((
+                    ProjectileBundle::new(
+                        WeaponId::GaussGun,
+                        1.0,
+                        Entity::PLACEHOLDER,
+                        position,
+                        Vec2::X,
+                        100.0,
+                        1.0,
+                        Color::WHITE,
+                        4.0,
+                    ),
+                    HitKnockback(400.0),
+                ));
+                (creature_type, creature)
+            })
+            .collect();
+        app.update();
+
+        let shove = |creature: Entity| app.world().get::<Knockback>(creature).map(|k| k.velocity);
+        let (zombie, boss, turret) = (targets[0], targets[1], targets[2]);
+        assert_eq!(shove(zombie.1), Some(Vec2::new(400.0 / zombie.0.mass(), 0.0)));
+        assert_eq!(shove(boss.1), Some(Vec2::new(400.0 / boss.0.mass(), 0.0)));
+        assert_eq!(shove(turret.1), None);
+    }
+
+    /// App running the Pistol fallback with a custom weapon that cannot reload
+    fn fallback_app() -> App {
+        let mut registry = WeaponRegistry::new();
+        let mut data = registry.get(WeaponId::Uzi).unwrap().clone();
+        data.reload_time = 0.0;
+        registry.custom.push(crate::weapons::CustomWeapon {
+            id: "test:disposable".into(),
+            tier: 0,
+            data,
+        });
+        let mut app = App::new();
+        app.insert_resource(registry)
+            .add_event::<OutOfAmmoEvent>()
+            .add_systems(Update, fall_back_to_pistol);
+        app
+    }
+
+    #[test]
+    fn clip_size_perks_resize_clips_keeping_them_as_full() {
+        let mut app = App::new();
+        app.add_systems(Update, apply_clip_size_bonuses);
+        let mut shotgun = EquippedWeapon::new(WeaponId::Shotgun, Some(50));
+        shotgun.ammo = Some(25);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                PerkBonuses {
+                    clip_size_multiplier: 1.25,
+                    ..default()
+                },
+                shotgun,
+                AlternateWeaponSlot {
+                    stowed: Some(EquippedWeapon::new(WeaponId::Uzi, Some(40))),
+                },
+            ))
+            .id();
+        let clips = |app: &App| {
+            let weapon = app.world().get::<EquippedWeapon>(player).unwrap();
+            let stowed = app.world().get::<AlternateWeaponSlot>(player).unwrap().stowed.clone().unwrap();
+            (weapon.ammo, weapon.max_ammo, stowed.ammo, stowed.max_ammo)
+        };
+
+        app.update();
+        assert_eq!(clips(&app), (Some(32), Some(63), Some(50), Some(50)));
+
+        // MyFavouriteWeapon picked on top: +2, from the unmodified clip size
+        app.world_mut().get_mut::<PerkBonuses>(player).unwrap().clip_size_bonus = 2;
+        app.update();
+        assert_eq!(clips(&app), (Some(33), Some(65), Some(52), Some(52)));
+
+        // A newly equipped weapon gets the bonus too
+        *app.world_mut().get_mut::<EquippedWeapon>(player).unwrap() = EquippedWeapon::new(WeaponId::Smg, Some(30));
+        app.update();
+        assert_eq!(app.world().get::<EquippedWeapon>(player).unwrap().max_ammo, Some(40));
+    }
+
+    fn empty(weapon: WeaponKey) -> EquippedWeapon {
+        let mut equipped = EquippedWeapon::new(weapon, Some(30));
+        equipped.ammo = Some(0);
+        equipped
+    }
+
+    #[test]
+    fn weapon_that_cannot_reload_falls_back_to_the_pistol() {
+        let disposable = WeaponKey::Custom("test:disposable".into());
+        let mut app = fallback_app();
+        let dropper = app.world_mut().spawn((Player::default(), Transform::default(), empty(disposable.clone()))).id();
+        let stower = app
+            .world_mut()
+            .spawn((
+                Player::default(),
+                Transform::default(),
+                empty(disposable.clone()),
+                AlternateWeaponSlot::default(),
+            ))
+            .id();
+        // Built-in weapons reload instead
+        let reloader = app
+            .world_mut()
+            .spawn((Player::default(), Transform::default(), empty(WeaponId::Uzi.into())))
+            .id();
+        app.update();
+
+        let equipped = |app: &App, entity: Entity| app.world().get::<EquippedWeapon>(entity).unwrap().weapon.clone();
+        assert_eq!(equipped(&app, dropper), WeaponKey::Builtin(WeaponId::Pistol));
+        assert_eq!(equipped(&app, stower), WeaponKey::Builtin(WeaponId::Pistol));
+        let stowed = app.world().get::<AlternateWeaponSlot>(stower).unwrap().stowed.clone();
+        assert_eq!(stowed.map(|w| w.weapon), Some(disposable));
+        assert_eq!(equipped(&app, reloader), WeaponKey::Builtin(WeaponId::Uzi));
+
+        let events = app.world().resource::<Events<OutOfAmmoEvent>>();
+        let mut warned: Vec<Entity> = events.get_reader().read(events).map(|e| e.player_entity).collect();
+        warned.sort();
+        let mut expected = vec![dropper, stower];
+        expected.sort();
+        assert_eq!(warned, expected);
+    }
+
+    #[test]
+    fn crits_roll_on_every_hit_of_a_piercing_shot() {
+        use crate::creatures::{CreatureBundle, CreatureType};
+
+        let mut app = App::new();
+        app.insert_resource(Time::<()>::default())
+            .init_resource::<CreatureGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_event::<ProjectileHitEvent>()
+            .add_event::<SpawnEffectEvent>()
+            .add_systems(Update, (rebuild_creature_grid, projectile_collision).chain());
+        let shooter = app
+            .world_mut()
+            .spawn(PerkBonuses {
+                crit_chance: 1.0,
+                crit_multiplier: 3.0,
+                ..default()
+            })
+            .id();
+        for y in [-10.0, 0.0, 10.0] {
+            app.world_mut()
+                .spawn(CreatureBundle::new(CreatureType::Giant, Vec3::new(0.0, y, 0.0)));
+        }
+        let projectile = app
+            .world_mut()
+            .spawn(
+                ProjectileBundle::new(
+                    WeaponId::GaussGun,
+                    10.0,
+                    shooter,
+                    Vec3::ZERO,
+                    Vec2::X,
+                    0.0,
+                    1.0,
+                    Color::WHITE,
+                    4.0,
+                )
+                .with_pierce(5),
+            )
+            .id();
+        app.update();
+
+        let events = app.world().resource::<Events<ProjectileHitEvent>>();
+        let hits: Vec<(f32, bool)> = events.get_reader().read(events).map(|e| (e.damage, e.was_crit)).collect();
+        assert_eq!(hits, vec![(30.0, true); 3]);
+        // The roll never compounds into the projectile itself
+        assert_eq!(app.world().get::<Projectile>(projectile).unwrap().damage, 10.0);
+    }
+
+    /// Detonates one explosive of `weapon` 20px from a player for each entry of
+    /// `invincible_for` (seconds of invincibility) and returns their health afterwards
+    fn blast_next_to(invincible_for: &[f32], weapon: WeaponId) -> Vec<f32> {
+        use crate::player::components::{Health, Invincibility};
+        use crate::player::resources::PlayerConfig;
+        use crate::player::systems::{apply_player_damage, PlayerHitBlockedEvent, PlayerHitLandedEvent};
+
+        let mut app = App::new();
+        app.init_resource::<CreatureGrid>()
+            .init_resource::<PlayerConfig>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<PlayerDamageEvent>()
+            .add_event::<PlayerHitBlockedEvent>()
+            .add_event::<PlayerHitLandedEvent>()
+            .add_event::<crate::audio::PlaySoundEvent>()
+            .add_systems(
+                Update,
+                (detonate_explosives, apply_explosion_damage, apply_player_damage).chain(),
+            );
+        let data = WeaponRegistry::new().get(weapon).unwrap().clone();
+        let players: Vec<Entity> = invincible_for
+            .iter()
+            .enumerate()
+            .map(|(i, &invincibility)| {
+                let position = Vec3::new(i as f32 * 1000.0, 0.0, 0.0);
+                let player = app
+                    .world_mut()
+                    .spawn((
+                        Player::default(),
+                        Transform::from_translation(position),
+                        Health::new(100.0),
+                        PerkBonuses::default(),
+                        ActiveBonusEffects::default(),
+                        Invincibility::new(invincibility),
+                    ))
+                    .id();
+                app.world_mut().spawn((
+                    ProjectileBundle::new(
+                        weapon,
+                        data.damage,
+                        player,
+                        position + Vec3::new(20.0, 0.0, 0.0),
+                        Vec2::X,
+                        0.0,
+                        1.0,
+                        Color::WHITE,
+                        4.0,
+                    ),
+                    Explosive {
+                        radius: data.explosive_radius,
+                        damage: data.damage,
+                        self_damage: data.self_damage,
+                    },
+                    ProjectileDespawn,
+                ));
+                player
+            })
+            .collect();
+        app.update();
+        players
+            .iter()
+            .map(|&player| app.world().get::<Health>(player).unwrap().current)
+            .collect()
+    }
+
+    #[test]
+    fn point_blank_rockets_hurt_the_shooter() {
+        let rocket = WeaponRegistry::new().get(WeaponId::RocketLauncher).unwrap().clone();
+        assert!(rocket.self_damage);
+        let health = blast_next_to(&[0.0, 1.0], WeaponId::RocketLauncher);
+        let falloff = 1.0 - 20.0 / rocket.explosive_radius;
+        let expected = 100.0 - rocket.damage * falloff * PLAYER_BLAST_DAMAGE_SCALE;
+        assert!((health[0] - expected).abs() < 1e-3, "health {}", health[0]);
+        // Invincibility still blocks the blast
+        assert_eq!(health[1], 100.0);
+    }
+
+    #[test]
+    fn creature_blasts_hurt_players_at_full_strength() {
+        let mut app = App::new();
+        app.init_resource::<CreatureGrid>()
+            .add_event::<ExplosionEvent>()
+            .add_event::<PlayerDamageEvent>()
+            .add_systems(Update, apply_explosion_damage);
+        app.world_mut().spawn((Player::default(), Transform::default()));
+        let exploder = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(ExplosionEvent {
+            position: Vec3::new(50.0, 0.0, 0.0),
+            radius: 100.0,
+            damage: 40.0,
+            spared: Vec::new(),
+            owner: exploder,
+            self_damage: true,
+        });
+        app.update();
+
+        let damage: Vec<f32> = app
+            .world_mut()
+            .resource_mut::<Events<PlayerDamageEvent>>()
+            .drain()
+            .map(|e| e.damage)
+            .collect();
+        assert_eq!(damage, vec![20.0]);
+    }
 }
@@ -165,7 +165,6 @@ mod collision_tests {
     }
 }
 
-#[cfg(feature = "proptest")]
 mod property_tests {
     use proptest::prelude::*;
 